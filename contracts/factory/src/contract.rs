@@ -3,17 +3,19 @@ use std::collections::HashSet;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, from_binary, to_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Reply,
-    ReplyOn, Response, StdError, StdResult, SubMsg, SubMsgResponse, SubMsgResult, WasmMsg,
+    attr, from_binary, to_binary, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Empty, Env,
+    MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, SubMsg, SubMsgResponse,
+    SubMsgResult, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
-use cw_utils::parse_instantiate_response_data;
+use cw_utils::{must_pay, parse_instantiate_response_data};
 
 use astroport::asset::{addr_opt_validate, AssetInfo, PairInfo};
 use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
 use astroport::factory::{
-    Config, ConfigResponse, ExecuteMsg, FeeInfoResponse, InstantiateMsg, MigrateMsg, PairConfig,
-    PairType, PairsResponse, QueryMsg,
+    Config, ConfigResponse, ExecuteMsg, FeeInfoResponse, InstantiateMsg, MigrateMsg,
+    PairCodeIdResponse, PairConfig, PairFeeOverride, PairType, PairsMigrationState, PairsResponse,
+    PauseStatus, PauseTarget, QueryMsg,
 };
 use astroport::generator::ExecuteMsg::DeactivatePool;
 use astroport::pair::InstantiateMsg as PairInstantiateMsg;
@@ -22,10 +24,11 @@ use itertools::Itertools;
 use crate::error::ContractError;
 use crate::migration;
 use crate::migration::{migrate_configs, migrate_pair_configs};
-use crate::querier::query_pair_info;
+use crate::querier::{query_contract_info, query_pair_info};
 use crate::state::{
-    check_asset_infos, pair_key, read_pairs, TmpPairInfo, CONFIG, OWNERSHIP_PROPOSAL, PAIRS,
-    PAIR_CONFIGS, TMP_PAIR_INFO,
+    check_asset_infos, pair_key, read_pairs, read_pairs_after_key, read_pairs_by_creator,
+    TmpPairInfo, CONFIG, OWNERSHIP_PROPOSAL, PAIRS, PAIRS_BY_CREATOR, PAIRS_MIGRATION,
+    PAIR_CONFIGS, PAIR_CREATORS, PAIR_FEE_OVERRIDES, PAIR_PAUSES, PAIR_TYPE_PAUSES, TMP_PAIR_INFO,
 };
 
 /// Contract name that is used for migration.
@@ -54,6 +57,7 @@ pub fn instantiate(
         generator_address: None,
         whitelist_code_id: msg.whitelist_code_id,
         coin_registry_address: deps.api.addr_validate(&msg.coin_registry_address)?,
+        guardian: None,
     };
 
     config.generator_address = addr_opt_validate(deps.api, &msg.generator_address)?;
@@ -75,6 +79,7 @@ pub fn instantiate(
         if !pc.valid_fee_bps() {
             return Err(ContractError::PairConfigInvalidFeeBps {});
         }
+        validate_pair_creation_fee(&config, pc)?;
         PAIR_CONFIGS.save(deps.storage, pc.pair_type.to_string(), pc)?;
     }
     CONFIG.save(deps.storage, &config)?;
@@ -122,6 +127,19 @@ pub struct UpdateConfig {
 /// * **ExecuteMsg::DropOwnershipProposal {}** Removes a request to change contract ownership.
 ///
 /// * **ExecuteMsg::ClaimOwnership {}** Claims contract ownership.
+///
+/// * **ExecuteMsg::UpdateGuardian { guardian }** Sets or removes the address allowed to pause pairs.
+///
+/// * **ExecuteMsg::UpdatePauseStatus { target, status }** Pauses or unpauses swaps and liquidity
+/// provision for a pair type or a single pair.
+///
+/// * **ExecuteMsg::MigratePairs { pair_type, code_id, msg, limit }** Migrates a batch of registered
+/// pairs of a specific type to a new code id.
+///
+/// * **ExecuteMsg::CancelPairsMigration {}** Drops the progress of the current pairs migration.
+///
+/// * **ExecuteMsg::UpdatePairFeeOverride { pair_addr, fee_override }** Sets or removes the fees of
+/// a single pair which override its pair type fees.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -152,7 +170,7 @@ pub fn execute(
             pair_type,
             asset_infos,
             init_params,
-        } => execute_create_pair(deps, env, pair_type, asset_infos, init_params),
+        } => execute_create_pair(deps, env, info, pair_type, asset_infos, init_params),
         ExecuteMsg::Deregister { asset_infos } => deregister(deps, info, asset_infos),
         ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
             let config = CONFIG.load(deps.storage)?;
@@ -185,6 +203,21 @@ pub fn execute(
             })
             .map_err(Into::into)
         }
+        ExecuteMsg::UpdateGuardian { guardian } => update_guardian(deps, info, guardian),
+        ExecuteMsg::UpdatePauseStatus { target, status } => {
+            update_pause_status(deps, info, target, status)
+        }
+        ExecuteMsg::MigratePairs {
+            pair_type,
+            code_id,
+            msg,
+            limit,
+        } => execute_migrate_pairs(deps, env, info, pair_type, code_id, msg, limit),
+        ExecuteMsg::CancelPairsMigration {} => execute_cancel_pairs_migration(deps, info),
+        ExecuteMsg::UpdatePairFeeOverride {
+            pair_addr,
+            fee_override,
+        } => execute_update_pair_fee_override(deps, info, pair_addr, fee_override),
     }
 }
 
@@ -255,6 +288,7 @@ pub fn execute_update_pair_config(
     if !pair_config.valid_fee_bps() {
         return Err(ContractError::PairConfigInvalidFeeBps {});
     }
+    validate_pair_creation_fee(&config, &pair_config)?;
 
     PAIR_CONFIGS.save(
         deps.storage,
@@ -265,6 +299,292 @@ pub fn execute_update_pair_config(
     Ok(Response::new().add_attribute("action", "update_pair_config"))
 }
 
+/// Sets or removes the fees of a single pair which override its pair type fees.
+///
+/// * **pair_addr** is the pair contract address.
+///
+/// * **fee_override** is the new fees of the pair. If not set, the override is removed.
+///
+/// ## Executor
+/// Only the owner can execute this.
+pub fn execute_update_pair_fee_override(
+    deps: DepsMut,
+    info: MessageInfo,
+    pair_addr: String,
+    fee_override: Option<PairFeeOverride>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pair_addr = deps.api.addr_validate(&pair_addr)?;
+
+    let mut attributes = vec![
+        attr("action", "update_pair_fee_override"),
+        attr("pair_contract_addr", &pair_addr),
+    ];
+
+    if let Some(fee_override) = fee_override {
+        if !fee_override.valid_fee_bps() {
+            return Err(ContractError::PairFeeOverrideInvalidFeeBps {});
+        }
+
+        // Overrides can only be set for registered pairs
+        let pair_info = query_pair_info(&deps.querier, &pair_addr)?;
+        if PAIRS.may_load(deps.storage, &pair_key(&pair_info.asset_infos))?
+            != Some(pair_addr.clone())
+        {
+            return Err(ContractError::PairNotRegistered {});
+        }
+
+        PAIR_FEE_OVERRIDES.save(deps.storage, &pair_addr, &fee_override)?;
+        attributes.extend([
+            attr("total_fee_bps", fee_override.total_fee_bps.to_string()),
+            attr("maker_fee_bps", fee_override.maker_fee_bps.to_string()),
+        ]);
+    } else {
+        PAIR_FEE_OVERRIDES.remove(deps.storage, &pair_addr);
+        attributes.push(attr("fee_override", "removed"));
+    }
+
+    Ok(Response::new().add_attributes(attributes))
+}
+
+/// Checks that the pair creation fee (if any) is well-formed and can be forwarded to the `fee_address`.
+fn validate_pair_creation_fee(
+    config: &Config,
+    pair_config: &PairConfig,
+) -> Result<(), ContractError> {
+    if !pair_config.valid_pair_creation_fee() {
+        return Err(ContractError::PairConfigInvalidCreationFee {});
+    }
+    if pair_config.pair_creation_fee.is_some() && config.fee_address.is_none() {
+        return Err(ContractError::FeeAddressNotSet {});
+    }
+
+    Ok(())
+}
+
+/// Sets or removes the address allowed to pause pairs.
+///
+/// * **guardian** is the new guardian address. If not set, the guardian is removed.
+///
+/// ## Executor
+/// Only the owner can execute this.
+pub fn update_guardian(
+    deps: DepsMut,
+    info: MessageInfo,
+    guardian: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // Permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    config.guardian = addr_opt_validate(deps.api, &guardian)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_guardian"),
+        attr("guardian", guardian.unwrap_or_else(|| "none".to_string())),
+    ]))
+}
+
+/// Pauses or unpauses swaps and liquidity provision for a pair type or a single pair.
+/// Withdrawals are never paused.
+///
+/// * **target** is the pair type or the pair to update.
+///
+/// * **status** is the new pause status.
+///
+/// ## Executor
+/// Only the owner or the guardian can execute this.
+pub fn update_pause_status(
+    deps: DepsMut,
+    info: MessageInfo,
+    target: PauseTarget,
+    status: PauseStatus,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Permission check
+    if info.sender != config.owner && Some(&info.sender) != config.guardian.as_ref() {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let is_paused = status.swaps_paused || status.provide_paused;
+    let target_attr = match target {
+        PauseTarget::PairType(pair_type) => {
+            let key = pair_type.to_string();
+            if !PAIR_CONFIGS.has(deps.storage, key.clone()) {
+                return Err(ContractError::PairConfigNotFound {});
+            }
+            if is_paused {
+                PAIR_TYPE_PAUSES.save(deps.storage, key.clone(), &status)?;
+            } else {
+                PAIR_TYPE_PAUSES.remove(deps.storage, key.clone());
+            }
+            key
+        }
+        PauseTarget::Pair(pair_addr) => {
+            let pair_addr = deps.api.addr_validate(&pair_addr)?;
+            if is_paused {
+                PAIR_PAUSES.save(deps.storage, &pair_addr, &status)?;
+            } else {
+                PAIR_PAUSES.remove(deps.storage, &pair_addr);
+            }
+            pair_addr.to_string()
+        }
+    };
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_pause_status"),
+        attr("target", target_attr),
+        attr("swaps_paused", status.swaps_paused.to_string()),
+        attr("provide_paused", status.provide_paused.to_string()),
+    ]))
+}
+
+/// Migrates a batch of registered pairs of `pair_type` to `code_id`. The progress is saved so the
+/// next call with the same pair type and code id continues from the last processed pair.
+/// The new code id is kept in the migration state and written to the pair config only once all
+/// pairs are processed. Pairs created in the meantime are instantiated with the new code.
+/// Pairs which already run `code_id` are ignored and pairs which the factory isn't the admin of
+/// are skipped.
+///
+/// * **pair_type** is the pair type to migrate.
+///
+/// * **code_id** is the code id to migrate the pairs to.
+///
+/// * **msg** is the message passed to the pairs' migrate entry point.
+///
+/// * **limit** is the maximum number of registered pairs to process in this batch.
+///
+/// ## Executor
+/// Only the owner can execute this.
+pub fn execute_migrate_pairs(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pair_type: PairType,
+    code_id: u64,
+    msg: Binary,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut state = match PAIRS_MIGRATION.may_load(deps.storage)? {
+        Some(state) if state.pair_type == pair_type && state.code_id == code_id => state,
+        Some(_) => return Err(ContractError::PairsMigrationInProgress {}),
+        None => {
+            if !PAIR_CONFIGS.has(deps.storage, pair_type.to_string()) {
+                return Err(ContractError::PairConfigNotFound {});
+            }
+
+            PairsMigrationState {
+                pair_type,
+                code_id,
+                last_pair_key: None,
+                migrated: 0,
+                skipped: 0,
+            }
+        }
+    };
+
+    let pairs = read_pairs_after_key(deps.storage, state.last_pair_key.as_deref(), limit)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut skipped_pairs = vec![];
+    for (_, pair_addr) in &pairs {
+        let pair_info = query_pair_info(&deps.querier, pair_addr)?;
+        if pair_info.pair_type != state.pair_type {
+            continue;
+        }
+
+        let contract_info = query_contract_info(&deps.querier, pair_addr)?;
+        if contract_info.code_id == code_id {
+            continue;
+        }
+        if contract_info.admin.as_deref() != Some(env.contract.address.as_str()) {
+            skipped_pairs.push(pair_addr.to_string());
+            continue;
+        }
+
+        messages.push(
+            WasmMsg::Migrate {
+                contract_addr: pair_addr.to_string(),
+                new_code_id: code_id,
+                msg: msg.clone(),
+            }
+            .into(),
+        );
+    }
+
+    state.migrated += messages.len() as u32;
+    state.skipped += skipped_pairs.len() as u32;
+    if let Some((last_key, _)) = pairs.last() {
+        state.last_pair_key = Some(last_key.clone().into());
+    }
+
+    let completed =
+        read_pairs_after_key(deps.storage, state.last_pair_key.as_deref(), Some(1))?.is_empty();
+    if completed {
+        PAIR_CONFIGS.update(deps.storage, state.pair_type.to_string(), |pair_config| {
+            let mut pair_config = pair_config.ok_or(ContractError::PairConfigNotFound {})?;
+            pair_config.code_id = code_id;
+            Ok::<_, ContractError>(pair_config)
+        })?;
+        PAIRS_MIGRATION.remove(deps.storage);
+    } else {
+        PAIRS_MIGRATION.save(deps.storage, &state)?;
+    }
+
+    let mut attributes = vec![
+        attr("action", "migrate_pairs"),
+        attr("pair_type", state.pair_type.to_string()),
+        attr("code_id", code_id.to_string()),
+        attr("migrated_pairs", messages.len().to_string()),
+        attr("completed", completed.to_string()),
+    ];
+    if !skipped_pairs.is_empty() {
+        attributes.push(attr("skipped_pairs", skipped_pairs.join(",")));
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attributes(attributes))
+}
+
+/// Drops the progress of the current pairs migration. The pair config keeps its previous code id.
+///
+/// ## Executor
+/// Only the owner can execute this.
+pub fn execute_cancel_pairs_migration(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    PAIRS_MIGRATION.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "cancel_pairs_migration"))
+}
+
 /// Creates a new pair of `pair_type` with the assets specified in `asset_infos`.
 ///
 /// * **pair_type** is the pair type of the newly created pair.
@@ -272,9 +592,12 @@ pub fn execute_update_pair_config(
 /// * **asset_infos** is a vector with assets for which we create a pair.
 ///
 /// * **init_params** These are packed params used for custom pair types that need extra data to be instantiated.
+///
+/// If the pair type has a creation fee, it must be attached to the message and is forwarded to the `fee_address`.
 pub fn execute_create_pair(
     deps: DepsMut,
     env: Env,
+    info: MessageInfo,
     pair_type: PairType,
     asset_infos: Vec<AssetInfo>,
     init_params: Option<Binary>,
@@ -297,14 +620,43 @@ pub fn execute_create_pair(
         return Err(ContractError::PairConfigDisabled {});
     }
 
+    // New pairs skip the pending migration of their pair type
+    let code_id = match PAIRS_MIGRATION.may_load(deps.storage)? {
+        Some(state) if state.pair_type == pair_type => state.code_id,
+        _ => pair_config.code_id,
+    };
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if let Some(fee) = pair_config.pair_creation_fee {
+        if must_pay(&info, &fee.denom)? != fee.amount {
+            return Err(ContractError::PairCreationFeeRequired(fee));
+        }
+        if let Some(fee_address) = &config.fee_address {
+            messages.push(
+                BankMsg::Send {
+                    to_address: fee_address.to_string(),
+                    amount: vec![fee],
+                }
+                .into(),
+            );
+        }
+    }
+
     let pair_key = pair_key(&asset_infos);
-    TMP_PAIR_INFO.save(deps.storage, &TmpPairInfo { pair_key })?;
+    TMP_PAIR_INFO.save(
+        deps.storage,
+        &TmpPairInfo {
+            pair_key,
+            creator: info.sender.clone(),
+        },
+    )?;
 
     let sub_msg: Vec<SubMsg> = vec![SubMsg {
         id: INSTANTIATE_PAIR_REPLY_ID,
         msg: WasmMsg::Instantiate {
-            admin: Some(config.owner.to_string()),
-            code_id: pair_config.code_id,
+            // The factory is the admin so it can migrate pairs with ExecuteMsg::MigratePairs
+            admin: Some(env.contract.address.to_string()),
+            code_id,
             msg: to_binary(&PairInstantiateMsg {
                 asset_infos: asset_infos.clone(),
                 token_code_id: config.token_code_id,
@@ -321,9 +673,11 @@ pub fn execute_create_pair(
 
     Ok(Response::new()
         .add_submessages(sub_msg)
+        .add_messages(messages)
         .add_attributes(vec![
             attr("action", "create_pair"),
             attr("pair", asset_infos.iter().join("-")),
+            attr("creator", info.sender),
         ]))
 }
 
@@ -349,6 +703,8 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             let pair_contract = deps.api.addr_validate(&init_response.contract_address)?;
 
             PAIRS.save(deps.storage, &tmp.pair_key, &pair_contract)?;
+            PAIR_CREATORS.save(deps.storage, &pair_contract, &tmp.creator)?;
+            PAIRS_BY_CREATOR.save(deps.storage, (&tmp.creator, &pair_contract), &Empty {})?;
 
             Ok(Response::new().add_attributes(vec![
                 attr("action", "register"),
@@ -380,6 +736,11 @@ pub fn deregister(
 
     let pair_addr = PAIRS.load(deps.storage, &pair_key(&asset_infos))?;
     PAIRS.remove(deps.storage, &pair_key(&asset_infos));
+    if let Some(creator) = PAIR_CREATORS.may_load(deps.storage, &pair_addr)? {
+        PAIR_CREATORS.remove(deps.storage, &pair_addr);
+        PAIRS_BY_CREATOR.remove(deps.storage, (&creator, &pair_addr));
+    }
+    PAIR_FEE_OVERRIDES.remove(deps.storage, &pair_addr);
 
     let mut messages: Vec<CosmosMsg> = vec![];
     if let Some(generator) = config.generator_address {
@@ -411,9 +772,22 @@ pub fn deregister(
 /// * **QueryMsg::Pairs { start_after, limit }** Returns an array that contains items of type [`PairInfo`].
 /// This returns information about multiple Astroport pairs
 ///
-/// * **QueryMsg::FeeInfo { pair_type }** Returns the fee structure (total and maker fees) for a specific pair type.
+/// * **QueryMsg::FeeInfo { pair_type, pair_addr }** Returns the fee structure (total and maker fees) for a specific pair type.
+/// If the pair has a fee override, the override is returned instead.
 ///
 /// * **QueryMsg::BlacklistedPairTypes {}** Returns a vector that contains blacklisted pair types (pair types that cannot get ASTRO emissions).
+///
+/// * **QueryMsg::PairsByCreator { creator, start_after, limit }** Returns an array that contains items of type [`PairInfo`]
+/// for the pairs created by a specific address.
+///
+/// * **QueryMsg::PairCreator { pair_addr }** Returns the address which created a specific pair.
+///
+/// * **QueryMsg::PauseStatus { pair_type, pair_addr }** Returns the pause status of a pair type or a single pair.
+///
+/// * **QueryMsg::PairCodeIds { start_after, limit }** Returns an array that contains items of type [`PairCodeIdResponse`]
+/// with the code id and the admin of each pair.
+///
+/// * **QueryMsg::PairsMigration {}** Returns the progress of the current pairs migration.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -422,8 +796,28 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Pairs { start_after, limit } => {
             to_binary(&query_pairs(deps, start_after, limit)?)
         }
-        QueryMsg::FeeInfo { pair_type } => to_binary(&query_fee_info(deps, pair_type)?),
+        QueryMsg::FeeInfo {
+            pair_type,
+            pair_addr,
+        } => to_binary(&query_fee_info(deps, pair_type, pair_addr)?),
         QueryMsg::BlacklistedPairTypes {} => to_binary(&query_blacklisted_pair_types(deps)?),
+        QueryMsg::PairsByCreator {
+            creator,
+            start_after,
+            limit,
+        } => to_binary(&query_pairs_by_creator(deps, creator, start_after, limit)?),
+        QueryMsg::PairCreator { pair_addr } => {
+            let pair_addr = deps.api.addr_validate(&pair_addr)?;
+            to_binary(&PAIR_CREATORS.may_load(deps.storage, &pair_addr)?)
+        }
+        QueryMsg::PauseStatus {
+            pair_type,
+            pair_addr,
+        } => to_binary(&query_pause_status(deps, pair_type, pair_addr)?),
+        QueryMsg::PairCodeIds { start_after, limit } => {
+            to_binary(&query_pair_code_ids(deps, start_after, limit)?)
+        }
+        QueryMsg::PairsMigration {} => to_binary(&PAIRS_MIGRATION.may_load(deps.storage)?),
     }
 }
 
@@ -458,6 +852,7 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         generator_address: config.generator_address,
         whitelist_code_id: config.whitelist_code_id,
         coin_registry_address: config.coin_registry_address,
+        guardian: config.guardian,
     };
 
     Ok(resp)
@@ -488,16 +883,111 @@ pub fn query_pairs(
     Ok(PairsResponse { pairs })
 }
 
+/// Returns a vector with the code id and the admin of each pair. Querying starts at `start_after` and returns `limit` pairs.
+/// * **start_after** is a field which accepts a vector with items of type [`AssetInfo`].
+/// This is the pair from which we start a query.
+///
+/// * **limit** sets the number of pairs to be retrieved.
+pub fn query_pair_code_ids(
+    deps: Deps,
+    start_after: Option<Vec<AssetInfo>>,
+    limit: Option<u32>,
+) -> StdResult<Vec<PairCodeIdResponse>> {
+    read_pairs(deps, start_after, limit)?
+        .into_iter()
+        .map(|pair_addr| {
+            let pair_info = query_pair_info(&deps.querier, &pair_addr)?;
+            let contract_info = query_contract_info(&deps.querier, &pair_addr)?;
+
+            Ok(PairCodeIdResponse {
+                contract_addr: pair_addr,
+                pair_type: pair_info.pair_type,
+                code_id: contract_info.code_id,
+                admin: contract_info.admin,
+            })
+        })
+        .collect()
+}
+
+/// Returns a vector with data of the pairs created by `creator`. Querying starts at `start_after` and returns `limit` pairs.
+/// * **creator** is the address which created the pairs.
+///
+/// * **start_after** is the pair contract address from which we start a query.
+///
+/// * **limit** sets the number of pairs to be retrieved.
+pub fn query_pairs_by_creator(
+    deps: Deps,
+    creator: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<PairsResponse> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+
+    let pairs = read_pairs_by_creator(deps, &creator, start_after, limit)?
+        .iter()
+        .map(|pair_addr| query_pair_info(&deps.querier, pair_addr))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PairsResponse { pairs })
+}
+
+/// Returns the pause status of a pair type. If `pair_addr` is specified, the pair's own status is
+/// merged with the status of its type.
+pub fn query_pause_status(
+    deps: Deps,
+    pair_type: PairType,
+    pair_addr: Option<String>,
+) -> StdResult<PauseStatus> {
+    let mut status = PAIR_TYPE_PAUSES
+        .may_load(deps.storage, pair_type.to_string())?
+        .unwrap_or_default();
+
+    if let Some(pair_addr) = pair_addr {
+        let pair_addr = deps.api.addr_validate(&pair_addr)?;
+        if let Some(pair_status) = PAIR_PAUSES.may_load(deps.storage, &pair_addr)? {
+            status.swaps_paused |= pair_status.swaps_paused;
+            status.provide_paused |= pair_status.provide_paused;
+        }
+    }
+
+    Ok(status)
+}
+
 /// Returns the fee setup for a specific pair type using a [`FeeInfoResponse`] struct.
 /// * **pair_type** is a struct that represents the fee information (total and maker fees) for a specific pair type.
-pub fn query_fee_info(deps: Deps, pair_type: PairType) -> StdResult<FeeInfoResponse> {
+///
+/// * **pair_addr** is the pair contract address. If the pair has a fee override, it is returned
+/// instead of the pair type fees.
+pub fn query_fee_info(
+    deps: Deps,
+    pair_type: PairType,
+    pair_addr: Option<String>,
+) -> StdResult<FeeInfoResponse> {
     let config = CONFIG.load(deps.storage)?;
-    let pair_config = PAIR_CONFIGS.load(deps.storage, pair_type.to_string())?;
+
+    let fee_override = pair_addr
+        .map(|pair_addr| {
+            let pair_addr = deps.api.addr_validate(&pair_addr)?;
+            PAIR_FEE_OVERRIDES.may_load(deps.storage, &pair_addr)
+        })
+        .transpose()?
+        .flatten();
+
+    let (total_fee_bps, maker_fee_bps) = match fee_override {
+        Some(fee_override) => (fee_override.total_fee_bps, fee_override.maker_fee_bps),
+        None => {
+            let pair_config = PAIR_CONFIGS.load(deps.storage, pair_type.to_string())?;
+            (pair_config.total_fee_bps, pair_config.maker_fee_bps)
+        }
+    };
 
     Ok(FeeInfoResponse {
         fee_address: config.fee_address,
-        total_fee_bps: pair_config.total_fee_bps,
-        maker_fee_bps: pair_config.maker_fee_bps,
+        total_fee_bps,
+        maker_fee_bps,
     })
 }
 
@@ -512,7 +1002,7 @@ pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response
                 let msg: migration::MigrationMsg = from_binary(&msg.params)?;
                 migrate_configs(&mut deps, &msg)?;
             }
-            "1.3.0" | "1.5.1" => {}
+            "1.3.0" | "1.5.1" | "1.6.0" => {}
             "1.3.1" | "1.5.0" => {
                 migrate_pair_configs(deps.storage)?;
             }
@@ -1,4 +1,5 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Coin, StdError};
+use cw_utils::PaymentError;
 use thiserror::Error;
 
 /// This enum describes factory contract errors
@@ -7,6 +8,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -36,4 +40,22 @@ pub enum ContractError {
 
     #[error("Failed to parse or process reply message")]
     FailedToParseReply {},
+
+    #[error("Pair creation fee must have a denom and a non-zero amount")]
+    PairConfigInvalidCreationFee {},
+
+    #[error("Fee address must be set to charge a pair creation fee")]
+    FeeAddressNotSet {},
+
+    #[error("Pair creation fee of {0} is required")]
+    PairCreationFeeRequired(Coin),
+
+    #[error("Another pairs migration is in progress")]
+    PairsMigrationInProgress {},
+
+    #[error("Fee bps in pair fee override must be smaller than or equal to 10,000")]
+    PairFeeOverrideInvalidFeeBps {},
+
+    #[error("Pair is not registered in the factory")]
+    PairNotRegistered {},
 }
@@ -41,6 +41,7 @@ pub fn migrate_configs(deps: &mut DepsMut, msg: &MigrationMsg) -> StdResult<()>
         fee_address: old_cfg.fee_address,
         whitelist_code_id: old_cfg.whitelist_code_id,
         coin_registry_address: deps.api.addr_validate(msg.coin_registry_address.as_str())?,
+        guardian: None,
     };
 
     CONFIG.save(deps.storage, &new_config)
@@ -92,6 +93,7 @@ pub fn migrate_pair_configs(storage: &mut dyn Storage) -> Result<(), StdError> {
             maker_fee_bps: old_pair_configs.maker_fee_bps,
             is_disabled: old_pair_configs.is_disabled,
             is_generator_disabled: old_pair_configs.is_generator_disabled,
+            pair_creation_fee: None,
         };
 
         if key != pair_type.to_string() {
@@ -1,6 +1,6 @@
 use astroport::asset::PairInfo;
 use astroport::pair::QueryMsg;
-use cosmwasm_std::{QuerierWrapper, StdResult};
+use cosmwasm_std::{ContractInfoResponse, QuerierWrapper, StdResult, WasmQuery};
 
 /// Returns information about a pair (using the [`PairInfo`] struct).
 ///
@@ -11,3 +11,18 @@ pub fn query_pair_info(
 ) -> StdResult<PairInfo> {
     querier.query_wasm_smart(pair_contract, &QueryMsg::Pair {})
 }
+
+/// Returns the code id and the admin of a contract (using the [`ContractInfoResponse`] struct).
+///
+/// `contract_addr` is the contract for which to retrieve information.
+pub fn query_contract_info(
+    querier: &QuerierWrapper,
+    contract_addr: impl Into<String>,
+) -> StdResult<ContractInfoResponse> {
+    querier.query(
+        &WasmQuery::ContractInfo {
+            contract_addr: contract_addr.into(),
+        }
+        .into(),
+    )
+}
@@ -1,16 +1,17 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Api, Deps, Order, StdResult};
+use cosmwasm_std::{Addr, Api, Deps, Empty, Order, StdResult, Storage};
 use cw_storage_plus::{Bound, Item, Map};
 use itertools::Itertools;
 
 use crate::error::ContractError;
 use astroport::asset::AssetInfo;
 use astroport::common::OwnershipProposal;
-use astroport::factory::{Config, PairConfig};
+use astroport::factory::{Config, PairConfig, PairFeeOverride, PairsMigrationState, PauseStatus};
 /// This is an intermediate structure for storing a pair's key. It is used in a submessage response.
 #[cw_serde]
 pub struct TmpPairInfo {
     pub pair_key: Vec<u8>,
+    pub creator: Addr,
 }
 
 /// Saves a pair's key
@@ -22,6 +23,12 @@ pub const CONFIG: Item<Config> = Item::new("config");
 /// Saves created pairs (from olders to latest)
 pub const PAIRS: Map<&[u8], Addr> = Map::new("pair_info");
 
+/// Saves the address which created each pair
+pub const PAIR_CREATORS: Map<&Addr, Addr> = Map::new("pair_creators");
+
+/// Saves pairs by the address which created them
+pub const PAIRS_BY_CREATOR: Map<(&Addr, &Addr), Empty> = Map::new("pairs_by_creator");
+
 /// Calculates a pair key from the specified parameters in the `asset_infos` variable.
 ///
 /// `asset_infos` is an array with multiple items of type [`AssetInfo`].
@@ -38,6 +45,18 @@ pub fn pair_key(asset_infos: &[AssetInfo]) -> Vec<u8> {
 /// Saves pair type configurations
 pub const PAIR_CONFIGS: Map<String, PairConfig> = Map::new("pair_configs");
 
+/// Saves pause statuses by pair type. Pair contracts read this storage with raw queries
+pub const PAIR_TYPE_PAUSES: Map<String, PauseStatus> = Map::new("pair_type_pauses");
+
+/// Saves pause statuses of individual pairs. Pair contracts read this storage with raw queries
+pub const PAIR_PAUSES: Map<&Addr, PauseStatus> = Map::new("pair_pauses");
+
+/// Saves fee overrides of individual pairs
+pub const PAIR_FEE_OVERRIDES: Map<&Addr, PairFeeOverride> = Map::new("pair_fee_overrides");
+
+/// Saves the progress of the current pairs migration
+pub const PAIRS_MIGRATION: Item<PairsMigrationState> = Item::new("pairs_migration");
+
 /// ## Pagination settings
 /// The maximum limit for reading pairs from [`PAIRS`]
 const MAX_LIMIT: u32 = 30;
@@ -83,6 +102,52 @@ pub fn read_pairs(
     }
 }
 
+/// Reads pairs created by `creator` from [`PAIRS_BY_CREATOR`] according to the `start_after` and `limit` variables.
+///
+/// `start_after` is the pair contract address from which the function starts to fetch results.
+///
+/// `limit` is the number of items to retrieve.
+pub fn read_pairs_by_creator(
+    deps: Deps,
+    creator: &Addr,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    PAIRS_BY_CREATOR
+        .prefix(creator)
+        .keys(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
+/// Reads pair keys and addresses from [`PAIRS`] starting after the pair key `start_after`.
+///
+/// `limit` is the number of items to retrieve.
+pub fn read_pairs_after_key(
+    storage: &dyn Storage,
+    start_after: Option<&[u8]>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Vec<u8>, Addr)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    PAIRS
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
 /// Calculates the key of a pair from which to start reading data.
 ///
 /// `start_after` is an [`Option`] type that accepts [`AssetInfo`] elements.
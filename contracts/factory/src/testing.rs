@@ -50,6 +50,7 @@ fn proper_initialization() {
                 maker_fee_bps: 10,
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             },
             PairConfig {
                 code_id: 325u64,
@@ -58,6 +59,7 @@ fn proper_initialization() {
                 maker_fee_bps: 10,
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             },
         ],
         token_code_id: 123u64,
@@ -82,6 +84,7 @@ fn proper_initialization() {
             maker_fee_bps: 10,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id: 123u64,
         fee_address: None,
@@ -108,6 +111,7 @@ fn proper_initialization() {
                 maker_fee_bps: 10,
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             },
             PairConfig {
                 code_id: 123u64,
@@ -116,6 +120,7 @@ fn proper_initialization() {
                 maker_fee_bps: 10,
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             },
         ],
         token_code_id: 123u64,
@@ -150,6 +155,7 @@ fn update_config() {
         maker_fee_bps: 166,
         is_disabled: false,
         is_generator_disabled: false,
+        pair_creation_fee: None,
     }];
 
     let msg = InstantiateMsg {
@@ -301,6 +307,7 @@ fn update_pair_config() {
         maker_fee_bps: 10,
         is_disabled: false,
         is_generator_disabled: false,
+        pair_creation_fee: None,
     }];
 
     let msg = InstantiateMsg {
@@ -332,6 +339,7 @@ fn update_pair_config() {
         maker_fee_bps: 2,
         is_disabled: false,
         is_generator_disabled: false,
+        pair_creation_fee: None,
     };
 
     // Unauthorized err
@@ -355,6 +363,7 @@ fn update_pair_config() {
             maker_fee_bps: 10_001,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         },
     };
 
@@ -382,6 +391,7 @@ fn update_pair_config() {
         maker_fee_bps: 20,
         is_disabled: false,
         is_generator_disabled: false,
+        pair_creation_fee: None,
     };
 
     let info = mock_info(owner.clone(), &[]);
@@ -411,6 +421,7 @@ fn create_pair() {
         maker_fee_bps: 10,
         is_disabled: false,
         is_generator_disabled: false,
+        pair_creation_fee: None,
     };
 
     let msg = InstantiateMsg {
@@ -488,7 +499,7 @@ fn create_pair() {
                 .unwrap(),
                 code_id: pair_config.code_id,
                 funds: vec![],
-                admin: Some(config.unwrap().owner.to_string()),
+                admin: Some(String::from(MOCK_CONTRACT_ADDR)),
                 label: String::from("Astroport pair"),
             }
             .into(),
@@ -512,6 +523,7 @@ fn register() {
             maker_fee_bps: 10,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id: 123u64,
         fee_address: None,
@@ -77,6 +77,7 @@ impl FactoryHelper {
                 maker_fee_bps: 10,
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             }],
             token_code_id: cw20_token_code_id,
             fee_address: None,
@@ -2,11 +2,20 @@
 
 mod factory_helper;
 
-use cosmwasm_std::{attr, Addr};
+use cosmwasm_std::{
+    attr, coin, to_binary, Addr, DepsMut, Empty, Env, Event, Response, StdResult, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
 
-use astroport::asset::{AssetInfo, PairInfo};
+use astroport::asset::{Asset, AssetInfo, PairInfo};
 use astroport::factory::{
-    ConfigResponse, ExecuteMsg, FeeInfoResponse, InstantiateMsg, PairConfig, PairType, QueryMsg,
+    ConfigResponse, ExecuteMsg, FeeInfoResponse, InstantiateMsg, PairCodeIdResponse, PairConfig,
+    PairFeeOverride, PairType, PairsMigrationState, PairsResponse, PauseStatus, PauseTarget,
+    QueryMsg,
+};
+use astroport::pair::{
+    Cw20HookMsg as PairCw20HookMsg, ExecuteMsg as PairExecuteMsg, QueryMsg as PairQueryMsg,
+    SimulationResponse,
 };
 
 use crate::factory_helper::{instantiate_token, FactoryHelper};
@@ -44,6 +53,7 @@ fn proper_initialization() {
         maker_fee_bps: 10,
         is_disabled: false,
         is_generator_disabled: false,
+        pair_creation_fee: None,
     }];
 
     let msg = InstantiateMsg {
@@ -202,6 +212,7 @@ fn test_create_pair() {
                 maker_fee_bps: 40,
                 is_disabled: true,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             },
         },
         &[],
@@ -234,6 +245,7 @@ fn test_create_pair() {
             &helper.factory,
             &QueryMsg::FeeInfo {
                 pair_type: PairType::Custom("Custom".to_string()),
+                pair_addr: None,
             },
         )
         .unwrap();
@@ -248,6 +260,896 @@ fn test_create_pair() {
     assert_eq!(pair_types, vec![PairType::Custom("Custom".to_string())]);
 }
 
+#[test]
+fn pair_creation_fee_and_creator_registry() {
+    let owner = Addr::unchecked("owner");
+    let creator = Addr::unchecked("creator");
+    let mut app = App::new(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &creator, vec![coin(1_000, "uusd")])
+            .unwrap()
+    });
+    let mut helper = FactoryHelper::init(&mut app, &owner);
+
+    let token1 = instantiate_token(
+        &mut app,
+        helper.cw20_token_code_id,
+        &owner,
+        "tokenX",
+        Some(18),
+    );
+    let token2 = instantiate_token(
+        &mut app,
+        helper.cw20_token_code_id,
+        &owner,
+        "tokenY",
+        Some(18),
+    );
+
+    let config_res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&helper.factory, &QueryMsg::Config {})
+        .unwrap();
+    let pair_config = PairConfig {
+        pair_creation_fee: Some(coin(100, "uusd")),
+        ..config_res.pair_configs[0].clone()
+    };
+
+    // The fee can't be charged without a fee address
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::UpdatePairConfig {
+                config: pair_config.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Fee address must be set to charge a pair creation fee"
+    );
+
+    helper
+        .update_config(
+            &mut app,
+            &owner,
+            None,
+            Some("maker".to_string()),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::UpdatePairConfig {
+                config: PairConfig {
+                    pair_creation_fee: Some(coin(0, "uusd")),
+                    ..pair_config.clone()
+                },
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Pair creation fee must have a denom and a non-zero amount"
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::UpdatePairConfig {
+            config: pair_config,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let create_pair_msg = ExecuteMsg::CreatePair {
+        pair_type: PairType::Xyk {},
+        asset_infos: vec![
+            AssetInfo::Token {
+                contract_addr: token1.clone(),
+            },
+            AssetInfo::Token {
+                contract_addr: token2.clone(),
+            },
+        ],
+        init_params: None,
+    };
+
+    let err = helper
+        .create_pair(
+            &mut app,
+            &creator,
+            PairType::Xyk {},
+            [&token1, &token2],
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "No funds sent");
+
+    let err = app
+        .execute_contract(
+            creator.clone(),
+            helper.factory.clone(),
+            &create_pair_msg,
+            &[coin(50, "uusd")],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Pair creation fee of 100uusd is required"
+    );
+
+    app.execute_contract(
+        creator.clone(),
+        helper.factory.clone(),
+        &create_pair_msg,
+        &[coin(100, "uusd")],
+    )
+    .unwrap();
+
+    // The fee is forwarded to the fee address
+    let balance = app.wrap().query_balance("maker", "uusd").unwrap();
+    assert_eq!(balance.amount.u128(), 100);
+    let balance = app.wrap().query_balance(&helper.factory, "uusd").unwrap();
+    assert_eq!(balance.amount.u128(), 0);
+
+    let res: PairsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PairsByCreator {
+                creator: creator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.pairs.len(), 1);
+    let pair_addr = res.pairs[0].contract_addr.clone();
+
+    let pair_creator: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PairCreator {
+                pair_addr: pair_addr.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(pair_creator, Some(creator.clone()));
+
+    let res: PairsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PairsByCreator {
+                creator: owner.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.pairs.is_empty());
+
+    // Deregistered pairs are removed from the registry
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::Deregister {
+            asset_infos: vec![
+                AssetInfo::Token {
+                    contract_addr: token1,
+                },
+                AssetInfo::Token {
+                    contract_addr: token2,
+                },
+            ],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: PairsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PairsByCreator {
+                creator: creator.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.pairs.is_empty());
+    let pair_creator: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PairCreator {
+                pair_addr: pair_addr.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(pair_creator, None);
+}
+
+#[test]
+fn pause_pair_type_and_pair() {
+    let mut app = mock_app();
+    let owner = Addr::unchecked("owner");
+    let guardian = Addr::unchecked("guardian");
+    let mut helper = FactoryHelper::init(&mut app, &owner);
+
+    let token1 = instantiate_token(
+        &mut app,
+        helper.cw20_token_code_id,
+        &owner,
+        "tokenX",
+        Some(18),
+    );
+    let token2 = instantiate_token(
+        &mut app,
+        helper.cw20_token_code_id,
+        &owner,
+        "tokenY",
+        Some(18),
+    );
+
+    helper
+        .create_pair(&mut app, &owner, PairType::Xyk {}, [&token1, &token2], None)
+        .unwrap();
+    let pair_info: PairInfo = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::Pair {
+                asset_infos: vec![
+                    AssetInfo::Token {
+                        contract_addr: token1.clone(),
+                    },
+                    AssetInfo::Token {
+                        contract_addr: token2.clone(),
+                    },
+                ],
+            },
+        )
+        .unwrap();
+    let pair = pair_info.contract_addr;
+
+    for token in [&token1, &token2] {
+        app.execute_contract(
+            owner.clone(),
+            token.clone(),
+            &Cw20ExecuteMsg::Mint {
+                recipient: owner.to_string(),
+                amount: Uint128::new(1_000_000),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            token.clone(),
+            &Cw20ExecuteMsg::IncreaseAllowance {
+                spender: pair.to_string(),
+                amount: Uint128::new(1_000_000),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let pause_swaps_msg = ExecuteMsg::UpdatePauseStatus {
+        target: PauseTarget::PairType(PairType::Xyk {}),
+        status: PauseStatus {
+            swaps_paused: true,
+            provide_paused: false,
+        },
+    };
+
+    // Only the owner or the guardian can pause
+    let err = app
+        .execute_contract(
+            guardian.clone(),
+            helper.factory.clone(),
+            &pause_swaps_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    let err = app
+        .execute_contract(
+            guardian.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::UpdateGuardian {
+                guardian: Some(guardian.to_string()),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::UpdateGuardian {
+            guardian: Some(guardian.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&helper.factory, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.guardian, Some(guardian.clone()));
+
+    // Pair types without a config can't be paused
+    let err = app
+        .execute_contract(
+            guardian.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::UpdatePauseStatus {
+                target: PauseTarget::PairType(PairType::Stable {}),
+                status: PauseStatus {
+                    swaps_paused: true,
+                    provide_paused: true,
+                },
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Pair config not found");
+
+    app.execute_contract(
+        guardian.clone(),
+        helper.factory.clone(),
+        &pause_swaps_msg,
+        &[],
+    )
+    .unwrap();
+
+    let status: PauseStatus = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PauseStatus {
+                pair_type: PairType::Xyk {},
+                pair_addr: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        status,
+        PauseStatus {
+            swaps_paused: true,
+            provide_paused: false,
+        }
+    );
+
+    // Liquidity can still be provided while swaps are paused
+    let provide_msg = PairExecuteMsg::ProvideLiquidity {
+        assets: vec![
+            Asset {
+                info: AssetInfo::Token {
+                    contract_addr: token1.clone(),
+                },
+                amount: Uint128::new(100_000),
+            },
+            Asset {
+                info: AssetInfo::Token {
+                    contract_addr: token2.clone(),
+                },
+                amount: Uint128::new(100_000),
+            },
+        ],
+        slippage_tolerance: None,
+        auto_stake: None,
+        receiver: None,
+    };
+    app.execute_contract(owner.clone(), pair.clone(), &provide_msg, &[])
+        .unwrap();
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            token1.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: pair.to_string(),
+                amount: Uint128::new(1_000),
+                msg: to_binary(&PairCw20HookMsg::Swap {
+                    ask_asset_info: None,
+                    belief_price: None,
+                    max_spread: None,
+                    to: None,
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Swaps are paused for this pair"
+    );
+
+    // Pause liquidity provision for the pair only
+    app.execute_contract(
+        guardian.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::UpdatePauseStatus {
+            target: PauseTarget::Pair(pair.to_string()),
+            status: PauseStatus {
+                swaps_paused: false,
+                provide_paused: true,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let status: PauseStatus = app
+        .wrap()
+        .query_wasm_smart(&pair, &PairQueryMsg::PauseStatus {})
+        .unwrap();
+    assert_eq!(
+        status,
+        PauseStatus {
+            swaps_paused: true,
+            provide_paused: true,
+        }
+    );
+
+    let err = app
+        .execute_contract(owner.clone(), pair.clone(), &provide_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Liquidity provision is paused for this pair"
+    );
+
+    // Withdrawals are never paused
+    app.execute_contract(
+        owner.clone(),
+        pair_info.liquidity_token.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: pair.to_string(),
+            amount: Uint128::new(10_000),
+            msg: to_binary(&PairCw20HookMsg::WithdrawLiquidity { assets: vec![] }).unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Unpausing the pair type leaves the pair's own status in place
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::UpdatePauseStatus {
+            target: PauseTarget::PairType(PairType::Xyk {}),
+            status: PauseStatus::default(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let status: PauseStatus = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PauseStatus {
+                pair_type: PairType::Xyk {},
+                pair_addr: Some(pair.to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        status,
+        PauseStatus {
+            swaps_paused: false,
+            provide_paused: true,
+        }
+    );
+}
+
+fn noop_migrate(_deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+    Ok(Response::default())
+}
+
+#[test]
+fn migrate_pairs_in_batches() {
+    let mut app = mock_app();
+    let owner = Addr::unchecked("owner");
+    let mut helper = FactoryHelper::init(&mut app, &owner);
+
+    let tokens = ["tokenX", "tokenY", "tokenZ"]
+        .map(|name| instantiate_token(&mut app, helper.cw20_token_code_id, &owner, name, None));
+    for (token1, token2) in [(0, 1), (0, 2), (1, 2)] {
+        helper
+            .create_pair(
+                &mut app,
+                &owner,
+                PairType::Xyk {},
+                [&tokens[token1], &tokens[token2]],
+                None,
+            )
+            .unwrap();
+    }
+
+    let pairs: Vec<PairCodeIdResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PairCodeIds {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(pairs.len(), 3);
+    let old_code_id = pairs[0].code_id;
+    // The factory is the admin of the pairs it creates
+    for pair in &pairs {
+        assert_eq!(pair.code_id, old_code_id);
+        assert_eq!(pair.admin, Some(helper.factory.to_string()));
+    }
+
+    // Emulate a pair created before the factory became the pair admin
+    app.execute(
+        helper.factory.clone(),
+        WasmMsg::UpdateAdmin {
+            contract_addr: pairs[2].contract_addr.to_string(),
+            admin: owner.to_string(),
+        }
+        .into(),
+    )
+    .unwrap();
+
+    let new_code_id = app.store_code(Box::new(
+        ContractWrapper::new_with_empty(
+            astroport_pair::contract::execute,
+            astroport_pair::contract::instantiate,
+            astroport_pair::contract::query,
+        )
+        .with_reply_empty(astroport_pair::contract::reply)
+        .with_migrate(noop_migrate),
+    ));
+
+    let migrate_msg = ExecuteMsg::MigratePairs {
+        pair_type: PairType::Xyk {},
+        code_id: new_code_id,
+        msg: to_binary(&Empty {}).unwrap(),
+        limit: Some(2),
+    };
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked("not_owner"),
+            helper.factory.clone(),
+            &migrate_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    app.execute_contract(owner.clone(), helper.factory.clone(), &migrate_msg, &[])
+        .unwrap();
+
+    let state: Option<PairsMigrationState> = app
+        .wrap()
+        .query_wasm_smart(&helper.factory, &QueryMsg::PairsMigration {})
+        .unwrap();
+    let state = state.unwrap();
+    assert_eq!(state.code_id, new_code_id);
+    assert_eq!(state.migrated, 2);
+    assert_eq!(state.skipped, 0);
+
+    // The pair config is updated only once the migration completes
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&helper.factory, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.pair_configs[0].code_id, old_code_id);
+
+    // Another migration can't start until the current one is completed or cancelled
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::MigratePairs {
+                pair_type: PairType::Xyk {},
+                code_id: old_code_id,
+                msg: to_binary(&Empty {}).unwrap(),
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Another pairs migration is in progress"
+    );
+
+    // Cancelling keeps the previous code id in the pair config
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::CancelPairsMigration {},
+        &[],
+    )
+    .unwrap();
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&helper.factory, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.pair_configs[0].code_id, old_code_id);
+
+    // Restarting ignores already migrated pairs. The last pair is skipped as the factory isn't its admin
+    let resp = app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::MigratePairs {
+                pair_type: PairType::Xyk {},
+                code_id: new_code_id,
+                msg: to_binary(&Empty {}).unwrap(),
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(resp.has_event(
+        &Event::new("wasm")
+            .add_attribute("completed", "true")
+            .add_attribute("skipped_pairs", pairs[2].contract_addr.to_string())
+    ));
+
+    let state: Option<PairsMigrationState> = app
+        .wrap()
+        .query_wasm_smart(&helper.factory, &QueryMsg::PairsMigration {})
+        .unwrap();
+    assert_eq!(state, None);
+
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&helper.factory, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.pair_configs[0].code_id, new_code_id);
+
+    let pairs: Vec<PairCodeIdResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PairCodeIds {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        pairs.iter().map(|pair| pair.code_id).collect::<Vec<_>>(),
+        vec![new_code_id, new_code_id, old_code_id]
+    );
+}
+
+#[test]
+fn pair_fee_override() {
+    let mut app = mock_app();
+    let owner = Addr::unchecked("owner");
+    let mut helper = FactoryHelper::init(&mut app, &owner);
+
+    let token1 = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "tokenX", None);
+    let token2 = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "tokenY", None);
+    let asset_infos = vec![
+        AssetInfo::Token {
+            contract_addr: token1.clone(),
+        },
+        AssetInfo::Token {
+            contract_addr: token2.clone(),
+        },
+    ];
+
+    helper
+        .create_pair(&mut app, &owner, PairType::Xyk {}, [&token1, &token2], None)
+        .unwrap();
+    let pair_info: PairInfo = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::Pair {
+                asset_infos: asset_infos.clone(),
+            },
+        )
+        .unwrap();
+    let pair = pair_info.contract_addr;
+
+    for token in [&token1, &token2] {
+        app.execute_contract(
+            owner.clone(),
+            token.clone(),
+            &Cw20ExecuteMsg::Mint {
+                recipient: owner.to_string(),
+                amount: Uint128::new(1_000_000),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            token.clone(),
+            &Cw20ExecuteMsg::IncreaseAllowance {
+                spender: pair.to_string(),
+                amount: Uint128::new(1_000_000),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    app.execute_contract(
+        owner.clone(),
+        pair.clone(),
+        &PairExecuteMsg::ProvideLiquidity {
+            assets: vec![
+                Asset {
+                    info: asset_infos[0].clone(),
+                    amount: Uint128::new(1_000_000),
+                },
+                Asset {
+                    info: asset_infos[1].clone(),
+                    amount: Uint128::new(1_000_000),
+                },
+            ],
+            slippage_tolerance: None,
+            auto_stake: None,
+            receiver: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let simulation_msg = PairQueryMsg::Simulation {
+        offer_asset: Asset {
+            info: asset_infos[0].clone(),
+            amount: Uint128::new(10_000),
+        },
+        ask_asset_info: None,
+    };
+    let sim: SimulationResponse = app.wrap().query_wasm_smart(&pair, &simulation_msg).unwrap();
+    // 1% pair type fee
+    assert_eq!(sim.commission_amount.u128(), 99);
+
+    let override_msg = ExecuteMsg::UpdatePairFeeOverride {
+        pair_addr: pair.to_string(),
+        fee_override: Some(PairFeeOverride {
+            total_fee_bps: 10,
+            maker_fee_bps: 0,
+        }),
+    };
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked("not_owner"),
+            helper.factory.clone(),
+            &override_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::UpdatePairFeeOverride {
+                pair_addr: pair.to_string(),
+                fee_override: Some(PairFeeOverride {
+                    total_fee_bps: 10_001,
+                    maker_fee_bps: 0,
+                }),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Fee bps in pair fee override must be smaller than or equal to 10,000"
+    );
+
+    let resp = app
+        .execute_contract(owner.clone(), helper.factory.clone(), &override_msg, &[])
+        .unwrap();
+    assert!(resp.has_event(
+        &Event::new("wasm")
+            .add_attribute("action", "update_pair_fee_override")
+            .add_attribute("pair_contract_addr", pair.to_string())
+            .add_attribute("total_fee_bps", "10")
+            .add_attribute("maker_fee_bps", "0")
+    ));
+
+    let fee_info: FeeInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::FeeInfo {
+                pair_type: PairType::Xyk {},
+                pair_addr: Some(pair.to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(fee_info.total_fee_bps, 10);
+    assert_eq!(fee_info.maker_fee_bps, 0);
+
+    // The pair type fees are left untouched
+    let fee_info: FeeInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::FeeInfo {
+                pair_type: PairType::Xyk {},
+                pair_addr: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(fee_info.total_fee_bps, 100);
+    assert_eq!(fee_info.maker_fee_bps, 10);
+
+    let sim: SimulationResponse = app.wrap().query_wasm_smart(&pair, &simulation_msg).unwrap();
+    // 0.1% pair fee
+    assert_eq!(sim.commission_amount.u128(), 9);
+
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::UpdatePairFeeOverride {
+            pair_addr: pair.to_string(),
+            fee_override: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let fee_info: FeeInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::FeeInfo {
+                pair_type: PairType::Xyk {},
+                pair_addr: Some(pair.to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(fee_info.total_fee_bps, 100);
+
+    // Deregistered pairs can't get an override
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::Deregister { asset_infos },
+        &[],
+    )
+    .unwrap();
+    let err = app
+        .execute_contract(owner.clone(), helper.factory.clone(), &override_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Pair is not registered in the factory"
+    );
+}
+
 #[test]
 fn check_update_owner() {
     let mut app = mock_app();
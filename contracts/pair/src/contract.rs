@@ -6,8 +6,8 @@ use std::vec;
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     attr, from_binary, to_binary, Addr, Binary, CosmosMsg, Decimal, Decimal256, Deps, DepsMut, Env,
-    Fraction, MessageInfo, QuerierWrapper, Reply, ReplyOn, Response, StdError, StdResult, SubMsg,
-    SubMsgResponse, SubMsgResult, Uint128, Uint256, Uint64, WasmMsg,
+    Fraction, MessageInfo, QuerierWrapper, Reply, ReplyOn, Response, StdError, StdResult, Storage,
+    SubMsg, SubMsgResponse, SubMsgResult, Uint128, Uint256, Uint64, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg, MinterResponse};
@@ -16,22 +16,30 @@ use astroport::asset::{
     addr_opt_validate, check_swap_parameters, format_lp_token_name, Asset, AssetInfo, CoinsExt,
     PairInfo, MINIMUM_LIQUIDITY_AMOUNT,
 };
+use astroport::cosmwasm_ext::AbsDiff;
 use astroport::factory::PairType;
 use astroport::generator::Cw20HookMsg as GeneratorHookMsg;
+use astroport::observation::{
+    accumulate_swap_sizes, query_observation, query_observation_twap, try_dec256_into_dec,
+    PrecommitObservation, OBSERVATIONS_SIZE,
+};
 use astroport::pair::{
-    ConfigResponse, FeeShareConfig, XYKPoolConfig, XYKPoolParams, XYKPoolUpdateParams,
-    DEFAULT_SLIPPAGE, MAX_ALLOWED_SLIPPAGE, MAX_FEE_SHARE_BPS,
+    ConfigResponse, DynamicFeeConfig, FeeShareConfig, XYKPoolConfig, XYKPoolParams,
+    XYKPoolUpdateParams, DEFAULT_SLIPPAGE, MAX_ALLOWED_SLIPPAGE, MAX_FEE_SHARE_BPS,
 };
 use astroport::pair::{
     CumulativePricesResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, PoolResponse,
     QueryMsg, ReverseSimulationResponse, SimulationResponse, TWAP_PRECISION,
 };
-use astroport::querier::{query_factory_config, query_fee_info, query_supply};
+use astroport::querier::{
+    query_factory_config, query_fee_info, query_pause_status, query_supply, FeeInfo,
+};
 use astroport::{token::InstantiateMsg as TokenInstantiateMsg, U256};
+use astroport_circular_buffer::BufferManager;
 use cw_utils::parse_instantiate_response_data;
 
 use crate::error::ContractError;
-use crate::state::{Config, BALANCES, CONFIG};
+use crate::state::{Config, BALANCES, CONFIG, OBSERVATIONS};
 
 /// Contract name that is used for migration.
 const CONTRACT_NAME: &str = "astroport-pair";
@@ -39,6 +47,10 @@ const CONTRACT_NAME: &str = "astroport-pair";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// A `reply` call code ID used for sub-messages.
 const INSTANTIATE_TOKEN_REPLY_ID: u64 = 1;
+/// Swaps with smaller offer or return amounts are not observed as their prices suffer from rounding errors.
+const MIN_OBSERVED_TRADE_SIZE: Uint128 = Uint128::new(1000);
+/// The maximum dynamic fee (in bps) that can be charged.
+pub const MAX_DYNAMIC_FEE_BPS: u16 = 10_000;
 
 /// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -81,6 +93,7 @@ pub fn instantiate(
         price1_cumulative_last: Uint128::zero(),
         track_asset_balances,
         fee_share: None,
+        dynamic_fee: None,
     };
 
     if track_asset_balances {
@@ -91,6 +104,8 @@ pub fn instantiate(
 
     CONFIG.save(deps.storage, &config)?;
 
+    BufferManager::init(deps.storage, OBSERVATIONS, OBSERVATIONS_SIZE)?;
+
     let token_name = format_lp_token_name(&msg.asset_infos, &deps.querier)?;
 
     // Create the LP token contract
@@ -176,6 +191,13 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 ///             receiver,
 ///         }** Provides liquidity in the pair with the specified input parameters.
 ///
+/// * **ExecuteMsg::ProvideSingleAsset {
+///             asset,
+///             min_lp_to_receive,
+///             auto_stake,
+///             receiver,
+///         }** Provides liquidity in the pair with a single asset which is partially swapped internally.
+///
 /// * **ExecuteMsg::Swap {
 ///             offer_asset,
 ///             belief_price,
@@ -205,6 +227,20 @@ pub fn execute(
             auto_stake,
             receiver,
         ),
+        ExecuteMsg::ProvideSingleAsset {
+            asset,
+            min_lp_to_receive,
+            auto_stake,
+            receiver,
+        } => provide_single_asset(
+            deps,
+            env,
+            info,
+            asset,
+            min_lp_to_receive,
+            auto_stake,
+            receiver,
+        ),
         ExecuteMsg::Swap {
             offer_asset,
             belief_price,
@@ -292,6 +328,18 @@ pub fn receive_cw20(
             cw20_msg.amount,
             assets,
         ),
+        Cw20HookMsg::WithdrawSingleAsset {
+            ask_asset_info,
+            min_amount_out,
+        } => withdraw_single_asset(
+            deps,
+            env,
+            info,
+            Addr::unchecked(cw20_msg.sender),
+            cw20_msg.amount,
+            ask_asset_info,
+            min_amount_out,
+        ),
     }
 }
 
@@ -327,6 +375,17 @@ pub fn provide_liquidity(
     let auto_stake = auto_stake.unwrap_or(false);
 
     let mut config = CONFIG.load(deps.storage)?;
+
+    if query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?
+    .provide_paused
+    {
+        return Err(ContractError::ProvidePaused {});
+    }
     info.funds
         .assert_coins_properly_sent(&assets, &config.pair_info.asset_infos)?;
     let mut pools = config
@@ -451,6 +510,203 @@ pub fn provide_liquidity(
     ]))
 }
 
+/// Provides liquidity in the pair with a single asset. Part of the asset is swapped internally
+/// so the rest of the deposit and the swap result match the pool ratio after the swap.
+///
+/// * **asset** is the asset to provide.
+///
+/// * **min_lp_to_receive** is an optional minimum amount of LP tokens to mint.
+///
+/// * **auto_stake** is an optional parameter which determines whether the LP tokens minted after
+/// liquidity provision are automatically staked in the Generator contract on behalf of the LP token receiver.
+///
+/// * **receiver** is an optional parameter which defines the receiver of the LP tokens.
+/// If no custom receiver is specified, the pair will mint LP tokens for the function caller.
+///
+/// NOTE - the address that wants to provide a CW20 token should approve the pair contract to pull it.
+pub fn provide_single_asset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset: Asset,
+    min_lp_to_receive: Option<Uint128>,
+    auto_stake: Option<bool>,
+    receiver: Option<String>,
+) -> Result<Response, ContractError> {
+    asset.info.check(deps.api)?;
+
+    let auto_stake = auto_stake.unwrap_or(false);
+
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // Single asset provision swaps internally, so it is blocked by both pauses
+    let pause_status = query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?;
+    if pause_status.provide_paused {
+        return Err(ContractError::ProvidePaused {});
+    }
+    if pause_status.swaps_paused {
+        return Err(ContractError::SwapsPaused {});
+    }
+    info.funds
+        .assert_coins_properly_sent(&[asset.clone()], &config.pair_info.asset_infos)?;
+
+    if asset.amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let pools = config
+        .pair_info
+        .query_pools(&deps.querier, &config.pair_info.contract_addr)?
+        .into_iter()
+        .map(|mut p| {
+            // If the asset is native token, the pool balance is already increased
+            if p.info.equal(&asset.info) && asset.is_native_token() {
+                p.amount = p.amount.checked_sub(asset.amount)?;
+            }
+            Ok(p)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let (offer_ind, ask_ind) = if asset.info.equal(&pools[0].info) {
+        (0, 1)
+    } else {
+        (1, 0)
+    };
+    let offer_pool = &pools[offer_ind];
+    let ask_pool = &pools[ask_ind];
+
+    let total_share = query_supply(&deps.querier, &config.pair_info.liquidity_token)?;
+    if total_share.is_zero() || offer_pool.amount.is_zero() || ask_pool.amount.is_zero() {
+        return Err(ContractError::InvalidProvideLPsWithSingleToken {});
+    }
+
+    let mut messages = vec![];
+    if let AssetInfo::Token { contract_addr, .. } = &asset.info {
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                owner: info.sender.to_string(),
+                recipient: env.contract.address.to_string(),
+                amount: asset.amount,
+            })?,
+            funds: vec![],
+        }));
+    }
+
+    // Get fee info from the factory
+    let fee_info = query_pair_fee_info(deps.as_ref(), &env, &config)?;
+
+    let swap_amount =
+        compute_single_asset_swap(offer_pool.amount, asset.amount, fee_info.total_fee_rate)?;
+    let (return_amount, _, commission_amount) = compute_swap(
+        offer_pool.amount,
+        ask_pool.amount,
+        swap_amount,
+        fee_info.total_fee_rate,
+    )?;
+    if return_amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let (fee_share_amount, maker_fee_amount) = distribute_commission(
+        &config,
+        &fee_info,
+        &ask_pool.info,
+        commission_amount,
+        &mut messages,
+    )?;
+
+    // Pool balances after the internal swap
+    let mut pools_after_swap = pools.clone();
+    pools_after_swap[offer_ind].amount += swap_amount;
+    pools_after_swap[ask_ind].amount = pools_after_swap[ask_ind]
+        .amount
+        .checked_sub(return_amount + maker_fee_amount + fee_share_amount)?;
+
+    let mut deposits = [Uint128::zero(); 2];
+    deposits[offer_ind] = asset.amount - swap_amount;
+    deposits[ask_ind] = return_amount;
+
+    let share = std::cmp::min(
+        deposits[0].multiply_ratio(total_share, pools_after_swap[0].amount),
+        deposits[1].multiply_ratio(total_share, pools_after_swap[1].amount),
+    );
+    if share.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    if let Some(min_lp_to_receive) = min_lp_to_receive {
+        if share < min_lp_to_receive {
+            return Err(ContractError::ProvideSlippageViolation(
+                share,
+                min_lp_to_receive,
+            ));
+        }
+    }
+
+    // Mint LP tokens for the sender or for the receiver (if set)
+    let receiver = addr_opt_validate(deps.api, &receiver)?.unwrap_or_else(|| info.sender.clone());
+    messages.extend(mint_liquidity_token_message(
+        deps.querier,
+        &config,
+        &env.contract.address,
+        &receiver,
+        share,
+        auto_stake,
+    )?);
+
+    if config.track_asset_balances {
+        for (i, pool) in pools_after_swap.iter().enumerate() {
+            BALANCES.save(
+                deps.storage,
+                &pool.info,
+                &pool.amount.checked_add(deposits[i])?,
+                env.block.height,
+            )?;
+        }
+    }
+
+    // Store observation from precommit data
+    accumulate_swap_sizes(deps.storage, &env, OBSERVATIONS)?;
+
+    // The internal swap is observed the same way as a regular swap
+    if swap_amount >= MIN_OBSERVED_TRADE_SIZE && return_amount >= MIN_OBSERVED_TRADE_SIZE {
+        let (base_amount, quote_amount) = if offer_ind == 0 {
+            (swap_amount, return_amount)
+        } else {
+            (return_amount, swap_amount)
+        };
+        PrecommitObservation::save(deps.storage, &env, base_amount, quote_amount)?;
+    }
+
+    // Accumulate prices for the assets in the pool
+    if let Some((price0_cumulative_new, price1_cumulative_new, block_time)) =
+        accumulate_prices(env, &config, pools[0].amount, pools[1].amount)?
+    {
+        config.price0_cumulative_last = price0_cumulative_new;
+        config.price1_cumulative_last = price1_cumulative_new;
+        config.block_time_last = block_time;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("action", "provide_liquidity"),
+        attr("sender", info.sender),
+        attr("receiver", receiver),
+        attr("assets", asset.to_string()),
+        attr("swap_amount", swap_amount),
+        attr("return_amount", return_amount),
+        attr("commission_amount", commission_amount),
+        attr("maker_fee_amount", maker_fee_amount),
+        attr("fee_share_amount", fee_share_amount),
+        attr("share", share),
+    ]))
+}
+
 /// Mint LP tokens for a beneficiary and auto stake the tokens in the Generator contract (if auto staking is specified).
 ///
 /// * **recipient** is the LP token recipient.
@@ -579,6 +835,156 @@ pub fn withdraw_liquidity(
     ]))
 }
 
+/// Withdraw liquidity from the pool in a single asset. The share of the other asset is swapped
+/// into the requested asset against the pool balances left after the withdrawal.
+///
+/// * **sender** is the address that will receive the asset back from the pair contract.
+///
+/// * **amount** is the amount of LP tokens to burn.
+///
+/// * **ask_asset_info** is the asset to receive.
+///
+/// * **min_amount_out** is the minimum amount of the asset to receive.
+pub fn withdraw_single_asset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    sender: Addr,
+    amount: Uint128,
+    ask_asset_info: AssetInfo,
+    min_amount_out: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.pair_info.liquidity_token {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Single asset withdrawal swaps internally, so it is blocked while swaps are paused
+    if query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?
+    .swaps_paused
+    {
+        return Err(ContractError::SwapsPaused {});
+    }
+
+    let (pools, total_share) = pool_info(deps.querier, &config)?;
+
+    let (ask_ind, offer_ind) = if ask_asset_info.equal(&pools[0].info) {
+        (0, 1)
+    } else if ask_asset_info.equal(&pools[1].info) {
+        (1, 0)
+    } else {
+        return Err(ContractError::AssetMismatch {});
+    };
+
+    let refund_assets = get_share_in_assets(&pools, amount, total_share);
+
+    // Pool balances after the balanced withdrawal
+    let offer_pool = pools[offer_ind].amount - refund_assets[offer_ind].amount;
+    let ask_pool = pools[ask_ind].amount - refund_assets[ask_ind].amount;
+    let swap_amount = refund_assets[offer_ind].amount;
+
+    // Get fee info from the factory
+    let fee_info = query_pair_fee_info(deps.as_ref(), &env, &config)?;
+
+    let (return_amount, commission_amount) = if swap_amount.is_zero() {
+        (Uint128::zero(), Uint128::zero())
+    } else {
+        let (return_amount, _, commission_amount) =
+            compute_swap(offer_pool, ask_pool, swap_amount, fee_info.total_fee_rate)?;
+        (return_amount, commission_amount)
+    };
+
+    let refund_amount = refund_assets[ask_ind].amount + return_amount;
+    if refund_amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    if let Some(min_amount_out) = min_amount_out {
+        if refund_amount < min_amount_out {
+            return Err(ContractError::WithdrawSlippageViolation(
+                refund_amount,
+                min_amount_out,
+            ));
+        }
+    }
+
+    let refund_asset = Asset {
+        info: ask_asset_info,
+        amount: refund_amount,
+    };
+    let mut messages = vec![refund_asset.clone().into_msg(&sender)?];
+
+    let (fee_share_amount, maker_fee_amount) = distribute_commission(
+        &config,
+        &fee_info,
+        &refund_asset.info,
+        commission_amount,
+        &mut messages,
+    )?;
+
+    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.pair_info.liquidity_token.to_string(),
+        msg: to_binary(&Cw20ExecuteMsg::Burn { amount })?,
+        funds: vec![],
+    }));
+
+    if config.track_asset_balances {
+        // The offer asset share never leaves the pool
+        BALANCES.save(
+            deps.storage,
+            &pools[offer_ind].info,
+            &pools[offer_ind].amount,
+            env.block.height,
+        )?;
+        BALANCES.save(
+            deps.storage,
+            &pools[ask_ind].info,
+            &(pools[ask_ind].amount - refund_amount - maker_fee_amount - fee_share_amount),
+            env.block.height,
+        )?;
+    }
+
+    // Store observation from precommit data
+    accumulate_swap_sizes(deps.storage, &env, OBSERVATIONS)?;
+
+    // The internal swap is observed the same way as a regular swap
+    if swap_amount >= MIN_OBSERVED_TRADE_SIZE && return_amount >= MIN_OBSERVED_TRADE_SIZE {
+        let (base_amount, quote_amount) = if offer_ind == 0 {
+            (swap_amount, return_amount)
+        } else {
+            (return_amount, swap_amount)
+        };
+        PrecommitObservation::save(deps.storage, &env, base_amount, quote_amount)?;
+    }
+
+    // Accumulate prices for the pair assets
+    if let Some((price0_cumulative_new, price1_cumulative_new, block_time)) =
+        accumulate_prices(env, &config, pools[0].amount, pools[1].amount)?
+    {
+        config.price0_cumulative_last = price0_cumulative_new;
+        config.price1_cumulative_last = price1_cumulative_new;
+        config.block_time_last = block_time;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("action", "withdraw_liquidity"),
+        attr("sender", sender),
+        attr("withdrawn_share", amount),
+        attr("refund_assets", refund_asset.to_string()),
+        attr("swap_amount", swap_amount),
+        attr("return_amount", return_amount),
+        attr("commission_amount", commission_amount),
+        attr("maker_fee_amount", maker_fee_amount),
+        attr("fee_share_amount", fee_share_amount),
+    ]))
+}
+
 /// Returns the amount of pool assets that correspond to an amount of LP tokens.
 ///
 /// * **pools** is the array with assets in the pool.
@@ -630,6 +1036,17 @@ pub fn swap(
 
     let mut config = CONFIG.load(deps.storage)?;
 
+    if query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?
+    .swaps_paused
+    {
+        return Err(ContractError::SwapsPaused {});
+    }
+
     // If the asset balance is already increased, we should subtract the user deposit from the pool amount
     let pools = config
         .pair_info
@@ -657,11 +1074,7 @@ pub fn swap(
     }
 
     // Get fee info from the factory
-    let fee_info = query_fee_info(
-        &deps.querier,
-        &config.factory_addr,
-        config.pair_info.pair_type.clone(),
-    )?;
+    let fee_info = query_pair_fee_info(deps.as_ref(), &env, &config)?;
 
     let offer_amount = offer_asset.amount;
 
@@ -692,43 +1105,13 @@ pub fn swap(
         messages.push(return_asset.into_msg(receiver.clone())?)
     }
 
-    // If this pool is configured to share fees, calculate the amount to send
-    // to the receiver and add the transfer message
-    // The calculation works as follows: We take the share percentage first,
-    // and the remainder is then split between LPs and maker
-    let mut fees_commission_amount = commission_amount;
-    let mut fee_share_amount = Uint128::zero();
-    if let Some(fee_share) = config.fee_share.clone() {
-        // Calculate the fee share amount from the full commission amount
-        let share_fee_rate = Decimal::from_ratio(fee_share.bps, 10000u16);
-        fee_share_amount = fees_commission_amount * share_fee_rate;
-
-        if !fee_share_amount.is_zero() {
-            // Subtract the fee share amount from the commission
-            fees_commission_amount = fees_commission_amount.saturating_sub(fee_share_amount);
-
-            // Build send message for the shared amount
-            let fee_share_msg = Asset {
-                info: ask_pool.info.clone(),
-                amount: fee_share_amount,
-            }
-            .into_msg(fee_share.recipient)?;
-            messages.push(fee_share_msg);
-        }
-    }
-
-    // Compute the Maker fee
-    let mut maker_fee_amount = Uint128::zero();
-    if let Some(fee_address) = fee_info.fee_address {
-        if let Some(f) = calculate_maker_fee(
-            &ask_pool.info,
-            fees_commission_amount,
-            fee_info.maker_fee_rate,
-        ) {
-            maker_fee_amount = f.amount;
-            messages.push(f.into_msg(fee_address)?);
-        }
-    }
+    let (fee_share_amount, maker_fee_amount) = distribute_commission(
+        &config,
+        &fee_info,
+        &ask_pool.info,
+        commission_amount,
+        &mut messages,
+    )?;
 
     if config.track_asset_balances {
         BALANCES.save(
@@ -745,6 +1128,21 @@ pub fn swap(
         )?;
     }
 
+    // Store observation from precommit data
+    accumulate_swap_sizes(deps.storage, &env, OBSERVATIONS)?;
+
+    // Store time series data in precommit observation.
+    // Skipping small unsafe values which can seriously mess oracle price due to rounding errors.
+    // This data will be reflected in observations on the next action.
+    if offer_amount >= MIN_OBSERVED_TRADE_SIZE && return_amount >= MIN_OBSERVED_TRADE_SIZE {
+        let (base_amount, quote_amount) = if offer_asset.info.equal(&pools[0].info) {
+            (offer_amount, return_amount)
+        } else {
+            (return_amount, offer_amount)
+        };
+        PrecommitObservation::save(deps.storage, &env, base_amount, quote_amount)?;
+    }
+
     // Accumulate prices for the assets in the pool
     if let Some((price0_cumulative_new, price1_cumulative_new, block_time)) =
         accumulate_prices(env, &config, pools[0].amount, pools[1].amount)?
@@ -776,6 +1174,123 @@ pub fn swap(
         ]))
 }
 
+/// Sends the fee share and the Maker fee out of the swap commission. The rest of the commission
+/// is absorbed by the pool. Returns the fee share and the Maker fee amounts.
+///
+/// * **ask_asset_info** is the asset in which the commission is charged.
+///
+/// * **commission_amount** is the total amount of fees charged for a swap.
+///
+/// * **messages** is the list the transfer messages are appended to.
+fn distribute_commission(
+    config: &Config,
+    fee_info: &FeeInfo,
+    ask_asset_info: &AssetInfo,
+    commission_amount: Uint128,
+    messages: &mut Vec<CosmosMsg>,
+) -> StdResult<(Uint128, Uint128)> {
+    // If this pool is configured to share fees, calculate the amount to send
+    // to the receiver and add the transfer message
+    // The calculation works as follows: We take the share percentage first,
+    // and the remainder is then split between LPs and maker
+    let mut fees_commission_amount = commission_amount;
+    let mut fee_share_amount = Uint128::zero();
+    if let Some(fee_share) = &config.fee_share {
+        // Calculate the fee share amount from the full commission amount
+        let share_fee_rate = Decimal::from_ratio(fee_share.bps, 10000u16);
+        fee_share_amount = fees_commission_amount * share_fee_rate;
+
+        if !fee_share_amount.is_zero() {
+            // Subtract the fee share amount from the commission
+            fees_commission_amount = fees_commission_amount.saturating_sub(fee_share_amount);
+
+            // Build send message for the shared amount
+            let fee_share_msg = Asset {
+                info: ask_asset_info.clone(),
+                amount: fee_share_amount,
+            }
+            .into_msg(&fee_share.recipient)?;
+            messages.push(fee_share_msg);
+        }
+    }
+
+    // Compute the Maker fee
+    let mut maker_fee_amount = Uint128::zero();
+    if let Some(fee_address) = &fee_info.fee_address {
+        if let Some(f) = calculate_maker_fee(
+            ask_asset_info,
+            fees_commission_amount,
+            fee_info.maker_fee_rate,
+        ) {
+            maker_fee_amount = f.amount;
+            messages.push(f.into_msg(fee_address)?);
+        }
+    }
+
+    Ok((fee_share_amount, maker_fee_amount))
+}
+
+/// Returns the fee information for the pair. If the dynamic fee is enabled, the total fee rate
+/// is derived from the observed price volatility. The factory total fee rate is used as long as
+/// the observations don't cover the dynamic fee window.
+pub fn query_pair_fee_info(deps: Deps, env: &Env, config: &Config) -> StdResult<FeeInfo> {
+    let mut fee_info = query_fee_info(
+        &deps.querier,
+        &config.factory_addr,
+        config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
+    )?;
+
+    if let Some(dynamic_fee) = &config.dynamic_fee {
+        let average_price =
+            query_observation_twap(deps, env.clone(), OBSERVATIONS, dynamic_fee.window, 0);
+        let last_price = query_observation_twap(deps, env.clone(), OBSERVATIONS, 1, 0);
+
+        if let (Ok(average_price), Ok(last_price)) = (average_price, last_price) {
+            fee_info.total_fee_rate =
+                compute_dynamic_fee_rate(dynamic_fee, last_price.price, average_price.price)?;
+        }
+    }
+
+    Ok(fee_info)
+}
+
+/// Returns the dynamic fee rate. The fee grows linearly from `min_fee_bps` to `max_fee_bps`
+/// with the relative deviation of `last_price` from `average_price` and stays at `max_fee_bps`
+/// once the deviation reaches `volatility_cap`.
+///
+/// * **dynamic_fee** dynamic fee parameters.
+///
+/// * **last_price** last observed price.
+///
+/// * **average_price** time-weighted average price over the dynamic fee window.
+pub fn compute_dynamic_fee_rate(
+    dynamic_fee: &DynamicFeeConfig,
+    last_price: Decimal,
+    average_price: Decimal,
+) -> StdResult<Decimal> {
+    let min_fee_rate = Decimal::from_ratio(dynamic_fee.min_fee_bps, 10000u16);
+    let max_fee_rate = Decimal::from_ratio(dynamic_fee.max_fee_bps, 10000u16);
+
+    if average_price.is_zero() {
+        return Ok(max_fee_rate);
+    }
+
+    // deviation / volatility_cap = |last_price - average_price| / (average_price * volatility_cap)
+    let volatility_ratio = Decimal256::from_ratio(
+        last_price
+            .diff(average_price)
+            .numerator()
+            .full_mul(Decimal::one().numerator()),
+        average_price
+            .numerator()
+            .full_mul(dynamic_fee.volatility_cap.numerator()),
+    )
+    .min(Decimal256::one());
+
+    Ok(min_fee_rate + (max_fee_rate - min_fee_rate) * try_dec256_into_dec(volatility_ratio)?)
+}
+
 /// Updates the pool configuration with the specified parameters in the `params` variable.
 ///
 /// * **params** new parameter values.
@@ -854,6 +1369,43 @@ pub fn update_config(
                 .attributes
                 .push(attr("action", "disable_fee_share"));
         }
+        XYKPoolUpdateParams::EnableDynamicFee {
+            min_fee_bps,
+            max_fee_bps,
+            window,
+            volatility_cap,
+        } => {
+            if min_fee_bps > max_fee_bps
+                || max_fee_bps > MAX_DYNAMIC_FEE_BPS
+                || window == 0
+                || volatility_cap.is_zero()
+            {
+                return Err(ContractError::InvalidDynamicFeeParams {});
+            }
+
+            config.dynamic_fee = Some(DynamicFeeConfig {
+                min_fee_bps,
+                max_fee_bps,
+                window,
+                volatility_cap,
+            });
+            CONFIG.save(deps.storage, &config)?;
+
+            response.attributes.extend([
+                attr("action", "enable_dynamic_fee"),
+                attr("min_fee_bps", min_fee_bps.to_string()),
+                attr("max_fee_bps", max_fee_bps.to_string()),
+                attr("window", window.to_string()),
+                attr("volatility_cap", volatility_cap.to_string()),
+            ]);
+        }
+        XYKPoolUpdateParams::DisableDynamicFee => {
+            config.dynamic_fee = None;
+            CONFIG.save(deps.storage, &config)?;
+            response
+                .attributes
+                .push(attr("action", "disable_dynamic_fee"));
+        }
     }
 
     Ok(response)
@@ -945,8 +1497,13 @@ pub fn calculate_maker_fee(
 ///
 /// * **QueryMsg::Config {}** Returns the configuration for the pair contract using a [`ConfigResponse`] object.
 ///
+/// * **QueryMsg::ObserveTwap { start_seconds_ago, end_seconds_ago }** Returns the time-weighted average
+/// price over the specified window computed from observations using an [`OracleTwap`] object.
+///
 /// * **QueryMsg::AssetBalanceAt { asset_info, block_height }** Returns the balance of the specified asset that was in the pool
 /// just preceeding the moment of the specified block height creation.
+///
+/// * **QueryMsg::PauseStatus {}** Returns the pause status of the pair set in the factory.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -954,10 +1511,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Pool {} => to_binary(&query_pool(deps)?),
         QueryMsg::Share { amount } => to_binary(&query_share(deps, amount)?),
         QueryMsg::Simulation { offer_asset, .. } => {
-            to_binary(&query_simulation(deps, offer_asset)?)
+            to_binary(&query_simulation(deps, env, offer_asset)?)
         }
         QueryMsg::ReverseSimulation { ask_asset, .. } => {
-            to_binary(&query_reverse_simulation(deps, ask_asset)?)
+            to_binary(&query_reverse_simulation(deps, env, ask_asset)?)
         }
         QueryMsg::CumulativePrices {} => to_binary(&query_cumulative_prices(deps, env)?),
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
@@ -965,6 +1522,28 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             asset_info,
             block_height,
         } => to_binary(&query_asset_balances_at(deps, asset_info, block_height)?),
+        QueryMsg::Observe { seconds_ago } => {
+            to_binary(&query_observation(deps, env, OBSERVATIONS, seconds_ago)?)
+        }
+        QueryMsg::ObserveTwap {
+            start_seconds_ago,
+            end_seconds_ago,
+        } => to_binary(&query_observation_twap(
+            deps,
+            env,
+            OBSERVATIONS,
+            start_seconds_ago,
+            end_seconds_ago,
+        )?),
+        QueryMsg::PauseStatus {} => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&query_pause_status(
+                &deps.querier,
+                config.factory_addr,
+                &config.pair_info.pair_type,
+                &env.contract.address,
+            )?)
+        }
         _ => Err(StdError::generic_err("Query is not supported")),
     }
 }
@@ -998,7 +1577,7 @@ pub fn query_share(deps: Deps, amount: Uint128) -> StdResult<Vec<Asset>> {
 /// Returns information about a swap simulation in a [`SimulationResponse`] object.
 ///
 /// * **offer_asset** is the asset to swap as well as an amount of the said asset.
-pub fn query_simulation(deps: Deps, offer_asset: Asset) -> StdResult<SimulationResponse> {
+pub fn query_simulation(deps: Deps, env: Env, offer_asset: Asset) -> StdResult<SimulationResponse> {
     let config = CONFIG.load(deps.storage)?;
 
     let pools = config
@@ -1020,11 +1599,7 @@ pub fn query_simulation(deps: Deps, offer_asset: Asset) -> StdResult<SimulationR
     }
 
     // Get fee info from the factory contract
-    let fee_info = query_fee_info(
-        &deps.querier,
-        config.factory_addr,
-        config.pair_info.pair_type,
-    )?;
+    let fee_info = query_pair_fee_info(deps, &env, &config)?;
 
     let (return_amount, spread_amount, commission_amount) = compute_swap(
         offer_pool.amount,
@@ -1046,6 +1621,7 @@ pub fn query_simulation(deps: Deps, offer_asset: Asset) -> StdResult<SimulationR
 /// assets to receive from the swap.
 pub fn query_reverse_simulation(
     deps: Deps,
+    env: Env,
     ask_asset: Asset,
 ) -> StdResult<ReverseSimulationResponse> {
     let config = CONFIG.load(deps.storage)?;
@@ -1069,11 +1645,7 @@ pub fn query_reverse_simulation(
     }
 
     // Get fee info from factory
-    let fee_info = query_fee_info(
-        &deps.querier,
-        config.factory_addr,
-        config.pair_info.pair_type,
-    )?;
+    let fee_info = query_pair_fee_info(deps, &env, &config)?;
 
     let (offer_amount, spread_amount, commission_amount) = compute_offer_amount(
         offer_pool.amount,
@@ -1137,6 +1709,7 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         params: Some(to_binary(&XYKPoolConfig {
             track_asset_balances: config.track_asset_balances,
             fee_share: config.fee_share,
+            dynamic_fee: config.dynamic_fee,
         })?),
         owner: factory_config.owner,
         factory_addr: config.factory_addr,
@@ -1197,6 +1770,57 @@ pub fn compute_swap(
     ))
 }
 
+/// Returns the amount of offer assets to swap when providing liquidity with a single asset so that
+/// the rest of the deposit and the swap result match the pool ratio after the swap.
+/// As the commission is charged on the return amount the swap amount `s` solves
+/// `s^2 + s * (offer_pool * (2 - fee) - offer_amount * fee) - offer_amount * offer_pool = 0`.
+///
+/// * **offer_pool** total amount of offer assets in the pool.
+///
+/// * **offer_amount** total amount of offer assets provided.
+///
+/// * **commission_rate** total amount of fees charged for the swap.
+pub fn compute_single_asset_swap(
+    offer_pool: Uint128,
+    offer_amount: Uint128,
+    commission_rate: Decimal,
+) -> StdResult<Uint128> {
+    let offer_pool = U256::from(offer_pool.u128());
+    let offer_amount = U256::from(offer_amount.u128());
+    let fee = U256::from(commission_rate.numerator().u128());
+    let denominator = U256::from(commission_rate.denominator().u128());
+
+    let overflow_err = || StdError::generic_err("Overflow while computing the single asset swap");
+
+    // b = offer_pool * (2 - fee) - offer_amount * fee
+    let pool_term = offer_pool * (denominator * 2 - fee) / denominator;
+    let amount_term = offer_amount * fee / denominator;
+    let b = if pool_term >= amount_term {
+        pool_term - amount_term
+    } else {
+        amount_term - pool_term
+    };
+
+    let discriminant = b
+        .checked_mul(b)
+        .and_then(|b_squared| {
+            offer_amount
+                .checked_mul(offer_pool)
+                .and_then(|ac| ac.checked_mul(U256::from(4u8)))
+                .and_then(|four_ac| b_squared.checked_add(four_ac))
+        })
+        .ok_or_else(overflow_err)?;
+    let sqrt_discriminant = discriminant.integer_sqrt();
+
+    let swap_amount = if pool_term >= amount_term {
+        (sqrt_discriminant - b) / 2
+    } else {
+        (sqrt_discriminant + b) / 2
+    };
+
+    Ok(Uint128::new(swap_amount.min(offer_amount).as_u128()))
+}
+
 /// Returns an amount of offer assets for a specified amount of ask assets.
 ///
 /// * **offer_pool** total amount of offer assets in the pool.
@@ -1332,8 +1956,11 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
         "astroport-pair" => match contract_version.version.as_ref() {
             "1.0.0" | "1.0.1" | "1.1.0" | "1.2.0" => {
                 migration::add_asset_balances_tracking_flag(deps.storage)?;
+                BufferManager::init(deps.storage, OBSERVATIONS, OBSERVATIONS_SIZE)?;
+            }
+            "1.3.0" | "1.3.1" | "1.4.0" | "1.5.0" => {
+                BufferManager::init(deps.storage, OBSERVATIONS, OBSERVATIONS_SIZE)?;
             }
-            "1.3.0" | "1.3.1" => {}
             _ => return Err(ContractError::MigrationError {}),
         },
         _ => return Err(ContractError::MigrationError {}),
@@ -1366,7 +1993,9 @@ pub fn pool_info(querier: QuerierWrapper, config: &Config) -> StdResult<(Vec<Ass
 mod tests {
     use cosmwasm_std::{Decimal, Uint128};
 
-    use crate::contract::compute_swap;
+    use astroport::pair::DynamicFeeConfig;
+
+    use crate::contract::{compute_dynamic_fee_rate, compute_single_asset_swap, compute_swap};
 
     #[test]
     fn compute_swap_does_not_panic_on_spread_calc() {
@@ -1381,4 +2010,62 @@ mod tests {
         assert_eq!(spread_amount, Uint128::zero());
         assert_eq!(commission_amount, Uint128::zero());
     }
+
+    #[test]
+    fn compute_single_asset_swap_balances_deposit() {
+        let offer_pool = Uint128::new(1_000_000_000000);
+        let ask_pool = Uint128::new(2_000_000_000000);
+        let offer_amount = Uint128::new(100_000_000000);
+        let commission_rate = Decimal::permille(3);
+
+        let swap_amount =
+            compute_single_asset_swap(offer_pool, offer_amount, commission_rate).unwrap();
+        let (return_amount, _, _) =
+            compute_swap(offer_pool, ask_pool, swap_amount, commission_rate).unwrap();
+
+        // The rest of the deposit and the swap result match the pool ratio after the swap
+        let deposit_ratio = Decimal::from_ratio(offer_amount - swap_amount, return_amount);
+        let pool_ratio = Decimal::from_ratio(offer_pool + swap_amount, ask_pool - return_amount);
+        let diff = if deposit_ratio > pool_ratio {
+            deposit_ratio - pool_ratio
+        } else {
+            pool_ratio - deposit_ratio
+        };
+        assert!(diff < Decimal::from_ratio(1u8, 1_000_000u32));
+
+        // Without fees the swap amount is sqrt(offer_pool * (offer_pool + offer_amount)) - offer_pool
+        let swap_amount =
+            compute_single_asset_swap(offer_pool, offer_amount, Decimal::zero()).unwrap();
+        assert_eq!(swap_amount, Uint128::new(48_808_848170));
+    }
+
+    #[test]
+    fn compute_dynamic_fee_rate_scales_with_volatility() {
+        let dynamic_fee = DynamicFeeConfig {
+            min_fee_bps: 10,
+            max_fee_bps: 110,
+            window: 600,
+            volatility_cap: Decimal::percent(2),
+        };
+
+        let fee_rate =
+            compute_dynamic_fee_rate(&dynamic_fee, Decimal::one(), Decimal::one()).unwrap();
+        assert_eq!(fee_rate, Decimal::from_ratio(10u8, 10000u16));
+
+        let fee_rate =
+            compute_dynamic_fee_rate(&dynamic_fee, Decimal::percent(101), Decimal::one()).unwrap();
+        assert_eq!(fee_rate, Decimal::from_ratio(60u8, 10000u16));
+
+        let fee_rate =
+            compute_dynamic_fee_rate(&dynamic_fee, Decimal::percent(99), Decimal::one()).unwrap();
+        assert_eq!(fee_rate, Decimal::from_ratio(60u8, 10000u16));
+
+        let fee_rate =
+            compute_dynamic_fee_rate(&dynamic_fee, Decimal::percent(150), Decimal::one()).unwrap();
+        assert_eq!(fee_rate, Decimal::from_ratio(110u8, 10000u16));
+
+        let fee_rate =
+            compute_dynamic_fee_rate(&dynamic_fee, Decimal::one(), Decimal::zero()).unwrap();
+        assert_eq!(fee_rate, Decimal::from_ratio(110u8, 10000u16));
+    }
 }
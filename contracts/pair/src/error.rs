@@ -1,13 +1,19 @@
 use astroport::{asset::MINIMUM_LIQUIDITY_AMOUNT, pair::MAX_FEE_SHARE_BPS};
-use cosmwasm_std::{OverflowError, StdError};
+use astroport_circular_buffer::error::BufferError;
+use cosmwasm_std::{OverflowError, StdError, Uint128};
 use thiserror::Error;
 
+use crate::contract::MAX_DYNAMIC_FEE_BPS;
+
 /// This enum describes pair contract errors
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    CircularBuffer(#[from] BufferError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -58,6 +64,27 @@ pub enum ContractError {
         MAX_FEE_SHARE_BPS
     )]
     FeeShareOutOfBounds {},
+
+    #[error(
+        "Dynamic fee bounds must satisfy min_fee_bps <= max_fee_bps <= {}, window and volatility_cap must be positive",
+        MAX_DYNAMIC_FEE_BPS
+    )]
+    InvalidDynamicFeeParams {},
+
+    #[error("It is not possible to provide liquidity with one token for an empty pool")]
+    InvalidProvideLPsWithSingleToken {},
+
+    #[error("Slippage is more than expected: received {0}, expected {1} LP tokens")]
+    ProvideSlippageViolation(Uint128, Uint128),
+
+    #[error("Slippage is more than expected: received {0}, expected at least {1}")]
+    WithdrawSlippageViolation(Uint128, Uint128),
+
+    #[error("Swaps are paused for this pair")]
+    SwapsPaused {},
+
+    #[error("Liquidity provision is paused for this pair")]
+    ProvidePaused {},
 }
 
 impl From<OverflowError> for ContractError {
@@ -1,7 +1,7 @@
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    from_binary, from_slice, to_binary, Addr, Coin, Empty, OwnedDeps, Querier, QuerierResult,
-    QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
+    from_binary, from_slice, to_binary, Addr, Binary, Coin, ContractResult, Empty, OwnedDeps,
+    Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
 };
 use std::collections::HashMap;
 
@@ -142,9 +142,14 @@ impl WasmMockQuerier {
                     }
                 }
             }
-            QueryRequest::Wasm(WasmQuery::Raw { contract_addr, .. }) => {
+            QueryRequest::Wasm(WasmQuery::Raw { contract_addr, key }) => {
                 if contract_addr == "factory" {
-                    SystemResult::Ok(to_binary(&Vec::<Addr>::new()).into())
+                    if key.as_slice() == b"pairs_to_migrate".as_slice() {
+                        SystemResult::Ok(to_binary(&Vec::<Addr>::new()).into())
+                    } else {
+                        // Pause statuses are not set
+                        SystemResult::Ok(ContractResult::Ok(Binary::default()))
+                    }
                 } else {
                     panic!("DO NOT ENTER HERE");
                 }
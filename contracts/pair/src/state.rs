@@ -1,7 +1,9 @@
 use astroport::{
     asset::{AssetInfo, PairInfo},
-    pair::FeeShareConfig,
+    observation::Observation,
+    pair::{DynamicFeeConfig, FeeShareConfig},
 };
+use astroport_circular_buffer::CircularBuffer;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::{Item, SnapshotMap};
@@ -23,11 +25,17 @@ pub struct Config {
     pub track_asset_balances: bool,
     // The config for swap fee sharing
     pub fee_share: Option<FeeShareConfig>,
+    /// The config for the volatility-based dynamic fee
+    pub dynamic_fee: Option<DynamicFeeConfig>,
 }
 
 /// Stores the config struct at the given key
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// Circular buffer to store trade size observations
+pub const OBSERVATIONS: CircularBuffer<Observation> =
+    CircularBuffer::new("observations_state", "observations_buffer");
+
 /// Stores asset balances to query them later at any block height
 pub const BALANCES: SnapshotMap<&AssetInfo, Uint128> = SnapshotMap::new(
     "balances",
@@ -845,6 +845,7 @@ fn try_native_to_token() {
 
     let err = query_simulation(
         deps.as_ref(),
+        mock_env(),
         Asset {
             info: AssetInfo::NativeToken {
                 denom: "cny".to_string(),
@@ -860,6 +861,7 @@ fn try_native_to_token() {
 
     let simulation_res: SimulationResponse = query_simulation(
         deps.as_ref(),
+        mock_env(),
         Asset {
             info: AssetInfo::NativeToken {
                 denom: "uusd".to_string(),
@@ -875,6 +877,7 @@ fn try_native_to_token() {
     // Check reverse simulation result
     let err = query_reverse_simulation(
         deps.as_ref(),
+        mock_env(),
         Asset {
             info: AssetInfo::NativeToken {
                 denom: "cny".to_string(),
@@ -890,6 +893,7 @@ fn try_native_to_token() {
 
     let reverse_simulation_res: ReverseSimulationResponse = query_reverse_simulation(
         deps.as_ref(),
+        mock_env(),
         Asset {
             info: AssetInfo::Token {
                 contract_addr: Addr::unchecked("asset0000"),
@@ -1066,6 +1070,7 @@ fn try_token_to_native() {
 
     let simulation_res: SimulationResponse = query_simulation(
         deps.as_ref(),
+        mock_env(),
         Asset {
             amount: offer_amount,
             info: AssetInfo::Token {
@@ -1081,6 +1086,7 @@ fn try_token_to_native() {
     // Check reverse simulation result
     let reverse_simulation_res: ReverseSimulationResponse = query_reverse_simulation(
         deps.as_ref(),
+        mock_env(),
         Asset {
             amount: expected_return_amount,
             info: AssetInfo::NativeToken {
@@ -1421,6 +1427,7 @@ fn test_accumulate_prices() {
                 price1_cumulative_last: Uint128::new(case.last1),
                 track_asset_balances: false,
                 fee_share: None,
+                dynamic_fee: None,
             },
             Uint128::new(case.x_amount),
             Uint128::new(case.y_amount),
@@ -6,18 +6,19 @@ use std::rc::Rc;
 use astroport::asset::{native_asset_info, Asset, AssetInfo, AssetInfoExt, PairInfo};
 use astroport::factory::{
     ExecuteMsg as FactoryExecuteMsg, InstantiateMsg as FactoryInstantiateMsg, PairConfig, PairType,
-    QueryMsg as FactoryQueryMsg,
+    PauseStatus, PauseTarget, QueryMsg as FactoryQueryMsg,
 };
+use astroport::observation::{OracleObservation, OracleTwap};
 use astroport::pair::{
-    ConfigResponse, CumulativePricesResponse, Cw20HookMsg, ExecuteMsg, FeeShareConfig,
-    InstantiateMsg, PoolResponse, QueryMsg, XYKPoolConfig, XYKPoolParams, XYKPoolUpdateParams,
-    MAX_FEE_SHARE_BPS, TWAP_PRECISION,
+    ConfigResponse, CumulativePricesResponse, Cw20HookMsg, DynamicFeeConfig, ExecuteMsg,
+    FeeShareConfig, InstantiateMsg, PoolResponse, QueryMsg, SimulationResponse, XYKPoolConfig,
+    XYKPoolParams, XYKPoolUpdateParams, MAX_FEE_SHARE_BPS, TWAP_PRECISION,
 };
 use astroport::token::InstantiateMsg as TokenInstantiateMsg;
 use astroport_mocks::cw_multi_test::{App, BasicApp, ContractWrapper, Executor};
 use astroport_mocks::{astroport_address, MockGeneratorBuilder, MockXykPairBuilder};
 use astroport_pair::error::ContractError;
-use cosmwasm_std::{attr, to_binary, Addr, Coin, Decimal, Uint128, Uint64};
+use cosmwasm_std::{attr, from_binary, to_binary, Addr, Coin, Decimal, Uint128, Uint64};
 use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, MinterResponse};
 
 const OWNER: &str = "owner";
@@ -80,6 +81,7 @@ fn instantiate_pair(mut router: &mut App, owner: &Addr) -> Addr {
             total_fee_bps: 0,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id: token_contract_code_id,
         generator_address: Some(String::from("generator")),
@@ -342,6 +344,7 @@ fn test_provide_and_withdraw_liquidity() {
                 to_binary(&XYKPoolConfig {
                     track_asset_balances: false,
                     fee_share: None,
+                    dynamic_fee: None,
                 })
                 .unwrap()
             ),
@@ -484,6 +487,7 @@ fn test_compatibility_of_tokens_with_different_precision() {
             total_fee_bps: 0,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id,
         generator_address: Some(String::from("generator")),
@@ -726,6 +730,125 @@ fn test_if_twap_is_calculated_correctly_when_pool_idles() {
     assert_eq!(twap1 / price_precision, Uint128::new(129600)); //   1.5 * ELAPSED_SECONDS
 }
 
+#[test]
+fn test_observations_across_blocks() {
+    let owner = Addr::unchecked("owner");
+    let user1 = Addr::unchecked("user1");
+
+    let mut app = mock_app(
+        owner.clone(),
+        vec![
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(100_000_000_000000u128),
+            },
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(100_000_000_000000u128),
+            },
+        ],
+    );
+
+    app.send_tokens(
+        owner.clone(),
+        user1.clone(),
+        &[
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(2000000_000000),
+            },
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(2000000_000000),
+            },
+        ],
+    )
+    .unwrap();
+
+    let pair_instance = instantiate_pair(&mut app, &user1);
+
+    let (msg, coins) = provide_liquidity_msg(
+        Uint128::new(1000000_000000),
+        Uint128::new(1000000_000000),
+        None,
+        None,
+    );
+    app.execute_contract(user1.clone(), pair_instance.clone(), &msg, &coins)
+        .unwrap();
+
+    let err = app
+        .wrap()
+        .query_wasm_smart::<OracleObservation>(
+            &pair_instance,
+            &QueryMsg::Observe { seconds_ago: 0 },
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("Buffer is empty"));
+
+    let swap = |app: &mut App, denom: &str| {
+        let offer_asset = Asset {
+            info: native_asset_info(denom.to_string()),
+            amount: Uint128::new(10000_000000),
+        };
+        app.execute_contract(
+            user1.clone(),
+            pair_instance.clone(),
+            &ExecuteMsg::Swap {
+                offer_asset: offer_asset.clone(),
+                ask_asset_info: None,
+                belief_price: None,
+                max_spread: Some(Decimal::percent(50)),
+                to: None,
+            },
+            &[offer_asset.as_coin().unwrap()],
+        )
+        .unwrap();
+        app.update_block(|b| {
+            b.height += 1;
+            b.time = b.time.plus_seconds(100);
+        });
+    };
+
+    // Each swap is committed as an observation in the next block
+    swap(&mut app, "uusd");
+    swap(&mut app, "uusd");
+    swap(&mut app, "uluna");
+
+    let first: OracleObservation = app
+        .wrap()
+        .query_wasm_smart(&pair_instance, &QueryMsg::Observe { seconds_ago: 300 })
+        .unwrap();
+    // uusd was offered, so the price of uusd in uluna is slightly above 1
+    assert!(first.price > Decimal::one() && first.price < Decimal::percent(102));
+
+    let last: OracleObservation = app
+        .wrap()
+        .query_wasm_smart(&pair_instance, &QueryMsg::Observe { seconds_ago: 0 })
+        .unwrap();
+    // Second swap moved the price further, so the moving average grows
+    assert!(last.price > first.price);
+
+    let twap: OracleTwap = app
+        .wrap()
+        .query_wasm_smart(
+            &pair_instance,
+            &QueryMsg::ObserveTwap {
+                start_seconds_ago: 300,
+                end_seconds_ago: 100,
+            },
+        )
+        .unwrap();
+    // Both committed observations lasted 100 seconds each within the window
+    assert_eq!(twap.price, last.price);
+
+    app.wrap()
+        .query_wasm_smart::<OracleObservation>(
+            &pair_instance,
+            &QueryMsg::Observe { seconds_ago: 301 },
+        )
+        .unwrap_err();
+}
+
 #[test]
 fn create_pair_with_same_assets() {
     let owner = Addr::unchecked("owner");
@@ -874,6 +997,7 @@ fn asset_balances_tracking_works_correctly() {
             total_fee_bps: 0,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id,
         generator_address: Some(String::from("generator")),
@@ -1457,6 +1581,7 @@ fn update_pair_config() {
                 to_binary(&XYKPoolConfig {
                     track_asset_balances: false,
                     fee_share: None,
+                    dynamic_fee: None,
                 })
                 .unwrap()
             ),
@@ -1498,6 +1623,7 @@ fn update_pair_config() {
                 to_binary(&XYKPoolConfig {
                     track_asset_balances: true,
                     fee_share: None,
+                    dynamic_fee: None,
                 })
                 .unwrap()
             ),
@@ -1588,6 +1714,7 @@ fn enable_disable_fee_sharing() {
                 to_binary(&XYKPoolConfig {
                     track_asset_balances: false,
                     fee_share: None,
+                    dynamic_fee: None,
                 })
                 .unwrap()
             ),
@@ -1660,6 +1787,7 @@ fn enable_disable_fee_sharing() {
                         bps: fee_share_bps,
                         recipient: Addr::unchecked(fee_share_contract),
                     }),
+                    dynamic_fee: None,
                 })
                 .unwrap()
             ),
@@ -1689,6 +1817,7 @@ fn enable_disable_fee_sharing() {
                 to_binary(&XYKPoolConfig {
                     track_asset_balances: false,
                     fee_share: None,
+                    dynamic_fee: None,
                 })
                 .unwrap()
             ),
@@ -1983,6 +2112,7 @@ fn test_fee_share(
             total_fee_bps,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id,
         generator_address: Some(String::from("generator")),
@@ -2269,3 +2399,516 @@ fn test_provide_liquidity_without_funds() {
         "Generic error: Native token balance mismatch between the argument (100000000uusd) and the transferred (0uusd)"
     );
 }
+
+#[test]
+fn provide_single_asset() {
+    let owner = Addr::unchecked("owner");
+    let alice_address = Addr::unchecked("alice");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+        ],
+    );
+
+    router
+        .send_tokens(
+            owner.clone(),
+            alice_address.clone(),
+            &[
+                Coin {
+                    denom: "uusd".to_string(),
+                    amount: Uint128::new(233_000_000u128),
+                },
+                Coin {
+                    denom: "uluna".to_string(),
+                    amount: Uint128::new(200_000_000u128),
+                },
+            ],
+        )
+        .unwrap();
+
+    let pair_instance = instantiate_pair(&mut router, &owner);
+
+    let zap_msg = |min_lp_to_receive: Option<Uint128>| ExecuteMsg::ProvideSingleAsset {
+        asset: native_asset_info("uusd".to_string()).with_balance(10_000_000u128),
+        min_lp_to_receive,
+        auto_stake: None,
+        receiver: None,
+    };
+    let zap_coins = [Coin {
+        denom: "uusd".to_string(),
+        amount: Uint128::new(10_000_000u128),
+    }];
+
+    // Single asset provide requires existing liquidity
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            pair_instance.clone(),
+            &zap_msg(None),
+            &zap_coins,
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidProvideLPsWithSingleToken {},
+        err.downcast().unwrap()
+    );
+
+    let (msg, coins) = provide_liquidity_msg(
+        Uint128::new(100_000_000),
+        Uint128::new(100_000_000),
+        None,
+        None,
+    );
+    router
+        .execute_contract(alice_address.clone(), pair_instance.clone(), &msg, &coins)
+        .unwrap();
+
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            pair_instance.clone(),
+            &zap_msg(Some(Uint128::new(5_000_000))),
+            &zap_coins,
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::ProvideSlippageViolation(Uint128::new(4_880_883), Uint128::new(5_000_000)),
+        err.downcast().unwrap()
+    );
+
+    router
+        .execute_contract(
+            alice_address.clone(),
+            pair_instance.clone(),
+            &zap_msg(Some(Uint128::new(4_880_000))),
+            &zap_coins,
+        )
+        .unwrap();
+
+    let res: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            "contract2",
+            &Cw20QueryMsg::Balance {
+                address: alice_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::new(99_999_000 + 4_880_883));
+
+    // The whole deposit went to the pool
+    let res: PoolResponse = router
+        .wrap()
+        .query_wasm_smart(&pair_instance, &QueryMsg::Pool {})
+        .unwrap();
+    assert_eq!(res.assets[0].amount, Uint128::new(110_000_000));
+    assert_eq!(res.assets[1].amount, Uint128::new(95_346_260));
+    assert_eq!(res.total_share, Uint128::new(100_000_000 + 4_880_883));
+}
+
+#[test]
+fn withdraw_single_asset() {
+    let owner = Addr::unchecked("owner");
+    let alice_address = Addr::unchecked("alice");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+        ],
+    );
+
+    router
+        .send_tokens(
+            owner.clone(),
+            alice_address.clone(),
+            &[
+                Coin {
+                    denom: "uusd".to_string(),
+                    amount: Uint128::new(233_000_000u128),
+                },
+                Coin {
+                    denom: "uluna".to_string(),
+                    amount: Uint128::new(200_000_000u128),
+                },
+            ],
+        )
+        .unwrap();
+
+    let pair_instance = instantiate_pair(&mut router, &owner);
+
+    let (msg, coins) = provide_liquidity_msg(
+        Uint128::new(100_000_000),
+        Uint128::new(100_000_000),
+        None,
+        None,
+    );
+    router
+        .execute_contract(alice_address.clone(), pair_instance.clone(), &msg, &coins)
+        .unwrap();
+
+    let withdraw_msg =
+        |ask_asset_info: AssetInfo, min_amount_out: Option<Uint128>| Cw20ExecuteMsg::Send {
+            contract: pair_instance.to_string(),
+            amount: Uint128::new(10_000_000),
+            msg: to_binary(&Cw20HookMsg::WithdrawSingleAsset {
+                ask_asset_info,
+                min_amount_out,
+            })
+            .unwrap(),
+        };
+
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            Addr::unchecked("contract2"),
+            &withdraw_msg(native_asset_info("uandr".to_string()), None),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Asset mismatch between the requested and the stored asset in contract"
+    );
+
+    // 10_000_000 uusd share + 9_000_000 uusd from swapping the 10_000_000 uluna share
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            Addr::unchecked("contract2"),
+            &withdraw_msg(
+                native_asset_info("uusd".to_string()),
+                Some(Uint128::new(20_000_000)),
+            ),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Slippage is more than expected: received 19000000, expected at least 20000000"
+    );
+
+    router
+        .execute_contract(
+            alice_address.clone(),
+            Addr::unchecked("contract2"),
+            &withdraw_msg(
+                native_asset_info("uusd".to_string()),
+                Some(Uint128::new(19_000_000)),
+            ),
+            &[],
+        )
+        .unwrap();
+
+    let balance = router
+        .wrap()
+        .query_balance(alice_address.to_string(), "uusd")
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(133_000_000 + 19_000_000));
+    let balance = router
+        .wrap()
+        .query_balance(alice_address.to_string(), "uluna")
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(100_000_000));
+
+    // The uluna share stayed in the pool
+    let res: PoolResponse = router
+        .wrap()
+        .query_wasm_smart(&pair_instance, &QueryMsg::Pool {})
+        .unwrap();
+    assert_eq!(res.assets[0].amount, Uint128::new(81_000_000));
+    assert_eq!(res.assets[1].amount, Uint128::new(100_000_000));
+    assert_eq!(res.total_share, Uint128::new(90_000_000));
+}
+
+#[test]
+fn single_asset_operations_are_blocked_while_swaps_paused() {
+    let owner = Addr::unchecked("owner");
+    let alice_address = Addr::unchecked("alice");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+        ],
+    );
+
+    router
+        .send_tokens(
+            owner.clone(),
+            alice_address.clone(),
+            &[
+                Coin {
+                    denom: "uusd".to_string(),
+                    amount: Uint128::new(233_000_000u128),
+                },
+                Coin {
+                    denom: "uluna".to_string(),
+                    amount: Uint128::new(200_000_000u128),
+                },
+            ],
+        )
+        .unwrap();
+
+    let pair_instance = instantiate_pair(&mut router, &owner);
+    // The factory is the first contract instantiated by the helper
+    let factory_instance = Addr::unchecked("contract0");
+
+    let (msg, coins) = provide_liquidity_msg(
+        Uint128::new(100_000_000),
+        Uint128::new(100_000_000),
+        None,
+        None,
+    );
+    router
+        .execute_contract(alice_address.clone(), pair_instance.clone(), &msg, &coins)
+        .unwrap();
+
+    router
+        .execute_contract(
+            owner.clone(),
+            factory_instance,
+            &FactoryExecuteMsg::UpdatePauseStatus {
+                target: PauseTarget::Pair(pair_instance.to_string()),
+                status: PauseStatus {
+                    swaps_paused: true,
+                    provide_paused: false,
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Single asset provision swaps internally
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            pair_instance.clone(),
+            &ExecuteMsg::ProvideSingleAsset {
+                asset: native_asset_info("uusd".to_string()).with_balance(10_000_000u128),
+                min_lp_to_receive: None,
+                auto_stake: None,
+                receiver: None,
+            },
+            &[Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(10_000_000u128),
+            }],
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::SwapsPaused {}, err.downcast().unwrap());
+
+    // Single asset withdrawal swaps internally
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            Addr::unchecked("contract2"),
+            &Cw20ExecuteMsg::Send {
+                contract: pair_instance.to_string(),
+                amount: Uint128::new(10_000_000),
+                msg: to_binary(&Cw20HookMsg::WithdrawSingleAsset {
+                    ask_asset_info: native_asset_info("uusd".to_string()),
+                    min_amount_out: None,
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Swaps are paused for this pair"
+    );
+
+    // Balanced withdrawal is never paused
+    router
+        .execute_contract(
+            alice_address.clone(),
+            Addr::unchecked("contract2"),
+            &Cw20ExecuteMsg::Send {
+                contract: pair_instance.to_string(),
+                amount: Uint128::new(10_000_000),
+                msg: to_binary(&Cw20HookMsg::WithdrawLiquidity { assets: vec![] }).unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let balance = router
+        .wrap()
+        .query_balance(alice_address.to_string(), "uusd")
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(133_000_000 + 10_000_000));
+}
+
+#[test]
+fn dynamic_fee() {
+    let owner = Addr::unchecked("owner");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+        ],
+    );
+
+    let pair_instance = instantiate_pair(&mut router, &owner);
+
+    let (msg, coins) = provide_liquidity_msg(
+        Uint128::new(100_000_000),
+        Uint128::new(100_000_000),
+        None,
+        None,
+    );
+    router
+        .execute_contract(owner.clone(), pair_instance.clone(), &msg, &coins)
+        .unwrap();
+
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            pair_instance.clone(),
+            &ExecuteMsg::UpdateConfig {
+                params: to_binary(&XYKPoolUpdateParams::EnableDynamicFee {
+                    min_fee_bps: 100,
+                    max_fee_bps: 10,
+                    window: 15,
+                    volatility_cap: Decimal::percent(1),
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidDynamicFeeParams {}.to_string()
+    );
+
+    let dynamic_fee = DynamicFeeConfig {
+        min_fee_bps: 10,
+        max_fee_bps: 100,
+        window: 25,
+        volatility_cap: Decimal::percent(1),
+    };
+    router
+        .execute_contract(
+            owner.clone(),
+            pair_instance.clone(),
+            &ExecuteMsg::UpdateConfig {
+                params: to_binary(&XYKPoolUpdateParams::EnableDynamicFee {
+                    min_fee_bps: dynamic_fee.min_fee_bps,
+                    max_fee_bps: dynamic_fee.max_fee_bps,
+                    window: dynamic_fee.window,
+                    volatility_cap: dynamic_fee.volatility_cap,
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(&pair_instance, &QueryMsg::Config {})
+        .unwrap();
+    let params: XYKPoolConfig = from_binary(&res.params.unwrap()).unwrap();
+    assert_eq!(params.dynamic_fee, Some(dynamic_fee));
+
+    let simulation_msg = QueryMsg::Simulation {
+        offer_asset: native_asset_info("uusd".to_string()).with_balance(1_000_000u128),
+        ask_asset_info: None,
+    };
+    let fee_rate = |router: &App| {
+        let res: SimulationResponse = router
+            .wrap()
+            .query_wasm_smart(&pair_instance, &simulation_msg)
+            .unwrap();
+        Decimal::from_ratio(
+            res.commission_amount,
+            res.return_amount + res.commission_amount,
+        )
+    };
+
+    // The pair type fee is used until observations cover the window
+    assert_eq!(fee_rate(&router), Decimal::zero());
+
+    let swap_msg = ExecuteMsg::Swap {
+        offer_asset: native_asset_info("uusd".to_string()).with_balance(1_000_000u128),
+        ask_asset_info: None,
+        belief_price: None,
+        max_spread: Some(Decimal::percent(10)),
+        to: None,
+    };
+    for _ in 0..3 {
+        router
+            .execute_contract(
+                owner.clone(),
+                pair_instance.clone(),
+                &swap_msg,
+                &[Coin {
+                    denom: "uusd".to_string(),
+                    amount: Uint128::new(1_000_000),
+                }],
+            )
+            .unwrap();
+        router.update_block(|b| {
+            b.height += 1;
+            b.time = b.time.plus_seconds(10);
+        });
+    }
+
+    // Observations are committed with the next trade, so the window now covers the first two
+    // trade prices and the last observed price deviates from the average one
+    let volatile_fee_rate = fee_rate(&router);
+    assert!(volatile_fee_rate > Decimal::from_ratio(10u8, 10000u16));
+    assert!(volatile_fee_rate < Decimal::from_ratio(100u8, 10000u16));
+
+    // No trades during the whole window
+    router.update_block(|b| {
+        b.height += 100;
+        b.time = b.time.plus_seconds(1000);
+    });
+    let calm_fee_rate = fee_rate(&router);
+    assert!(calm_fee_rate < volatile_fee_rate);
+    assert!(calm_fee_rate <= Decimal::from_ratio(10u8, 10000u16));
+
+    router
+        .execute_contract(
+            owner.clone(),
+            pair_instance.clone(),
+            &ExecuteMsg::UpdateConfig {
+                params: to_binary(&XYKPoolUpdateParams::DisableDynamicFee).unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(fee_rate(&router), Decimal::zero());
+}
@@ -27,7 +27,7 @@ use astroport::pair::{
 use astroport::pair_concentrated::{
     ConcentratedPoolParams, ConcentratedPoolUpdateParams, MigrateMsg, UpdatePoolParams,
 };
-use astroport::querier::{query_factory_config, query_fee_info, query_supply};
+use astroport::querier::{query_factory_config, query_fee_info, query_pause_status, query_supply};
 use astroport::token::InstantiateMsg as TokenInstantiateMsg;
 use astroport_circular_buffer::BufferManager;
 use astroport_pcl_common::state::{
@@ -308,6 +308,7 @@ pub fn execute(
             })
             .map_err(Into::into)
         }
+        ExecuteMsg::ProvideSingleAsset { .. } => Err(ContractError::NonSupported {}),
     }
 }
 
@@ -351,6 +352,7 @@ fn receive_cw20(
             cw20_msg.amount,
             assets,
         ),
+        Cw20HookMsg::WithdrawSingleAsset { .. } => Err(ContractError::NonSupported {}),
     }
 }
 
@@ -387,6 +389,17 @@ pub fn provide_liquidity(
         return Err(ContractError::PairIsNotRegistered {});
     }
 
+    if query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?
+    .provide_paused
+    {
+        return Err(ContractError::ProvidePaused {});
+    }
+
     match assets.len() {
         0 => {
             return Err(StdError::generic_err("Nothing to provide").into());
@@ -713,6 +726,17 @@ fn swap(
     let offer_asset_dec = offer_asset.to_decimal_asset(offer_asset_prec)?;
     let mut config = CONFIG.load(deps.storage)?;
 
+    if query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?
+    .swaps_paused
+    {
+        return Err(ContractError::SwapsPaused {});
+    }
+
     let mut pools = query_pools(deps.querier, &env.contract.address, &config, &precisions)?;
 
     let (offer_ind, _) = pools
@@ -733,6 +757,7 @@ fn swap(
         &deps.querier,
         &config.factory_addr,
         config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
     )?;
     let mut maker_fee_share = Decimal256::zero();
     if fee_info.fee_address.is_some() {
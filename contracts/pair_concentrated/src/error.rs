@@ -61,4 +61,13 @@ pub enum ContractError {
         MAX_FEE_SHARE_BPS
     )]
     FeeShareOutOfBounds {},
+
+    #[error("Operation non supported")]
+    NonSupported {},
+
+    #[error("Swaps are paused for this pair")]
+    SwapsPaused {},
+
+    #[error("Liquidity provision is paused for this pair")]
+    ProvidePaused {},
 }
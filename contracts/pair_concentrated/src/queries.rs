@@ -7,13 +7,13 @@ use itertools::Itertools;
 
 use astroport::asset::{Asset, AssetInfo};
 use astroport::cosmwasm_ext::{DecimalToInteger, IntegerToDecimal};
-use astroport::observation::query_observation;
+use astroport::observation::{query_observation, query_observation_twap};
 use astroport::pair::{
     ConfigResponse, PoolResponse, ReverseSimulationResponse, SimulationResponse,
 };
 
 use astroport::pair_concentrated::{ConcentratedPoolConfig, QueryMsg};
-use astroport::querier::{query_factory_config, query_fee_info, query_supply};
+use astroport::querier::{query_factory_config, query_fee_info, query_pause_status, query_supply};
 
 use crate::contract::LP_TOKEN_PRECISION;
 use crate::error::ContractError;
@@ -48,8 +48,13 @@ use crate::utils::{pool_info, query_pools};
 ///
 /// * **QueryMsg::Config {}** Returns the configuration for the pair contract using a [`ConfigResponse`] object.
 ///
+/// * **QueryMsg::ObserveTwap { start_seconds_ago, end_seconds_ago }** Returns the time-weighted average
+/// price over the specified window computed from observations using an [`OracleTwap`] object.
+///
 /// * **QueryMsg::AssetBalanceAt { asset_info, block_height }** Returns the balance of the specified
 /// asset that was in the pool just preceding the moment of the specified block height creation.
+///
+/// * **QueryMsg::PauseStatus {}** Returns the pause status of the pair set in the factory.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -72,6 +77,16 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Observe { seconds_ago } => {
             to_binary(&query_observation(deps, env, OBSERVATIONS, seconds_ago)?)
         }
+        QueryMsg::ObserveTwap {
+            start_seconds_ago,
+            end_seconds_ago,
+        } => to_binary(&query_observation_twap(
+            deps,
+            env,
+            OBSERVATIONS,
+            start_seconds_ago,
+            end_seconds_ago,
+        )?),
         QueryMsg::Config {} => to_binary(&query_config(deps, env)?),
         QueryMsg::LpPrice {} => to_binary(&query_lp_price(deps, env)?),
         QueryMsg::ComputeD {} => to_binary(&query_compute_d(deps, env)?),
@@ -79,6 +94,15 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             asset_info,
             block_height,
         } => to_binary(&query_asset_balances_at(deps, asset_info, block_height)?),
+        QueryMsg::PauseStatus {} => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&query_pause_status(
+                &deps.querier,
+                config.factory_addr,
+                &config.pair_info.pair_type,
+                &env.contract.address,
+            )?)
+        }
     }
 }
 
@@ -157,6 +181,7 @@ pub fn query_simulation(
         &deps.querier,
         &config.factory_addr,
         config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
     )?;
     let mut maker_fee_share = Decimal256::zero();
     if fee_info.fee_address.is_some() {
@@ -309,7 +334,9 @@ mod testing {
     use std::error::Error;
     use std::str::FromStr;
 
-    use astroport::observation::{query_observation, Observation, OracleObservation};
+    use astroport::observation::{
+        query_observation, query_observation_twap, Observation, OracleObservation, OracleTwap,
+    };
     use astroport_circular_buffer::BufferManager;
     use cosmwasm_std::testing::{mock_dependencies, mock_env};
     use cosmwasm_std::Timestamp;
@@ -473,4 +500,75 @@ mod testing {
             }
         }
     }
+
+    #[test]
+    fn observations_twap() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(100_000);
+        BufferManager::init(&mut deps.storage, OBSERVATIONS, 20).unwrap();
+
+        let mut buffer = BufferManager::new(&deps.storage, OBSERVATIONS).unwrap();
+
+        let err = query_observation_twap(deps.as_ref(), env.clone(), OBSERVATIONS, 10_000, 5_000)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Buffer is empty");
+
+        let array = (1..=30)
+            .into_iter()
+            .map(|i| Observation {
+                ts: env.block.time.seconds() + i * 1000,
+                price: Decimal::from_ratio(i, 1u8),
+                price_sma: Default::default(),
+            })
+            .collect_vec();
+        buffer.push_many(&array);
+        buffer.commit(&mut deps.storage).unwrap();
+
+        env.block.time = env.block.time.plus_seconds(30_000);
+
+        assert_eq!(
+            OracleTwap {
+                start_timestamp: 120_000,
+                end_timestamp: 125_000,
+                price: f64_to_dec(22.0),
+            },
+            query_observation_twap(deps.as_ref(), env.clone(), OBSERVATIONS, 10_000, 5_000)
+                .unwrap()
+        );
+
+        assert_eq!(
+            OracleTwap {
+                start_timestamp: 120_500,
+                end_timestamp: 121_500,
+                price: f64_to_dec(20.5),
+            },
+            query_observation_twap(deps.as_ref(), env.clone(), OBSERVATIONS, 9_500, 8_500).unwrap()
+        );
+
+        let err = query_observation_twap(deps.as_ref(), env.clone(), OBSERVATIONS, 5_000, 5_000)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Generic error: start_seconds_ago must be greater than end_seconds_ago"
+        );
+
+        let err = query_observation_twap(deps.as_ref(), env.clone(), OBSERVATIONS, 25_000, 5_000)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Generic error: Requested window is not fully covered by observations. Last known observation is at 111000"
+        );
+
+        // The last observed price is valid until the end of the window
+        env.block.time = env.block.time.plus_seconds(2_000);
+        assert_eq!(
+            OracleTwap {
+                start_timestamp: 129_000,
+                end_timestamp: 132_000,
+                price: Decimal::from_ratio(89u8, 3u8),
+            },
+            query_observation_twap(deps.as_ref(), env, OBSERVATIONS, 3_000, 0).unwrap()
+        );
+    }
 }
@@ -231,6 +231,7 @@ impl Helper {
                 pair_type: pair_type.clone(),
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             }],
             token_code_id,
             generator_address: None,
@@ -746,6 +746,7 @@ where
         &deps.querier,
         &config.factory_addr,
         config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
     )?;
     let mut maker_fee_share = Decimal256::zero();
     if fee_info.fee_address.is_some() {
@@ -425,6 +425,7 @@ where
         &querier,
         &config.factory_addr,
         config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
     )?;
 
     let mut messages = vec![];
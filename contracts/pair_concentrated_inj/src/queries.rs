@@ -7,7 +7,7 @@ use itertools::Itertools;
 
 use astroport::asset::Asset;
 use astroport::cosmwasm_ext::{DecimalToInteger, IntegerToDecimal};
-use astroport::observation::query_observation;
+use astroport::observation::{query_observation, query_observation_twap};
 use astroport::pair::{
     ConfigResponse, PoolResponse, ReverseSimulationResponse, SimulationResponse,
 };
@@ -46,6 +46,9 @@ use crate::utils::query_pools;
 /// pool using a [`CumulativePricesResponse`] object.
 ///
 /// * **QueryMsg::Config {}** Returns the configuration for the pair contract using a [`ConfigResponse`] object.
+///
+/// * **QueryMsg::ObserveTwap { start_seconds_ago, end_seconds_ago }** Returns the time-weighted average
+/// price over the specified window computed from observations using an [`OracleTwap`] object.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps<InjectiveQueryWrapper>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -73,6 +76,16 @@ pub fn query(deps: Deps<InjectiveQueryWrapper>, env: Env, msg: QueryMsg) -> StdR
         QueryMsg::Observe { seconds_ago } => {
             to_binary(&query_observation(deps, env, OBSERVATIONS, seconds_ago)?)
         }
+        QueryMsg::ObserveTwap {
+            start_seconds_ago,
+            end_seconds_ago,
+        } => to_binary(&query_observation_twap(
+            deps,
+            env,
+            OBSERVATIONS,
+            start_seconds_ago,
+            end_seconds_ago,
+        )?),
         QueryMsg::OrderbookState {} => {
             let resp: OrderbookStateResponse = OrderbookState::load(deps.storage)?.into();
             to_binary(&resp)
@@ -186,6 +199,7 @@ pub fn query_simulation(
         &deps.querier,
         &config.factory_addr,
         config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
     )?;
     let mut maker_fee_share = Decimal256::zero();
     if fee_info.fee_address.is_some() {
@@ -288,6 +288,7 @@ impl Helper {
                     pair_type: PairType::Custom("concentrated".to_string()),
                     is_disabled: false,
                     is_generator_disabled: false,
+                    pair_creation_fee: None,
                 },
                 PairConfig {
                     code_id: app.store_code(orderbook_pair_contract()),
@@ -296,6 +297,7 @@ impl Helper {
                     pair_type: PairType::Custom("concentrated_inj_orderbook".to_string()),
                     is_disabled: false,
                     is_generator_disabled: false,
+                    pair_creation_fee: None,
                 },
             ],
             token_code_id,
@@ -6,7 +6,7 @@ use std::vec;
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     attr, from_binary, to_binary, wasm_execute, wasm_instantiate, Addr, Binary, CosmosMsg, Decimal,
-    Decimal256, Deps, DepsMut, Env, Fraction, MessageInfo, QuerierWrapper, Reply, Response,
+    Decimal256, Deps, DepsMut, Env, Fraction, MessageInfo, Order, QuerierWrapper, Reply, Response,
     StdError, StdResult, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
@@ -23,17 +23,24 @@ use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_ow
 use astroport::cosmwasm_ext::IntegerToDecimal;
 use astroport::factory::PairType;
 use astroport::pair::{
-    ConfigResponse, FeeShareConfig, InstantiateMsg, StablePoolParams, StablePoolUpdateParams,
-    DEFAULT_SLIPPAGE, MAX_ALLOWED_SLIPPAGE, MAX_FEE_SHARE_BPS, MIN_TRADE_SIZE,
+    ConfigResponse, FeeShareConfig, InstantiateMsg, RateProvider, StablePoolParams,
+    StablePoolUpdateParams, DEFAULT_SLIPPAGE, MAX_ALLOWED_SLIPPAGE, MAX_FEE_SHARE_BPS,
+    MIN_TRADE_SIZE,
 };
 
 use crate::migration::{migrate_config_from_v21, migrate_config_to_v210};
-use astroport::observation::{query_observation, PrecommitObservation, OBSERVATIONS_SIZE};
+use astroport::observation::{
+    accumulate_swap_sizes, query_observation, query_observation_twap, PrecommitObservation,
+    OBSERVATIONS_SIZE,
+};
 use astroport::pair::{
     Cw20HookMsg, ExecuteMsg, MigrateMsg, PoolResponse, QueryMsg, ReverseSimulationResponse,
     SimulationResponse, StablePoolConfig,
 };
-use astroport::querier::{query_factory_config, query_fee_info, query_supply};
+use astroport::pair_utils::{
+    determine_base_quote_amount, mint_liquidity_token_message, select_pools,
+};
+use astroport::querier::{query_factory_config, query_fee_info, query_pause_status, query_supply};
 use astroport::token::InstantiateMsg as TokenInstantiateMsg;
 use astroport::DecimalCheckedOps;
 use astroport_circular_buffer::BufferManager;
@@ -43,12 +50,13 @@ use crate::math::{
     calc_y, compute_d, AMP_PRECISION, MAX_AMP, MAX_AMP_CHANGE, MIN_AMP_CHANGING_TIME,
 };
 use crate::state::{
-    get_precision, store_precisions, Config, CONFIG, OBSERVATIONS, OWNERSHIP_PROPOSAL,
+    get_precision, store_precisions, Config, CACHED_RATES, CONFIG, OBSERVATIONS,
+    OWNERSHIP_PROPOSAL, RATE_PROVIDERS,
 };
 use crate::utils::{
-    accumulate_swap_sizes, adjust_precision, check_asset_infos, check_assets, check_cw20_in_pool,
-    compute_current_amp, compute_swap, determine_base_quote_amount, get_share_in_assets,
-    mint_liquidity_token_message, select_pools, SwapResult,
+    adjust_precision, cache_rates, check_asset_infos, check_assets, check_cw20_in_pool,
+    compute_current_amp, compute_swap, get_rates, get_share_in_assets, validate_rate_provider,
+    SwapResult,
 };
 
 /// Contract name that is used for migration.
@@ -110,6 +118,14 @@ pub fn instantiate(
     CONFIG.save(deps.storage, &config)?;
     BufferManager::init(deps.storage, OBSERVATIONS, OBSERVATIONS_SIZE)?;
 
+    for rate_provider in params.rate_providers.unwrap_or_default() {
+        let provider = validate_rate_provider(deps.api, &msg.asset_infos, &rate_provider)?;
+        if RATE_PROVIDERS.has(deps.storage, provider.asset_info.to_string()) {
+            return Err(ContractError::DoublingAssets {});
+        }
+        RATE_PROVIDERS.save(deps.storage, provider.asset_info.to_string(), &provider)?;
+    }
+
     let token_name = format_lp_token_name(&msg.asset_infos, &deps.querier)?;
 
     // Create LP token
@@ -268,6 +284,7 @@ pub fn execute(
             })
             .map_err(|e| e.into())
         }
+        ExecuteMsg::ProvideSingleAsset { .. } => Err(ContractError::NonSupported {}),
     }
 }
 
@@ -316,6 +333,18 @@ pub fn receive_cw20(
             cw20_msg.amount,
             assets,
         ),
+        Cw20HookMsg::WithdrawSingleAsset {
+            ask_asset_info,
+            min_amount_out,
+        } => withdraw_single_asset(
+            deps,
+            env,
+            info,
+            Addr::unchecked(cw20_msg.sender),
+            cw20_msg.amount,
+            ask_asset_info,
+            min_amount_out,
+        ),
     }
 }
 
@@ -341,6 +370,18 @@ pub fn provide_liquidity(
 
     let auto_stake = auto_stake.unwrap_or(false);
     let config = CONFIG.load(deps.storage)?;
+
+    if query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?
+    .provide_paused
+    {
+        return Err(ContractError::ProvidePaused {});
+    }
+
     info.funds
         .assert_coins_properly_sent(&assets, &config.pair_info.asset_infos)?;
 
@@ -437,10 +478,18 @@ pub fn provide_liquidity(
 
     let amp = compute_current_amp(&config, &env)?;
 
+    let asset_infos = assets_collection
+        .iter()
+        .map(|(deposit, _)| deposit.info.clone())
+        .collect_vec();
+    let rates = get_rates(deps.querier, deps.storage, &env, &asset_infos)?;
+    cache_rates(deps.storage, &env, &asset_infos, &rates)?;
+
     // Invariant (D) after deposit added
     let new_balances = assets_collection
         .iter()
-        .map(|(deposit, pool)| Ok(pool + deposit.amount))
+        .zip(&rates)
+        .map(|((deposit, pool), &rate)| Ok((pool + deposit.amount) * rate))
         .collect::<StdResult<Vec<_>>>()?;
     let deposit_d = compute_d(amp, &new_balances)?;
 
@@ -458,7 +507,8 @@ pub fn provide_liquidity(
 
         messages.extend(mint_liquidity_token_message(
             deps.querier,
-            &config,
+            &config.pair_info.liquidity_token,
+            &config.factory_addr,
             &env.contract.address,
             &env.contract.address,
             MINIMUM_LIQUIDITY_AMOUNT,
@@ -470,7 +520,8 @@ pub fn provide_liquidity(
         // Initial invariant (D)
         let old_balances = assets_collection
             .iter()
-            .map(|(_, pool)| *pool)
+            .zip(&rates)
+            .map(|((_, pool), &rate)| *pool * rate)
             .collect_vec();
         let init_d = compute_d(amp, &old_balances)?;
 
@@ -489,7 +540,8 @@ pub fn provide_liquidity(
     let receiver = addr_opt_validate(deps.api, &receiver)?.unwrap_or_else(|| info.sender.clone());
     messages.extend(mint_liquidity_token_message(
         deps.querier,
-        &config,
+        &config.pair_info.liquidity_token,
+        &config.factory_addr,
         &env.contract.address,
         &receiver,
         share,
@@ -553,6 +605,217 @@ pub fn withdraw_liquidity(
     ]))
 }
 
+/// Withdraw liquidity from the pool in a single asset. The share of the other asset is swapped
+/// into the requested asset along the stableswap curve of the pool left after the withdrawal.
+///
+/// * **sender** is the address that will receive the asset back from the pair contract.
+///
+/// * **amount** is the amount of LP tokens to burn.
+///
+/// * **ask_asset_info** is the asset to receive.
+///
+/// * **min_amount_out** is the minimum amount of the asset to receive.
+pub fn withdraw_single_asset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    sender: Addr,
+    amount: Uint128,
+    ask_asset_info: AssetInfo,
+    min_amount_out: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.pair_info.liquidity_token {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Single asset withdrawal swaps internally, so it is blocked while swaps are paused
+    if query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?
+    .swaps_paused
+    {
+        return Err(ContractError::SwapsPaused {});
+    }
+
+    let (pools, total_share) = pool_info(deps.querier, &config)?;
+    if !pools.iter().any(|pool| pool.info.equal(&ask_asset_info)) {
+        return Err(ContractError::InvalidAsset(ask_asset_info.to_string()));
+    }
+
+    let refund_assets = get_share_in_assets(&pools, amount, total_share);
+    let (offer_refund, ask_refund): (Vec<_>, Vec<_>) = refund_assets
+        .into_iter()
+        .partition(|asset| !asset.info.equal(&ask_asset_info));
+    let offer_asset = offer_refund[0].clone();
+    let ask_refund_amount = ask_refund[0].amount;
+
+    // Pool balances after the balanced withdrawal
+    let pools_after_withdraw = pools
+        .iter()
+        .map(|pool| {
+            let refund = if pool.info.equal(&ask_asset_info) {
+                ask_refund_amount
+            } else {
+                offer_asset.amount
+            };
+            let token_precision = get_precision(deps.storage, &pool.info)?;
+            Ok(DecimalAsset {
+                info: pool.info.clone(),
+                amount: Decimal256::with_precision(
+                    pool.amount.checked_sub(refund)?,
+                    token_precision,
+                )?,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let (offer_pool, ask_pool) = select_pools(
+        Some(&offer_asset.info),
+        Some(&ask_asset_info),
+        &pools_after_withdraw,
+    )?;
+
+    // Get fee info from the factory
+    let fee_info = query_fee_info(
+        &deps.querier,
+        &config.factory_addr,
+        config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
+    )?;
+
+    let offer_precision = get_precision(deps.storage, &offer_asset.info)?;
+    let offer_asset_dec = offer_asset.to_decimal_asset(offer_precision)?;
+
+    let rates = get_rates(
+        deps.querier,
+        deps.storage,
+        &env,
+        &config.pair_info.asset_infos,
+    )?;
+    cache_rates(deps.storage, &env, &config.pair_info.asset_infos, &rates)?;
+
+    let (return_amount, commission_amount) = if offer_asset.amount.is_zero() {
+        (Uint128::zero(), Uint128::zero())
+    } else {
+        // Check if the liquidity left is non-zero
+        check_swap_parameters(
+            pools_after_withdraw
+                .iter()
+                .map(|pool| {
+                    pool.amount
+                        .to_uint128_with_precision(get_precision(deps.storage, &pool.info)?)
+                })
+                .collect::<StdResult<Vec<Uint128>>>()?,
+            offer_asset.amount,
+        )?;
+
+        let SwapResult { return_amount, .. } = compute_swap(
+            deps.storage,
+            &env,
+            &config,
+            &offer_asset_dec,
+            &offer_pool,
+            &ask_pool,
+            &pools_after_withdraw,
+            &rates,
+        )?;
+        let commission_amount = fee_info.total_fee_rate.checked_mul_uint128(return_amount)?;
+        (
+            return_amount.saturating_sub(commission_amount),
+            commission_amount,
+        )
+    };
+
+    let refund_amount = ask_refund_amount + return_amount;
+    if refund_amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    if let Some(min_amount_out) = min_amount_out {
+        if refund_amount < min_amount_out {
+            return Err(ContractError::WithdrawSlippageViolation(
+                refund_amount,
+                min_amount_out,
+            ));
+        }
+    }
+
+    let refund_asset = Asset {
+        info: ask_asset_info,
+        amount: refund_amount,
+    };
+    let mut messages = vec![refund_asset.clone().into_msg(&sender)?];
+
+    // The fee share and the Maker fee are charged on the internal swap the same way as on a swap
+    let mut fees_commission_amount = commission_amount;
+    let mut fee_share_amount = Uint128::zero();
+    if let Some(fee_share) = config.fee_share {
+        let share_fee_rate = Decimal::from_ratio(fee_share.bps, 10000u16);
+        fee_share_amount = fees_commission_amount * share_fee_rate;
+
+        if !fee_share_amount.is_zero() {
+            fees_commission_amount = fees_commission_amount.saturating_sub(fee_share_amount);
+            messages.push(
+                Asset {
+                    info: refund_asset.info.clone(),
+                    amount: fee_share_amount,
+                }
+                .into_msg(fee_share.recipient)?,
+            );
+        }
+    }
+
+    let mut maker_fee_amount = Uint128::zero();
+    if let Some(fee_address) = fee_info.fee_address {
+        if let Some(f) = calculate_maker_fee(
+            &refund_asset.info,
+            fees_commission_amount,
+            fee_info.maker_fee_rate,
+        ) {
+            maker_fee_amount = f.amount;
+            messages.push(f.into_msg(fee_address)?);
+        }
+    }
+
+    messages.push(
+        wasm_execute(
+            &config.pair_info.liquidity_token,
+            &Cw20ExecuteMsg::Burn { amount },
+            vec![],
+        )?
+        .into(),
+    );
+
+    // Store observation from precommit data
+    accumulate_swap_sizes(deps.storage, &env, OBSERVATIONS)?;
+
+    // The internal swap is observed the same way as a regular swap
+    let ask_precision = get_precision(deps.storage, &refund_asset.info)?;
+    if offer_asset_dec.amount >= MIN_TRADE_SIZE
+        && return_amount.to_decimal256(ask_precision)? >= MIN_TRADE_SIZE
+    {
+        let (base_amount, quote_amount) =
+            determine_base_quote_amount(&pools_after_withdraw, &offer_asset, return_amount)?;
+        PrecommitObservation::save(deps.storage, &env, base_amount, quote_amount)?;
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("action", "withdraw_liquidity"),
+        attr("sender", sender),
+        attr("withdrawn_share", amount),
+        attr("refund_assets", refund_asset.to_string()),
+        attr("swap_amount", offer_asset.amount),
+        attr("return_amount", return_amount),
+        attr("commission_amount", commission_amount),
+        attr("maker_fee_amount", maker_fee_amount),
+        attr("fee_share_amount", fee_share_amount),
+    ]))
+}
+
 /// Performs an swap operation with the specified parameters.
 ///
 /// * **sender** is the sender of the swap operation.
@@ -579,6 +842,17 @@ pub fn swap(
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
+    if query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?
+    .swaps_paused
+    {
+        return Err(ContractError::SwapsPaused {});
+    }
+
     // If the asset balance already increased
     // We should subtract the user deposit from the pool offer asset amount
     let pools = config
@@ -616,6 +890,14 @@ pub fn swap(
 
     let offer_asset_dec = offer_asset.to_decimal_asset(offer_precision)?;
 
+    let rates = get_rates(
+        deps.querier,
+        deps.storage,
+        &env,
+        &config.pair_info.asset_infos,
+    )?;
+    cache_rates(deps.storage, &env, &config.pair_info.asset_infos, &rates)?;
+
     let SwapResult {
         return_amount,
         spread_amount,
@@ -627,6 +909,7 @@ pub fn swap(
         &offer_pool,
         &ask_pool,
         &pools,
+        &rates,
     )?;
 
     // Get fee info from the factory
@@ -634,6 +917,7 @@ pub fn swap(
         &deps.querier,
         &config.factory_addr,
         config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
     )?;
     let commission_amount = fee_info.total_fee_rate.checked_mul_uint128(return_amount)?;
     let return_amount = return_amount.saturating_sub(commission_amount);
@@ -698,7 +982,7 @@ pub fn swap(
     }
 
     // Store observation from precommit data
-    accumulate_swap_sizes(deps.storage, &env)?;
+    accumulate_swap_sizes(deps.storage, &env, OBSERVATIONS)?;
 
     // Store time series data in precommit observation.
     // Skipping small unsafe values which can seriously mess oracle price due to rounding errors.
@@ -777,6 +1061,11 @@ pub fn calculate_maker_fee(
 /// pool using a [`CumulativePricesResponse`] object.
 ///
 /// * **QueryMsg::Config {}** Returns the configuration for the pair contract using a [`ConfigResponse`] object.
+///
+/// * **QueryMsg::ObserveTwap { start_seconds_ago, end_seconds_ago }** Returns the time-weighted average
+/// price over the specified window computed from observations using an [`OracleTwap`] object.
+///
+/// * **QueryMsg::PauseStatus {}** Returns the pause status of the pair set in the factory.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -802,8 +1091,27 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Observe { seconds_ago } => {
             to_binary(&query_observation(deps, env, OBSERVATIONS, seconds_ago)?)
         }
+        QueryMsg::ObserveTwap {
+            start_seconds_ago,
+            end_seconds_ago,
+        } => to_binary(&query_observation_twap(
+            deps,
+            env,
+            OBSERVATIONS,
+            start_seconds_ago,
+            end_seconds_ago,
+        )?),
         QueryMsg::Config {} => to_binary(&query_config(deps, env)?),
         QueryMsg::QueryComputeD {} => to_binary(&query_compute_d(deps, env)?),
+        QueryMsg::PauseStatus {} => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&query_pause_status(
+                &deps.querier,
+                config.factory_addr,
+                &config.pair_info.pair_type,
+                &env.contract.address,
+            )?)
+        }
         _ => Err(StdError::generic_err("Query is not supported")),
     }
 }
@@ -875,6 +1183,14 @@ pub fn query_simulation(
         });
     }
 
+    let rates = get_rates(
+        deps.querier,
+        deps.storage,
+        &env,
+        &config.pair_info.asset_infos,
+    )
+    .map_err(|err| StdError::generic_err(format!("{err}")))?;
+
     let SwapResult {
         return_amount,
         spread_amount,
@@ -886,6 +1202,7 @@ pub fn query_simulation(
         &offer_pool,
         &ask_pool,
         &pools,
+        &rates,
     )
     .map_err(|err| StdError::generic_err(format!("{err}")))?;
 
@@ -894,6 +1211,7 @@ pub fn query_simulation(
         &deps.querier,
         &config.factory_addr,
         config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
     )?;
 
     let commission_amount = fee_info.total_fee_rate.checked_mul_uint128(return_amount)?;
@@ -957,6 +1275,7 @@ pub fn query_reverse_simulation(
         &deps.querier,
         &config.factory_addr,
         config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
     )?;
     let before_commission = (Decimal256::one()
         - Decimal256::new(fee_info.total_fee_rate.atomics().into()))
@@ -964,25 +1283,50 @@ pub fn query_reverse_simulation(
     .ok_or_else(|| StdError::generic_err("The pool must have less than 100% fee!"))?
     .checked_mul(Decimal256::with_precision(ask_asset.amount, ask_precision)?)?;
 
-    let xp = pools.into_iter().map(|pool| pool.amount).collect_vec();
+    let rates = get_rates(
+        deps.querier,
+        deps.storage,
+        &env,
+        &config.pair_info.asset_infos,
+    )
+    .map_err(|err| StdError::generic_err(format!("{err}")))?;
+    let rate_of = |asset_info: &AssetInfo| {
+        pools
+            .iter()
+            .position(|pool| pool.info.eq(asset_info))
+            .map(|ind| rates[ind])
+            .ok_or_else(|| StdError::generic_err(format!("Invalid asset {asset_info}")))
+    };
+    let offer_rate = rate_of(&offer_pool.info)?;
+    let ask_rate = rate_of(&ask_pool.info)?;
+
+    let xp = pools
+        .iter()
+        .zip(&rates)
+        .map(|(pool, &rate)| pool.amount * rate)
+        .collect_vec();
     let new_offer_pool_amount = calc_y(
         compute_current_amp(&config, &env)?,
-        ask_pool.amount - before_commission,
+        (ask_pool.amount - before_commission) * ask_rate,
         &xp,
         config.greatest_precision,
     )?;
 
+    // The new offer pool is scaled by the offer rate
     let offer_amount = new_offer_pool_amount.checked_sub(
-        offer_pool
-            .amount
-            .to_uint128_with_precision(config.greatest_precision)?,
+        (offer_pool.amount * offer_rate).to_uint128_with_precision(config.greatest_precision)?,
     )?;
+    let offer_amount = (Decimal256::with_precision(offer_amount, config.greatest_precision)?
+        / offer_rate)
+        .to_uint128_with_precision(config.greatest_precision)?;
     let offer_amount = adjust_precision(offer_amount, config.greatest_precision, offer_precision)?;
 
     Ok(ReverseSimulationResponse {
         offer_amount,
-        spread_amount: offer_amount
-            .saturating_sub(before_commission.to_uint128_with_precision(offer_precision)?),
+        spread_amount: offer_amount.saturating_sub(
+            (before_commission * ask_rate / offer_rate)
+                .to_uint128_with_precision(offer_precision)?,
+        ),
         commission_amount: fee_info
             .total_fee_rate
             .checked_mul_uint128(before_commission.to_uint128_with_precision(ask_precision)?)?,
@@ -998,6 +1342,16 @@ pub fn query_config(deps: Deps, env: Env) -> StdResult<ConfigResponse> {
         params: Some(to_binary(&StablePoolConfig {
             amp: Decimal::from_ratio(compute_current_amp(&config, &env)?, AMP_PRECISION),
             fee_share: config.fee_share,
+            rate_providers: RATE_PROVIDERS
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|item| {
+                    item.map(|(_, provider)| RateProvider {
+                        asset_info: provider.asset_info,
+                        provider_addr: provider.provider_addr.to_string(),
+                        max_staleness: provider.max_staleness,
+                    })
+                })
+                .collect::<StdResult<_>>()?,
         })?),
         owner: config.owner.unwrap_or(factory_config.owner),
         factory_addr: config.factory_addr,
@@ -1066,7 +1420,7 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
             "2.1.1" | "2.1.2" => {
                 migrate_config_from_v21(deps.branch())?;
             }
-            "3.0.0" | "3.1.0" => {}
+            "3.0.0" | "3.1.0" | "3.4.0" => {}
             _ => return Err(ContractError::MigrationError {}),
         },
         _ => return Err(ContractError::MigrationError {}),
@@ -1158,6 +1512,31 @@ pub fn update_config(
                 .attributes
                 .push(attr("action", "disable_fee_share"));
         }
+        StablePoolUpdateParams::SetRateProvider { rate_provider } => {
+            let provider =
+                validate_rate_provider(deps.api, &config.pair_info.asset_infos, &rate_provider)?;
+            RATE_PROVIDERS.save(deps.storage, provider.asset_info.to_string(), &provider)?;
+            // The cached rate may come from the previous provider
+            CACHED_RATES.remove(deps.storage, provider.asset_info.to_string());
+
+            response.attributes.extend([
+                attr("action", "set_rate_provider"),
+                attr("asset_info", provider.asset_info.to_string()),
+                attr("provider_addr", provider.provider_addr),
+            ]);
+        }
+        StablePoolUpdateParams::RemoveRateProvider { asset_info } => {
+            if !RATE_PROVIDERS.has(deps.storage, asset_info.to_string()) {
+                return Err(ContractError::InvalidAsset(asset_info.to_string()));
+            }
+            RATE_PROVIDERS.remove(deps.storage, asset_info.to_string());
+            CACHED_RATES.remove(deps.storage, asset_info.to_string());
+
+            response.attributes.extend([
+                attr("action", "remove_rate_provider"),
+                attr("asset_info", asset_info.to_string()),
+            ]);
+        }
     }
 
     Ok(response)
@@ -1227,11 +1606,19 @@ fn query_compute_d(deps: Deps, env: Env) -> StdResult<Uint128> {
     let config = CONFIG.load(deps.storage)?;
 
     let amp = compute_current_amp(&config, &env)?;
+    let rates = get_rates(
+        deps.querier,
+        deps.storage,
+        &env,
+        &config.pair_info.asset_infos,
+    )
+    .map_err(|err| StdError::generic_err(format!("{err}")))?;
     let pools = config
         .pair_info
         .query_pools_decimal(&deps.querier, env.contract.address, &config.factory_addr)?
         .into_iter()
-        .map(|pool| pool.amount)
+        .zip(rates)
+        .map(|(pool, rate)| pool.amount * rate)
         .collect::<Vec<_>>();
 
     compute_d(amp, &pools)
@@ -1,6 +1,9 @@
-use cosmwasm_std::{CheckedMultiplyRatioError, ConversionOverflowError, OverflowError, StdError};
+use cosmwasm_std::{
+    CheckedMultiplyRatioError, ConversionOverflowError, OverflowError, StdError, Uint128,
+};
 use thiserror::Error;
 
+use astroport::pair_utils::PairUtilsError;
 use astroport::{asset::MINIMUM_LIQUIDITY_AMOUNT, pair::MAX_FEE_SHARE_BPS};
 use astroport_circular_buffer::error::BufferError;
 
@@ -95,6 +98,27 @@ pub enum ContractError {
         MAX_FEE_SHARE_BPS
     )]
     FeeShareOutOfBounds {},
+
+    #[error("Operation non supported")]
+    NonSupported {},
+
+    #[error("Slippage is more than expected: received {0}, expected at least {1}")]
+    WithdrawSlippageViolation(Uint128, Uint128),
+
+    #[error("Swaps are paused for this pair")]
+    SwapsPaused {},
+
+    #[error("Liquidity provision is paused for this pair")]
+    ProvidePaused {},
+
+    #[error("Rate provider max staleness must be greater than 0")]
+    InvalidMaxStaleness {},
+
+    #[error("Rate provider returned zero rate for {0}")]
+    InvalidRate(String),
+
+    #[error("Rate of {0} is stale")]
+    StaleRate(String),
 }
 
 impl From<OverflowError> for ContractError {
@@ -108,3 +132,16 @@ impl From<ConversionOverflowError> for ContractError {
         StdError::from(o).into()
     }
 }
+
+impl From<PairUtilsError> for ContractError {
+    fn from(err: PairUtilsError) -> Self {
+        match err {
+            PairUtilsError::Std(err) => ContractError::Std(err),
+            PairUtilsError::AssetMismatch {} => ContractError::AssetMismatch {},
+            PairUtilsError::AutoStakeError {} => ContractError::AutoStakeError {},
+            PairUtilsError::InvalidAsset(asset) => ContractError::InvalidAsset(asset),
+            PairUtilsError::VariableAssetMissed {} => ContractError::VariableAssetMissed {},
+            PairUtilsError::SameAssets {} => ContractError::SameAssets {},
+        }
+    }
+}
@@ -2,8 +2,8 @@ use astroport::factory::QueryMsg::{Config, FeeInfo};
 use astroport::factory::{Config as FactoryConfig, ConfigResponse, FeeInfoResponse};
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    from_binary, from_slice, to_binary, Addr, Coin, Empty, OwnedDeps, Querier, QuerierResult,
-    QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
+    from_binary, from_slice, to_binary, Addr, Binary, Coin, ContractResult, Empty, OwnedDeps,
+    Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
 };
 use cw20::{BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 use std::collections::HashMap;
@@ -97,6 +97,7 @@ impl WasmMockQuerier {
                                 generator_address: None,
                                 whitelist_code_id: 0,
                                 coin_registry_address: Addr::unchecked("coin_registry"),
+                                guardian: None,
                             })
                             .into(),
                         ),
@@ -164,13 +165,15 @@ impl WasmMockQuerier {
                                 generator_address: None,
                                 whitelist_code_id: 0,
                                 coin_registry_address: Addr::unchecked("coin_registry"),
+                                guardian: None,
                             })
                             .into(),
                         )
                     } else if key.as_slice() == b"pairs_to_migrate".as_slice() {
                         SystemResult::Ok(to_binary(&Vec::<Addr>::new()).into())
                     } else {
-                        panic!("DO NOT ENTER HERE");
+                        // Pause statuses are not set
+                        SystemResult::Ok(ContractResult::Ok(Binary::default()))
                     }
                 } else if contract_addr == "coin_registry" {
                     SystemResult::Ok(to_binary(&6).into())
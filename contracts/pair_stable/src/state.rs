@@ -4,7 +4,7 @@ use astroport::observation::Observation;
 use astroport::pair::FeeShareConfig;
 use astroport_circular_buffer::CircularBuffer;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, DepsMut, StdResult, Storage};
+use cosmwasm_std::{Addr, Decimal256, DepsMut, StdResult, Storage};
 use cw_storage_plus::{Item, Map};
 
 /// This structure stores the main stableswap pair parameters.
@@ -41,6 +41,32 @@ pub const CONFIG: Item<Config> = Item::new("config");
 /// Stores map of AssetInfo (as String) -> precision
 pub const PRECISIONS: Map<String, u8> = Map::new("precisions");
 
+/// This structure stores the rate provider of a pool asset.
+#[cw_serde]
+pub struct RateProviderInfo {
+    /// The asset scaled by the rate
+    pub asset_info: AssetInfo,
+    /// The contract which is queried for the current rate
+    pub provider_addr: Addr,
+    /// The maximum age (in seconds) of the rate reported by the provider
+    pub max_staleness: u64,
+}
+
+/// This structure stores a rate fetched from a rate provider.
+#[cw_serde]
+pub struct CachedRate {
+    /// The rate reported by the provider
+    pub rate: Decimal256,
+    /// The block height when the rate was fetched
+    pub block_height: u64,
+}
+
+/// Stores map of AssetInfo (as String) -> rate provider
+pub const RATE_PROVIDERS: Map<String, RateProviderInfo> = Map::new("rate_providers");
+
+/// Stores map of AssetInfo (as String) -> the rate fetched in the latest block the pool was used
+pub const CACHED_RATES: Map<String, CachedRate> = Map::new("cached_rates");
+
 /// Stores the latest contract ownership transfer proposal
 pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
 
@@ -4,8 +4,8 @@ use std::str::FromStr;
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
     attr, coin, from_binary, to_binary, Addr, BankMsg, BlockInfo, Coin, CosmosMsg, Decimal,
-    DepsMut, Env, Reply, ReplyOn, Response, SubMsg, SubMsgResponse, SubMsgResult, Timestamp,
-    Uint128, WasmMsg,
+    Decimal256, DepsMut, Env, Reply, ReplyOn, Response, SubMsg, SubMsgResponse, SubMsgResult,
+    Timestamp, Uint128, WasmMsg,
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg, MinterResponse};
 use itertools::Itertools;
@@ -31,7 +31,8 @@ use crate::contract::{
 use crate::error::ContractError;
 use crate::mock_querier::mock_dependencies;
 use crate::state::{CONFIG, OBSERVATIONS};
-use crate::utils::{compute_swap, select_pools};
+use crate::utils::compute_swap;
+use astroport::pair_utils::select_pools;
 
 #[derive(Clone, PartialEq, Message)]
 struct MsgInstantiateContractResponse {
@@ -87,6 +88,7 @@ fn proper_initialization() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -177,6 +179,7 @@ fn provide_liquidity() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -559,6 +562,7 @@ fn withdraw_liquidity() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -686,6 +690,7 @@ fn try_native_to_token() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -843,6 +848,7 @@ fn try_token_to_native() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -1065,6 +1071,7 @@ fn test_query_pool() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -1136,6 +1143,7 @@ fn test_query_share() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -1334,7 +1342,11 @@ proptest! {
             factory_addr: String::from("factory"),
             asset_infos: vec![offer_asset.info.clone(), ask_asset.clone()],
             token_code_id: 10u64,
-            init_params: Some(to_binary(&StablePoolParams { amp, owner: None }).unwrap()),
+            init_params: Some(to_binary(&StablePoolParams {
+                amp,
+                owner: None,
+                rate_providers: None,
+            }).unwrap()),
         };
 
         let env = mock_env();
@@ -1358,6 +1370,7 @@ proptest! {
             &offer_pool,
             &ask_pool,
             &pools,
+            &[Decimal256::one(); 2],
         )
         .unwrap();
 
@@ -1424,6 +1437,7 @@ fn update_owner() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: Some(owner.to_owned()),
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -1,23 +1,18 @@
 use std::cmp::Ordering;
 
 use cosmwasm_std::{
-    to_binary, wasm_execute, Addr, Api, CosmosMsg, Decimal, Env, QuerierWrapper, StdResult,
-    Storage, Uint128, Uint64,
+    Addr, Api, Decimal, Decimal256, Env, QuerierWrapper, StdResult, Storage, Uint128, Uint64,
 };
-use cw20::Cw20ExecuteMsg;
 use itertools::Itertools;
 
 use astroport::asset::{Asset, AssetInfo, Decimal256Ext, DecimalAsset};
-use astroport::observation::{
-    safe_sma_buffer_not_full, safe_sma_calculation, Observation, PrecommitObservation,
-};
-use astroport::querier::query_factory_config;
-use astroport_circular_buffer::error::BufferResult;
-use astroport_circular_buffer::BufferManager;
+use astroport::pair::{RateProvider, RateProviderQueryMsg, RateResponse};
 
 use crate::error::ContractError;
 use crate::math::calc_y;
-use crate::state::{get_precision, Config, OBSERVATIONS};
+use crate::state::{
+    get_precision, CachedRate, Config, RateProviderInfo, CACHED_RATES, RATE_PROVIDERS,
+};
 
 /// Helper function to check if the given asset infos are valid.
 pub(crate) fn check_asset_infos(
@@ -54,58 +49,99 @@ pub(crate) fn check_cw20_in_pool(config: &Config, cw20_sender: &Addr) -> Result<
     Err(ContractError::Unauthorized {})
 }
 
-/// Select offer and ask pools based on given offer and ask infos.
-/// This function works with pools with up to 5 assets. Returns (offer_pool, ask_pool) in case of success.
-/// If it is impossible to define offer and ask pools, returns [`ContractError`].
+/// Validates a rate provider of a pool asset.
 ///
-/// * **offer_asset_info** - asset info of the offer asset.
+/// * **asset_infos** - assets of the pool.
 ///
-/// * **ask_asset_info** - asset info of the ask asset.
+/// * **rate_provider** - rate provider to validate.
+pub(crate) fn validate_rate_provider(
+    api: &dyn Api,
+    asset_infos: &[AssetInfo],
+    rate_provider: &RateProvider,
+) -> Result<RateProviderInfo, ContractError> {
+    if !asset_infos.contains(&rate_provider.asset_info) {
+        return Err(ContractError::InvalidAsset(
+            rate_provider.asset_info.to_string(),
+        ));
+    }
+
+    if rate_provider.max_staleness == 0 {
+        return Err(ContractError::InvalidMaxStaleness {});
+    }
+
+    Ok(RateProviderInfo {
+        asset_info: rate_provider.asset_info.clone(),
+        provider_addr: api.addr_validate(&rate_provider.provider_addr)?,
+        max_staleness: rate_provider.max_staleness,
+    })
+}
+
+/// Returns the rates of the given assets in the same order. Assets without a rate provider have a rate of 1.
+/// A rate fetched in the current block is reused, otherwise the rate provider is queried.
 ///
-/// * **pools** - list of pools.
-pub(crate) fn select_pools(
-    offer_asset_info: Option<&AssetInfo>,
-    ask_asset_info: Option<&AssetInfo>,
-    pools: &[DecimalAsset],
-) -> Result<(DecimalAsset, DecimalAsset), ContractError> {
-    if pools.len() == 2 {
-        match (offer_asset_info, ask_asset_info) {
-            (Some(offer_asset_info), _) => {
-                let (offer_ind, offer_pool) = pools
-                    .iter()
-                    .find_position(|pool| pool.info.eq(offer_asset_info))
-                    .ok_or(ContractError::AssetMismatch {})?;
-                Ok((offer_pool.clone(), pools[(offer_ind + 1) % 2].clone()))
+/// * **asset_infos** - assets to return the rates for.
+pub(crate) fn get_rates(
+    querier: QuerierWrapper,
+    storage: &dyn Storage,
+    env: &Env,
+    asset_infos: &[AssetInfo],
+) -> Result<Vec<Decimal256>, ContractError> {
+    asset_infos
+        .iter()
+        .map(|asset_info| {
+            let provider = match RATE_PROVIDERS.may_load(storage, asset_info.to_string())? {
+                Some(provider) => provider,
+                None => return Ok(Decimal256::one()),
+            };
+
+            if let Some(cached) = CACHED_RATES.may_load(storage, asset_info.to_string())? {
+                if cached.block_height == env.block.height {
+                    return Ok(cached.rate);
+                }
             }
-            (_, Some(ask_asset_info)) => {
-                let (ask_ind, ask_pool) = pools
-                    .iter()
-                    .find_position(|pool| pool.info.eq(ask_asset_info))
-                    .ok_or(ContractError::AssetMismatch {})?;
-                Ok((pools[(ask_ind + 1) % 2].clone(), ask_pool.clone()))
+
+            let response: RateResponse = querier
+                .query_wasm_smart(&provider.provider_addr, &RateProviderQueryMsg::Rate {})?;
+
+            if response.rate.is_zero() {
+                return Err(ContractError::InvalidRate(asset_info.to_string()));
             }
-            _ => Err(ContractError::VariableAssetMissed {}), // Should always be unreachable
-        }
-    } else if let (Some(offer_asset_info), Some(ask_asset_info)) =
-        (offer_asset_info, ask_asset_info)
-    {
-        if ask_asset_info.eq(offer_asset_info) {
-            return Err(ContractError::SameAssets {});
-        }
 
-        let offer_pool = pools
-            .iter()
-            .find(|pool| pool.info.eq(offer_asset_info))
-            .ok_or(ContractError::AssetMismatch {})?;
-        let ask_pool = pools
-            .iter()
-            .find(|pool| pool.info.eq(ask_asset_info))
-            .ok_or(ContractError::AssetMismatch {})?;
+            if env.block.time.seconds() > response.updated_at.saturating_add(provider.max_staleness)
+            {
+                return Err(ContractError::StaleRate(asset_info.to_string()));
+            }
 
-        Ok((offer_pool.clone(), ask_pool.clone()))
-    } else {
-        Err(ContractError::VariableAssetMissed {}) // Should always be unreachable
+            Ok(Decimal256::new(response.rate.atomics().into()))
+        })
+        .collect()
+}
+
+/// Caches the rates of assets which have a rate provider so they are not queried again in the current block.
+///
+/// * **asset_infos** - assets the rates belong to.
+///
+/// * **rates** - rates in the same order as `asset_infos`.
+pub(crate) fn cache_rates(
+    storage: &mut dyn Storage,
+    env: &Env,
+    asset_infos: &[AssetInfo],
+    rates: &[Decimal256],
+) -> StdResult<()> {
+    for (asset_info, rate) in asset_infos.iter().zip(rates) {
+        if RATE_PROVIDERS.has(storage, asset_info.to_string()) {
+            CACHED_RATES.save(
+                storage,
+                asset_info.to_string(),
+                &CachedRate {
+                    rate: *rate,
+                    block_height: env.block.height,
+                },
+            )?;
+        }
     }
+
+    Ok(())
 }
 
 /// Compute the current pool amplification coefficient (AMP).
@@ -157,68 +193,6 @@ pub(crate) fn adjust_precision(
     })
 }
 
-/// Mint LP tokens for a beneficiary and auto stake the tokens in the Generator contract (if auto staking is specified).
-///
-/// * **recipient** LP token recipient.
-///
-/// * **amount** amount of LP tokens that will be minted for the recipient.
-///
-/// * **auto_stake** whether the newly minted LP tokens will be automatically staked in the Generator on behalf of the recipient.
-pub(crate) fn mint_liquidity_token_message(
-    querier: QuerierWrapper,
-    config: &Config,
-    contract_address: &Addr,
-    recipient: &Addr,
-    amount: Uint128,
-    auto_stake: bool,
-) -> Result<Vec<CosmosMsg>, ContractError> {
-    let lp_token = &config.pair_info.liquidity_token;
-
-    // If no auto-stake - just mint to recipient
-    if !auto_stake {
-        return Ok(vec![wasm_execute(
-            lp_token,
-            &Cw20ExecuteMsg::Mint {
-                recipient: recipient.to_string(),
-                amount,
-            },
-            vec![],
-        )?
-        .into()]);
-    }
-
-    // Mint for the pair contract and stake into the Generator contract
-    let generator = query_factory_config(&querier, &config.factory_addr)?.generator_address;
-
-    if let Some(generator) = generator {
-        Ok(vec![
-            wasm_execute(
-                lp_token,
-                &Cw20ExecuteMsg::Mint {
-                    recipient: contract_address.to_string(),
-                    amount,
-                },
-                vec![],
-            )?
-            .into(),
-            wasm_execute(
-                lp_token,
-                &Cw20ExecuteMsg::Send {
-                    contract: generator.to_string(),
-                    amount,
-                    msg: to_binary(&astroport::generator::Cw20HookMsg::DepositFor(
-                        recipient.to_string(),
-                    ))?,
-                },
-                vec![],
-            )?
-            .into(),
-        ])
-    } else {
-        Err(ContractError::AutoStakeError {})
-    }
-}
-
 /// Return the amount of tokens that a specific amount of LP tokens would withdraw.
 ///
 /// * **pools** array with assets available in the pool.
@@ -260,6 +234,9 @@ pub(crate) struct SwapResult {
 /// * **ask_pool** asked asset.
 ///
 /// * **pools** array with assets available in the pool.
+///
+/// * **rates** rates of the pool assets in the same order as `pools`.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn compute_swap(
     storage: &dyn Storage,
     env: &Env,
@@ -268,23 +245,41 @@ pub(crate) fn compute_swap(
     offer_pool: &DecimalAsset,
     ask_pool: &DecimalAsset,
     pools: &[DecimalAsset],
+    rates: &[Decimal256],
 ) -> Result<SwapResult, ContractError> {
     let token_precision = get_precision(storage, &ask_pool.info)?;
-    let xp = pools.iter().map(|p| p.amount).collect_vec();
+    let rate_of = |asset_info: &AssetInfo| {
+        pools
+            .iter()
+            .position(|pool| pool.info.eq(asset_info))
+            .map(|ind| rates[ind])
+            .ok_or_else(|| ContractError::InvalidAsset(asset_info.to_string()))
+    };
+    let offer_rate = rate_of(&offer_pool.info)?;
+    let ask_rate = rate_of(&ask_pool.info)?;
+
+    // Balances are scaled by rates thus the curve works with the values of the assets
+    let xp = pools
+        .iter()
+        .zip(rates)
+        .map(|(pool, &rate)| pool.amount * rate)
+        .collect_vec();
 
     let new_ask_pool = calc_y(
         compute_current_amp(config, env)?,
-        offer_pool.amount + offer_asset.amount,
+        (offer_pool.amount + offer_asset.amount) * offer_rate,
         &xp,
         token_precision,
     )?;
 
-    let return_amount = ask_pool.amount.to_uint128_with_precision(token_precision)? - new_ask_pool;
-    let offer_asset_amount = offer_asset
-        .amount
+    let return_amount =
+        (ask_pool.amount * ask_rate).to_uint128_with_precision(token_precision)? - new_ask_pool;
+    let return_amount = (Decimal256::with_precision(return_amount, token_precision)? / ask_rate)
         .to_uint128_with_precision(token_precision)?;
+    let offer_asset_amount =
+        (offer_asset.amount * offer_rate / ask_rate).to_uint128_with_precision(token_precision)?;
 
-    // We consider swap rate 1:1 in stable swap thus any difference is considered as spread.
+    // We consider swap rate 1:1 (adjusted by rates) in stable swap thus any difference is considered as spread.
     let spread_amount = offer_asset_amount.saturating_sub(return_amount);
 
     Ok(SwapResult {
@@ -292,84 +287,3 @@ pub(crate) fn compute_swap(
         spread_amount,
     })
 }
-
-/// Calculate and save price moving average
-pub fn accumulate_swap_sizes(storage: &mut dyn Storage, env: &Env) -> BufferResult<()> {
-    if let Some(PrecommitObservation {
-        base_amount,
-        quote_amount,
-        precommit_ts,
-    }) = PrecommitObservation::may_load(storage)?
-    {
-        let mut buffer = BufferManager::new(storage, OBSERVATIONS)?;
-        let observed_price = Decimal::from_ratio(base_amount, quote_amount);
-
-        let new_observation;
-        if let Some(last_obs) = buffer.read_last(storage)? {
-            // Skip saving observation if it has been already saved
-            if last_obs.ts < precommit_ts {
-                // Since this is circular buffer the next index contains the oldest value
-                let count = buffer.capacity();
-                if let Some(oldest_obs) = buffer.read_single(storage, buffer.head() + 1)? {
-                    let price_sma = safe_sma_calculation(
-                        last_obs.price_sma,
-                        oldest_obs.price,
-                        count,
-                        observed_price,
-                    )?;
-                    new_observation = Observation {
-                        ts: precommit_ts,
-                        price: observed_price,
-                        price_sma,
-                    };
-                } else {
-                    // Buffer is not full yet
-                    let count = buffer.head();
-                    let price_sma =
-                        safe_sma_buffer_not_full(last_obs.price_sma, count, observed_price)?;
-                    new_observation = Observation {
-                        ts: precommit_ts,
-                        price: observed_price,
-                        price_sma,
-                    };
-                }
-
-                buffer.instant_push(storage, &new_observation)?
-            }
-        } else {
-            // Buffer is empty
-            if env.block.time.seconds() > precommit_ts {
-                new_observation = Observation {
-                    ts: precommit_ts,
-                    price: observed_price,
-                    price_sma: observed_price,
-                };
-
-                buffer.instant_push(storage, &new_observation)?
-            }
-        }
-    }
-
-    Ok(())
-}
-
-/// Internal function to determine which asset is base one, which is quote one
-pub(crate) fn determine_base_quote_amount(
-    pools: &[DecimalAsset],
-    offer_asset: &Asset,
-    return_amount: Uint128,
-) -> Result<(Uint128, Uint128), ContractError> {
-    let offer_index = pools
-        .iter()
-        .find_position(|asset| asset.info == offer_asset.info)
-        .ok_or_else(|| ContractError::InvalidAsset(offer_asset.info.to_string()))?
-        .0;
-
-    let (base_amount, quote_amount) = if offer_index == 0 {
-        (offer_asset.amount, return_amount)
-    } else {
-        (return_amount, offer_asset.amount)
-    };
-
-    Ok((base_amount, quote_amount))
-}
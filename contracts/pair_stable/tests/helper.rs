@@ -6,7 +6,10 @@ use std::str::FromStr;
 
 use anyhow::Result as AnyResult;
 use astroport_mocks::cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
-use cosmwasm_std::{coin, to_binary, Addr, Coin, Decimal, Empty, StdResult, Uint128};
+use cosmwasm_std::{
+    coin, to_binary, to_vec, Addr, Binary, Coin, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult, Uint128,
+};
 use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
 use derivative::Derivative;
 use itertools::Itertools;
@@ -15,8 +18,9 @@ use astroport::asset::{native_asset_info, token_asset_info, Asset, AssetInfo, Pa
 use astroport::factory::{PairConfig, PairType};
 use astroport::observation::OracleObservation;
 use astroport::pair::{
-    CumulativePricesResponse, Cw20HookMsg, ExecuteMsg, QueryMsg, ReverseSimulationResponse,
-    SimulationResponse, StablePoolParams,
+    CumulativePricesResponse, Cw20HookMsg, ExecuteMsg, QueryMsg, RateProvider,
+    RateProviderQueryMsg, RateResponse, ReverseSimulationResponse, SimulationResponse,
+    StablePoolParams, StablePoolUpdateParams,
 };
 pub const NATIVE_TOKEN_PRECISION: u8 = 6;
 use astroport_pair_stable::contract::{execute, instantiate, query, reply};
@@ -103,6 +107,29 @@ fn store_coin_registry_code() -> Box<dyn Contract<Empty>> {
     ))
 }
 
+fn save_rate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: RateResponse,
+) -> StdResult<Response> {
+    deps.storage.set(b"rate", &to_vec(&msg)?);
+    Ok(Response::new())
+}
+
+fn query_rate(deps: Deps, _env: Env, _msg: RateProviderQueryMsg) -> StdResult<Binary> {
+    deps.storage
+        .get(b"rate")
+        .map(Binary)
+        .ok_or_else(|| StdError::generic_err("Rate is not set"))
+}
+
+fn rate_provider_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        save_rate, save_rate, query_rate,
+    ))
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct Helper {
@@ -188,6 +215,7 @@ impl Helper {
                 pair_type: PairType::Stable {},
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             }],
             token_code_id,
             generator_address: None,
@@ -213,7 +241,14 @@ impl Helper {
         let init_pair_msg = astroport::factory::ExecuteMsg::CreatePair {
             pair_type: PairType::Stable {},
             asset_infos: asset_infos.clone(),
-            init_params: Some(to_binary(&StablePoolParams { amp, owner: None }).unwrap()),
+            init_params: Some(
+                to_binary(&StablePoolParams {
+                    amp,
+                    owner: None,
+                    rate_providers: None,
+                })
+                .unwrap(),
+            ),
         };
 
         app.execute_contract(owner.clone(), factory.clone(), &init_pair_msg, &[])?;
@@ -265,6 +300,27 @@ impl Helper {
             .execute_contract(sender.clone(), self.lp_token.clone(), &msg, &[])
     }
 
+    pub fn withdraw_single_asset(
+        &mut self,
+        sender: &Addr,
+        amount: u128,
+        ask_asset_info: AssetInfo,
+        min_amount_out: Option<u128>,
+    ) -> AnyResult<AppResponse> {
+        let msg = Cw20ExecuteMsg::Send {
+            contract: self.pair_addr.to_string(),
+            amount: Uint128::from(amount),
+            msg: to_binary(&Cw20HookMsg::WithdrawSingleAsset {
+                ask_asset_info,
+                min_amount_out: min_amount_out.map(Uint128::from),
+            })
+            .unwrap(),
+        };
+
+        self.app
+            .execute_contract(sender.clone(), self.lp_token.clone(), &msg, &[])
+    }
+
     pub fn swap(
         &mut self,
         sender: &Addr,
@@ -418,6 +474,58 @@ impl Helper {
         }
     }
 
+    /// Instantiates a mock rate provider which reports `rate` updated at the current block time.
+    pub fn init_rate_provider(&mut self, rate: Decimal) -> Addr {
+        let code_id = self.app.store_code(rate_provider_contract());
+        let updated_at = self.app.block_info().time.seconds();
+        self.app
+            .instantiate_contract(
+                code_id,
+                self.owner.clone(),
+                &RateResponse { rate, updated_at },
+                &[],
+                "Rate provider",
+                None,
+            )
+            .unwrap()
+    }
+
+    /// Updates the rate reported by the mock rate provider at the current block time.
+    pub fn update_rate(&mut self, provider: &Addr, rate: Decimal) {
+        let updated_at = self.app.block_info().time.seconds();
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                provider.clone(),
+                &RateResponse { rate, updated_at },
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn set_rate_provider(
+        &mut self,
+        asset_info: &AssetInfo,
+        provider: &Addr,
+        max_staleness: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            self.owner.clone(),
+            self.pair_addr.clone(),
+            &ExecuteMsg::UpdateConfig {
+                params: to_binary(&StablePoolUpdateParams::SetRateProvider {
+                    rate_provider: RateProvider {
+                        asset_info: asset_info.clone(),
+                        provider_addr: provider.to_string(),
+                        max_staleness,
+                    },
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+    }
+
     pub fn observe_price(&self, seconds_ago: u64) -> StdResult<Decimal> {
         self.app
             .wrap()
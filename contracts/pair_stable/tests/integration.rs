@@ -129,6 +129,7 @@ fn instantiate_pair(mut router: &mut App, owner: &Addr) -> Addr {
             pair_type: PairType::Stable {},
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id: token_contract_code_id,
         generator_address: None,
@@ -192,6 +193,7 @@ fn instantiate_pair(mut router: &mut App, owner: &Addr) -> Addr {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -492,6 +494,7 @@ fn provide_lp_for_single_token() {
             pair_type: PairType::Stable {},
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id,
         generator_address: Some(String::from("generator")),
@@ -525,6 +528,7 @@ fn provide_lp_for_single_token() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -826,6 +830,7 @@ fn test_compatibility_of_tokens_with_different_precision() {
             pair_type: PairType::Stable {},
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id,
         generator_address: Some(String::from("generator")),
@@ -859,6 +864,7 @@ fn test_compatibility_of_tokens_with_different_precision() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -1081,6 +1087,7 @@ fn update_pair_config() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -1328,6 +1335,7 @@ fn enable_disable_fee_sharing() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -1903,6 +1911,7 @@ fn test_fee_share(
             pair_type: PairType::Stable {},
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id,
         generator_address: Some(String::from("generator")),
@@ -1936,6 +1945,7 @@ fn test_fee_share(
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: Some(owner.to_string()),
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -1,11 +1,14 @@
 #![cfg(not(tarpaulin_include))]
 
-use cosmwasm_std::{Addr, Decimal, StdError};
+use cosmwasm_std::{from_binary, Addr, Decimal, StdError};
 use std::str::FromStr;
 
 use astroport::asset::AssetInfoExt;
 use astroport::cosmwasm_ext::AbsDiff;
+use astroport::factory::{self, PauseStatus, PauseTarget};
 use astroport::observation::OracleObservation;
+use astroport::pair::{ConfigResponse, QueryMsg, RateProvider, StablePoolConfig};
+use astroport_mocks::cw_multi_test::Executor;
 use astroport_pair_stable::error::ContractError;
 use helper::AppExtension;
 
@@ -516,3 +519,202 @@ fn check_pool_prices() {
     );
     helper.app.next_block(10);
 }
+
+#[test]
+fn withdraw_single_asset() {
+    let owner = Addr::unchecked("owner");
+
+    let test_coins = vec![TestCoin::native("uluna"), TestCoin::cw20("USDC")];
+    let new_helper = || {
+        let mut helper = Helper::new(&owner, test_coins.clone(), 100u64, None).unwrap();
+        let assets = vec![
+            helper.assets[&test_coins[0]].with_balance(100_000_000000u128),
+            helper.assets[&test_coins[1]].with_balance(100_000_000000u128),
+        ];
+        helper.provide_liquidity(&owner, &assets).unwrap();
+        helper
+    };
+    let withdraw_amount = 10_000_000000u128;
+
+    // Balanced withdrawal followed by a swap of the USDC share for reference
+    let mut helper = new_helper();
+    let luna_before = helper.coin_balance(&test_coins[0], &owner);
+    let usdc_before = helper.coin_balance(&test_coins[1], &owner);
+    helper
+        .withdraw_liquidity(&owner, withdraw_amount, vec![])
+        .unwrap();
+    let usdc_share = helper.assets[&test_coins[1]]
+        .with_balance(helper.coin_balance(&test_coins[1], &owner) - usdc_before);
+    helper
+        .swap(
+            &owner,
+            &usdc_share,
+            Some(helper.assets[&test_coins[0]].clone()),
+        )
+        .unwrap();
+    let expected_amount = helper.coin_balance(&test_coins[0], &owner) - luna_before;
+
+    let mut helper = new_helper();
+    let err = helper
+        .withdraw_single_asset(
+            &owner,
+            withdraw_amount,
+            helper.assets[&test_coins[0]].clone(),
+            Some(expected_amount + 1),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!(
+            "Slippage is more than expected: received {}, expected at least {}",
+            expected_amount,
+            expected_amount + 1
+        )
+    );
+
+    let luna_before = helper.coin_balance(&test_coins[0], &owner);
+    let usdc_before = helper.coin_balance(&test_coins[1], &owner);
+    helper
+        .withdraw_single_asset(
+            &owner,
+            withdraw_amount,
+            helper.assets[&test_coins[0]].clone(),
+            Some(expected_amount),
+        )
+        .unwrap();
+    assert_eq!(
+        expected_amount,
+        helper.coin_balance(&test_coins[0], &owner) - luna_before
+    );
+    assert_eq!(usdc_before, helper.coin_balance(&test_coins[1], &owner));
+}
+
+#[test]
+fn withdraw_single_asset_is_blocked_while_swaps_paused() {
+    let owner = Addr::unchecked("owner");
+
+    let test_coins = vec![TestCoin::native("uluna"), TestCoin::cw20("USDC")];
+    let mut helper = Helper::new(&owner, test_coins.clone(), 100u64, None).unwrap();
+    let assets = vec![
+        helper.assets[&test_coins[0]].with_balance(100_000_000000u128),
+        helper.assets[&test_coins[1]].with_balance(100_000_000000u128),
+    ];
+    helper.provide_liquidity(&owner, &assets).unwrap();
+
+    helper
+        .app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &factory::ExecuteMsg::UpdatePauseStatus {
+                target: PauseTarget::Pair(helper.pair_addr.to_string()),
+                status: PauseStatus {
+                    swaps_paused: true,
+                    provide_paused: false,
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Single asset withdrawal swaps internally
+    let err = helper
+        .withdraw_single_asset(
+            &owner,
+            10_000_000000u128,
+            helper.assets[&test_coins[0]].clone(),
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::SwapsPaused {}.to_string()
+    );
+
+    // Balanced withdrawal is never paused
+    let luna_before = helper.coin_balance(&test_coins[0], &owner);
+    let usdc_before = helper.coin_balance(&test_coins[1], &owner);
+    helper
+        .withdraw_liquidity(&owner, 10_000_000000u128, vec![])
+        .unwrap();
+    assert!(helper.coin_balance(&test_coins[0], &owner) > luna_before);
+    assert!(helper.coin_balance(&test_coins[1], &owner) > usdc_before);
+}
+
+#[test]
+fn rate_provider_scaled_assets() {
+    let owner = Addr::unchecked("owner");
+
+    let test_coins = vec![TestCoin::native("uluna"), TestCoin::cw20("stLUNA")];
+    let mut helper = Helper::new(&owner, test_coins.clone(), 100u64, Some(0u16)).unwrap();
+    let stluna = helper.assets[&test_coins[1]].clone();
+
+    let provider = helper.init_rate_provider(Decimal::percent(110));
+    let err = helper.set_rate_provider(&stluna, &provider, 0).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidMaxStaleness {}.to_string()
+    );
+    helper.set_rate_provider(&stluna, &provider, 3600).unwrap();
+
+    let config: ConfigResponse = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&helper.pair_addr, &QueryMsg::Config {})
+        .unwrap();
+    let params: StablePoolConfig = from_binary(&config.params.unwrap()).unwrap();
+    assert_eq!(
+        params.rate_providers,
+        vec![RateProvider {
+            asset_info: stluna.clone(),
+            provider_addr: provider.to_string(),
+            max_staleness: 3600,
+        }]
+    );
+
+    // The pool is balanced by value as 1 stLUNA is worth 1.1 LUNA
+    let assets = vec![
+        helper.assets[&test_coins[0]].with_balance(1_100_000000u128),
+        stluna.with_balance(1_000_000000u128),
+    ];
+    helper.provide_liquidity(&owner, &assets).unwrap();
+
+    let offer_asset = stluna.with_balance(1_000000u128);
+    let sim = helper.simulate_swap(&offer_asset, None).unwrap();
+    assert!((1_099_000..=1_100_001).contains(&sim.return_amount.u128()));
+
+    let reverse_sim = helper
+        .simulate_reverse_swap(
+            &helper.assets[&test_coins[0]].with_balance(1_100000u128),
+            None,
+        )
+        .unwrap();
+    assert!((999_999..=1_001_000).contains(&reverse_sim.offer_amount.u128()));
+
+    let luna_before = helper.coin_balance(&test_coins[0], &owner);
+    helper.swap(&owner, &offer_asset, None).unwrap();
+    assert_eq!(
+        helper.coin_balance(&test_coins[0], &owner) - luna_before,
+        sim.return_amount.u128()
+    );
+
+    // The rate is cached within the block
+    helper.update_rate(&provider, Decimal::percent(120));
+    let sim = helper.simulate_swap(&offer_asset, None).unwrap();
+    assert!((1_099_000..=1_100_001).contains(&sim.return_amount.u128()));
+
+    helper.app.next_block(10);
+    let sim = helper.simulate_swap(&offer_asset, None).unwrap();
+    assert!(sim.return_amount.u128() > 1_150_000);
+
+    // The rate was updated more than max staleness ago
+    helper.app.next_block(3600);
+    let err = helper.swap(&owner, &offer_asset, None).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::StaleRate(stluna.to_string()).to_string()
+    );
+
+    helper.update_rate(&provider, Decimal::percent(120));
+    helper.swap(&owner, &offer_asset, None).unwrap();
+}
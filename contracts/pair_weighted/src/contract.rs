@@ -0,0 +1,1122 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::vec;
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    attr, from_binary, to_binary, wasm_execute, wasm_instantiate, Addr, Binary, CosmosMsg, Decimal,
+    Decimal256, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, Response, StdError,
+    StdResult, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg, MinterResponse};
+use cw_utils::parse_instantiate_response_data;
+use itertools::Itertools;
+
+use astroport::asset::{
+    addr_opt_validate, check_swap_parameters, format_lp_token_name, Asset, AssetInfo, CoinsExt,
+    Decimal256Ext, DecimalAsset, PairInfo, MINIMUM_LIQUIDITY_AMOUNT,
+};
+use astroport::cosmwasm_ext::IntegerToDecimal;
+use astroport::factory::PairType;
+use astroport::observation::{
+    accumulate_swap_sizes, query_observation, query_observation_twap, PrecommitObservation,
+    OBSERVATIONS_SIZE,
+};
+use astroport::pair::{
+    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, PoolResponse, QueryMsg,
+    ReverseSimulationResponse, SimulationResponse, WeightedPoolConfig, WeightedPoolParams,
+    DEFAULT_SLIPPAGE, MAX_ALLOWED_SLIPPAGE, MIN_TRADE_SIZE,
+};
+use astroport::pair_utils::{
+    determine_base_quote_amount, mint_liquidity_token_message, select_pools,
+};
+use astroport::querier::{query_factory_config, query_fee_info, query_pause_status, query_supply};
+use astroport::token::InstantiateMsg as TokenInstantiateMsg;
+use astroport::DecimalCheckedOps;
+use astroport_circular_buffer::BufferManager;
+
+use crate::error::ContractError;
+use crate::math::{
+    calc_in_given_out, calc_invariant_ratio, calc_weighted_product, MAX_ASSETS, MIN_WEIGHT,
+    WEIGHTS_SUM_TOLERANCE,
+};
+use crate::state::{get_precision, store_precisions, Config, CONFIG, OBSERVATIONS};
+use crate::utils::{
+    check_asset_infos, check_assets, check_cw20_in_pool, compute_swap, get_share_in_assets,
+    get_weight, SwapResult,
+};
+
+/// Contract name that is used for migration.
+const CONTRACT_NAME: &str = "astroport-pair-weighted";
+/// Contract version that is used for migration.
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// A `reply` call code ID of sub-message.
+const INSTANTIATE_TOKEN_REPLY_ID: u64 = 1;
+/// The name of the custom pair type under which weighted pairs are registered in the factory.
+pub const WEIGHTED_PAIR_TYPE: &str = "weighted";
+
+/// Creates a new contract with the specified parameters in [`InstantiateMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    check_asset_infos(deps.api, &msg.asset_infos)?;
+
+    if !(2..=MAX_ASSETS).contains(&msg.asset_infos.len()) {
+        return Err(ContractError::InvalidNumberOfAssets {});
+    }
+
+    let params: WeightedPoolParams = msg
+        .init_params
+        .map(|params| from_binary(&params))
+        .transpose()?
+        .ok_or(ContractError::InitParamsNotFound {})?;
+
+    let weights_sum = params.weights.iter().sum::<Decimal>();
+    let weights_sum_deviation = if weights_sum > Decimal::one() {
+        weights_sum - Decimal::one()
+    } else {
+        Decimal::one() - weights_sum
+    };
+    if params.weights.len() != msg.asset_infos.len()
+        || weights_sum_deviation > WEIGHTS_SUM_TOLERANCE
+    {
+        return Err(ContractError::InvalidWeights {});
+    }
+
+    // Rounded weights (e.g. three weights of 0.333333) are scaled to sum up to 1
+    let weights = params
+        .weights
+        .iter()
+        .map(|weight| Decimal::from_ratio(weight.atomics(), weights_sum.atomics()))
+        .collect_vec();
+    if weights.iter().any(|weight| *weight < MIN_WEIGHT) {
+        return Err(ContractError::InvalidWeights {});
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let factory_addr = deps.api.addr_validate(&msg.factory_addr)?;
+    let greatest_precision = store_precisions(deps.branch(), &msg.asset_infos, &factory_addr)?;
+
+    let config = Config {
+        pair_info: PairInfo {
+            contract_addr: env.contract.address.clone(),
+            liquidity_token: Addr::unchecked(""),
+            asset_infos: msg.asset_infos.clone(),
+            pair_type: PairType::Custom(WEIGHTED_PAIR_TYPE.to_string()),
+        },
+        factory_addr,
+        block_time_last: 0,
+        weights,
+        greatest_precision,
+    };
+
+    CONFIG.save(deps.storage, &config)?;
+    BufferManager::init(deps.storage, OBSERVATIONS, OBSERVATIONS_SIZE)?;
+
+    let token_name = format_lp_token_name(&msg.asset_infos, &deps.querier)?;
+
+    // Create LP token
+    let sub_msg = SubMsg::reply_on_success(
+        wasm_instantiate(
+            msg.token_code_id,
+            &TokenInstantiateMsg {
+                name: token_name,
+                symbol: "uLP".to_string(),
+                decimals: greatest_precision,
+                initial_balances: vec![],
+                mint: Some(MinterResponse {
+                    minter: env.contract.address.to_string(),
+                    cap: None,
+                }),
+                marketing: None,
+            },
+            vec![],
+            String::from("Astroport LP token"),
+        )?,
+        INSTANTIATE_TOKEN_REPLY_ID,
+    );
+
+    Ok(Response::new().add_submessage(sub_msg))
+}
+
+/// The entry point to the contract for processing replies from submessages.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg {
+        Reply {
+            id: INSTANTIATE_TOKEN_REPLY_ID,
+            result:
+                SubMsgResult::Ok(SubMsgResponse {
+                    data: Some(data), ..
+                }),
+        } => {
+            let mut config = CONFIG.load(deps.storage)?;
+
+            if config.pair_info.liquidity_token != Addr::unchecked("") {
+                return Err(ContractError::Unauthorized {});
+            }
+
+            let init_response = parse_instantiate_response_data(data.as_slice())
+                .map_err(|e| StdError::generic_err(format!("{e}")))?;
+            config.pair_info.liquidity_token =
+                deps.api.addr_validate(&init_response.contract_address)?;
+            CONFIG.save(deps.storage, &config)?;
+            Ok(Response::new()
+                .add_attribute("liquidity_token_addr", config.pair_info.liquidity_token))
+        }
+        _ => Err(ContractError::FailedToParseReply {}),
+    }
+}
+
+/// Exposes all the execute functions available in the contract.
+///
+/// ## Variants
+/// * **ExecuteMsg::Receive(msg)** Receives a message of type [`Cw20ReceiveMsg`] and processes
+/// it depending on the received template.
+///
+/// * **ExecuteMsg::ProvideLiquidity {
+///             assets,
+///             slippage_tolerance,
+///             auto_stake,
+///             receiver,
+///         }** Provides liquidity in the pair using the specified input parameters.
+///
+/// * **ExecuteMsg::Swap {
+///             offer_asset,
+///             belief_price,
+///             max_spread,
+///             to,
+///         }** Performs an swap using the specified parameters.
+///
+/// Weights are fixed at instantiation, so the pair has no configuration to update or owner to transfer.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
+        ExecuteMsg::ProvideLiquidity {
+            assets,
+            slippage_tolerance,
+            auto_stake,
+            receiver,
+        } => provide_liquidity(
+            deps,
+            env,
+            info,
+            assets,
+            slippage_tolerance,
+            auto_stake,
+            receiver,
+        ),
+        ExecuteMsg::Swap {
+            offer_asset,
+            ask_asset_info,
+            belief_price,
+            max_spread,
+            to,
+        } => {
+            offer_asset.info.check(deps.api)?;
+            if !offer_asset.is_native_token() {
+                return Err(ContractError::Cw20DirectSwap {});
+            }
+            offer_asset.assert_sent_native_token_balance(&info)?;
+
+            let to_addr = addr_opt_validate(deps.api, &to)?;
+
+            swap(
+                deps,
+                env,
+                info.sender,
+                offer_asset,
+                ask_asset_info,
+                belief_price,
+                max_spread,
+                to_addr,
+            )
+        }
+        _ => Err(ContractError::NonSupported {}),
+    }
+}
+
+/// Receives a message of type [`Cw20ReceiveMsg`] and processes it depending on the received template.
+///
+/// * **cw20_msg** is the CW20 receive message to process.
+pub fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    match from_binary(&cw20_msg.msg)? {
+        Cw20HookMsg::Swap {
+            ask_asset_info,
+            belief_price,
+            max_spread,
+            to,
+        } => {
+            let config = CONFIG.load(deps.storage)?;
+
+            // Only asset contract can execute this message
+            check_cw20_in_pool(&config, &info.sender)?;
+
+            let to_addr = addr_opt_validate(deps.api, &to)?;
+            swap(
+                deps,
+                env,
+                Addr::unchecked(cw20_msg.sender),
+                Asset {
+                    info: AssetInfo::Token {
+                        contract_addr: info.sender,
+                    },
+                    amount: cw20_msg.amount,
+                },
+                ask_asset_info,
+                belief_price,
+                max_spread,
+                to_addr,
+            )
+        }
+        Cw20HookMsg::WithdrawLiquidity { assets } => withdraw_liquidity(
+            deps,
+            info,
+            Addr::unchecked(cw20_msg.sender),
+            cw20_msg.amount,
+            assets,
+        ),
+        Cw20HookMsg::WithdrawSingleAsset { .. } => Err(ContractError::NonSupported {}),
+    }
+}
+
+/// Provides liquidity with the specified input parameters.
+/// Assets which are omitted are considered to be deposited in zero amount. The part of a deposit
+/// which exceeds the pool proportions is charged with the swap fee.
+///
+/// * **assets** vector with assets available in the pool.
+///
+/// * **slippage_tolerance** max allowed shortfall of the minted share compared to the share of
+/// the pool value added by the deposit. The share isn't checked if this isn't specified.
+///
+/// * **auto_stake** determines whether the resulting LP tokens are automatically staked in
+/// the Generator contract to receive token incentives.
+///
+/// * **receiver** address that receives LP tokens. If this address isn't specified, the function will default to the caller.
+///
+/// NOTE - the address that wants to provide liquidity should approve the pair contract to pull its relevant tokens.
+pub fn provide_liquidity(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    assets: Vec<Asset>,
+    slippage_tolerance: Option<Decimal>,
+    auto_stake: Option<bool>,
+    receiver: Option<String>,
+) -> Result<Response, ContractError> {
+    check_assets(deps.api, &assets)?;
+
+    let auto_stake = auto_stake.unwrap_or(false);
+    let config = CONFIG.load(deps.storage)?;
+
+    if query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?
+    .provide_paused
+    {
+        return Err(ContractError::ProvidePaused {});
+    }
+
+    info.funds
+        .assert_coins_properly_sent(&assets, &config.pair_info.asset_infos)?;
+
+    let deposits: HashMap<_, _> = assets
+        .iter()
+        .map(|asset| {
+            if !config.pair_info.asset_infos.contains(&asset.info) {
+                return Err(ContractError::InvalidAsset(asset.info.to_string()));
+            }
+            Ok((&asset.info, asset.amount))
+        })
+        .collect::<Result<_, ContractError>>()?;
+
+    if deposits.values().all(|amount| amount.is_zero()) {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    // Deposits and pools are aligned with the pool assets and weights
+    let mut messages = vec![];
+    let mut deposits_collection = vec![];
+    let mut pools_collection = vec![];
+    for mut pool in config
+        .pair_info
+        .query_pools(&deps.querier, &env.contract.address)?
+    {
+        let deposit = deposits.get(&pool.info).copied().unwrap_or_default();
+
+        if !deposit.is_zero() {
+            // If the pool is a token contract, then we need to execute a TransferFrom msg to receive funds
+            if let AssetInfo::Token { contract_addr } = &pool.info {
+                messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: contract_addr.to_string(),
+                    msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                        owner: info.sender.to_string(),
+                        recipient: env.contract.address.to_string(),
+                        amount: deposit,
+                    })?,
+                    funds: vec![],
+                }))
+            } else {
+                // If the asset is a native token, the pool balance already increased
+                // To calculate the pool balance properly, we should subtract the user deposit from the recorded pool token amount
+                pool.amount = pool.amount.checked_sub(deposit)?;
+            }
+        }
+
+        let precision = get_precision(deps.storage, &pool.info)?;
+        deposits_collection.push(Decimal256::with_precision(deposit, precision)?);
+        pools_collection.push(Decimal256::with_precision(pool.amount, precision)?);
+    }
+
+    let weights = config
+        .weights
+        .iter()
+        .map(|weight| Decimal256::from(*weight))
+        .collect_vec();
+
+    let total_share = query_supply(&deps.querier, &config.pair_info.liquidity_token)?;
+    let share = if total_share.is_zero() {
+        // The initial deposit must contain all pool assets
+        if deposits_collection.iter().any(|deposit| deposit.is_zero()) {
+            return Err(ContractError::InvalidProvideLPsWithSingleToken {});
+        }
+
+        let share = calc_weighted_product(&deposits_collection, &weights)?
+            .to_uint128_with_precision(config.greatest_precision)?
+            .checked_sub(MINIMUM_LIQUIDITY_AMOUNT)
+            .map_err(|_| ContractError::MinimumLiquidityAmountError {})?;
+
+        // share cannot become zero after minimum liquidity subtraction
+        if share.is_zero() {
+            return Err(ContractError::MinimumLiquidityAmountError {});
+        }
+
+        messages.extend(mint_liquidity_token_message(
+            deps.querier,
+            &config.pair_info.liquidity_token,
+            &config.factory_addr,
+            &env.contract.address,
+            &env.contract.address,
+            MINIMUM_LIQUIDITY_AMOUNT,
+            false,
+        )?);
+
+        share
+    } else {
+        // Get fee info from the factory
+        let fee_info = query_fee_info(
+            &deps.querier,
+            &config.factory_addr,
+            config.pair_info.pair_type.clone(),
+            &config.pair_info.contract_addr,
+        )?;
+
+        let invariant_ratio = calc_invariant_ratio(
+            &pools_collection,
+            &weights,
+            &deposits_collection,
+            fee_info.total_fee_rate.into(),
+        )?;
+
+        let share = Decimal256::with_precision(total_share, config.greatest_precision)?
+            .checked_mul(invariant_ratio.saturating_sub(Decimal256::one()))?
+            .to_uint128_with_precision(config.greatest_precision)?;
+
+        if share.is_zero() {
+            return Err(ContractError::LiquidityAmountTooSmall {});
+        }
+
+        if let Some(slippage_tolerance) = slippage_tolerance {
+            assert_slippage_tolerance(
+                slippage_tolerance,
+                &deposits_collection,
+                &pools_collection,
+                &weights,
+                share,
+                total_share,
+            )?;
+        }
+
+        share
+    };
+
+    // Mint LP token for the caller (or for the receiver if it was set)
+    let receiver = addr_opt_validate(deps.api, &receiver)?.unwrap_or_else(|| info.sender.clone());
+    messages.extend(mint_liquidity_token_message(
+        deps.querier,
+        &config.pair_info.liquidity_token,
+        &config.factory_addr,
+        &env.contract.address,
+        &receiver,
+        share,
+        auto_stake,
+    )?);
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("action", "provide_liquidity"),
+        attr("sender", info.sender),
+        attr("receiver", receiver),
+        attr("assets", assets.iter().join(", ")),
+        attr("share", share),
+    ]))
+}
+
+/// Withdraw liquidity from the pool.
+/// * **sender** is the address that will receive assets back from the pair contract.
+///
+/// * **amount** is the amount of LP tokens to burn.
+pub fn withdraw_liquidity(
+    deps: DepsMut,
+    info: MessageInfo,
+    sender: Addr,
+    amount: Uint128,
+    assets: Vec<Asset>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.pair_info.liquidity_token {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (pools, total_share) = pool_info(deps.querier, &config)?;
+
+    let refund_assets = if assets.is_empty() {
+        // Usual withdraw (balanced)
+        get_share_in_assets(&pools, amount, total_share)
+    } else {
+        return Err(StdError::generic_err("Imbalanced withdraw is currently disabled").into());
+    };
+
+    let mut messages = refund_assets
+        .clone()
+        .into_iter()
+        .map(|asset| asset.into_msg(&sender))
+        .collect::<StdResult<Vec<_>>>()?;
+    messages.push(
+        wasm_execute(
+            &config.pair_info.liquidity_token,
+            &Cw20ExecuteMsg::Burn { amount },
+            vec![],
+        )?
+        .into(),
+    );
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("action", "withdraw_liquidity"),
+        attr("sender", sender),
+        attr("withdrawn_share", amount),
+        attr("refund_assets", refund_assets.iter().join(", ")),
+    ]))
+}
+
+/// Performs an swap operation with the specified parameters.
+///
+/// * **sender** is the sender of the swap operation.
+///
+/// * **offer_asset** proposed asset for swapping.
+///
+/// * **ask_asset_info** asset to swap to. May be omitted only in case the pool length is 2.
+///
+/// * **belief_price** is used to calculate the maximum swap spread.
+///
+/// * **max_spread** sets the maximum spread of the swap operation.
+///
+/// * **to** sets the recipient of the swap operation.
+///
+/// NOTE - the address that wants to swap should approve the pair contract to pull the offer token.
+#[allow(clippy::too_many_arguments)]
+pub fn swap(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    offer_asset: Asset,
+    ask_asset_info: Option<AssetInfo>,
+    belief_price: Option<Decimal>,
+    max_spread: Option<Decimal>,
+    to: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if query_pause_status(
+        &deps.querier,
+        &config.factory_addr,
+        &config.pair_info.pair_type,
+        &env.contract.address,
+    )?
+    .swaps_paused
+    {
+        return Err(ContractError::SwapsPaused {});
+    }
+
+    // If the asset balance already increased
+    // We should subtract the user deposit from the pool offer asset amount
+    let pools = config
+        .pair_info
+        .query_pools(&deps.querier, &env.contract.address)?
+        .into_iter()
+        .map(|mut pool| {
+            if pool.info.equal(&offer_asset.info) {
+                pool.amount = pool.amount.checked_sub(offer_asset.amount)?;
+            }
+            let token_precision = get_precision(deps.storage, &pool.info)?;
+            Ok(DecimalAsset {
+                info: pool.info,
+                amount: Decimal256::with_precision(pool.amount, token_precision)?,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let (offer_pool, ask_pool) =
+        select_pools(Some(&offer_asset.info), ask_asset_info.as_ref(), &pools)?;
+
+    let offer_precision = get_precision(deps.storage, &offer_pool.info)?;
+
+    // Check if the liquidity is non-zero
+    check_swap_parameters(
+        pools
+            .iter()
+            .map(|pool| {
+                pool.amount
+                    .to_uint128_with_precision(get_precision(deps.storage, &pool.info)?)
+            })
+            .collect::<StdResult<Vec<Uint128>>>()?,
+        offer_asset.amount,
+    )?;
+
+    let offer_asset_dec = offer_asset.to_decimal_asset(offer_precision)?;
+
+    let SwapResult {
+        return_amount,
+        spread_amount,
+    } = compute_swap(
+        deps.storage,
+        &config,
+        &offer_asset_dec,
+        &offer_pool,
+        &ask_pool,
+    )?;
+
+    // Get fee info from the factory
+    let fee_info = query_fee_info(
+        &deps.querier,
+        &config.factory_addr,
+        config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
+    )?;
+    let commission_amount = fee_info.total_fee_rate.checked_mul_uint128(return_amount)?;
+    let return_amount = return_amount.saturating_sub(commission_amount);
+
+    // Check the max spread limit (if it was specified)
+    assert_max_spread(
+        belief_price,
+        max_spread,
+        offer_asset.amount,
+        return_amount + commission_amount,
+        spread_amount,
+    )?;
+
+    let receiver = to.unwrap_or_else(|| sender.clone());
+
+    let return_asset = Asset {
+        info: ask_pool.info.clone(),
+        amount: return_amount,
+    };
+
+    let mut messages = vec![];
+    if !return_amount.is_zero() {
+        messages.push(return_asset.into_msg(receiver.clone())?)
+    }
+
+    // Compute the Maker fee
+    let mut maker_fee_amount = Uint128::zero();
+    if let Some(fee_address) = fee_info.fee_address {
+        if let Some(f) =
+            calculate_maker_fee(&ask_pool.info, commission_amount, fee_info.maker_fee_rate)
+        {
+            maker_fee_amount = f.amount;
+            messages.push(f.into_msg(fee_address)?);
+        }
+    }
+
+    // Store observation from precommit data
+    accumulate_swap_sizes(deps.storage, &env, OBSERVATIONS)?;
+
+    // Store time series data in precommit observation.
+    // Skipping small unsafe values which can seriously mess oracle price due to rounding errors.
+    // This data will be reflected in observations on the next action.
+    let ask_precision = get_precision(deps.storage, &ask_pool.info)?;
+    if offer_asset_dec.amount >= MIN_TRADE_SIZE
+        && return_amount.to_decimal256(ask_precision)? >= MIN_TRADE_SIZE
+    {
+        // Store time series data
+        let (base_amount, quote_amount) =
+            determine_base_quote_amount(&pools, &offer_asset, return_amount)?;
+        PrecommitObservation::save(deps.storage, &env, base_amount, quote_amount)?;
+    }
+
+    Ok(Response::new()
+        .add_messages(
+            // 1. send collateral tokens from the contract to a user
+            // 2. send inactive commission fees to the Maker contract
+            messages,
+        )
+        .add_attributes(vec![
+            attr("action", "swap"),
+            attr("sender", sender),
+            attr("receiver", receiver),
+            attr("offer_asset", offer_asset.info.to_string()),
+            attr("ask_asset", ask_pool.info.to_string()),
+            attr("offer_amount", offer_asset.amount),
+            attr("return_amount", return_amount),
+            attr("spread_amount", spread_amount),
+            attr("commission_amount", commission_amount),
+            attr("maker_fee_amount", maker_fee_amount),
+        ]))
+}
+
+/// Calculates the amount of fees the Maker contract gets according to specified pair parameters.
+/// Returns a [`None`] if the Maker fee is zero, otherwise returns a [`Asset`] struct with the specified attributes.
+///
+/// * **pool_info** contains information about the pool asset for which the commission will be calculated.
+///
+/// * **commission_amount** is the total amount of fees charged for a swap.
+///
+/// * **maker_commission_rate** is the percentage of fees that go to the Maker contract.
+pub fn calculate_maker_fee(
+    pool_info: &AssetInfo,
+    commission_amount: Uint128,
+    maker_commission_rate: Decimal,
+) -> Option<Asset> {
+    let maker_fee: Uint128 = commission_amount * maker_commission_rate;
+    if maker_fee.is_zero() {
+        return None;
+    }
+
+    Some(Asset {
+        info: pool_info.clone(),
+        amount: maker_fee,
+    })
+}
+
+/// Exposes all the queries available in the contract.
+/// ## Queries
+/// * **QueryMsg::Pair {}** Returns information about the pair in an object of type [`PairInfo`].
+///
+/// * **QueryMsg::Pool {}** Returns information about the amount of assets in the pair contract as
+/// well as the amount of LP tokens issued using an object of type [`PoolResponse`].
+///
+/// * **QueryMsg::Share { amount }** Returns the amount of assets that could be withdrawn from the pool
+/// using a specific amount of LP tokens. The result is returned in a vector that contains objects of type [`Asset`].
+///
+/// * **QueryMsg::Simulation { offer_asset }** Returns the result of a swap simulation using a [`SimulationResponse`] object.
+///
+/// * **QueryMsg::ReverseSimulation { ask_asset }** Returns the result of a reverse swap simulation using
+/// a [`ReverseSimulationResponse`] object.
+///
+/// * **QueryMsg::Config {}** Returns the configuration for the pair contract using a [`ConfigResponse`] object.
+///
+/// * **QueryMsg::Observe { seconds_ago }** Returns the price observation at the specified point.
+///
+/// * **QueryMsg::ObserveTwap { start_seconds_ago, end_seconds_ago }** Returns the time-weighted average
+/// price over the specified window computed from observations using an [`OracleTwap`] object.
+///
+/// * **QueryMsg::PauseStatus {}** Returns the pause status of the pair set in the factory.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Pair {} => to_binary(&CONFIG.load(deps.storage)?.pair_info),
+        QueryMsg::Pool {} => to_binary(&query_pool(deps)?),
+        QueryMsg::Share { amount } => to_binary(&query_share(deps, amount)?),
+        QueryMsg::Simulation {
+            offer_asset,
+            ask_asset_info,
+        } => to_binary(&query_simulation(deps, offer_asset, ask_asset_info)?),
+        QueryMsg::ReverseSimulation {
+            offer_asset_info,
+            ask_asset,
+        } => to_binary(&query_reverse_simulation(
+            deps,
+            ask_asset,
+            offer_asset_info,
+        )?),
+        QueryMsg::CumulativePrices {} => Err(StdError::generic_err(
+            stringify!(Not implemented. Use {"observe": {"seconds_ago": ... }} instead.),
+        )),
+        QueryMsg::Observe { seconds_ago } => {
+            to_binary(&query_observation(deps, env, OBSERVATIONS, seconds_ago)?)
+        }
+        QueryMsg::ObserveTwap {
+            start_seconds_ago,
+            end_seconds_ago,
+        } => to_binary(&query_observation_twap(
+            deps,
+            env,
+            OBSERVATIONS,
+            start_seconds_ago,
+            end_seconds_ago,
+        )?),
+        QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        QueryMsg::PauseStatus {} => {
+            let config = CONFIG.load(deps.storage)?;
+            to_binary(&query_pause_status(
+                &deps.querier,
+                config.factory_addr,
+                &config.pair_info.pair_type,
+                &env.contract.address,
+            )?)
+        }
+        _ => Err(StdError::generic_err("Query is not supported")),
+    }
+}
+
+/// Returns the amounts of assets in the pair contract as well as the amount of LP
+/// tokens currently minted in an object of type [`PoolResponse`].
+pub fn query_pool(deps: Deps) -> StdResult<PoolResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let (assets, total_share) = pool_info(deps.querier, &config)?;
+
+    let resp = PoolResponse {
+        assets,
+        total_share,
+    };
+
+    Ok(resp)
+}
+
+/// Returns the amount of assets that could be withdrawn from the pool using a specific amount of LP tokens.
+/// The result is returned in a vector that contains objects of type [`Asset`].
+///
+/// * **amount** is the amount of LP tokens for which we calculate associated amounts of assets.
+pub fn query_share(deps: Deps, amount: Uint128) -> StdResult<Vec<Asset>> {
+    let config = CONFIG.load(deps.storage)?;
+    let (pools, total_share) = pool_info(deps.querier, &config)?;
+    let refund_assets = get_share_in_assets(&pools, amount, total_share);
+
+    Ok(refund_assets)
+}
+
+/// Returns information about a swap simulation in a [`SimulationResponse`] object.
+///
+/// * **offer_asset** is the asset to swap as well as an amount of the said asset.
+///
+/// * **ask_asset_info** is optional field which specifies the asset to swap to.
+/// May be omitted only in case the pool length is 2.
+pub fn query_simulation(
+    deps: Deps,
+    offer_asset: Asset,
+    ask_asset_info: Option<AssetInfo>,
+) -> StdResult<SimulationResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let pools = config.pair_info.query_pools_decimal(
+        &deps.querier,
+        &config.pair_info.contract_addr,
+        &config.factory_addr,
+    )?;
+
+    let (offer_pool, ask_pool) =
+        select_pools(Some(&offer_asset.info), ask_asset_info.as_ref(), &pools)
+            .map_err(|err| StdError::generic_err(format!("{err}")))?;
+
+    let offer_precision = get_precision(deps.storage, &offer_pool.info)?;
+
+    if check_swap_parameters(
+        pools
+            .iter()
+            .map(|pool| {
+                pool.amount
+                    .to_uint128_with_precision(get_precision(deps.storage, &pool.info)?)
+            })
+            .collect::<StdResult<Vec<Uint128>>>()?,
+        offer_asset.amount,
+    )
+    .is_err()
+    {
+        return Ok(SimulationResponse {
+            return_amount: Uint128::zero(),
+            spread_amount: Uint128::zero(),
+            commission_amount: Uint128::zero(),
+        });
+    }
+
+    let SwapResult {
+        return_amount,
+        spread_amount,
+    } = compute_swap(
+        deps.storage,
+        &config,
+        &offer_asset.to_decimal_asset(offer_precision)?,
+        &offer_pool,
+        &ask_pool,
+    )
+    .map_err(|err| StdError::generic_err(format!("{err}")))?;
+
+    // Get fee info from factory
+    let fee_info = query_fee_info(
+        &deps.querier,
+        &config.factory_addr,
+        config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
+    )?;
+
+    let commission_amount = fee_info.total_fee_rate.checked_mul_uint128(return_amount)?;
+    let return_amount = return_amount.saturating_sub(commission_amount);
+
+    Ok(SimulationResponse {
+        return_amount,
+        spread_amount,
+        commission_amount,
+    })
+}
+
+/// Returns information about a reverse swap simulation in a [`ReverseSimulationResponse`] object.
+///
+/// * **ask_asset** is the asset to swap to as well as the desired amount of ask
+/// assets to receive from the swap.
+///
+/// * **offer_asset_info** is optional field which specifies the asset to swap from.
+/// May be omitted only in case the pool length is 2.
+pub fn query_reverse_simulation(
+    deps: Deps,
+    ask_asset: Asset,
+    offer_asset_info: Option<AssetInfo>,
+) -> StdResult<ReverseSimulationResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let pools = config.pair_info.query_pools_decimal(
+        &deps.querier,
+        &config.pair_info.contract_addr,
+        &config.factory_addr,
+    )?;
+    let (offer_pool, ask_pool) =
+        select_pools(offer_asset_info.as_ref(), Some(&ask_asset.info), &pools)
+            .map_err(|err| StdError::generic_err(format!("{err}")))?;
+
+    let offer_precision = get_precision(deps.storage, &offer_pool.info)?;
+    let ask_precision = get_precision(deps.storage, &ask_asset.info)?;
+
+    // Check the swap parameters are valid
+    if check_swap_parameters(
+        pools
+            .iter()
+            .map(|pool| {
+                pool.amount
+                    .to_uint128_with_precision(get_precision(deps.storage, &pool.info)?)
+            })
+            .collect::<StdResult<Vec<Uint128>>>()?,
+        ask_asset.amount,
+    )
+    .is_err()
+    {
+        return Ok(ReverseSimulationResponse {
+            offer_amount: Uint128::zero(),
+            spread_amount: Uint128::zero(),
+            commission_amount: Uint128::zero(),
+        });
+    }
+
+    // Get fee info from the factory
+    let fee_info = query_fee_info(
+        &deps.querier,
+        &config.factory_addr,
+        config.pair_info.pair_type.clone(),
+        &config.pair_info.contract_addr,
+    )?;
+    let before_commission = (Decimal256::one()
+        - Decimal256::new(fee_info.total_fee_rate.atomics().into()))
+    .inv()
+    .ok_or_else(|| StdError::generic_err("The pool must have less than 100% fee!"))?
+    .checked_mul(Decimal256::with_precision(ask_asset.amount, ask_precision)?)?;
+
+    let offer_weight = Decimal256::from(
+        get_weight(&config, &offer_pool.info)
+            .map_err(|err| StdError::generic_err(format!("{err}")))?,
+    );
+    let ask_weight = Decimal256::from(
+        get_weight(&config, &ask_pool.info)
+            .map_err(|err| StdError::generic_err(format!("{err}")))?,
+    );
+
+    let offer_amount = calc_in_given_out(
+        offer_pool.amount,
+        offer_weight,
+        ask_pool.amount,
+        ask_weight,
+        before_commission,
+    )
+    .map_err(|err| StdError::generic_err(format!("{err}")))?;
+
+    // Offer amount needed at the spot price: before_commission * (offer_pool / offer_weight) / (ask_pool / ask_weight)
+    let expected_offer_amount =
+        before_commission * offer_pool.amount * ask_weight / (ask_pool.amount * offer_weight);
+
+    Ok(ReverseSimulationResponse {
+        offer_amount: offer_amount.to_uint128_with_precision(offer_precision)?,
+        spread_amount: offer_amount
+            .saturating_sub(expected_offer_amount)
+            .to_uint128_with_precision(offer_precision)?,
+        commission_amount: fee_info
+            .total_fee_rate
+            .checked_mul_uint128(before_commission.to_uint128_with_precision(ask_precision)?)?,
+    })
+}
+
+/// Returns the pair contract configuration in a [`ConfigResponse`] object.
+pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let factory_config = query_factory_config(&deps.querier, &config.factory_addr)?;
+    Ok(ConfigResponse {
+        block_time_last: config.block_time_last,
+        params: Some(to_binary(&WeightedPoolConfig {
+            weights: config.weights,
+        })?),
+        owner: factory_config.owner,
+        factory_addr: config.factory_addr,
+    })
+}
+
+/// If `belief_price` and `max_spread` are both specified, we compute a new spread,
+/// otherwise we just use the swap spread to check `max_spread`.
+///
+/// * **belief_price** belief price used in the swap.
+///
+/// * **max_spread** max spread allowed so that the swap can be executed successfully.
+///
+/// * **offer_amount** amount of assets to swap.
+///
+/// * **return_amount** amount of assets to receive from the swap.
+///
+/// * **spread_amount** spread used in the swap.
+pub fn assert_max_spread(
+    belief_price: Option<Decimal>,
+    max_spread: Option<Decimal>,
+    offer_amount: Uint128,
+    return_amount: Uint128,
+    spread_amount: Uint128,
+) -> Result<(), ContractError> {
+    let default_spread = Decimal::from_str(DEFAULT_SLIPPAGE)?;
+    let max_allowed_spread = Decimal::from_str(MAX_ALLOWED_SLIPPAGE)?;
+
+    let max_spread = max_spread.unwrap_or(default_spread);
+    if max_spread.gt(&max_allowed_spread) {
+        return Err(ContractError::AllowedSpreadAssertion {});
+    }
+
+    if let Some(belief_price) = belief_price {
+        let expected_return = offer_amount
+            * belief_price.inv().ok_or_else(|| {
+                ContractError::Std(StdError::generic_err(
+                    "Invalid belief_price. Check the input values.",
+                ))
+            })?;
+
+        let spread_amount = expected_return.saturating_sub(return_amount);
+
+        if return_amount < expected_return
+            && Decimal::from_ratio(spread_amount, expected_return) > max_spread
+        {
+            return Err(ContractError::MaxSpreadAssertion {});
+        }
+    } else if Decimal::from_ratio(spread_amount, return_amount + spread_amount) > max_spread {
+        return Err(ContractError::MaxSpreadAssertion {});
+    }
+
+    Ok(())
+}
+
+/// Ensures the minted share is not less than the share of the pool value added by the deposits
+/// reduced by the slippage tolerance. Every pool asset makes up its weight of the pool value,
+/// so a deposit adds `sum(weight_i * deposit_i / pool_i)` of the pool value at the spot prices.
+///
+/// * **slippage_tolerance** max allowed shortfall of the minted share.
+///
+/// * **deposits** deposited amounts aligned with the pool assets.
+///
+/// * **pools** pool amounts before the deposit.
+///
+/// * **weights** pool asset weights.
+///
+/// * **share** amount of LP tokens minted for the deposit.
+///
+/// * **total_share** total amount of LP tokens before the deposit.
+pub fn assert_slippage_tolerance(
+    slippage_tolerance: Decimal,
+    deposits: &[Decimal256],
+    pools: &[Decimal256],
+    weights: &[Decimal256],
+    share: Uint128,
+    total_share: Uint128,
+) -> Result<(), ContractError> {
+    if slippage_tolerance > Decimal::from_str(MAX_ALLOWED_SLIPPAGE)? {
+        return Err(ContractError::AllowedSpreadAssertion {});
+    }
+
+    let expected_share_ratio = deposits
+        .iter()
+        .zip(pools)
+        .zip(weights)
+        .filter(|((deposit, pool), _)| !deposit.is_zero() && !pool.is_zero())
+        .try_fold(Decimal256::zero(), |acc, ((deposit, pool), weight)| {
+            acc.checked_add(*weight * *deposit / *pool)
+        })?;
+    let share_ratio = Decimal256::from_ratio(share, total_share);
+
+    if share_ratio
+        < expected_share_ratio * (Decimal256::one() - Decimal256::from(slippage_tolerance))
+    {
+        return Err(ContractError::MaxSlippageAssertion {});
+    }
+
+    Ok(())
+}
+
+/// Manages the contract migration.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract_version = get_contract_version(deps.storage)?;
+
+    match contract_version.contract.as_ref() {
+        "astroport-pair-weighted" => match contract_version.version.as_ref() {
+            "1.0.0" => {}
+            _ => return Err(ContractError::MigrationError {}),
+        },
+        _ => return Err(ContractError::MigrationError {}),
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("previous_contract_name", &contract_version.contract)
+        .add_attribute("previous_contract_version", &contract_version.version)
+        .add_attribute("new_contract_name", CONTRACT_NAME)
+        .add_attribute("new_contract_version", CONTRACT_VERSION))
+}
+
+/// Returns the total amount of assets in the pool as well as the total amount of LP tokens currently minted.
+pub fn pool_info(querier: QuerierWrapper, config: &Config) -> StdResult<(Vec<Asset>, Uint128)> {
+    let pools = config
+        .pair_info
+        .query_pools(&querier, &config.pair_info.contract_addr)?;
+    let total_share = query_supply(&querier, &config.pair_info.liquidity_token)?;
+
+    Ok((pools, total_share))
+}
@@ -0,0 +1,127 @@
+use cosmwasm_std::{CheckedMultiplyRatioError, ConversionOverflowError, OverflowError, StdError};
+use thiserror::Error;
+
+use astroport::asset::MINIMUM_LIQUIDITY_AMOUNT;
+use astroport::pair_utils::PairUtilsError;
+use astroport_circular_buffer::error::BufferError;
+
+use crate::math::{MAX_ASSETS, MAX_IN_RATIO, MAX_OUT_RATIO, MIN_WEIGHT};
+
+/// This enum describes weighted pair contract errors
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    CheckedMultiplyRatioError(#[from] CheckedMultiplyRatioError),
+
+    #[error("{0}")]
+    CircularBuffer(#[from] BufferError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("CW20 tokens can be swapped via Cw20::Send message only")]
+    Cw20DirectSwap {},
+
+    #[error("Doubling assets in asset infos")]
+    DoublingAssets {},
+
+    #[error("Event of zero transfer")]
+    InvalidZeroAmount {},
+
+    #[error("Insufficient amount of liquidity")]
+    LiquidityAmountTooSmall {},
+
+    #[error("Provided spread amount exceeds allowed limit")]
+    AllowedSpreadAssertion {},
+
+    #[error("Operation exceeds max spread limit")]
+    MaxSpreadAssertion {},
+
+    #[error("Operation exceeds max slippage tolerance")]
+    MaxSlippageAssertion {},
+
+    #[error("Native token balance mismatch between the argument and the transferred")]
+    AssetMismatch {},
+
+    #[error("You need to provide init params")]
+    InitParamsNotFound {},
+
+    #[error("Generator address is not set in factory. Cannot autostake")]
+    AutoStakeError {},
+
+    #[error("It is not possible to provide liquidity with one token for an empty pool")]
+    InvalidProvideLPsWithSingleToken {},
+
+    #[error("The asset {0} does not belong to the pair")]
+    InvalidAsset(String),
+
+    #[error("Ask or offer asset is missed")]
+    VariableAssetMissed {},
+
+    #[error("Source and target assets are the same")]
+    SameAssets {},
+
+    #[error(
+        "Invalid number of assets. This pair supports from 2 to {} assets",
+        MAX_ASSETS
+    )]
+    InvalidNumberOfAssets {},
+
+    #[error(
+        "Each asset must have a weight of at least {} and the weights must sum up to 1",
+        MIN_WEIGHT
+    )]
+    InvalidWeights {},
+
+    #[error("Offer amount exceeds {} of the offer pool", MAX_IN_RATIO)]
+    MaxInRatioExceeded {},
+
+    #[error("Ask amount exceeds {} of the ask pool", MAX_OUT_RATIO)]
+    MaxOutRatioExceeded {},
+
+    #[error("Contract can't be migrated!")]
+    MigrationError {},
+
+    #[error("Initial liquidity must be more than {}", MINIMUM_LIQUIDITY_AMOUNT)]
+    MinimumLiquidityAmountError {},
+
+    #[error("Failed to parse or process reply message")]
+    FailedToParseReply {},
+
+    #[error("Operation non supported")]
+    NonSupported {},
+
+    #[error("Swaps are paused for this pair")]
+    SwapsPaused {},
+
+    #[error("Liquidity provision is paused for this pair")]
+    ProvidePaused {},
+}
+
+impl From<OverflowError> for ContractError {
+    fn from(o: OverflowError) -> Self {
+        StdError::from(o).into()
+    }
+}
+
+impl From<ConversionOverflowError> for ContractError {
+    fn from(o: ConversionOverflowError) -> Self {
+        StdError::from(o).into()
+    }
+}
+
+impl From<PairUtilsError> for ContractError {
+    fn from(err: PairUtilsError) -> Self {
+        match err {
+            PairUtilsError::Std(err) => ContractError::Std(err),
+            PairUtilsError::AssetMismatch {} => ContractError::AssetMismatch {},
+            PairUtilsError::AutoStakeError {} => ContractError::AutoStakeError {},
+            PairUtilsError::InvalidAsset(asset) => ContractError::InvalidAsset(asset),
+            PairUtilsError::VariableAssetMissed {} => ContractError::VariableAssetMissed {},
+            PairUtilsError::SameAssets {} => ContractError::SameAssets {},
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod contract;
+pub mod math;
+pub mod state;
+
+pub mod error;
+
+pub mod utils;
@@ -0,0 +1,324 @@
+use cosmwasm_std::{Decimal, Decimal256, StdError, StdResult, Uint128};
+
+use crate::error::ContractError;
+
+/// The minimum weight of an asset in the pool
+pub const MIN_WEIGHT: Decimal = Decimal::percent(1);
+/// The max deviation of the weights sum from 1. Weights within it are normalized on instantiation
+pub const WEIGHTS_SUM_TOLERANCE: Decimal = Decimal::raw(1_000_000_000_000);
+/// The maximum number of assets in the pool
+pub const MAX_ASSETS: usize = 8;
+/// The maximum share of the offer pool which can be swapped in at once
+pub const MAX_IN_RATIO: Decimal256 = Decimal256::percent(30);
+/// The maximum share of the ask pool which can be swapped out at once
+pub const MAX_OUT_RATIO: Decimal256 = Decimal256::percent(30);
+/// The number of binary digits of the fractional part of an exponent used in [`pow`]
+const POW_FRACTION_BITS: u32 = 48;
+
+/// Raises `base` to the power of `exp`.
+/// The integer part of the exponent is applied directly, while the fractional part is decomposed
+/// into binary digits and applied as a product of repeated square roots of the base.
+pub fn pow(base: Decimal256, exp: Decimal256) -> StdResult<Decimal256> {
+    let whole_exp = exp.to_uint_floor();
+    let mut result = base.checked_pow(Uint128::try_from(whole_exp)?.u128() as u32)?;
+
+    let mut fraction = exp - Decimal256::from_ratio(whole_exp, 1u8);
+    let mut root = base;
+    for _ in 0..POW_FRACTION_BITS {
+        if fraction.is_zero() {
+            break;
+        }
+
+        root = root.sqrt();
+        fraction = fraction.checked_mul(Decimal256::from_ratio(2u8, 1u8))?;
+        if fraction >= Decimal256::one() {
+            result = result.checked_mul(root)?;
+            fraction -= Decimal256::one();
+        }
+    }
+
+    Ok(result)
+}
+
+/// Calculates the amount of ask tokens returned for `offer_amount` of offer tokens.
+/// Formula: ask_pool * (1 - (offer_pool / (offer_pool + offer_amount)) ^ (offer_weight / ask_weight))
+///
+/// * **offer_pool** offer asset pool balance.
+///
+/// * **offer_weight** offer asset weight.
+///
+/// * **ask_pool** ask asset pool balance.
+///
+/// * **ask_weight** ask asset weight.
+///
+/// * **offer_amount** amount of offer tokens to swap.
+pub fn calc_out_given_in(
+    offer_pool: Decimal256,
+    offer_weight: Decimal256,
+    ask_pool: Decimal256,
+    ask_weight: Decimal256,
+    offer_amount: Decimal256,
+) -> Result<Decimal256, ContractError> {
+    if offer_amount > offer_pool * MAX_IN_RATIO {
+        return Err(ContractError::MaxInRatioExceeded {});
+    }
+
+    let base = offer_pool / (offer_pool + offer_amount);
+    let power = pow(base, offer_weight / ask_weight)?;
+
+    Ok(ask_pool * (Decimal256::one() - power.min(Decimal256::one())))
+}
+
+/// Calculates the amount of offer tokens required to receive `ask_amount` of ask tokens.
+/// Formula: offer_pool * ((ask_pool / (ask_pool - ask_amount)) ^ (ask_weight / offer_weight) - 1)
+///
+/// * **offer_pool** offer asset pool balance.
+///
+/// * **offer_weight** offer asset weight.
+///
+/// * **ask_pool** ask asset pool balance.
+///
+/// * **ask_weight** ask asset weight.
+///
+/// * **ask_amount** amount of ask tokens to receive.
+pub fn calc_in_given_out(
+    offer_pool: Decimal256,
+    offer_weight: Decimal256,
+    ask_pool: Decimal256,
+    ask_weight: Decimal256,
+    ask_amount: Decimal256,
+) -> Result<Decimal256, ContractError> {
+    if ask_amount > ask_pool * MAX_OUT_RATIO {
+        return Err(ContractError::MaxOutRatioExceeded {});
+    }
+
+    let base = ask_pool / (ask_pool - ask_amount);
+    let power = pow(base, ask_weight / offer_weight)?;
+
+    Ok(offer_pool * (power - Decimal256::one()))
+}
+
+/// Calculates the ratio by which the pool invariant grows after `deposits` are added to `pools`.
+/// The part of a deposit exceeding the proportional one is taxed with `fee_rate` as if it was swapped.
+/// Formula: prod((pool + taxed_deposit) / pool) ^ weight)
+///
+/// * **pools** pool balances.
+///
+/// * **weights** asset weights in the same order as the pool balances.
+///
+/// * **deposits** deposited amounts in the same order as the pool balances.
+///
+/// * **fee_rate** swap fee rate.
+pub fn calc_invariant_ratio(
+    pools: &[Decimal256],
+    weights: &[Decimal256],
+    deposits: &[Decimal256],
+    fee_rate: Decimal256,
+) -> StdResult<Decimal256> {
+    let balance_ratios = pools
+        .iter()
+        .zip(deposits)
+        .map(|(&pool, &deposit)| {
+            if pool.is_zero() {
+                return Err(StdError::generic_err("Pool balance must be positive"));
+            }
+            Ok((pool + deposit) / pool)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    let proportional_ratio = balance_ratios
+        .iter()
+        .zip(weights)
+        .map(|(&ratio, &weight)| ratio * weight)
+        .sum::<Decimal256>();
+
+    pools
+        .iter()
+        .zip(weights)
+        .zip(deposits)
+        .zip(balance_ratios)
+        .try_fold(
+            Decimal256::one(),
+            |invariant_ratio, (((&pool, &weight), &deposit), balance_ratio)| {
+                let taxed_deposit = if balance_ratio > proportional_ratio {
+                    let non_taxable = pool * (proportional_ratio - Decimal256::one());
+                    non_taxable
+                        + deposit.saturating_sub(non_taxable) * (Decimal256::one() - fee_rate)
+                } else {
+                    deposit
+                };
+
+                Ok(invariant_ratio.checked_mul(pow((pool + taxed_deposit) / pool, weight)?)?)
+            },
+        )
+}
+
+/// Calculates the weighted geometric mean of the given amounts.
+/// It is used to calculate the amount of LP tokens minted for the initial deposit.
+/// Formula: prod(amount ^ weight)
+///
+/// * **amounts** deposited amounts.
+///
+/// * **weights** asset weights in the same order as the amounts.
+pub fn calc_weighted_product(
+    amounts: &[Decimal256],
+    weights: &[Decimal256],
+) -> StdResult<Decimal256> {
+    amounts
+        .iter()
+        .zip(weights)
+        .try_fold(Decimal256::one(), |product, (&amount, &weight)| {
+            Ok(product.checked_mul(pow(amount, weight)?)?)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Decimal256, expected: Decimal256) {
+        let diff = if actual > expected {
+            actual - expected
+        } else {
+            expected - actual
+        };
+        assert!(
+            diff <= expected * Decimal256::from_ratio(1u8, 1_000_000_000_000u64),
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(
+            pow(Decimal256::percent(50), Decimal256::from_ratio(2u8, 1u8)).unwrap(),
+            Decimal256::percent(25)
+        );
+        assert_close(
+            pow(Decimal256::from_ratio(4u8, 1u8), Decimal256::percent(50)).unwrap(),
+            Decimal256::from_ratio(2u8, 1u8),
+        );
+        assert_close(
+            pow(Decimal256::from_ratio(16u8, 1u8), Decimal256::percent(125)).unwrap(),
+            Decimal256::from_ratio(32u8, 1u8),
+        );
+        assert_close(
+            pow(
+                Decimal256::from_ratio(27u8, 1u8),
+                Decimal256::from_ratio(1u8, 3u8),
+            )
+            .unwrap(),
+            Decimal256::from_ratio(3u8, 1u8),
+        );
+        assert_eq!(
+            pow(Decimal256::percent(70), Decimal256::zero()).unwrap(),
+            Decimal256::one()
+        );
+    }
+
+    #[test]
+    fn test_swap_math() {
+        // Equal weights behave like the constant product formula
+        let out = calc_out_given_in(
+            Decimal256::from_ratio(1000u16, 1u8),
+            Decimal256::percent(50),
+            Decimal256::from_ratio(1000u16, 1u8),
+            Decimal256::percent(50),
+            Decimal256::from_ratio(100u8, 1u8),
+        )
+        .unwrap();
+        assert_close(out, Decimal256::from_ratio(1000_000u32, 11000u16));
+
+        // 80/20 pool
+        let out = calc_out_given_in(
+            Decimal256::from_ratio(1000u16, 1u8),
+            Decimal256::percent(80),
+            Decimal256::from_ratio(1000u16, 1u8),
+            Decimal256::percent(20),
+            Decimal256::from_ratio(100u8, 1u8),
+        )
+        .unwrap();
+        let back = calc_in_given_out(
+            Decimal256::from_ratio(1000u16, 1u8),
+            Decimal256::percent(80),
+            Decimal256::from_ratio(1000u16, 1u8),
+            Decimal256::percent(20),
+            out,
+        )
+        .unwrap();
+        assert_close(back, Decimal256::from_ratio(100u8, 1u8));
+
+        assert_eq!(
+            calc_out_given_in(
+                Decimal256::from_ratio(1000u16, 1u8),
+                Decimal256::percent(50),
+                Decimal256::from_ratio(1000u16, 1u8),
+                Decimal256::percent(50),
+                Decimal256::from_ratio(301u16, 1u8),
+            )
+            .unwrap_err(),
+            ContractError::MaxInRatioExceeded {}
+        );
+        assert_eq!(
+            calc_in_given_out(
+                Decimal256::from_ratio(1000u16, 1u8),
+                Decimal256::percent(50),
+                Decimal256::from_ratio(1000u16, 1u8),
+                Decimal256::percent(50),
+                Decimal256::from_ratio(301u16, 1u8),
+            )
+            .unwrap_err(),
+            ContractError::MaxOutRatioExceeded {}
+        );
+    }
+
+    #[test]
+    fn test_invariant_ratio() {
+        let pools = [
+            Decimal256::from_ratio(800u16, 1u8),
+            Decimal256::from_ratio(200u16, 1u8),
+        ];
+        let weights = [Decimal256::percent(80), Decimal256::percent(20)];
+
+        // Proportional deposits are not taxed
+        let ratio = calc_invariant_ratio(
+            &pools,
+            &weights,
+            &[
+                Decimal256::from_ratio(80u8, 1u8),
+                Decimal256::from_ratio(20u8, 1u8),
+            ],
+            Decimal256::percent(1),
+        )
+        .unwrap();
+        assert_close(ratio, Decimal256::percent(110));
+
+        // Single-sided deposits are taxed
+        let taxed = calc_invariant_ratio(
+            &pools,
+            &weights,
+            &[Decimal256::from_ratio(80u8, 1u8), Decimal256::zero()],
+            Decimal256::percent(1),
+        )
+        .unwrap();
+        let untaxed = calc_invariant_ratio(
+            &pools,
+            &weights,
+            &[Decimal256::from_ratio(80u8, 1u8), Decimal256::zero()],
+            Decimal256::zero(),
+        )
+        .unwrap();
+        assert!(taxed < untaxed);
+
+        assert_close(
+            calc_weighted_product(
+                &[
+                    Decimal256::from_ratio(16u8, 1u8),
+                    Decimal256::from_ratio(81u8, 1u8),
+                ],
+                &[Decimal256::percent(50), Decimal256::percent(50)],
+            )
+            .unwrap(),
+            Decimal256::from_ratio(36u8, 1u8),
+        );
+    }
+}
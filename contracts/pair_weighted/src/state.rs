@@ -0,0 +1,52 @@
+use astroport::asset::{AssetInfo, PairInfo};
+use astroport::observation::Observation;
+use astroport_circular_buffer::CircularBuffer;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, DepsMut, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+
+/// This structure stores the main weighted pair parameters.
+#[cw_serde]
+pub struct Config {
+    /// The pair information stored in a [`PairInfo`] struct
+    pub pair_info: PairInfo,
+    /// The factory contract address
+    pub factory_addr: Addr,
+    /// The last timestamp when the pair contract update the asset cumulative prices
+    pub block_time_last: u64,
+    /// Normalized asset weights in the same order as the assets in `pair_info`
+    pub weights: Vec<Decimal>,
+    /// The greatest precision of assets in the pool
+    pub greatest_precision: u8,
+}
+
+/// Circular buffer to store trade size observations
+pub const OBSERVATIONS: CircularBuffer<Observation> =
+    CircularBuffer::new("observations_state", "observations_buffer");
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Stores map of AssetInfo (as String) -> precision
+pub const PRECISIONS: Map<String, u8> = Map::new("precisions");
+
+/// Store all token precisions and return the greatest one.
+pub(crate) fn store_precisions(
+    deps: DepsMut,
+    asset_infos: &[AssetInfo],
+    factory_addr: &Addr,
+) -> StdResult<u8> {
+    let mut max = 0u8;
+
+    for asset_info in asset_infos {
+        let precision = asset_info.decimals(&deps.querier, factory_addr)?;
+        max = max.max(precision);
+        PRECISIONS.save(deps.storage, asset_info.to_string(), &precision)?;
+    }
+
+    Ok(max)
+}
+
+/// Loads precision of the given asset info.
+pub(crate) fn get_precision(storage: &dyn Storage, asset_info: &AssetInfo) -> StdResult<u8> {
+    PRECISIONS.load(storage, asset_info.to_string())
+}
@@ -0,0 +1,129 @@
+use cosmwasm_std::{Addr, Api, Decimal, Decimal256, Storage, Uint128};
+use itertools::Itertools;
+
+use astroport::asset::{Asset, AssetInfo, Decimal256Ext, DecimalAsset};
+
+use crate::error::ContractError;
+use crate::math::calc_out_given_in;
+use crate::state::{get_precision, Config};
+
+/// Helper function to check if the given asset infos are valid.
+pub(crate) fn check_asset_infos(
+    api: &dyn Api,
+    asset_infos: &[AssetInfo],
+) -> Result<(), ContractError> {
+    if !asset_infos.iter().all_unique() {
+        return Err(ContractError::DoublingAssets {});
+    }
+
+    asset_infos
+        .iter()
+        .try_for_each(|asset_info| asset_info.check(api))
+        .map_err(Into::into)
+}
+
+/// Helper function to check that the assets in a given array are valid.
+pub(crate) fn check_assets(api: &dyn Api, assets: &[Asset]) -> Result<(), ContractError> {
+    let asset_infos = assets.iter().map(|asset| asset.info.clone()).collect_vec();
+    check_asset_infos(api, &asset_infos)
+}
+
+/// Checks that cw20 token is part of the pool.
+///
+/// * **cw20_sender** is cw20 token address which is being checked.
+pub(crate) fn check_cw20_in_pool(config: &Config, cw20_sender: &Addr) -> Result<(), ContractError> {
+    for asset_info in &config.pair_info.asset_infos {
+        match asset_info {
+            AssetInfo::Token { contract_addr } if contract_addr == cw20_sender => return Ok(()),
+            _ => {}
+        }
+    }
+
+    Err(ContractError::Unauthorized {})
+}
+
+/// Return the amount of tokens that a specific amount of LP tokens would withdraw.
+///
+/// * **pools** array with assets available in the pool.
+///
+/// * **amount** amount of LP tokens to calculate underlying amounts for.
+///
+/// * **total_share** total amount of LP tokens currently issued by the pool.
+pub(crate) fn get_share_in_assets(
+    pools: &[Asset],
+    amount: Uint128,
+    total_share: Uint128,
+) -> Vec<Asset> {
+    let mut share_ratio = Decimal::zero();
+    if !total_share.is_zero() {
+        share_ratio = Decimal::from_ratio(amount, total_share);
+    }
+
+    pools
+        .iter()
+        .map(|pool| Asset {
+            info: pool.info.clone(),
+            amount: pool.amount * share_ratio,
+        })
+        .collect()
+}
+
+/// Returns the weight of the given asset.
+///
+/// * **asset_info** asset to return the weight for.
+pub(crate) fn get_weight(
+    config: &Config,
+    asset_info: &AssetInfo,
+) -> Result<Decimal, ContractError> {
+    config
+        .pair_info
+        .asset_infos
+        .iter()
+        .position(|info| info.eq(asset_info))
+        .map(|ind| config.weights[ind])
+        .ok_or_else(|| ContractError::InvalidAsset(asset_info.to_string()))
+}
+
+/// Structure for internal use which represents swap result.
+pub(crate) struct SwapResult {
+    pub return_amount: Uint128,
+    pub spread_amount: Uint128,
+}
+
+/// Returns the result of a swap in form of a [`SwapResult`] object.
+/// The spread is calculated against the spot price of the pool.
+///
+/// * **offer_asset** asset that is being offered.
+///
+/// * **offer_pool** pool of offered asset.
+///
+/// * **ask_pool** asked asset.
+pub(crate) fn compute_swap(
+    storage: &dyn Storage,
+    config: &Config,
+    offer_asset: &DecimalAsset,
+    offer_pool: &DecimalAsset,
+    ask_pool: &DecimalAsset,
+) -> Result<SwapResult, ContractError> {
+    let token_precision = get_precision(storage, &ask_pool.info)?;
+    let offer_weight = Decimal256::from(get_weight(config, &offer_pool.info)?);
+    let ask_weight = Decimal256::from(get_weight(config, &ask_pool.info)?);
+
+    let return_amount = calc_out_given_in(
+        offer_pool.amount,
+        offer_weight,
+        ask_pool.amount,
+        ask_weight,
+        offer_asset.amount,
+    )?;
+
+    // Spot price: (ask_pool / ask_weight) / (offer_pool / offer_weight)
+    let expected_return =
+        offer_asset.amount * ask_pool.amount * offer_weight / (offer_pool.amount * ask_weight);
+    let spread_amount = expected_return.saturating_sub(return_amount);
+
+    Ok(SwapResult {
+        return_amount: return_amount.to_uint128_with_precision(token_precision)?,
+        spread_amount: spread_amount.to_uint128_with_precision(token_precision)?,
+    })
+}
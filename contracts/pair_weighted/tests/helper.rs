@@ -0,0 +1,381 @@
+#![cfg(not(tarpaulin_include))]
+
+use std::collections::HashMap;
+
+use anyhow::Result as AnyResult;
+use astroport_mocks::cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+use cosmwasm_std::{coin, to_binary, Addr, Coin, Decimal, Empty, StdResult, Uint128};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
+use itertools::Itertools;
+
+use astroport::asset::{native_asset_info, token_asset_info, Asset, AssetInfo, PairInfo};
+use astroport::factory::{PairConfig, PairType};
+use astroport::pair::{
+    Cw20HookMsg, ExecuteMsg, QueryMsg, ReverseSimulationResponse, SimulationResponse,
+    WeightedPoolParams,
+};
+use astroport_pair_weighted::contract::{execute, instantiate, query, reply, WEIGHTED_PAIR_TYPE};
+
+const INIT_BALANCE: u128 = 1_000_000_000000;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TestCoin {
+    Cw20(String),
+    Native(String),
+}
+
+impl TestCoin {
+    pub fn denom(&self) -> Option<String> {
+        match self {
+            TestCoin::Native(denom) => Some(denom.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn cw20_name(&self) -> Option<String> {
+        match self {
+            TestCoin::Cw20(name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn native(denom: &str) -> Self {
+        Self::Native(denom.to_string())
+    }
+
+    pub fn cw20(name: &str) -> Self {
+        Self::Cw20(name.to_string())
+    }
+}
+
+pub fn init_native_coins(test_coins: &[TestCoin]) -> Vec<Coin> {
+    test_coins
+        .iter()
+        .filter_map(|test_coin| match test_coin {
+            TestCoin::Native(name) => Some(coin(INIT_BALANCE * 1_000000, name)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn token_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        astroport_token::contract::execute,
+        astroport_token::contract::instantiate,
+        astroport_token::contract::query,
+    ))
+}
+
+fn pair_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(execute, instantiate, query).with_reply_empty(reply))
+}
+
+fn factory_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            astroport_factory::contract::execute,
+            astroport_factory::contract::instantiate,
+            astroport_factory::contract::query,
+        )
+        .with_reply_empty(astroport_factory::contract::reply),
+    )
+}
+
+fn store_coin_registry_code() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        astroport_native_coin_registry::contract::execute,
+        astroport_native_coin_registry::contract::instantiate,
+        astroport_native_coin_registry::contract::query,
+    ))
+}
+
+pub struct Helper {
+    pub app: App,
+    pub assets: HashMap<TestCoin, AssetInfo>,
+    pub pair_addr: Addr,
+    pub lp_token: Addr,
+}
+
+impl Helper {
+    pub fn new(
+        owner: &Addr,
+        test_coins: Vec<TestCoin>,
+        weights: Vec<Decimal>,
+        swap_fee: Option<u16>,
+    ) -> AnyResult<Self> {
+        let mut app = App::new(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, owner, init_native_coins(&test_coins))
+                .unwrap()
+        });
+
+        let token_code_id = app.store_code(token_contract());
+
+        let asset_infos_vec = test_coins
+            .iter()
+            .map(|coin| match coin.cw20_name() {
+                Some(name) => {
+                    let token_addr = Self::init_token(&mut app, token_code_id, name, owner);
+                    (coin.clone(), token_asset_info(token_addr))
+                }
+                None => (coin.clone(), native_asset_info(coin.denom().unwrap())),
+            })
+            .collect_vec();
+
+        let coin_registry_id = app.store_code(store_coin_registry_code());
+        let coin_registry_address = app
+            .instantiate_contract(
+                coin_registry_id,
+                owner.clone(),
+                &astroport::native_coin_registry::InstantiateMsg {
+                    owner: owner.to_string(),
+                },
+                &[],
+                "Coin registry",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            owner.clone(),
+            coin_registry_address.clone(),
+            &astroport::native_coin_registry::ExecuteMsg::Add {
+                native_coins: test_coins
+                    .iter()
+                    .filter_map(|coin| Some((coin.denom()?, 6)))
+                    .collect(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let pair_code_id = app.store_code(pair_contract());
+        let factory_code_id = app.store_code(factory_contract());
+
+        let init_msg = astroport::factory::InstantiateMsg {
+            fee_address: None,
+            pair_configs: vec![PairConfig {
+                code_id: pair_code_id,
+                maker_fee_bps: 5000,
+                total_fee_bps: swap_fee.unwrap_or(30u16),
+                pair_type: PairType::Custom(WEIGHTED_PAIR_TYPE.to_string()),
+                is_disabled: false,
+                is_generator_disabled: false,
+                pair_creation_fee: None,
+            }],
+            token_code_id,
+            generator_address: None,
+            owner: owner.to_string(),
+            whitelist_code_id: 234u64,
+            coin_registry_address: coin_registry_address.to_string(),
+        };
+
+        let factory = app.instantiate_contract(
+            factory_code_id,
+            owner.clone(),
+            &init_msg,
+            &[],
+            "FACTORY",
+            None,
+        )?;
+
+        let asset_infos = asset_infos_vec
+            .iter()
+            .map(|(_, asset_info)| asset_info.clone())
+            .collect_vec();
+        let init_pair_msg = astroport::factory::ExecuteMsg::CreatePair {
+            pair_type: PairType::Custom(WEIGHTED_PAIR_TYPE.to_string()),
+            asset_infos: asset_infos.clone(),
+            init_params: Some(to_binary(&WeightedPoolParams { weights }).unwrap()),
+        };
+
+        app.execute_contract(owner.clone(), factory.clone(), &init_pair_msg, &[])?;
+
+        let resp: PairInfo = app.wrap().query_wasm_smart(
+            &factory,
+            &astroport::factory::QueryMsg::Pair { asset_infos },
+        )?;
+
+        Ok(Self {
+            app,
+            assets: asset_infos_vec.into_iter().collect(),
+            pair_addr: resp.contract_addr,
+            lp_token: resp.liquidity_token,
+        })
+    }
+
+    pub fn provide_liquidity(&mut self, sender: &Addr, assets: &[Asset]) -> AnyResult<AppResponse> {
+        self.provide_liquidity_with_slip_tolerance(sender, assets, None)
+    }
+
+    pub fn provide_liquidity_with_slip_tolerance(
+        &mut self,
+        sender: &Addr,
+        assets: &[Asset],
+        slippage_tolerance: Option<Decimal>,
+    ) -> AnyResult<AppResponse> {
+        let mut funds = vec![];
+        for asset in assets {
+            match &asset.info {
+                AssetInfo::Token { contract_addr } => {
+                    self.app.execute_contract(
+                        sender.clone(),
+                        contract_addr.clone(),
+                        &Cw20ExecuteMsg::IncreaseAllowance {
+                            spender: self.pair_addr.to_string(),
+                            amount: asset.amount,
+                            expires: None,
+                        },
+                        &[],
+                    )?;
+                }
+                AssetInfo::NativeToken { denom } => {
+                    funds.push(coin(asset.amount.u128(), denom));
+                }
+            }
+        }
+        funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        let msg = ExecuteMsg::ProvideLiquidity {
+            assets: assets.to_vec(),
+            slippage_tolerance,
+            auto_stake: None,
+            receiver: None,
+        };
+
+        self.app
+            .execute_contract(sender.clone(), self.pair_addr.clone(), &msg, &funds)
+    }
+
+    pub fn withdraw_liquidity(&mut self, sender: &Addr, amount: u128) -> AnyResult<AppResponse> {
+        let msg = Cw20ExecuteMsg::Send {
+            contract: self.pair_addr.to_string(),
+            amount: Uint128::from(amount),
+            msg: to_binary(&Cw20HookMsg::WithdrawLiquidity { assets: vec![] }).unwrap(),
+        };
+
+        self.app
+            .execute_contract(sender.clone(), self.lp_token.clone(), &msg, &[])
+    }
+
+    pub fn swap(
+        &mut self,
+        sender: &Addr,
+        offer_asset: &Asset,
+        ask_asset_info: Option<AssetInfo>,
+    ) -> AnyResult<AppResponse> {
+        match &offer_asset.info {
+            AssetInfo::Token { contract_addr } => {
+                let msg = Cw20ExecuteMsg::Send {
+                    contract: self.pair_addr.to_string(),
+                    amount: offer_asset.amount,
+                    msg: to_binary(&Cw20HookMsg::Swap {
+                        ask_asset_info,
+                        belief_price: None,
+                        max_spread: Some(Decimal::percent(50)),
+                        to: None,
+                    })
+                    .unwrap(),
+                };
+
+                self.app
+                    .execute_contract(sender.clone(), contract_addr.clone(), &msg, &[])
+            }
+            AssetInfo::NativeToken { denom } => {
+                let msg = ExecuteMsg::Swap {
+                    offer_asset: offer_asset.clone(),
+                    ask_asset_info,
+                    belief_price: None,
+                    max_spread: Some(Decimal::percent(50)),
+                    to: None,
+                };
+
+                self.app.execute_contract(
+                    sender.clone(),
+                    self.pair_addr.clone(),
+                    &msg,
+                    &[coin(offer_asset.amount.u128(), denom)],
+                )
+            }
+        }
+    }
+
+    pub fn simulate_swap(
+        &self,
+        offer_asset: &Asset,
+        ask_asset_info: Option<AssetInfo>,
+    ) -> StdResult<SimulationResponse> {
+        self.app.wrap().query_wasm_smart(
+            &self.pair_addr,
+            &QueryMsg::Simulation {
+                offer_asset: offer_asset.clone(),
+                ask_asset_info,
+            },
+        )
+    }
+
+    pub fn simulate_reverse_swap(
+        &self,
+        ask_asset: &Asset,
+        offer_asset_info: Option<AssetInfo>,
+    ) -> StdResult<ReverseSimulationResponse> {
+        self.app.wrap().query_wasm_smart(
+            &self.pair_addr,
+            &QueryMsg::ReverseSimulation {
+                ask_asset: ask_asset.clone(),
+                offer_asset_info,
+            },
+        )
+    }
+
+    fn init_token(app: &mut App, token_code: u64, name: String, owner: &Addr) -> Addr {
+        app.instantiate_contract(
+            token_code,
+            owner.clone(),
+            &astroport::token::InstantiateMsg {
+                symbol: name.to_string(),
+                name,
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: owner.to_string(),
+                    amount: Uint128::from(INIT_BALANCE * 1_000000),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "{name}_token",
+            None,
+        )
+        .unwrap()
+    }
+
+    pub fn token_balance(&self, token_addr: &Addr, user: &Addr) -> u128 {
+        let resp: BalanceResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                token_addr,
+                &Cw20QueryMsg::Balance {
+                    address: user.to_string(),
+                },
+            )
+            .unwrap();
+
+        resp.balance.u128()
+    }
+
+    pub fn coin_balance(&self, coin: &TestCoin, user: &Addr) -> u128 {
+        match &self.assets[coin] {
+            AssetInfo::Token { contract_addr } => self.token_balance(contract_addr, user),
+            AssetInfo::NativeToken { denom } => self
+                .app
+                .wrap()
+                .query_balance(user, denom)
+                .unwrap()
+                .amount
+                .u128(),
+        }
+    }
+}
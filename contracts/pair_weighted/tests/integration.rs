@@ -0,0 +1,369 @@
+#![cfg(not(tarpaulin_include))]
+
+use cosmwasm_std::{from_binary, Addr, Decimal};
+
+use astroport::asset::{AssetInfoExt, MINIMUM_LIQUIDITY_AMOUNT};
+use astroport::factory::PairType;
+use astroport::pair::{ConfigResponse, PoolResponse, QueryMsg, WeightedPoolConfig};
+use astroport_pair_weighted::contract::WEIGHTED_PAIR_TYPE;
+use astroport_pair_weighted::error::ContractError;
+
+use crate::helper::{Helper, TestCoin};
+
+mod helper;
+
+fn assert_approx_eq(actual: u128, expected: u128, tolerance: u128) {
+    assert!(
+        actual.abs_diff(expected) <= tolerance,
+        "{actual} differs from {expected} by more than {tolerance}"
+    );
+}
+
+#[test]
+fn invalid_weights() {
+    let owner = Addr::unchecked("owner");
+    let test_coins = vec![TestCoin::native("uusd"), TestCoin::cw20("ASTRO")];
+
+    let err = Helper::new(
+        &owner,
+        test_coins.clone(),
+        vec![Decimal::percent(50), Decimal::percent(40)],
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidWeights {}.to_string()
+    );
+
+    let err = Helper::new(
+        &owner,
+        test_coins.clone(),
+        vec![Decimal::permille(995), Decimal::permille(5)],
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidWeights {}.to_string()
+    );
+
+    let err = Helper::new(&owner, test_coins, vec![Decimal::one()], None).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidWeights {}.to_string()
+    );
+}
+
+#[test]
+fn provide_and_withdraw() {
+    let owner = Addr::unchecked("owner");
+    let test_coins = vec![TestCoin::native("uusd"), TestCoin::cw20("ASTRO")];
+
+    let mut helper = Helper::new(
+        &owner,
+        test_coins.clone(),
+        vec![Decimal::percent(80), Decimal::percent(20)],
+        None,
+    )
+    .unwrap();
+
+    let pair_info: astroport::asset::PairInfo = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&helper.pair_addr, &QueryMsg::Pair {})
+        .unwrap();
+    assert_eq!(
+        pair_info.pair_type,
+        PairType::Custom(WEIGHTED_PAIR_TYPE.to_string())
+    );
+
+    let config: ConfigResponse = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&helper.pair_addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(
+        from_binary::<WeightedPoolConfig>(&config.params.unwrap()).unwrap(),
+        WeightedPoolConfig {
+            weights: vec![Decimal::percent(80), Decimal::percent(20)]
+        }
+    );
+
+    // The initial deposit must contain all assets
+    let err = helper
+        .provide_liquidity(
+            &owner,
+            &[helper.assets[&test_coins[0]].with_balance(800_000000u128)],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidProvideLPsWithSingleToken {}.to_string()
+    );
+
+    let assets = vec![
+        helper.assets[&test_coins[0]].with_balance(800_000000u128),
+        helper.assets[&test_coins[1]].with_balance(200_000000u128),
+    ];
+    helper.provide_liquidity(&owner, &assets).unwrap();
+
+    // 800^0.8 * 200^0.2 = 606.286626...
+    let initial_share = helper.token_balance(&helper.lp_token, &owner);
+    assert_approx_eq(
+        initial_share,
+        606_286626 - MINIMUM_LIQUIDITY_AMOUNT.u128(),
+        1,
+    );
+
+    // Proportional deposits are not charged with fees
+    let assets = vec![
+        helper.assets[&test_coins[0]].with_balance(80_000000u128),
+        helper.assets[&test_coins[1]].with_balance(20_000000u128),
+    ];
+    helper.provide_liquidity(&owner, &assets).unwrap();
+    let share = helper.token_balance(&helper.lp_token, &owner) - initial_share;
+    assert_approx_eq(share, 60_628662, 1);
+
+    let uusd_before = helper.coin_balance(&test_coins[0], &owner);
+    let astro_before = helper.coin_balance(&test_coins[1], &owner);
+    helper.withdraw_liquidity(&owner, share).unwrap();
+    assert_approx_eq(
+        helper.coin_balance(&test_coins[0], &owner) - uusd_before,
+        80_000000,
+        1,
+    );
+    assert_approx_eq(
+        helper.coin_balance(&test_coins[1], &owner) - astro_before,
+        20_000000,
+        1,
+    );
+}
+
+#[test]
+fn swap_80_20() {
+    let owner = Addr::unchecked("owner");
+    let test_coins = vec![TestCoin::native("uusd"), TestCoin::cw20("ASTRO")];
+
+    let mut helper = Helper::new(
+        &owner,
+        test_coins.clone(),
+        vec![Decimal::percent(80), Decimal::percent(20)],
+        Some(0),
+    )
+    .unwrap();
+
+    let assets = vec![
+        helper.assets[&test_coins[0]].with_balance(800_000000u128),
+        helper.assets[&test_coins[1]].with_balance(200_000000u128),
+    ];
+    helper.provide_liquidity(&owner, &assets).unwrap();
+
+    // 800 * (1 - (200 / 210) ^ (0.2 / 0.8)) = 9.698762...
+    let offer_asset = helper.assets[&test_coins[1]].with_balance(10_000000u128);
+    let sim = helper.simulate_swap(&offer_asset, None).unwrap();
+    assert_approx_eq(sim.return_amount.u128(), 9_698762, 2);
+    assert_eq!(sim.commission_amount.u128(), 0);
+    // The spot price is 1:1 thus the difference is considered as spread
+    assert_approx_eq(sim.spread_amount.u128(), 10_000000 - 9_698762, 2);
+
+    // 200 * ((800 / 795) ^ (0.8 / 0.2) - 1) = 5.079112...
+    let ask_asset = helper.assets[&test_coins[0]].with_balance(5_000000u128);
+    let reverse_sim = helper.simulate_reverse_swap(&ask_asset, None).unwrap();
+    assert_approx_eq(reverse_sim.offer_amount.u128(), 5_079112, 2);
+
+    let uusd_before = helper.coin_balance(&test_coins[0], &owner);
+    helper.swap(&owner, &offer_asset, None).unwrap();
+    assert_eq!(
+        helper.coin_balance(&test_coins[0], &owner) - uusd_before,
+        sim.return_amount.u128()
+    );
+
+    let pool: PoolResponse = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&helper.pair_addr, &QueryMsg::Pool {})
+        .unwrap();
+    assert_eq!(
+        pool.assets[0].amount.u128(),
+        800_000000 - sim.return_amount.u128()
+    );
+    assert_eq!(pool.assets[1].amount.u128(), 210_000000);
+
+    // Swaps larger than 30% of the offer pool are rejected
+    let offer_asset = helper.assets[&test_coins[1]].with_balance(70_000000u128);
+    let err = helper.swap(&owner, &offer_asset, None).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::MaxInRatioExceeded {}.to_string()
+    );
+}
+
+#[test]
+fn multi_asset_pool() {
+    let owner = Addr::unchecked("owner");
+    let test_coins = vec![
+        TestCoin::native("uusd"),
+        TestCoin::native("uluna"),
+        TestCoin::cw20("ASTRO"),
+    ];
+
+    let mut helper = Helper::new(
+        &owner,
+        test_coins.clone(),
+        vec![
+            Decimal::percent(50),
+            Decimal::percent(25),
+            Decimal::percent(25),
+        ],
+        None,
+    )
+    .unwrap();
+
+    let assets = vec![
+        helper.assets[&test_coins[0]].with_balance(500_000000u128),
+        helper.assets[&test_coins[1]].with_balance(250_000000u128),
+        helper.assets[&test_coins[2]].with_balance(250_000000u128),
+    ];
+    helper.provide_liquidity(&owner, &assets).unwrap();
+
+    // 500^0.5 * 250^0.25 * 250^0.25 = 353.553390...
+    let initial_share = helper.token_balance(&helper.lp_token, &owner);
+    assert_approx_eq(
+        initial_share,
+        353_553390 - MINIMUM_LIQUIDITY_AMOUNT.u128(),
+        1,
+    );
+
+    // The ask asset must be specified in pools with more than 2 assets
+    let offer_asset = helper.assets[&test_coins[1]].with_balance(10_000000u128);
+    let err = helper.swap(&owner, &offer_asset, None).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::VariableAssetMissed {}.to_string()
+    );
+
+    let astro_before = helper.coin_balance(&test_coins[2], &owner);
+    let sim = helper
+        .simulate_swap(&offer_asset, Some(helper.assets[&test_coins[2]].clone()))
+        .unwrap();
+    helper
+        .swap(
+            &owner,
+            &offer_asset,
+            Some(helper.assets[&test_coins[2]].clone()),
+        )
+        .unwrap();
+    assert_eq!(
+        helper.coin_balance(&test_coins[2], &owner) - astro_before,
+        sim.return_amount.u128()
+    );
+
+    // Single-sided deposits are charged with the swap fee for the non-proportional part
+    let share_before = helper.token_balance(&helper.lp_token, &owner);
+    helper
+        .provide_liquidity(
+            &owner,
+            &[helper.assets[&test_coins[0]].with_balance(50_000000u128)],
+        )
+        .unwrap();
+    let share = helper.token_balance(&helper.lp_token, &owner) - share_before;
+
+    // The fee and the price impact exceed a 1% slippage tolerance
+    let err = helper
+        .provide_liquidity_with_slip_tolerance(
+            &owner,
+            &[helper.assets[&test_coins[0]].with_balance(50_000000u128)],
+            Some(Decimal::percent(1)),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::MaxSlippageAssertion {}.to_string()
+    );
+
+    // Without fees the share would be total_share * ((550 / 500) ^ 0.5 - 1)
+    let untaxed_share = (share_before + MINIMUM_LIQUIDITY_AMOUNT.u128()) * 48808848 / 1000000000;
+    assert!(share < untaxed_share);
+    assert!(share > untaxed_share * 99 / 100);
+}
+
+#[test]
+fn equal_weights_are_normalized() {
+    let owner = Addr::unchecked("owner");
+    let test_coins = vec![
+        TestCoin::native("uusd"),
+        TestCoin::native("uluna"),
+        TestCoin::cw20("ASTRO"),
+    ];
+
+    let helper = Helper::new(
+        &owner,
+        test_coins,
+        vec![Decimal::from_ratio(1u128, 3u128); 3],
+        None,
+    )
+    .unwrap();
+
+    let config: ConfigResponse = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&helper.pair_addr, &QueryMsg::Config {})
+        .unwrap();
+    let weights = from_binary::<WeightedPoolConfig>(&config.params.unwrap())
+        .unwrap()
+        .weights;
+    assert_eq!(
+        weights.iter().fold(Decimal::zero(), |acc, w| acc + *w),
+        Decimal::one()
+    );
+}
+
+#[test]
+fn provide_with_slippage_tolerance() {
+    let owner = Addr::unchecked("owner");
+    let test_coins = vec![TestCoin::native("uusd"), TestCoin::cw20("ASTRO")];
+
+    let mut helper = Helper::new(
+        &owner,
+        test_coins.clone(),
+        vec![Decimal::percent(50), Decimal::percent(50)],
+        None,
+    )
+    .unwrap();
+
+    let assets = vec![
+        helper.assets[&test_coins[0]].with_balance(100_000000u128),
+        helper.assets[&test_coins[1]].with_balance(100_000000u128),
+    ];
+    helper.provide_liquidity(&owner, &assets).unwrap();
+
+    // Proportional deposits fit into any tolerance
+    let assets = vec![
+        helper.assets[&test_coins[0]].with_balance(10_000000u128),
+        helper.assets[&test_coins[1]].with_balance(10_000000u128),
+    ];
+    helper
+        .provide_liquidity_with_slip_tolerance(&owner, &assets, Some(Decimal::permille(1)))
+        .unwrap();
+
+    let single = [helper.assets[&test_coins[0]].with_balance(20_000000u128)];
+    let err = helper
+        .provide_liquidity_with_slip_tolerance(&owner, &single, Some(Decimal::percent(2)))
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::MaxSlippageAssertion {}.to_string()
+    );
+    helper
+        .provide_liquidity_with_slip_tolerance(&owner, &single, Some(Decimal::percent(10)))
+        .unwrap();
+
+    let err = helper
+        .provide_liquidity_with_slip_tolerance(&owner, &single, Some(Decimal::percent(51)))
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::AllowedSpreadAssertion {}.to_string()
+    );
+}
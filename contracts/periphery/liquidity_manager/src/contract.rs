@@ -19,6 +19,10 @@ use astroport_pair::contract::get_share_in_assets;
 use crate::error::ContractError;
 use crate::state::{ActionParams, Config, ReplyData, CONFIG, REPLY_DATA};
 use crate::utils::{query_cw20_minter, query_lp_amount, xyk_provide_simulation};
+use crate::vault::{
+    compound_vault, create_vault, deposit_to_vault, handle_callback, update_vault,
+    withdraw_from_vault,
+};
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -53,7 +57,24 @@ pub fn execute(
             min_lp_to_receive,
         } => {
             let pair_addr = deps.api.addr_validate(&pair_addr)?;
-            provide_liquidity(deps, info, env, pair_addr, msg, min_lp_to_receive)
+            match msg {
+                PairExecuteMsg::ProvideSingleAsset {
+                    asset,
+                    min_lp_to_receive: pair_min_lp_to_receive,
+                    auto_stake,
+                    receiver,
+                } => provide_single_asset(
+                    deps,
+                    info,
+                    env,
+                    pair_addr,
+                    asset,
+                    pair_min_lp_to_receive.max(min_lp_to_receive),
+                    auto_stake,
+                    receiver,
+                ),
+                _ => provide_liquidity(deps, info, env, pair_addr, msg, min_lp_to_receive),
+            }
         }
         ExecuteMsg::Receive(cw20_msg) => match from_binary(&cw20_msg.msg)? {
             Cw20HookMsg::WithdrawLiquidity {
@@ -67,8 +88,52 @@ pub fn execute(
                 msg,
                 min_assets_to_receive,
             ),
+            Cw20HookMsg::DepositToVault {
+                receiver,
+                min_shares,
+            } => {
+                let receiver = addr_opt_validate(deps.api, &receiver)?
+                    .unwrap_or_else(|| Addr::unchecked(cw20_msg.sender));
+                deposit_to_vault(
+                    deps,
+                    env,
+                    info.sender,
+                    receiver,
+                    cw20_msg.amount,
+                    min_shares,
+                )
+            }
             _ => Err(ContractError::UnsupportedCw20HookMsg {}),
         },
+        ExecuteMsg::CreateVault {
+            lp_token,
+            router,
+            reward_routes,
+            max_spread,
+            keeper,
+        } => create_vault(
+            deps,
+            info,
+            lp_token,
+            router,
+            reward_routes,
+            max_spread,
+            keeper,
+        ),
+        ExecuteMsg::UpdateVault {
+            lp_token,
+            reward_routes,
+            max_spread,
+            keeper,
+        } => update_vault(deps, info, lp_token, reward_routes, max_spread, keeper),
+        ExecuteMsg::WithdrawFromVault { lp_token, shares } => {
+            withdraw_from_vault(deps, env, info, lp_token, shares)
+        }
+        ExecuteMsg::CompoundVault {
+            lp_token,
+            min_lp_to_receive,
+        } => compound_vault(deps, env, info, lp_token, min_lp_to_receive),
+        ExecuteMsg::Callback(msg) => handle_callback(deps, env, info, msg),
     }
 }
 
@@ -337,6 +402,61 @@ fn provide_liquidity(
     }
 }
 
+/// Forwards single asset liquidity provision to the pair. The pair swaps part of the asset internally
+/// and enforces the minimum LP amount itself thus no reply is needed.
+#[allow(clippy::too_many_arguments)]
+fn provide_single_asset(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    pair_addr: Addr,
+    asset: Asset,
+    min_lp_to_receive: Option<Uint128>,
+    auto_stake: Option<bool>,
+    receiver: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut messages = vec![];
+
+    // pull cw20 tokens and increase allowance for pair contract
+    if let AssetInfo::Token { contract_addr } = &asset.info {
+        messages.push(wasm_execute(
+            contract_addr,
+            &Cw20ExecuteMsg::TransferFrom {
+                owner: info.sender.to_string(),
+                recipient: env.contract.address.to_string(),
+                amount: asset.amount,
+            },
+            vec![],
+        )?);
+        messages.push(wasm_execute(
+            contract_addr,
+            &Cw20ExecuteMsg::IncreaseAllowance {
+                spender: pair_addr.to_string(),
+                amount: asset.amount,
+                expires: Some(Expiration::AtHeight(env.block.height + 1)),
+            },
+            vec![],
+        )?);
+    }
+
+    let receiver = addr_opt_validate(deps.api, &receiver)?.unwrap_or(info.sender);
+    messages.push(wasm_execute(
+        &pair_addr,
+        &PairExecuteMsg::ProvideSingleAsset {
+            asset,
+            min_lp_to_receive,
+            auto_stake,
+            receiver: Some(receiver.to_string()),
+        },
+        info.funds,
+    )?);
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("contract", "liquidity_manager")
+        .add_attribute("action", "provide_single_asset"))
+}
+
 fn withdraw_liquidity(
     deps: DepsMut,
     lp_token_addr: Addr,
@@ -38,4 +38,43 @@ pub enum ContractError {
 
     #[error("Liquidity manager supports only pools with 2 assets")]
     WrongPoolLength {},
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Vault for {0} already exists")]
+    VaultAlreadyExists(String),
+
+    #[error("Vault for {0} doesn't exist")]
+    VaultNotFound(String),
+
+    #[error("Invalid reward route for {0}")]
+    InvalidRewardRoute(String),
+
+    #[error("Duplicated reward route for {0}")]
+    DuplicatedRewardRoute(String),
+
+    #[error("Amount must be greater than zero")]
+    InvalidZeroAmount {},
+
+    #[error("Not enough shares: available {available}, requested {requested}")]
+    NotEnoughShares {
+        available: Uint128,
+        requested: Uint128,
+    },
+
+    #[error("Max spread can not exceed {0}")]
+    MaxSpreadTooHigh(String),
+
+    #[error("Vault has no deposits")]
+    EmptyVault {},
+
+    #[error("Initial vault deposit must be greater than {0} LP tokens")]
+    MinimumVaultDeposit(Uint128),
+
+    #[error("Slippage is more than expected: received {0}, expected {1} vault shares")]
+    VaultSlippageViolation(Uint128, Uint128),
+
+    #[error("Compounding yielded {0} LP tokens but expected at least {1}")]
+    CompoundSlippageViolation(Uint128, Uint128),
 }
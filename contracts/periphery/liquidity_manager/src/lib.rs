@@ -3,3 +3,4 @@ pub mod error;
 pub mod query;
 pub mod state;
 pub mod utils;
+pub mod vault;
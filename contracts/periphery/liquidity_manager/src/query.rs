@@ -4,13 +4,16 @@ use cosmwasm_std::{to_binary, Binary, Deps, Env, StdError, StdResult, Uint128};
 
 use astroport::asset::{Asset, PairInfo};
 use astroport::factory::PairType;
-use astroport::liquidity_manager::QueryMsg;
+use astroport::liquidity_manager::{QueryMsg, VaultResponse, VaultSharesResponse};
 use astroport::pair::{ExecuteMsg as PairExecuteMsg, QueryMsg as PairQueryMsg};
 use astroport::querier::query_supply;
 use astroport_pair::contract::get_share_in_assets;
 
 use crate::error::ContractError;
-use crate::utils::{convert_config, stableswap_provide_simulation, xyk_provide_simulation};
+use crate::state::{CONFIG, VAULTS, VAULT_SHARES};
+use crate::utils::{
+    convert_config, query_vault_lp_amount, stableswap_provide_simulation, xyk_provide_simulation,
+};
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
@@ -23,6 +26,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             pair_addr,
             lp_tokens,
         } => simulate_withdraw(deps, pair_addr, lp_tokens),
+        QueryMsg::Vault { lp_token } => to_binary(&query_vault(deps, env, lp_token)?),
+        QueryMsg::VaultShares { lp_token, user } => {
+            to_binary(&query_vault_shares(deps, env, lp_token, user)?)
+        }
     }
 }
 
@@ -137,6 +144,61 @@ fn simulate_withdraw(deps: Deps, pair_addr: String, lp_tokens: Uint128) -> StdRe
     to_binary(&assets)
 }
 
+fn query_vault(deps: Deps, env: Env, lp_token: String) -> StdResult<VaultResponse> {
+    let lp_token = deps.api.addr_validate(&lp_token)?;
+    let vault = VAULTS.load(deps.storage, &lp_token)?;
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
+    let total_lp = query_vault_lp_amount(
+        deps.querier,
+        &factory_addr,
+        &vault,
+        &lp_token,
+        &env.contract.address,
+    )?;
+
+    Ok(VaultResponse {
+        pair_addr: vault.pair_info.contract_addr,
+        lp_token,
+        router_addr: vault.router_addr,
+        reward_routes: vault.reward_routes,
+        max_spread: vault.max_spread,
+        keeper: vault.keeper,
+        total_lp,
+        total_shares: vault.total_shares,
+        idle_rewards: vault.idle_rewards,
+    })
+}
+
+fn query_vault_shares(
+    deps: Deps,
+    env: Env,
+    lp_token: String,
+    user: String,
+) -> StdResult<VaultSharesResponse> {
+    let lp_token = deps.api.addr_validate(&lp_token)?;
+    let user = deps.api.addr_validate(&user)?;
+    let vault = VAULTS.load(deps.storage, &lp_token)?;
+    let shares = VAULT_SHARES
+        .may_load(deps.storage, (&lp_token, &user))?
+        .unwrap_or_default();
+
+    let lp_amount = if shares.is_zero() {
+        Uint128::zero()
+    } else {
+        let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
+        query_vault_lp_amount(
+            deps.querier,
+            &factory_addr,
+            &vault,
+            &lp_token,
+            &env.contract.address,
+        )?
+        .multiply_ratio(shares, vault.total_shares)
+    };
+
+    Ok(VaultSharesResponse { shares, lp_amount })
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
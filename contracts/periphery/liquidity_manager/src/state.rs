@@ -1,8 +1,9 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw_storage_plus::{Item, Map};
 
-use astroport::asset::Asset;
+use astroport::asset::{Asset, PairInfo};
+use astroport::liquidity_manager::RewardRoute;
 
 #[cw_serde]
 pub struct Config {
@@ -32,3 +33,26 @@ pub struct ReplyData {
 }
 
 pub const REPLY_DATA: Item<ReplyData> = Item::new("reply_data");
+
+#[cw_serde]
+pub struct Vault {
+    /// The pair which vault compounds into
+    pub pair_info: PairInfo,
+    /// The router contract used to swap rewards into pool assets
+    pub router_addr: Addr,
+    /// Swap routes for the rewards which are not pool assets
+    pub reward_routes: Vec<RewardRoute>,
+    /// The max spread used for every reward swap and liquidity provision
+    pub max_spread: Decimal,
+    /// The address which is allowed to compound the vault
+    pub keeper: Addr,
+    /// The total amount of vault shares
+    pub total_shares: Uint128,
+    /// Rewards which were claimed but not compounded yet
+    pub idle_rewards: Vec<Asset>,
+}
+
+/// Vaults keyed by LP token
+pub const VAULTS: Map<&Addr, Vault> = Map::new("vaults");
+/// Vault shares keyed by (LP token, user)
+pub const VAULT_SHARES: Map<(&Addr, &Addr), Uint128> = Map::new("vault_shares");
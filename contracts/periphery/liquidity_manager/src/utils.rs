@@ -4,9 +4,12 @@ use cosmwasm_std::{
     from_slice, Addr, Decimal, Decimal256, Env, QuerierWrapper, StdError, StdResult, Uint128,
 };
 
-use astroport::asset::{Asset, Decimal256Ext, DecimalAsset, PairInfo, MINIMUM_LIQUIDITY_AMOUNT};
+use astroport::asset::{
+    Asset, AssetInfo, Decimal256Ext, DecimalAsset, PairInfo, MINIMUM_LIQUIDITY_AMOUNT,
+};
 use astroport::generator::QueryMsg as GeneratorQueryMsg;
 use astroport::liquidity_manager::CompatPairStableConfig;
+use astroport::pair::{RateProviderQueryMsg, RateResponse};
 use astroport::querier::{query_supply, query_token_balance};
 use astroport::U256;
 use astroport_pair::{
@@ -14,9 +17,11 @@ use astroport_pair::{
 };
 use astroport_pair_stable::error::ContractError as StableContractError;
 use astroport_pair_stable::math::compute_d;
-use astroport_pair_stable::state::Config as PairStableConfig;
+use astroport_pair_stable::state::{Config as PairStableConfig, CACHED_RATES, RATE_PROVIDERS};
 use astroport_pair_stable::utils::compute_current_amp;
 
+use crate::state::Vault;
+
 pub fn query_lp_amount(
     querier: QuerierWrapper,
     lp_token_addr: String,
@@ -25,27 +30,48 @@ pub fn query_lp_amount(
     user: &String,
 ) -> StdResult<Uint128> {
     if staked_in_generator {
-        let maybe_generator = astroport_factory::state::CONFIG
-            .query(&querier, factory_addr)?
-            .generator_address;
-        if let Some(generator_addr) = maybe_generator {
-            querier.query_wasm_smart(
-                generator_addr,
-                &GeneratorQueryMsg::Deposit {
-                    lp_token: lp_token_addr,
-                    user: user.to_string(),
-                },
-            )
-        } else {
-            Err(StdError::generic_err(
-                "Generator address is not set in factory config",
-            ))
-        }
+        let generator_addr = query_generator_addr(querier, &factory_addr)?;
+        querier.query_wasm_smart(
+            generator_addr,
+            &GeneratorQueryMsg::Deposit {
+                lp_token: lp_token_addr,
+                user: user.to_string(),
+            },
+        )
     } else {
         query_token_balance(&querier, lp_token_addr, user)
     }
 }
 
+pub fn query_generator_addr(querier: QuerierWrapper, factory_addr: &Addr) -> StdResult<Addr> {
+    astroport_factory::state::CONFIG
+        .query(&querier, factory_addr.clone())?
+        .generator_address
+        .ok_or_else(|| StdError::generic_err("Generator address is not set in factory config"))
+}
+
+/// Returns the amount of LP tokens staked by the vault.
+/// The incentives contract doesn't keep empty positions thus the query is skipped for empty vaults.
+pub fn query_vault_lp_amount(
+    querier: QuerierWrapper,
+    factory_addr: &Addr,
+    vault: &Vault,
+    lp_token_addr: &Addr,
+    contract_addr: &Addr,
+) -> StdResult<Uint128> {
+    if vault.total_shares.is_zero() {
+        Ok(Uint128::zero())
+    } else {
+        query_lp_amount(
+            querier,
+            lp_token_addr.to_string(),
+            factory_addr.clone(),
+            true,
+            &contract_addr.to_string(),
+        )
+    }
+}
+
 pub fn query_cw20_minter(querier: QuerierWrapper, lp_token_addr: Addr) -> StdResult<Addr> {
     cw20_base::state::TOKEN_INFO
         .query(&querier, lp_token_addr.clone())?
@@ -201,10 +227,17 @@ pub fn stableswap_provide_simulation(
 
     let amp = compute_current_amp(&config, &env)?;
 
+    let asset_infos = assets_collection
+        .iter()
+        .map(|(deposit, _)| deposit.info.clone())
+        .collect::<Vec<_>>();
+    let rates = query_stable_rates(querier, &env, &config.pair_info.contract_addr, &asset_infos)?;
+
     // Invariant (D) after deposit added
     let new_balances = assets_collection
         .iter()
-        .map(|(deposit, pool)| Ok(pool + deposit.amount))
+        .zip(&rates)
+        .map(|((deposit, pool), &rate)| Ok((pool + deposit.amount) * rate))
         .collect::<StdResult<Vec<_>>>()?;
     let deposit_d = compute_d(amp, &new_balances)?;
 
@@ -225,7 +258,8 @@ pub fn stableswap_provide_simulation(
         // Initial invariant (D)
         let old_balances = assets_collection
             .iter()
-            .map(|(_, pool)| *pool)
+            .zip(&rates)
+            .map(|((_, pool), &rate)| *pool * rate)
             .collect::<Vec<_>>();
         let init_d = compute_d(amp, &old_balances)?;
 
@@ -243,6 +277,48 @@ pub fn stableswap_provide_simulation(
     Ok(share)
 }
 
+/// Returns the rates the stable pair scales its assets by. Mirrors the pair logic: assets without
+/// a rate provider have a rate of one and a rate cached by the pair in the current block is reused.
+pub fn query_stable_rates(
+    querier: QuerierWrapper,
+    env: &Env,
+    pair_addr: &Addr,
+    asset_infos: &[AssetInfo],
+) -> Result<Vec<Decimal256>, StableContractError> {
+    asset_infos
+        .iter()
+        .map(|asset_info| {
+            let provider =
+                match RATE_PROVIDERS.query(&querier, pair_addr.clone(), asset_info.to_string())? {
+                    Some(provider) => provider,
+                    None => return Ok(Decimal256::one()),
+                };
+
+            if let Some(cached) =
+                CACHED_RATES.query(&querier, pair_addr.clone(), asset_info.to_string())?
+            {
+                if cached.block_height == env.block.height {
+                    return Ok(cached.rate);
+                }
+            }
+
+            let response: RateResponse = querier
+                .query_wasm_smart(&provider.provider_addr, &RateProviderQueryMsg::Rate {})?;
+
+            if response.rate.is_zero() {
+                return Err(StableContractError::InvalidRate(asset_info.to_string()));
+            }
+
+            if env.block.time.seconds() > response.updated_at.saturating_add(provider.max_staleness)
+            {
+                return Err(StableContractError::StaleRate(asset_info.to_string()));
+            }
+
+            Ok(Decimal256::new(response.rate.atomics().into()))
+        })
+        .collect()
+}
+
 pub fn convert_config(
     querier: QuerierWrapper,
     config_data: Vec<u8>,
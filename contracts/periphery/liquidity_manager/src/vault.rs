@@ -0,0 +1,836 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use cosmwasm_std::{
+    to_binary, wasm_execute, Addr, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    QuerierWrapper, Response, StdResult, Storage, Uint128,
+};
+use cw20::{Cw20ExecuteMsg, Expiration};
+
+use astroport::asset::{Asset, AssetInfo, AssetInfoExt, PairInfo, MINIMUM_LIQUIDITY_AMOUNT};
+use astroport::factory::PairType;
+use astroport::incentives::{Cw20Msg as IncentivesCw20Msg, ExecuteMsg as IncentivesExecuteMsg};
+use astroport::liquidity_manager::{CallbackMsg, RewardRoute};
+use astroport::pair::{
+    ExecuteMsg as PairExecuteMsg, QueryMsg as PairQueryMsg, MAX_ALLOWED_SLIPPAGE,
+};
+use astroport::router::{
+    Cw20HookMsg as RouterCw20HookMsg, ExecuteMsg as RouterExecuteMsg, MAX_SWAP_OPERATIONS,
+};
+
+use crate::error::ContractError;
+use crate::state::{Vault, CONFIG, VAULTS, VAULT_SHARES};
+use crate::utils::{query_cw20_minter, query_generator_addr, query_vault_lp_amount};
+
+/// Creates a new vault for the specified LP token.
+///
+/// * **lp_token** LP token of the pair which vault compounds into.
+///
+/// * **router** router contract used to swap rewards into pool assets.
+///
+/// * **reward_routes** swap routes for the rewards which are not pool assets.
+///
+/// * **max_spread** max spread used for every reward swap and liquidity provision.
+///
+/// * **keeper** address which is allowed to compound the vault.
+pub fn create_vault(
+    deps: DepsMut,
+    info: MessageInfo,
+    lp_token: String,
+    router: String,
+    reward_routes: Vec<RewardRoute>,
+    max_spread: Decimal,
+    keeper: String,
+) -> Result<Response, ContractError> {
+    assert_factory_owner(
+        deps.querier,
+        &info.sender,
+        &CONFIG.load(deps.storage)?.factory_addr,
+    )?;
+
+    let lp_token = deps.api.addr_validate(&lp_token)?;
+    if VAULTS.has(deps.storage, &lp_token) {
+        return Err(ContractError::VaultAlreadyExists(lp_token.to_string()));
+    }
+
+    let pair_addr = query_cw20_minter(deps.querier, lp_token.clone())?;
+    let pair_info: PairInfo = deps
+        .querier
+        .query_wasm_smart(&pair_addr, &PairQueryMsg::Pair {})?;
+    if pair_info.asset_infos.len() != 2 {
+        return Err(ContractError::WrongPoolLength {});
+    }
+
+    validate_reward_routes(&pair_info, &reward_routes)?;
+    validate_max_spread(max_spread)?;
+
+    let vault = Vault {
+        pair_info,
+        router_addr: deps.api.addr_validate(&router)?,
+        reward_routes,
+        max_spread,
+        keeper: deps.api.addr_validate(&keeper)?,
+        total_shares: Uint128::zero(),
+        idle_rewards: vec![],
+    };
+    VAULTS.save(deps.storage, &lp_token, &vault)?;
+
+    Ok(Response::new()
+        .add_attribute("contract", "liquidity_manager")
+        .add_attribute("action", "create_vault")
+        .add_attribute("lp_token", lp_token))
+}
+
+/// Updates vault reward routes, max spread and keeper.
+pub fn update_vault(
+    deps: DepsMut,
+    info: MessageInfo,
+    lp_token: String,
+    reward_routes: Option<Vec<RewardRoute>>,
+    max_spread: Option<Decimal>,
+    keeper: Option<String>,
+) -> Result<Response, ContractError> {
+    assert_factory_owner(
+        deps.querier,
+        &info.sender,
+        &CONFIG.load(deps.storage)?.factory_addr,
+    )?;
+
+    let lp_token = deps.api.addr_validate(&lp_token)?;
+    let mut vault = load_vault(deps.as_ref().storage, &lp_token)?;
+
+    if let Some(reward_routes) = reward_routes {
+        validate_reward_routes(&vault.pair_info, &reward_routes)?;
+        vault.reward_routes = reward_routes;
+    }
+
+    if let Some(max_spread) = max_spread {
+        validate_max_spread(max_spread)?;
+        vault.max_spread = max_spread;
+    }
+
+    if let Some(keeper) = keeper {
+        vault.keeper = deps.api.addr_validate(&keeper)?;
+    }
+
+    VAULTS.save(deps.storage, &lp_token, &vault)?;
+
+    Ok(Response::new()
+        .add_attribute("contract", "liquidity_manager")
+        .add_attribute("action", "update_vault")
+        .add_attribute("lp_token", lp_token))
+}
+
+/// Compounds pending vault rewards and schedules minting of vault shares for the deposited LP tokens.
+/// Rewards accrued before the deposit belong to the current shareholders thus they are compounded first
+/// and shares are priced by staked LP tokens only.
+pub fn deposit_to_vault(
+    deps: DepsMut,
+    env: Env,
+    lp_token: Addr,
+    receiver: Addr,
+    amount: Uint128,
+    min_shares: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let vault = load_vault(deps.as_ref().storage, &lp_token)?;
+
+    let mut messages = vec![];
+    if !vault.total_shares.is_zero() {
+        messages.extend(claim_rewards_msgs(deps.as_ref(), &env, &vault, &lp_token)?);
+        messages.push(
+            CallbackMsg::SwapRewards {
+                lp_token: lp_token.clone(),
+            }
+            .into_cosmos_msg(&env.contract.address)?,
+        );
+    }
+    messages.push(
+        CallbackMsg::MintShares {
+            lp_token: lp_token.clone(),
+            receiver: receiver.clone(),
+            amount,
+            min_shares,
+        }
+        .into_cosmos_msg(&env.contract.address)?,
+    );
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("contract", "liquidity_manager")
+        .add_attribute("action", "deposit_to_vault")
+        .add_attribute("lp_token", lp_token)
+        .add_attribute("receiver", receiver)
+        .add_attribute("amount", amount))
+}
+
+/// Claims pending vault rewards and schedules burning of the sender vault shares.
+pub fn withdraw_from_vault(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    lp_token: String,
+    shares: Uint128,
+) -> Result<Response, ContractError> {
+    if shares.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let lp_token = deps.api.addr_validate(&lp_token)?;
+    let vault = load_vault(deps.as_ref().storage, &lp_token)?;
+    assert_enough_shares(deps.as_ref().storage, &lp_token, &info.sender, shares)?;
+
+    let mut messages = claim_rewards_msgs(deps.as_ref(), &env, &vault, &lp_token)?;
+    messages.push(
+        CallbackMsg::BurnShares {
+            lp_token: lp_token.clone(),
+            owner: info.sender,
+            shares,
+        }
+        .into_cosmos_msg(&env.contract.address)?,
+    );
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("contract", "liquidity_manager")
+        .add_attribute("action", "withdraw_from_vault")
+        .add_attribute("lp_token", lp_token)
+        .add_attribute("shares", shares))
+}
+
+/// Claims vault rewards and schedules their conversion into LP tokens.
+/// The whole compounding fails if the vault receives less than `min_lp_to_receive` LP tokens.
+pub fn compound_vault(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    lp_token: String,
+    min_lp_to_receive: Uint128,
+) -> Result<Response, ContractError> {
+    let lp_token = deps.api.addr_validate(&lp_token)?;
+    let vault = load_vault(deps.as_ref().storage, &lp_token)?;
+    if info.sender != vault.keeper {
+        return Err(ContractError::Unauthorized {});
+    }
+    if vault.total_shares.is_zero() {
+        return Err(ContractError::EmptyVault {});
+    }
+
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
+    let lp_before = query_vault_lp_amount(
+        deps.querier,
+        &factory_addr,
+        &vault,
+        &lp_token,
+        &env.contract.address,
+    )?;
+
+    let mut messages = claim_rewards_msgs(deps.as_ref(), &env, &vault, &lp_token)?;
+    messages.extend([
+        CallbackMsg::SwapRewards {
+            lp_token: lp_token.clone(),
+        }
+        .into_cosmos_msg(&env.contract.address)?,
+        CallbackMsg::AssertCompounded {
+            lp_token: lp_token.clone(),
+            lp_before,
+            min_lp_to_receive,
+        }
+        .into_cosmos_msg(&env.contract.address)?,
+    ]);
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("contract", "liquidity_manager")
+        .add_attribute("action", "compound_vault")
+        .add_attribute("lp_token", lp_token))
+}
+
+pub fn handle_callback(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: CallbackMsg,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    match msg {
+        CallbackMsg::TrackRewards {
+            lp_token,
+            balances_before,
+        } => track_rewards(deps, env, lp_token, balances_before),
+        CallbackMsg::SwapRewards { lp_token } => swap_rewards(deps, env, lp_token),
+        CallbackMsg::ProvideAndStake {
+            lp_token,
+            balances_before,
+        } => provide_and_stake(deps, env, lp_token, balances_before),
+        CallbackMsg::MintShares {
+            lp_token,
+            receiver,
+            amount,
+            min_shares,
+        } => mint_shares(deps, env, lp_token, receiver, amount, min_shares),
+        CallbackMsg::BurnShares {
+            lp_token,
+            owner,
+            shares,
+        } => burn_shares(deps, env, lp_token, owner, shares),
+        CallbackMsg::AssertCompounded {
+            lp_token,
+            lp_before,
+            min_lp_to_receive,
+        } => assert_compounded(deps, env, lp_token, lp_before, min_lp_to_receive),
+    }
+}
+
+/// Stakes LP tokens in the incentives contract on behalf of the vault and mints vault shares for the receiver.
+/// Shares are priced by the amount of LP tokens staked by the vault. Idle rewards are compounded before
+/// minting thus they are not priced at spot.
+/// The first deposit locks [`MINIMUM_LIQUIDITY_AMOUNT`] shares forever so the share price can't be inflated
+/// by donations to an empty vault.
+fn mint_shares(
+    deps: DepsMut,
+    env: Env,
+    lp_token: Addr,
+    receiver: Addr,
+    amount: Uint128,
+    min_shares: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let mut vault = load_vault(deps.as_ref().storage, &lp_token)?;
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
+
+    let shares = if vault.total_shares.is_zero() {
+        let shares = amount
+            .checked_sub(MINIMUM_LIQUIDITY_AMOUNT)
+            .ok()
+            .filter(|shares| !shares.is_zero())
+            .ok_or(ContractError::MinimumVaultDeposit(MINIMUM_LIQUIDITY_AMOUNT))?;
+        vault.total_shares = MINIMUM_LIQUIDITY_AMOUNT;
+        shares
+    } else {
+        let total_lp = query_vault_lp_amount(
+            deps.querier,
+            &factory_addr,
+            &vault,
+            &lp_token,
+            &env.contract.address,
+        )?;
+        if total_lp.is_zero() {
+            return Err(ContractError::EmptyVault {});
+        }
+        amount.multiply_ratio(vault.total_shares, total_lp)
+    };
+    if shares.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    if let Some(min_shares) = min_shares {
+        if shares < min_shares {
+            return Err(ContractError::VaultSlippageViolation(shares, min_shares));
+        }
+    }
+
+    vault.total_shares = vault.total_shares.checked_add(shares)?;
+    VAULTS.save(deps.storage, &lp_token, &vault)?;
+    VAULT_SHARES.update(
+        deps.storage,
+        (&lp_token, &receiver),
+        |balance| -> StdResult<_> { Ok(balance.unwrap_or_default().checked_add(shares)?) },
+    )?;
+
+    let incentives_addr = query_generator_addr(deps.querier, &factory_addr)?;
+    let balances_before = tracked_balances(deps.querier, &vault, &env.contract.address)?;
+    let messages: Vec<CosmosMsg> = vec![
+        wasm_execute(
+            &lp_token,
+            &Cw20ExecuteMsg::Send {
+                contract: incentives_addr.to_string(),
+                amount,
+                msg: to_binary(&IncentivesCw20Msg::Deposit { recipient: None })?,
+            },
+            vec![],
+        )?
+        .into(),
+        CallbackMsg::TrackRewards {
+            lp_token: lp_token.clone(),
+            balances_before,
+        }
+        .into_cosmos_msg(&env.contract.address)?,
+    ];
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "mint_shares")
+        .add_attribute("receiver", receiver)
+        .add_attribute("shares", shares))
+}
+
+/// Burns vault shares, unstakes the corresponding amount of LP tokens and sends them to the owner
+/// together with the same part of idle rewards.
+fn burn_shares(
+    deps: DepsMut,
+    env: Env,
+    lp_token: Addr,
+    owner: Addr,
+    shares: Uint128,
+) -> Result<Response, ContractError> {
+    let mut vault = load_vault(deps.as_ref().storage, &lp_token)?;
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
+    let available = assert_enough_shares(deps.storage, &lp_token, &owner, shares)?;
+
+    let total_lp = query_vault_lp_amount(
+        deps.querier,
+        &factory_addr,
+        &vault,
+        &lp_token,
+        &env.contract.address,
+    )?;
+    let amount = total_lp.multiply_ratio(shares, vault.total_shares);
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    // Balances are snapshotted before idle rewards are sent out
+    let balances_before = tracked_balances(deps.querier, &vault, &env.contract.address)?;
+
+    let mut idle_parts = vec![];
+    for reward in vault.idle_rewards.iter_mut() {
+        let part = reward.amount.multiply_ratio(shares, vault.total_shares);
+        if !part.is_zero() {
+            reward.amount -= part;
+            idle_parts.push(reward.info.with_balance(part));
+        }
+    }
+    vault.idle_rewards.retain(|reward| !reward.amount.is_zero());
+
+    vault.total_shares -= shares;
+    VAULTS.save(deps.storage, &lp_token, &vault)?;
+    VAULT_SHARES.save(deps.storage, (&lp_token, &owner), &(available - shares))?;
+
+    let incentives_addr = query_generator_addr(deps.querier, &factory_addr)?;
+    let mut messages: Vec<CosmosMsg> = vec![
+        wasm_execute(
+            incentives_addr,
+            &IncentivesExecuteMsg::Withdraw {
+                lp_token: lp_token.to_string(),
+                amount,
+            },
+            vec![],
+        )?
+        .into(),
+        CallbackMsg::TrackRewards {
+            lp_token: lp_token.clone(),
+            balances_before,
+        }
+        .into_cosmos_msg(&env.contract.address)?,
+        wasm_execute(
+            &lp_token,
+            &Cw20ExecuteMsg::Transfer {
+                recipient: owner.to_string(),
+                amount,
+            },
+            vec![],
+        )?
+        .into(),
+    ];
+    for part in &idle_parts {
+        messages.push(part.clone().into_msg(&owner)?);
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "burn_shares")
+        .add_attribute("owner", owner)
+        .add_attribute("amount", amount)
+        .add_attribute(
+            "idle_rewards",
+            idle_parts
+                .iter()
+                .map(|asset| asset.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        ))
+}
+
+/// Compares the amount of LP tokens staked by the vault with the snapshot taken before compounding.
+fn assert_compounded(
+    deps: DepsMut,
+    env: Env,
+    lp_token: Addr,
+    lp_before: Uint128,
+    min_lp_to_receive: Uint128,
+) -> Result<Response, ContractError> {
+    let vault = load_vault(deps.as_ref().storage, &lp_token)?;
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
+
+    let received = query_vault_lp_amount(
+        deps.querier,
+        &factory_addr,
+        &vault,
+        &lp_token,
+        &env.contract.address,
+    )?
+    .saturating_sub(lp_before);
+    if received < min_lp_to_receive {
+        return Err(ContractError::CompoundSlippageViolation(
+            received,
+            min_lp_to_receive,
+        ));
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "assert_compounded")
+        .add_attribute("compounded_lp", received))
+}
+
+/// Adds balance increments since the snapshot to the vault idle rewards.
+fn track_rewards(
+    deps: DepsMut,
+    env: Env,
+    lp_token: Addr,
+    balances_before: Vec<Asset>,
+) -> Result<Response, ContractError> {
+    let mut vault = load_vault(deps.as_ref().storage, &lp_token)?;
+
+    for before in balances_before {
+        let received = before
+            .info
+            .query_pool(&deps.querier, &env.contract.address)?
+            .saturating_sub(before.amount);
+        if received.is_zero() {
+            continue;
+        }
+
+        match vault
+            .idle_rewards
+            .iter_mut()
+            .find(|asset| asset.info == before.info)
+        {
+            Some(asset) => asset.amount += received,
+            None => vault.idle_rewards.push(before.info.with_balance(received)),
+        }
+    }
+
+    VAULTS.save(deps.storage, &lp_token, &vault)?;
+
+    Ok(Response::new().add_attribute("action", "track_rewards"))
+}
+
+/// Swaps idle rewards into pool assets via the router. Rewards which are pool assets are provided as is.
+/// Rewards without a configured route stay idle.
+fn swap_rewards(deps: DepsMut, env: Env, lp_token: Addr) -> Result<Response, ContractError> {
+    let mut vault = load_vault(deps.as_ref().storage, &lp_token)?;
+
+    // Pool assets which are already among idle rewards must be accounted as received
+    let balances_before = vault
+        .pair_info
+        .asset_infos
+        .iter()
+        .map(|info| {
+            let balance = info.query_pool(&deps.querier, &env.contract.address)?;
+            let idle = vault
+                .idle_rewards
+                .iter()
+                .find(|asset| asset.info == *info)
+                .map(|asset| asset.amount)
+                .unwrap_or_default();
+            Ok(info.with_balance(balance.checked_sub(idle)?))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut leftovers = vec![];
+    for reward in vault.idle_rewards {
+        if vault.pair_info.asset_infos.contains(&reward.info) {
+            continue;
+        }
+
+        match vault
+            .reward_routes
+            .iter()
+            .find(|route| route.reward == reward.info)
+        {
+            Some(route) => messages.push(swap_msg(
+                &vault.router_addr,
+                &reward,
+                route,
+                vault.max_spread,
+            )?),
+            None => leftovers.push(reward),
+        }
+    }
+
+    vault.idle_rewards = leftovers;
+    VAULTS.save(deps.storage, &lp_token, &vault)?;
+
+    messages.push(
+        CallbackMsg::ProvideAndStake {
+            lp_token,
+            balances_before,
+        }
+        .into_cosmos_msg(&env.contract.address)?,
+    );
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "swap_rewards"))
+}
+
+/// Provides pool assets received since the snapshot and stakes the minted LP tokens on behalf of the vault.
+/// Xyk pair doesn't allow imbalanced provides thus each asset is provided separately.
+fn provide_and_stake(
+    deps: DepsMut,
+    env: Env,
+    lp_token: Addr,
+    balances_before: Vec<Asset>,
+) -> Result<Response, ContractError> {
+    let vault = load_vault(deps.as_ref().storage, &lp_token)?;
+    let pair_addr = &vault.pair_info.contract_addr;
+
+    let assets = balances_before
+        .into_iter()
+        .map(|before| {
+            let balance = before
+                .info
+                .query_pool(&deps.querier, &env.contract.address)?;
+            Ok(before
+                .info
+                .with_balance(balance.checked_sub(before.amount)?))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    if assets.iter().all(|asset| asset.amount.is_zero()) {
+        return Ok(Response::new().add_attribute("action", "provide_and_stake"));
+    }
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    for asset in assets.iter().filter(|asset| !asset.amount.is_zero()) {
+        if let AssetInfo::Token { contract_addr } = &asset.info {
+            messages.push(
+                wasm_execute(
+                    contract_addr,
+                    &Cw20ExecuteMsg::IncreaseAllowance {
+                        spender: pair_addr.to_string(),
+                        amount: asset.amount,
+                        expires: Some(Expiration::AtHeight(env.block.height + 1)),
+                    },
+                    vec![],
+                )?
+                .into(),
+            );
+        }
+    }
+
+    match &vault.pair_info.pair_type {
+        PairType::Xyk {} => {
+            for asset in assets.into_iter().filter(|asset| !asset.amount.is_zero()) {
+                let funds = asset.as_coin().map(|coin| vec![coin]).unwrap_or_default();
+                messages.push(
+                    wasm_execute(
+                        pair_addr,
+                        &PairExecuteMsg::ProvideSingleAsset {
+                            asset,
+                            min_lp_to_receive: None,
+                            auto_stake: Some(true),
+                            receiver: Some(env.contract.address.to_string()),
+                        },
+                        funds,
+                    )?
+                    .into(),
+                );
+            }
+        }
+        _ => {
+            let funds = assets
+                .iter()
+                .filter(|asset| !asset.amount.is_zero())
+                .filter_map(|asset| asset.as_coin().ok())
+                .collect::<Vec<_>>();
+            messages.push(
+                wasm_execute(
+                    pair_addr,
+                    &PairExecuteMsg::ProvideLiquidity {
+                        assets,
+                        slippage_tolerance: Some(vault.max_spread),
+                        auto_stake: Some(true),
+                        receiver: Some(env.contract.address.to_string()),
+                    },
+                    funds,
+                )?
+                .into(),
+            );
+        }
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "provide_and_stake"))
+}
+
+fn swap_msg(
+    router_addr: &Addr,
+    reward: &Asset,
+    route: &RewardRoute,
+    max_spread: Decimal,
+) -> StdResult<CosmosMsg> {
+    let msg = match &reward.info {
+        AssetInfo::Token { contract_addr } => wasm_execute(
+            contract_addr,
+            &Cw20ExecuteMsg::Send {
+                contract: router_addr.to_string(),
+                amount: reward.amount,
+                msg: to_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+                    operations: route.operations.clone(),
+                    minimum_receive: None,
+                    to: None,
+                    max_spread: Some(max_spread),
+                })?,
+            },
+            vec![],
+        )?,
+        AssetInfo::NativeToken { .. } => wasm_execute(
+            router_addr,
+            &RouterExecuteMsg::ExecuteSwapOperations {
+                operations: route.operations.clone(),
+                minimum_receive: None,
+                to: None,
+                max_spread: Some(max_spread),
+            },
+            vec![reward.as_coin()?],
+        )?,
+    };
+
+    Ok(msg.into())
+}
+
+/// Returns contract balances of all assets which can be received as vault rewards.
+fn tracked_balances(
+    querier: QuerierWrapper,
+    vault: &Vault,
+    contract_addr: &Addr,
+) -> StdResult<Vec<Asset>> {
+    vault
+        .pair_info
+        .asset_infos
+        .iter()
+        .chain(vault.reward_routes.iter().map(|route| &route.reward))
+        .map(|info| Ok(info.with_balance(info.query_pool(&querier, contract_addr)?)))
+        .collect()
+}
+
+/// Claims pending vault rewards and attributes them to the vault idle rewards.
+fn claim_rewards_msgs(
+    deps: Deps,
+    env: &Env,
+    vault: &Vault,
+    lp_token: &Addr,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let factory_addr = CONFIG.load(deps.storage)?.factory_addr;
+    let incentives_addr = query_generator_addr(deps.querier, &factory_addr)?;
+    let balances_before = tracked_balances(deps.querier, vault, &env.contract.address)?;
+
+    Ok(vec![
+        wasm_execute(
+            incentives_addr,
+            &IncentivesExecuteMsg::ClaimRewards {
+                lp_tokens: vec![lp_token.to_string()],
+            },
+            vec![],
+        )?
+        .into(),
+        CallbackMsg::TrackRewards {
+            lp_token: lp_token.clone(),
+            balances_before,
+        }
+        .into_cosmos_msg(&env.contract.address)?,
+    ])
+}
+
+/// Returns the user vault shares if they cover the requested amount.
+fn assert_enough_shares(
+    storage: &dyn Storage,
+    lp_token: &Addr,
+    user: &Addr,
+    shares: Uint128,
+) -> Result<Uint128, ContractError> {
+    let available = VAULT_SHARES
+        .may_load(storage, (lp_token, user))?
+        .unwrap_or_default();
+    if available < shares {
+        return Err(ContractError::NotEnoughShares {
+            available,
+            requested: shares,
+        });
+    }
+
+    Ok(available)
+}
+
+fn load_vault(storage: &dyn Storage, lp_token: &Addr) -> Result<Vault, ContractError> {
+    VAULTS
+        .may_load(storage, lp_token)?
+        .ok_or_else(|| ContractError::VaultNotFound(lp_token.to_string()))
+}
+
+fn assert_factory_owner(
+    querier: QuerierWrapper,
+    sender: &Addr,
+    factory_addr: &Addr,
+) -> Result<(), ContractError> {
+    let owner = astroport_factory::state::CONFIG
+        .query(&querier, factory_addr.clone())?
+        .owner;
+    if *sender != owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    Ok(())
+}
+
+fn validate_max_spread(max_spread: Decimal) -> Result<(), ContractError> {
+    if max_spread > Decimal::from_str(MAX_ALLOWED_SLIPPAGE)? {
+        return Err(ContractError::MaxSpreadTooHigh(
+            MAX_ALLOWED_SLIPPAGE.to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Every route must start with its reward, be continuous and end up with one of the pool assets.
+fn validate_reward_routes(
+    pair_info: &PairInfo,
+    reward_routes: &[RewardRoute],
+) -> Result<(), ContractError> {
+    let mut rewards = HashSet::new();
+    for route in reward_routes {
+        if !rewards.insert(route.reward.to_string()) {
+            return Err(ContractError::DuplicatedRewardRoute(
+                route.reward.to_string(),
+            ));
+        }
+
+        let invalid_route = || ContractError::InvalidRewardRoute(route.reward.to_string());
+
+        if pair_info.asset_infos.contains(&route.reward)
+            || route.operations.is_empty()
+            || route.operations.len() > MAX_SWAP_OPERATIONS
+        {
+            return Err(invalid_route());
+        }
+
+        let mut offer_asset = route.reward.clone();
+        for operation in &route.operations {
+            if operation.get_offer_asset_info() != offer_asset {
+                return Err(invalid_route());
+            }
+            offer_asset = operation.get_target_asset_info();
+        }
+
+        if !pair_info.asset_infos.contains(&offer_asset) {
+            return Err(invalid_route());
+        }
+    }
+
+    Ok(())
+}
@@ -9,23 +9,28 @@ use std::str::FromStr;
 use anyhow::Result as AnyResult;
 use cosmwasm_schema::serde::de::DeserializeOwned;
 use cosmwasm_std::{
-    coin, from_slice, to_binary, Addr, Coin, Decimal, Empty, StdError, StdResult, Uint128,
+    coin, from_slice, to_binary, to_vec, Addr, Binary, Coin, Decimal, Deps, DepsMut, Empty, Env,
+    MessageInfo, Response, StdError, StdResult, Timestamp, Uint128,
 };
 use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
 use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
 use derivative::Derivative;
 use itertools::Itertools;
 
-use astroport::asset::{native_asset_info, token_asset_info, Asset, AssetInfo, PairInfo};
+use astroport::asset::{
+    native_asset_info, token_asset_info, Asset, AssetInfo, AssetInfoExt, PairInfo,
+};
 use astroport::factory::{PairConfig, PairType};
-use astroport::liquidity_manager::{Cw20HookMsg, ExecuteMsg};
-use astroport::liquidity_manager::{InstantiateMsg, QueryMsg};
+use astroport::incentives::{InputSchedule, EPOCHS_START};
+use astroport::liquidity_manager::{Cw20HookMsg, ExecuteMsg, RewardRoute};
+use astroport::liquidity_manager::{InstantiateMsg, QueryMsg, VaultResponse, VaultSharesResponse};
 use astroport::pair::{Cw20HookMsg as PairCw20HookMsg, ExecuteMsg as PairExecuteMsg};
 use astroport::pair::{
-    ReverseSimulationResponse, SimulationResponse, StablePoolParams, XYKPoolParams,
+    RateProvider, RateProviderQueryMsg, RateResponse, ReverseSimulationResponse,
+    SimulationResponse, StablePoolParams, StablePoolUpdateParams, XYKPoolParams,
 };
 use astroport::pair_concentrated::{ConcentratedPoolParams, QueryMsg as PairQueryMsg};
-use astroport::{factory, generator};
+use astroport::{factory, generator, incentives};
 use astroport_liquidity_manager::contract::{execute, instantiate, reply};
 use astroport_liquidity_manager::query::query;
 
@@ -159,10 +164,55 @@ fn generator_contract() -> Box<dyn Contract<Empty>> {
     )
 }
 
+fn incentives_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            astroport_incentives::execute::execute,
+            astroport_incentives::instantiate::instantiate,
+            astroport_incentives::query::query,
+        )
+        .with_reply_empty(astroport_incentives::reply::reply),
+    )
+}
+
+fn router_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            astroport_router::contract::execute,
+            astroport_router::contract::instantiate,
+            astroport_router::contract::query,
+        )
+        .with_reply_empty(astroport_router::contract::reply),
+    )
+}
+
 fn manager_contract() -> Box<dyn Contract<Empty>> {
     Box::new(ContractWrapper::new_with_empty(execute, instantiate, query).with_reply_empty(reply))
 }
 
+fn save_rate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: RateResponse,
+) -> StdResult<Response> {
+    deps.storage.set(b"rate", &to_vec(&msg)?);
+    Ok(Response::new())
+}
+
+fn query_rate(deps: Deps, _env: Env, _msg: RateProviderQueryMsg) -> StdResult<Binary> {
+    deps.storage
+        .get(b"rate")
+        .map(Binary)
+        .ok_or_else(|| StdError::generic_err("Rate is not set"))
+}
+
+fn rate_provider_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        save_rate, save_rate, query_rate,
+    ))
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct Helper {
@@ -259,6 +309,7 @@ impl Helper {
                 pair_type: pair_type.clone(),
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             }],
             token_code_id,
             generator_address: None,
@@ -458,6 +509,77 @@ impl Helper {
         }
     }
 
+    /// Provides a single asset via liquidity manager contract.
+    pub fn provide_single_asset(
+        &mut self,
+        sender: &Addr,
+        asset: &Asset,
+        min_lp_receive: Option<Uint128>,
+    ) -> AnyResult<AppResponse> {
+        let funds = asset.mock_coin_sent(
+            &mut self.app,
+            sender,
+            &self.liquidity_manager,
+            SendType::Allowance,
+        );
+
+        let manager_msg = ExecuteMsg::ProvideLiquidity {
+            pair_addr: self.pair_addr.to_string(),
+            pair_msg: PairExecuteMsg::ProvideSingleAsset {
+                asset: asset.clone(),
+                min_lp_to_receive: None,
+                auto_stake: None,
+                receiver: None,
+            },
+            min_lp_to_receive: min_lp_receive,
+        };
+        self.app.execute_contract(
+            sender.clone(),
+            self.liquidity_manager.clone(),
+            &manager_msg,
+            &funds,
+        )
+    }
+
+    /// Instantiates a mock rate provider which reports `rate` updated at the current block time.
+    pub fn init_rate_provider(&mut self, rate: Decimal) -> Addr {
+        let code_id = self.app.store_code(rate_provider_contract());
+        let updated_at = self.app.block_info().time.seconds();
+        self.app
+            .instantiate_contract(
+                code_id,
+                self.owner.clone(),
+                &RateResponse { rate, updated_at },
+                &[],
+                "Rate provider",
+                None,
+            )
+            .unwrap()
+    }
+
+    pub fn set_rate_provider(
+        &mut self,
+        asset_info: &AssetInfo,
+        provider: &Addr,
+        max_staleness: u64,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            self.owner.clone(),
+            self.pair_addr.clone(),
+            &PairExecuteMsg::UpdateConfig {
+                params: to_binary(&StablePoolUpdateParams::SetRateProvider {
+                    rate_provider: RateProvider {
+                        asset_info: asset_info.clone(),
+                        provider_addr: provider.to_string(),
+                        max_staleness,
+                    },
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+    }
+
     pub fn withdraw_liquidity(
         &mut self,
         sender: &Addr,
@@ -664,6 +786,243 @@ impl Helper {
             },
         )
     }
+
+    /// Replaces the generator in the factory config with the incentives contract.
+    /// Block time is moved to the first incentives epoch.
+    pub fn init_incentives(&mut self) -> Addr {
+        let incentives_code_id = self.app.store_code(incentives_contract());
+        let incentives = self
+            .app
+            .instantiate_contract(
+                incentives_code_id,
+                self.owner.clone(),
+                &incentives::InstantiateMsg {
+                    owner: self.owner.to_string(),
+                    factory: self.factory.to_string(),
+                    astro_token: native_asset_info("astro".to_string()),
+                    vesting_contract: "vesting".to_string(),
+                    incentivization_fee_info: None,
+                    guardian: None,
+                },
+                &[],
+                "Incentives",
+                None,
+            )
+            .unwrap();
+
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                self.factory.clone(),
+                &factory::ExecuteMsg::UpdateConfig {
+                    token_code_id: None,
+                    fee_address: None,
+                    generator_address: Some(incentives.to_string()),
+                    whitelist_code_id: None,
+                    coin_registry_address: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        self.app.update_block(|block| {
+            block.time = Timestamp::from_seconds(EPOCHS_START);
+        });
+
+        incentives
+    }
+
+    pub fn init_router(&mut self) -> Addr {
+        let router_code_id = self.app.store_code(router_contract());
+        self.app
+            .instantiate_contract(
+                router_code_id,
+                self.owner.clone(),
+                &astroport::router::InstantiateMsg {
+                    astroport_factory: self.factory.to_string(),
+                },
+                &[],
+                "Router",
+                None,
+            )
+            .unwrap()
+    }
+
+    /// Creates a cw20 reward token and an xyk pair of the reward token and the specified pool asset
+    /// with equal liquidity on both sides.
+    pub fn create_reward_pair(&mut self, pool_coin: &TestCoin, liquidity: u128) -> Addr {
+        let token_code_id = self.app.store_code(token_contract());
+        let reward_token = Self::init_token(
+            &mut self.app,
+            token_code_id,
+            "REWARD".to_string(),
+            6,
+            &self.owner,
+        );
+
+        let asset_infos = vec![
+            token_asset_info(reward_token.clone()),
+            self.assets[pool_coin].clone(),
+        ];
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                self.factory.clone(),
+                &factory::ExecuteMsg::CreatePair {
+                    pair_type: PairType::Xyk {},
+                    asset_infos: asset_infos.clone(),
+                    init_params: None,
+                },
+                &[],
+            )
+            .unwrap();
+        let pair_info: PairInfo = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.factory,
+                &factory::QueryMsg::Pair {
+                    asset_infos: asset_infos.clone(),
+                },
+            )
+            .unwrap();
+
+        let assets = asset_infos
+            .iter()
+            .map(|info| info.with_balance(liquidity))
+            .collect_vec();
+        let funds = assets.as_slice().mock_coins_sent(
+            &mut self.app,
+            &self.owner,
+            &pair_info.contract_addr,
+            SendType::Allowance,
+        );
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                pair_info.contract_addr,
+                &PairExecuteMsg::ProvideLiquidity {
+                    assets,
+                    slippage_tolerance: None,
+                    auto_stake: None,
+                    receiver: None,
+                },
+                &funds,
+            )
+            .unwrap();
+
+        reward_token
+    }
+
+    pub fn incentivize(&mut self, incentives: &Addr, reward: &Asset, duration_periods: u64) {
+        let funds =
+            reward.mock_coin_sent(&mut self.app, &self.owner, incentives, SendType::Allowance);
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                incentives.clone(),
+                &incentives::ExecuteMsg::Incentivize {
+                    lp_token: self.lp_token.to_string(),
+                    schedule: InputSchedule {
+                        reward: reward.clone(),
+                        duration_periods,
+                    },
+                },
+                &funds,
+            )
+            .unwrap();
+    }
+
+    pub fn create_vault(
+        &mut self,
+        sender: &Addr,
+        router: &Addr,
+        reward_routes: Vec<RewardRoute>,
+        max_spread: Decimal,
+        keeper: &Addr,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.liquidity_manager.clone(),
+            &ExecuteMsg::CreateVault {
+                lp_token: self.lp_token.to_string(),
+                router: router.to_string(),
+                reward_routes,
+                max_spread,
+                keeper: keeper.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn deposit_to_vault(
+        &mut self,
+        sender: &Addr,
+        amount: u128,
+        min_shares: Option<u128>,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.lp_token.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: self.liquidity_manager.to_string(),
+                amount: Uint128::from(amount),
+                msg: to_binary(&Cw20HookMsg::DepositToVault {
+                    receiver: None,
+                    min_shares: min_shares.map(Uint128::from),
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+    }
+
+    pub fn withdraw_from_vault(&mut self, sender: &Addr, shares: u128) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.liquidity_manager.clone(),
+            &ExecuteMsg::WithdrawFromVault {
+                lp_token: self.lp_token.to_string(),
+                shares: Uint128::from(shares),
+            },
+            &[],
+        )
+    }
+
+    pub fn compound_vault(
+        &mut self,
+        sender: &Addr,
+        min_lp_to_receive: u128,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.liquidity_manager.clone(),
+            &ExecuteMsg::CompoundVault {
+                lp_token: self.lp_token.to_string(),
+                min_lp_to_receive: Uint128::from(min_lp_to_receive),
+            },
+            &[],
+        )
+    }
+
+    pub fn query_vault(&self) -> StdResult<VaultResponse> {
+        self.app.wrap().query_wasm_smart(
+            &self.liquidity_manager,
+            &QueryMsg::Vault {
+                lp_token: self.lp_token.to_string(),
+            },
+        )
+    }
+
+    pub fn query_vault_shares(&self, user: &Addr) -> StdResult<VaultSharesResponse> {
+        self.app.wrap().query_wasm_smart(
+            &self.liquidity_manager,
+            &QueryMsg::VaultShares {
+                lp_token: self.lp_token.to_string(),
+                user: user.to_string(),
+            },
+        )
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -6,7 +6,7 @@ use astroport::asset::{native_asset, AssetInfoExt};
 use astroport::pair::{StablePoolParams, XYKPoolParams};
 use astroport_liquidity_manager::error::ContractError;
 
-use crate::helper::{f64_to_dec, Helper, PoolParams, TestCoin};
+use crate::helper::{f64_to_dec, AppExtension, Helper, PoolParams, TestCoin};
 
 mod helper;
 
@@ -160,6 +160,52 @@ fn test_xyk() {
     assert_eq!(asset2_bal, 7_999998);
 }
 
+#[test]
+fn test_xyk_single_asset() {
+    let owner = Addr::unchecked("owner");
+    let test_coins = vec![TestCoin::native("uluna"), TestCoin::cw20("TEST")];
+    let mut helper = Helper::new(
+        &owner,
+        test_coins.clone(),
+        PoolParams::Constant(XYKPoolParams {
+            track_asset_balances: None,
+        }),
+    )
+    .unwrap();
+
+    helper
+        .provide_liquidity(
+            &owner,
+            &[
+                helper.assets[&test_coins[0]].with_balance(100_000_000000_u128),
+                helper.assets[&test_coins[1]].with_balance(100_000_000000_u128),
+            ],
+            Some(Uint128::MIN), // setting zero just to make initial provision via manager contract,
+        )
+        .unwrap();
+
+    let user = Addr::unchecked("user");
+    let provide_asset = helper.assets[&test_coins[1]].with_balance(100_000000u128);
+    helper.give_me_money(&[provide_asset.clone()], &user);
+
+    // Balanced provide of the same value would mint ~50_000000 LP tokens
+    let err = helper
+        .provide_single_asset(&user, &provide_asset, Some(50_000000u128.into()))
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .starts_with("Slippage is more than expected"));
+
+    helper
+        .provide_single_asset(&user, &provide_asset, Some(49_800000u128.into()))
+        .unwrap();
+
+    assert_eq!(helper.coin_balance(&test_coins[1], &user), 0);
+    let lp_balance = helper.token_balance(&helper.lp_token, &user);
+    assert!(lp_balance >= 49_800000 && lp_balance < 50_000000);
+}
+
 #[test]
 fn test_stableswap_without_manager() {
     let owner = Addr::unchecked("owner");
@@ -170,6 +216,7 @@ fn test_stableswap_without_manager() {
         PoolParams::Stable(StablePoolParams {
             amp: 40,
             owner: None,
+            rate_providers: None,
         }),
     )
     .unwrap();
@@ -245,6 +292,7 @@ fn test_stableswap_with_manager() {
         PoolParams::Stable(StablePoolParams {
             amp: 40,
             owner: None,
+            rate_providers: None,
         }),
     )
     .unwrap();
@@ -304,6 +352,77 @@ fn test_stableswap_with_manager() {
     );
 }
 
+#[test]
+fn test_stableswap_with_rate_provider() {
+    let owner = Addr::unchecked("owner");
+    let test_coins = vec![TestCoin::native("uusd"), TestCoin::cw20("stUST")];
+    let mut helper = Helper::new(
+        &owner,
+        test_coins.clone(),
+        PoolParams::Stable(StablePoolParams {
+            amp: 40,
+            owner: None,
+            rate_providers: None,
+        }),
+    )
+    .unwrap();
+
+    // stUST is worth 1.1 uusd
+    let provider = helper.init_rate_provider(f64_to_dec(1.1));
+    helper
+        .set_rate_provider(&helper.assets[&test_coins[1]].clone(), &provider, 3600)
+        .unwrap();
+
+    helper
+        .provide_liquidity(
+            &owner,
+            &[
+                helper.assets[&test_coins[0]].with_balance(110_000_000000_u128),
+                helper.assets[&test_coins[1]].with_balance(100_000_000000_u128),
+            ],
+            None,
+        )
+        .unwrap();
+    helper.app.next_block(10);
+
+    // Imbalanced provide in terms of raw amounts. Simulation must scale pool assets by their rates
+    let provide_assets = [
+        helper.assets[&test_coins[0]].with_balance(100_000000u128),
+        helper.assets[&test_coins[1]].with_balance(50_000000u128),
+    ];
+    let sim_lp_amount = helper.simulate_provide(None, &provide_assets).unwrap();
+
+    let user = Addr::unchecked("user");
+    helper.give_me_money(&provide_assets, &user);
+    helper
+        .provide_liquidity(&user, &provide_assets, None)
+        .unwrap();
+    assert_eq!(
+        helper.token_balance(&helper.lp_token, &user),
+        sim_lp_amount.u128()
+    );
+
+    // Provide via liquidity manager passes the simulated minimum
+    helper.app.next_block(10);
+    let sim_lp_amount = helper.simulate_provide(None, &provide_assets).unwrap();
+    let user2 = Addr::unchecked("user2");
+    helper.give_me_money(&provide_assets, &user2);
+    helper
+        .provide_liquidity_with_slip_tolerance(
+            &user2,
+            &provide_assets,
+            Some(f64_to_dec(0.5)),
+            Some(sim_lp_amount),
+            false,
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        helper.token_balance(&helper.lp_token, &user2),
+        sim_lp_amount.u128()
+    );
+}
+
 #[test]
 fn test_auto_stake_and_receiver() {
     let owner = Addr::unchecked("owner");
@@ -314,6 +433,7 @@ fn test_auto_stake_and_receiver() {
         PoolParams::Stable(StablePoolParams {
             amp: 40,
             owner: None,
+            rate_providers: None,
         }),
     )
     .unwrap();
@@ -486,6 +606,7 @@ fn test_onesided_provide_stable() {
         PoolParams::Stable(StablePoolParams {
             amp: 40,
             owner: None,
+            rate_providers: None,
         }),
     )
     .unwrap();
@@ -0,0 +1,290 @@
+#![cfg(not(tarpaulin_include))]
+
+use cosmwasm_std::{Addr, Uint128};
+
+use astroport::asset::{token_asset_info, AssetInfoExt, MINIMUM_LIQUIDITY_AMOUNT};
+use astroport::incentives::EPOCH_LENGTH;
+use astroport::liquidity_manager::RewardRoute;
+use astroport::pair::XYKPoolParams;
+use astroport::router::SwapOperation;
+use astroport_liquidity_manager::error::ContractError;
+
+use crate::helper::{f64_to_dec, AppExtension, Helper, PoolParams, TestCoin};
+
+mod helper;
+
+#[test]
+fn test_vault_compounding() {
+    let owner = Addr::unchecked("owner");
+    let test_coins = vec![TestCoin::native("uluna"), TestCoin::native("uusd")];
+    let mut helper = Helper::new(
+        &owner,
+        test_coins.clone(),
+        PoolParams::Constant(XYKPoolParams {
+            track_asset_balances: None,
+        }),
+    )
+    .unwrap();
+
+    let incentives = helper.init_incentives();
+    let router = helper.init_router();
+    let reward_token = helper.create_reward_pair(&test_coins[1], 10_000_000_000000);
+    let reward_info = token_asset_info(reward_token.clone());
+
+    helper
+        .provide_liquidity(
+            &owner,
+            &[
+                helper.assets[&test_coins[0]].with_balance(100_000_000000u128),
+                helper.assets[&test_coins[1]].with_balance(100_000_000000u128),
+            ],
+            None,
+        )
+        .unwrap();
+
+    let provide_assets = [
+        helper.assets[&test_coins[0]].with_balance(100_000000u128),
+        helper.assets[&test_coins[1]].with_balance(100_000000u128),
+    ];
+    let user1 = Addr::unchecked("user1");
+    let user2 = Addr::unchecked("user2");
+    for user in [&user1, &user2] {
+        helper.give_me_money(&provide_assets, user);
+        helper
+            .provide_liquidity(user, &provide_assets, None)
+            .unwrap();
+    }
+    let lp_amount = helper.token_balance(&helper.lp_token, &user1);
+
+    let reward_routes = vec![RewardRoute {
+        reward: reward_info.clone(),
+        operations: vec![SwapOperation::AstroSwap {
+            offer_asset_info: reward_info.clone(),
+            ask_asset_info: helper.assets[&test_coins[1]].clone(),
+        }],
+    }];
+
+    let keeper = Addr::unchecked("keeper");
+    let err = helper
+        .create_vault(
+            &user1,
+            &router,
+            reward_routes.clone(),
+            f64_to_dec(0.1),
+            &keeper,
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    // Route must start with the reward token
+    let err = helper
+        .create_vault(
+            &owner,
+            &router,
+            vec![RewardRoute {
+                reward: reward_info.clone(),
+                operations: vec![SwapOperation::AstroSwap {
+                    offer_asset_info: helper.assets[&test_coins[0]].clone(),
+                    ask_asset_info: helper.assets[&test_coins[1]].clone(),
+                }],
+            }],
+            f64_to_dec(0.1),
+            &keeper,
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidRewardRoute(reward_info.to_string()),
+        err.downcast().unwrap()
+    );
+
+    helper
+        .create_vault(
+            &owner,
+            &router,
+            reward_routes.clone(),
+            f64_to_dec(0.1),
+            &keeper,
+        )
+        .unwrap();
+
+    let err = helper
+        .create_vault(&owner, &router, reward_routes, f64_to_dec(0.1), &keeper)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::VaultAlreadyExists(helper.lp_token.to_string()),
+        err.downcast().unwrap()
+    );
+
+    let err = helper.compound_vault(&keeper, 0).unwrap_err();
+    assert_eq!(ContractError::EmptyVault {}, err.downcast().unwrap());
+
+    // Minimum shares are locked on the first deposit
+    helper.deposit_to_vault(&user1, lp_amount, None).unwrap();
+    let user1_shares = helper.query_vault_shares(&user1).unwrap();
+    let locked = MINIMUM_LIQUIDITY_AMOUNT.u128();
+    assert_eq!(user1_shares.shares.u128(), lp_amount - locked);
+    assert_eq!(user1_shares.lp_amount.u128(), lp_amount - locked);
+    assert_eq!(helper.token_balance(&helper.lp_token, &user1), 0);
+
+    helper.incentivize(
+        &incentives,
+        &reward_info.with_balance(EPOCH_LENGTH as u128 * 1_000000),
+        1,
+    );
+
+    helper.app.next_block(86400);
+
+    // Only the keeper can compound
+    let err = helper.compound_vault(&user1, 0).unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    // Compounding fails if the vault receives less LP tokens than expected
+    let err = helper.compound_vault(&keeper, u128::MAX).unwrap_err();
+    assert!(matches!(
+        err.downcast().unwrap(),
+        ContractError::CompoundSlippageViolation(_, expected) if expected == Uint128::MAX
+    ));
+
+    helper.compound_vault(&keeper, 1).unwrap();
+
+    let vault = helper.query_vault().unwrap();
+    assert!(vault.idle_rewards.is_empty());
+    assert_eq!(vault.total_shares.u128(), lp_amount);
+    assert!(vault.total_lp.u128() > lp_amount);
+    assert_eq!(
+        helper.token_balance(&reward_token, &helper.liquidity_manager),
+        0
+    );
+
+    // Late depositor receives fewer shares for the same amount of LP tokens
+    helper.deposit_to_vault(&user2, lp_amount, None).unwrap();
+    let user2_shares = helper.query_vault_shares(&user2).unwrap();
+    assert!(user2_shares.shares.u128() < lp_amount);
+
+    let err = helper.withdraw_from_vault(&user1, lp_amount).unwrap_err();
+    assert_eq!(
+        ContractError::NotEnoughShares {
+            available: Uint128::from(lp_amount - locked),
+            requested: Uint128::from(lp_amount),
+        },
+        err.downcast().unwrap()
+    );
+
+    helper.app.next_block(3600);
+    let user1_lp = helper.query_vault_shares(&user1).unwrap().lp_amount.u128();
+    assert!(user1_lp > lp_amount);
+    helper
+        .withdraw_from_vault(&user1, lp_amount - locked)
+        .unwrap();
+    assert_eq!(helper.token_balance(&helper.lp_token, &user1), user1_lp);
+    assert_eq!(helper.query_vault_shares(&user1).unwrap().shares.u128(), 0);
+
+    // Rewards claimed during withdrawal are shared with the withdrawer
+    assert!(helper.token_balance(&reward_token, &user1) > 0);
+    let vault = helper.query_vault().unwrap();
+    assert_eq!(vault.idle_rewards.len(), 1);
+    assert_eq!(vault.idle_rewards[0].info, reward_info);
+
+    helper.app.next_block(86400);
+    helper.compound_vault(&keeper, 1).unwrap();
+    let vault = helper.query_vault().unwrap();
+    assert!(vault.idle_rewards.is_empty());
+    assert!(helper.query_vault_shares(&user2).unwrap().lp_amount.u128() > lp_amount);
+}
+
+#[test]
+fn test_vault_deposit_protection() {
+    let owner = Addr::unchecked("owner");
+    let test_coins = vec![TestCoin::native("uluna"), TestCoin::native("uusd")];
+    let mut helper = Helper::new(
+        &owner,
+        test_coins.clone(),
+        PoolParams::Constant(XYKPoolParams {
+            track_asset_balances: None,
+        }),
+    )
+    .unwrap();
+
+    let incentives = helper.init_incentives();
+    let router = helper.init_router();
+    let reward_token = helper.create_reward_pair(&test_coins[1], 10_000_000_000000);
+    let reward_info = token_asset_info(reward_token.clone());
+
+    let provide_assets = [
+        helper.assets[&test_coins[0]].with_balance(100_000000u128),
+        helper.assets[&test_coins[1]].with_balance(100_000000u128),
+    ];
+    let user1 = Addr::unchecked("user1");
+    let user2 = Addr::unchecked("user2");
+    for user in [&owner, &user1, &user2] {
+        helper.give_me_money(&provide_assets, user);
+        helper
+            .provide_liquidity(user, &provide_assets, None)
+            .unwrap();
+    }
+    let lp_amount = helper.token_balance(&helper.lp_token, &user1);
+
+    let keeper = Addr::unchecked("keeper");
+    let reward_routes = vec![RewardRoute {
+        reward: reward_info.clone(),
+        operations: vec![SwapOperation::AstroSwap {
+            offer_asset_info: reward_info.clone(),
+            ask_asset_info: helper.assets[&test_coins[1]].clone(),
+        }],
+    }];
+    helper
+        .create_vault(&owner, &router, reward_routes, f64_to_dec(0.1), &keeper)
+        .unwrap();
+
+    // The first deposit must exceed the locked amount
+    let locked = MINIMUM_LIQUIDITY_AMOUNT.u128();
+    let err = helper.deposit_to_vault(&user1, locked, None).unwrap_err();
+    assert_eq!(
+        ContractError::MinimumVaultDeposit(MINIMUM_LIQUIDITY_AMOUNT),
+        err.downcast().unwrap()
+    );
+
+    let err = helper
+        .deposit_to_vault(&user1, lp_amount, Some(lp_amount))
+        .unwrap_err();
+    assert_eq!(
+        ContractError::VaultSlippageViolation(
+            Uint128::from(lp_amount - locked),
+            Uint128::from(lp_amount)
+        ),
+        err.downcast().unwrap()
+    );
+
+    helper
+        .deposit_to_vault(&user1, lp_amount, Some(lp_amount - locked))
+        .unwrap();
+
+    helper.incentivize(
+        &incentives,
+        &reward_info.with_balance(EPOCH_LENGTH as u128 * 1_000000),
+        1,
+    );
+    helper.app.next_block(86400);
+
+    // Pending rewards are compounded before minting.
+    // Otherwise user2 would receive shares 1:1 with the deposited LP tokens.
+    helper.deposit_to_vault(&user2, lp_amount, None).unwrap();
+    let vault = helper.query_vault().unwrap();
+    assert!(vault.idle_rewards.is_empty());
+    assert_eq!(
+        helper.token_balance(&reward_token, &helper.liquidity_manager),
+        0
+    );
+    let user2_shares = helper.query_vault_shares(&user2).unwrap().shares.u128();
+    assert!(user2_shares < lp_amount);
+
+    // Shares are priced by the staked LP tokens only
+    let shares_before = vault.total_shares.u128() - user2_shares;
+    let lp_before = vault.total_lp.u128() - lp_amount;
+    assert_eq!(
+        user2_shares,
+        Uint128::new(lp_amount)
+            .multiply_ratio(shares_before, lp_before)
+            .u128()
+    );
+}
@@ -2,18 +2,23 @@ use crate::error::ContractError;
 use crate::migration::PRICE_LAST_V100;
 use crate::querier::{query_cumulative_prices, query_prices};
 use crate::state::{
-    get_precision, store_precisions, Config, PriceCumulativeLast, CONFIG, PRICE_LAST,
+    get_precision, store_precisions, Config, PriceCumulativeLast, PriceFeed, CONFIG, FEEDS,
+    PRICE_LAST,
 };
 use astroport::asset::{Asset, AssetInfo};
-use astroport::oracle::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
-use astroport::pair::TWAP_PRECISION;
+use astroport::oracle::{
+    ConsultPathResponse, ExecuteMsg, FeedResponse, InstantiateMsg, MigrateMsg, QueryMsg,
+    MAX_CONSULT_PATH_LEN,
+};
+use astroport::pair::{CumulativePricesResponse, TWAP_PRECISION};
 use astroport::querier::query_pair_info;
 
 use cosmwasm_std::{
-    entry_point, to_binary, Binary, Decimal256, Deps, DepsMut, Env, MessageInfo, Response,
-    StdError, StdResult, Uint128, Uint256,
+    attr, entry_point, to_binary, Binary, Decimal256, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, Uint128, Uint256,
 };
 use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Bound;
 
 /// Contract name that is used for migration.
 const CONTRACT_NAME: &str = "astroport-oracle";
@@ -23,6 +28,12 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// Time between two consecutive TWAP updates.
 pub const PERIOD: u64 = 86400;
 
+/// ## Pagination settings
+/// The maximum limit for reading feeds from [`FEEDS`]
+const MAX_LIMIT: u32 = 30;
+/// The default limit for reading feeds from [`FEEDS`]
+const DEFAULT_LIMIT: u32 = 10;
+
 /// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -50,19 +61,10 @@ pub fn instantiate(
     CONFIG.save(deps.storage, &config)?;
 
     let prices = query_cumulative_prices(deps.querier, pair_info.contract_addr)?;
-    let average_prices = prices
-        .cumulative_prices
-        .iter()
-        .cloned()
-        .map(|(from, to, _)| (from, to, Decimal256::zero()))
-        .collect();
-
-    let price = PriceCumulativeLast {
-        cumulative_prices: prices.cumulative_prices,
-        average_prices,
-        block_timestamp_last: env.block.time.seconds(),
-    };
-    PRICE_LAST.save(deps.storage, &price)?;
+    PRICE_LAST.save(
+        deps.storage,
+        &initial_prices(prices, env.block.time.seconds()),
+    )?;
 
     Ok(Response::default())
 }
@@ -71,15 +73,32 @@ pub fn instantiate(
 ///
 /// ## Variants
 /// * **ExecuteMsg::Update {}** Updates the local TWAP values for the assets in the Astroport pool.
+///
+/// * **ExecuteMsg::RegisterFeed { asset_infos, period }** Registers a price feed for a factory pair.
+///
+/// * **ExecuteMsg::UpdateFeedPeriod { pair, period }** Changes the TWAP window of a registered feed.
+///
+/// * **ExecuteMsg::RemoveFeed { pair }** Removes a registered feed.
+///
+/// * **ExecuteMsg::UpdateFeed { pair }** Updates the local TWAP values of a registered feed.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
     env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::Update {} => update(deps, env),
+        ExecuteMsg::RegisterFeed {
+            asset_infos,
+            period,
+        } => register_feed(deps, env, info, asset_infos, period),
+        ExecuteMsg::UpdateFeedPeriod { pair, period } => {
+            update_feed_period(deps, info, pair, period)
+        }
+        ExecuteMsg::RemoveFeed { pair } => remove_feed(deps, info, pair),
+        ExecuteMsg::UpdateFeed { pair } => update_feed(deps, env, pair),
     }
 }
 
@@ -96,6 +115,172 @@ pub fn update(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
         return Err(ContractError::WrongPeriod {});
     }
 
+    PRICE_LAST.save(
+        deps.storage,
+        &accumulate_prices(&price_last, prices, env.block.time.seconds(), time_elapsed),
+    )?;
+    Ok(Response::default())
+}
+
+/// Registers a price feed for the factory pair with the specified assets.
+///
+/// * **asset_infos** assets of the pair.
+///
+/// * **period** TWAP window of the feed in seconds.
+pub fn register_feed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_infos: Vec<AssetInfo>,
+    period: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if period == 0 {
+        return Err(ContractError::InvalidPeriod {});
+    }
+
+    for asset_info in &asset_infos {
+        asset_info.check(deps.api)?;
+    }
+
+    let pair_info = query_pair_info(&deps.querier, &config.factory, &asset_infos)?;
+    if FEEDS.has(deps.storage, &pair_info.contract_addr) {
+        return Err(ContractError::FeedAlreadyExists(
+            pair_info.contract_addr.to_string(),
+        ));
+    }
+
+    let prices = query_cumulative_prices(deps.querier, &pair_info.contract_addr)?;
+    FEEDS.save(
+        deps.storage,
+        &pair_info.contract_addr,
+        &PriceFeed {
+            pair: pair_info.clone(),
+            period,
+            price_last: initial_prices(prices, env.block.time.seconds()),
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "register_feed"),
+        attr("pair", pair_info.contract_addr),
+        attr("period", period.to_string()),
+    ]))
+}
+
+/// Changes the TWAP window of a registered feed.
+///
+/// * **pair** pair contract address of the feed.
+///
+/// * **period** new TWAP window of the feed in seconds.
+pub fn update_feed_period(
+    deps: DepsMut,
+    info: MessageInfo,
+    pair: String,
+    period: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if period == 0 {
+        return Err(ContractError::InvalidPeriod {});
+    }
+
+    let pair = deps.api.addr_validate(&pair)?;
+    let mut feed = FEEDS.load(deps.storage, &pair)?;
+    feed.period = period;
+    FEEDS.save(deps.storage, &pair, &feed)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_feed_period"),
+        attr("pair", pair),
+        attr("period", period.to_string()),
+    ]))
+}
+
+/// Removes a registered feed.
+///
+/// * **pair** pair contract address of the feed.
+pub fn remove_feed(
+    deps: DepsMut,
+    info: MessageInfo,
+    pair: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pair = deps.api.addr_validate(&pair)?;
+    if !FEEDS.has(deps.storage, &pair) {
+        return Err(StdError::generic_err(format!("Feed for pair {pair} not found")).into());
+    }
+    FEEDS.remove(deps.storage, &pair);
+
+    Ok(Response::new().add_attributes(vec![attr("action", "remove_feed"), attr("pair", pair)]))
+}
+
+/// Updates the local TWAP values of a registered feed.
+///
+/// * **pair** pair contract address of the feed.
+pub fn update_feed(deps: DepsMut, env: Env, pair: String) -> Result<Response, ContractError> {
+    let pair = deps.api.addr_validate(&pair)?;
+    let mut feed = FEEDS.load(deps.storage, &pair)?;
+
+    let prices = query_cumulative_prices(deps.querier, &pair)?;
+    let time_elapsed = env.block.time.seconds() - feed.price_last.block_timestamp_last;
+
+    // Ensure that at least one full window has passed since the last update
+    if time_elapsed < feed.period {
+        return Err(ContractError::WrongPeriod {});
+    }
+
+    feed.price_last = accumulate_prices(
+        &feed.price_last,
+        prices,
+        env.block.time.seconds(),
+        time_elapsed,
+    );
+    FEEDS.save(deps.storage, &pair, &feed)?;
+
+    Ok(Response::new().add_attributes(vec![attr("action", "update_feed"), attr("pair", pair)]))
+}
+
+/// Returns the initial price state for a pair with zero average prices.
+///
+/// * **prices** current cumulative prices of the pair.
+fn initial_prices(prices: CumulativePricesResponse, block_time: u64) -> PriceCumulativeLast {
+    let average_prices = prices
+        .cumulative_prices
+        .iter()
+        .cloned()
+        .map(|(from, to, _)| (from, to, Decimal256::zero()))
+        .collect();
+
+    PriceCumulativeLast {
+        cumulative_prices: prices.cumulative_prices,
+        average_prices,
+        block_timestamp_last: block_time,
+    }
+}
+
+/// Calculates average prices for the elapsed time and returns the new price state.
+///
+/// * **price_last** price state saved during the previous update.
+///
+/// * **prices** current cumulative prices of the pair.
+fn accumulate_prices(
+    price_last: &PriceCumulativeLast,
+    prices: CumulativePricesResponse,
+    block_time: u64,
+    time_elapsed: u64,
+) -> PriceCumulativeLast {
     let mut average_prices = vec![];
     for (asset1_last, asset2_last, price_last) in price_last.cumulative_prices.iter() {
         for (asset1, asset2, price) in prices.cumulative_prices.iter() {
@@ -112,13 +297,11 @@ pub fn update(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
         }
     }
 
-    let prices = PriceCumulativeLast {
+    PriceCumulativeLast {
         cumulative_prices: prices.cumulative_prices,
         average_prices,
-        block_timestamp_last: env.block.time.seconds(),
-    };
-    PRICE_LAST.save(deps.storage, &prices)?;
-    Ok(Response::default())
+        block_timestamp_last: block_time,
+    }
 }
 
 /// Exposes all the queries available in the contract.
@@ -126,13 +309,139 @@ pub fn update(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
 /// ## Queries
 /// * **QueryMsg::Consult { token, amount }** Validates assets and calculates a new average
 /// amount with updated precision
+///
+/// * **QueryMsg::Feed { pair }** Returns information about a registered feed.
+///
+/// * **QueryMsg::Feeds { start_after, limit }** Returns information about registered feeds.
+///
+/// * **QueryMsg::ConsultPath { path, amount, max_age }** Converts an amount through the feeds
+/// registered for each consecutive pair of assets in the path.
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Consult { token, amount } => to_binary(&consult(deps, token, amount)?),
+        QueryMsg::Feed { pair } => to_binary(&query_feed(deps, pair)?),
+        QueryMsg::Feeds { start_after, limit } => {
+            to_binary(&query_feeds(deps, start_after, limit)?)
+        }
+        QueryMsg::ConsultPath {
+            path,
+            amount,
+            max_age,
+        } => to_binary(&consult_path(deps, env, path, amount, max_age)?),
     }
 }
 
+/// Returns information about a registered feed using a [`FeedResponse`] object.
+///
+/// * **pair** pair contract address of the feed.
+fn query_feed(deps: Deps, pair: String) -> StdResult<FeedResponse> {
+    let pair = deps.api.addr_validate(&pair)?;
+    FEEDS.load(deps.storage, &pair).map(feed_response)
+}
+
+/// Returns information about registered feeds using a vector of [`FeedResponse`] objects.
+///
+/// * **start_after** pair contract address of the feed to start reading from.
+///
+/// * **limit** number of feeds to return.
+fn query_feeds(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<FeedResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|pair| deps.api.addr_validate(&pair))
+        .transpose()?;
+
+    FEEDS
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, feed)| feed_response(feed)))
+        .collect()
+}
+
+fn feed_response(feed: PriceFeed) -> FeedResponse {
+    FeedResponse {
+        pair: feed.pair.contract_addr,
+        asset_infos: feed.pair.asset_infos,
+        period: feed.period,
+        average_prices: feed.price_last.average_prices,
+        last_updated: feed.price_last.block_timestamp_last,
+    }
+}
+
+/// Converts an amount of the first asset in **path** to the last one, multiplying it by the TWAP
+/// of the feed registered for each consecutive pair of assets (A→B→C).
+///
+/// * **amount** amount of the first asset in the path.
+///
+/// * **max_age** maximum number of seconds since the last update of every feed in the path.
+fn consult_path(
+    deps: Deps,
+    env: Env,
+    path: Vec<AssetInfo>,
+    amount: Uint128,
+    max_age: Option<u64>,
+) -> StdResult<ConsultPathResponse> {
+    if path.len() < 2 || path.len() > MAX_CONSULT_PATH_LEN {
+        return Err(StdError::generic_err(format!(
+            "Path must contain from 2 to {MAX_CONSULT_PATH_LEN} assets"
+        )));
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let price_precision = Uint256::from(10_u128.pow(TWAP_PRECISION.into()));
+
+    let mut amount = Uint256::from(amount);
+    let mut last_updated = env.block.time.seconds();
+
+    for assets in path.windows(2) {
+        let (from, to) = (&assets[0], &assets[1]);
+
+        let pair_info = query_pair_info(&deps.querier, &config.factory, assets)?;
+        let feed = FEEDS
+            .may_load(deps.storage, &pair_info.contract_addr)?
+            .ok_or_else(|| StdError::generic_err(format!("No feed for {from} -> {to}")))?;
+
+        let price_average = feed
+            .price_last
+            .average_prices
+            .iter()
+            .find(|(asset1, asset2, _)| asset1.equal(from) && asset2.equal(to))
+            .map(|(_, _, price_average)| *price_average)
+            .ok_or_else(|| StdError::generic_err(format!("No feed for {from} -> {to}")))?;
+
+        if price_average.is_zero() {
+            return Err(StdError::generic_err(format!(
+                "Feed for {from} -> {to} has not been updated yet"
+            )));
+        }
+
+        amount = amount * price_average / price_precision;
+        last_updated = last_updated.min(feed.price_last.block_timestamp_last);
+    }
+
+    if let Some(max_age) = max_age {
+        if env.block.time.seconds() - last_updated > max_age {
+            return Err(StdError::generic_err(format!(
+                "Price is stale: last updated at {last_updated}"
+            )));
+        }
+    }
+
+    Ok(ConsultPathResponse {
+        amount,
+        last_updated,
+    })
+}
+
 /// Multiplies a token amount by its latest TWAP value.
 /// * **token** token for which we multiply its TWAP value by an amount.
 ///
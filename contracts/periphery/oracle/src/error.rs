@@ -13,6 +13,12 @@ pub enum ContractError {
     #[error("Period not elapsed")]
     WrongPeriod {},
 
+    #[error("Period must be greater than zero")]
+    InvalidPeriod {},
+
+    #[error("Feed for pair {0} already exists")]
+    FeedAlreadyExists(String),
+
     #[error("Contract can't be migrated!")]
     MigrationError {},
 }
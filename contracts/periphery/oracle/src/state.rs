@@ -8,6 +8,8 @@ use cw_storage_plus::{Item, Map};
 pub const CONFIG: Item<Config> = Item::new("config");
 /// Stores the latest cumulative and average prices at the given key
 pub const PRICE_LAST: Item<PriceCumulativeLast> = Item::new("price_last");
+/// Stores registered price feeds with their pair contract address as the key
+pub const FEEDS: Map<&Addr, PriceFeed> = Map::new("feeds");
 
 /// This structure stores the latest cumulative and average token prices for the target pool
 #[cw_serde]
@@ -20,6 +22,17 @@ pub struct PriceCumulativeLast {
     pub block_timestamp_last: u64,
 }
 
+/// This structure stores a price feed registered for a factory pair
+#[cw_serde]
+pub struct PriceFeed {
+    /// Information about the pair (LP token address, pair type etc)
+    pub pair: PairInfo,
+    /// The TWAP window in seconds
+    pub period: u64,
+    /// The latest cumulative and average prices of the pair
+    pub price_last: PriceCumulativeLast,
+}
+
 /// Global configuration for the contract
 #[cw_serde]
 pub struct Config {
@@ -1,9 +1,10 @@
-use crate::contract::{execute, instantiate};
+use crate::contract::{execute, instantiate, query};
+use crate::error::ContractError;
 use crate::mock_querier::mock_dependencies;
 use astroport::asset::{Asset, AssetInfo};
-use astroport::oracle::{ExecuteMsg, InstantiateMsg};
+use astroport::oracle::{ConsultPathResponse, ExecuteMsg, FeedResponse, InstantiateMsg, QueryMsg};
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-use cosmwasm_std::{Addr, Decimal256, Uint128, Uint256};
+use cosmwasm_std::{from_binary, Addr, Decimal256, StdError, Uint128, Uint256};
 use std::ops::Mul;
 
 #[test]
@@ -107,3 +108,194 @@ fn oracle_overflow() {
     env.block.time = env.block.time.plus_seconds(86400);
     execute(deps.as_mut(), env, info, ExecuteMsg::Update {}).unwrap();
 }
+
+#[test]
+fn register_and_consult_feed() {
+    let mut deps = mock_dependencies(&[]);
+    let owner = mock_info("owner", &[]);
+
+    let mut env = mock_env();
+    let astro_token_contract = Addr::unchecked("astro-token");
+    let usdc_token_contract = Addr::unchecked("usdc-token");
+
+    deps.querier.with_token_balances(&[
+        (
+            &astro_token_contract.to_string(),
+            &[(&String::from(MOCK_CONTRACT_ADDR), &Uint128::new(10000))],
+        ),
+        (
+            &usdc_token_contract.to_string(),
+            &[(&String::from(MOCK_CONTRACT_ADDR), &Uint128::new(10000))],
+        ),
+    ]);
+
+    let astro_asset_info = AssetInfo::Token {
+        contract_addr: astro_token_contract,
+    };
+    let usdc_asset_info = AssetInfo::Token {
+        contract_addr: usdc_token_contract,
+    };
+    let assets = vec![
+        Asset {
+            info: astro_asset_info.clone(),
+            amount: Uint128::zero(),
+        },
+        Asset {
+            info: usdc_asset_info.clone(),
+            amount: Uint128::zero(),
+        },
+    ];
+
+    let cumulative_prices = |astro_price: u128, usdc_price: u128| {
+        vec![
+            (
+                astro_asset_info.clone(),
+                usdc_asset_info.clone(),
+                Uint128::from(astro_price),
+            ),
+            (
+                usdc_asset_info.clone(),
+                astro_asset_info.clone(),
+                Uint128::from(usdc_price),
+            ),
+        ]
+    };
+
+    deps.querier.set_cumulative_price(
+        Addr::unchecked("pair"),
+        assets.clone(),
+        Uint128::zero(),
+        cumulative_prices(1000, 1000),
+    );
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            factory_contract: "factory".to_string(),
+            asset_infos: vec![astro_asset_info.clone(), usdc_asset_info.clone()],
+        },
+    )
+    .unwrap();
+
+    let register_msg = ExecuteMsg::RegisterFeed {
+        asset_infos: vec![astro_asset_info.clone(), usdc_asset_info.clone()],
+        period: 3600,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("random", &[]),
+        register_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), ContractError::Unauthorized {}.to_string());
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        register_msg.clone(),
+    )
+    .unwrap();
+    let err = execute(deps.as_mut(), env.clone(), owner.clone(), register_msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::FeedAlreadyExists("pair".to_string()).to_string()
+    );
+
+    // The feed can't be consulted before its first update
+    let consult_msg = QueryMsg::ConsultPath {
+        path: vec![astro_asset_info.clone(), usdc_asset_info.clone()],
+        amount: Uint128::new(1000),
+        max_age: Some(600),
+    };
+    let err = query(deps.as_ref(), env.clone(), consult_msg.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Feed for astro-token -> usdc-token has not been updated yet")
+    );
+
+    let update_msg = ExecuteMsg::UpdateFeed {
+        pair: "pair".to_string(),
+    };
+    env.block.time = env.block.time.plus_seconds(1800);
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        update_msg.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), ContractError::WrongPeriod {}.to_string());
+
+    // 1 ASTRO = 2 USDC during the whole window
+    deps.querier.set_cumulative_price(
+        Addr::unchecked("pair"),
+        assets,
+        Uint128::zero(),
+        cumulative_prices(1000 + 3600 * 2_000000, 1000 + 3600 * 500000),
+    );
+    env.block.time = env.block.time.plus_seconds(1800);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("random", &[]),
+        update_msg,
+    )
+    .unwrap();
+
+    let feed: FeedResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Feed {
+                pair: "pair".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(feed.period, 3600);
+    assert_eq!(feed.last_updated, env.block.time.seconds());
+
+    let res: ConsultPathResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), consult_msg.clone()).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        ConsultPathResponse {
+            amount: Uint256::from(2000u128),
+            last_updated: env.block.time.seconds(),
+        }
+    );
+
+    // Composing prices through the same feed back and forth returns the initial amount
+    let res: ConsultPathResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::ConsultPath {
+                path: vec![
+                    astro_asset_info.clone(),
+                    usdc_asset_info.clone(),
+                    astro_asset_info.clone(),
+                ],
+                amount: Uint128::new(1000),
+                max_age: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.amount, Uint256::from(1000u128));
+
+    env.block.time = env.block.time.plus_seconds(601);
+    let err = query(deps.as_ref(), env, consult_msg).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(format!(
+            "Price is stale: last updated at {}",
+            feed.last_updated
+        ))
+    );
+}
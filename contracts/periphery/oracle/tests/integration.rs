@@ -149,6 +149,7 @@ fn instantiate_contracts(mut router: &mut App, owner: Addr) -> (Addr, Addr, u64)
                 maker_fee_bps: 0,
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             },
             PairConfig {
                 code_id: pair_stable_code_id,
@@ -157,6 +158,7 @@ fn instantiate_contracts(mut router: &mut App, owner: Addr) -> (Addr, Addr, u64)
                 maker_fee_bps: 0,
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             },
         ],
         token_code_id: 1u64,
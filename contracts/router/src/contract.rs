@@ -6,15 +6,20 @@ use cw2::{get_contract_version, set_contract_version};
 use cw20::Cw20ReceiveMsg;
 
 use astroport::asset::{addr_opt_validate, Asset, AssetInfo};
-use astroport::pair::{QueryMsg as PairQueryMsg, SimulationResponse};
+use astroport::pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse};
 use astroport::querier::query_pair_info;
 use astroport::router::{
     ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
-    SimulateSwapOperationsResponse, SwapOperation, SwapResponseData, MAX_SWAP_OPERATIONS,
+    SimulateSwapOperationsResponse, SwapOperation, SwapResponseData, SwapRoute,
+    MAX_SWAP_OPERATIONS, MAX_SWAP_ROUTES,
 };
 
 use crate::error::ContractError;
-use crate::operations::execute_swap_operation;
+use crate::operations::{
+    execute_reverse_swap_operations, execute_split_swap_operations, execute_swap_operation,
+    split_offer_amount,
+};
+use crate::path_finder::find_best_route;
 use crate::state::{Config, ReplyData, CONFIG, REPLY_DATA};
 
 /// Contract name that is used for migration.
@@ -56,6 +61,28 @@ pub fn instantiate(
 ///             to
 ///         }** Performs swap operations with the specified parameters.
 ///
+/// * **ExecuteMsg::ExecuteSplitSwapOperations {
+///             routes,
+///             minimum_receive,
+///             to
+///         }** Splits a swap across several weighted routes.
+///
+/// * **ExecuteMsg::ExecuteReverseSwapOperations {
+///             operations,
+///             ask_amount,
+///             max_offer,
+///             minimum_receive,
+///             to
+///         }** Swaps the offer amount needed to receive the specified ask amount and refunds the rest.
+///
+/// * **ExecuteMsg::ExecuteBestRouteSwap {
+///             offer_asset_info,
+///             ask_asset_info,
+///             max_hops,
+///             minimum_receive,
+///             to
+///         }** Swaps through the best route found among the factory pairs.
+///
 /// * **ExecuteMsg::ExecuteSwapOperation { operation, to }** Execute a single swap operation.
 ///
 /// * **ExecuteMsg::AssertMinimumReceive {
@@ -72,7 +99,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Receive(msg) => receive_cw20(deps, env, msg),
+        ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
         ExecuteMsg::ExecuteSwapOperations {
             operations,
             minimum_receive,
@@ -87,6 +114,56 @@ pub fn execute(
             to,
             max_spread,
         ),
+        ExecuteMsg::ExecuteSplitSwapOperations {
+            routes,
+            minimum_receive,
+            to,
+            max_spread,
+        } => execute_split_swap_operations(
+            deps,
+            env,
+            info.sender,
+            routes,
+            minimum_receive,
+            to,
+            max_spread,
+        ),
+        ExecuteMsg::ExecuteReverseSwapOperations {
+            operations,
+            ask_amount,
+            max_offer,
+            minimum_receive,
+            to,
+            max_spread,
+        } => execute_reverse_swap_operations(
+            deps,
+            env,
+            info.sender,
+            operations,
+            ask_amount,
+            max_offer,
+            minimum_receive,
+            to,
+            max_spread,
+        ),
+        ExecuteMsg::ExecuteBestRouteSwap {
+            offer_asset_info,
+            ask_asset_info,
+            max_hops,
+            minimum_receive,
+            to,
+            max_spread,
+        } => execute_best_route_swap(
+            deps,
+            env,
+            info.sender,
+            offer_asset_info,
+            ask_asset_info,
+            max_hops,
+            minimum_receive,
+            to,
+            max_spread,
+        ),
         ExecuteMsg::ExecuteSwapOperation {
             operation,
             to,
@@ -102,6 +179,7 @@ pub fn execute(
 pub fn receive_cw20(
     deps: DepsMut,
     env: Env,
+    info: MessageInfo,
     cw20_msg: Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
     match from_binary(&cw20_msg.msg)? {
@@ -119,6 +197,57 @@ pub fn receive_cw20(
             to,
             max_spread,
         ),
+        Cw20HookMsg::ExecuteSplitSwapOperations {
+            routes,
+            minimum_receive,
+            to,
+            max_spread,
+        } => execute_split_swap_operations(
+            deps,
+            env,
+            Addr::unchecked(cw20_msg.sender),
+            routes,
+            minimum_receive,
+            to,
+            max_spread,
+        ),
+        Cw20HookMsg::ExecuteReverseSwapOperations {
+            operations,
+            ask_amount,
+            max_offer,
+            minimum_receive,
+            to,
+            max_spread,
+        } => execute_reverse_swap_operations(
+            deps,
+            env,
+            Addr::unchecked(cw20_msg.sender),
+            operations,
+            ask_amount,
+            max_offer,
+            minimum_receive,
+            to,
+            max_spread,
+        ),
+        Cw20HookMsg::ExecuteBestRouteSwap {
+            ask_asset_info,
+            max_hops,
+            minimum_receive,
+            to,
+            max_spread,
+        } => execute_best_route_swap(
+            deps,
+            env,
+            Addr::unchecked(cw20_msg.sender),
+            AssetInfo::Token {
+                contract_addr: info.sender,
+            },
+            ask_asset_info,
+            max_hops,
+            minimum_receive,
+            to,
+            max_spread,
+        ),
     }
 }
 
@@ -193,6 +322,47 @@ pub fn execute_swap_operations(
     Ok(Response::new().add_submessages(messages))
 }
 
+/// Swaps the router balance of the offer asset through the best route found among the factory pairs.
+///
+/// * **sender** address that swaps tokens.
+///
+/// * **max_hops** maximum number of swaps in the route.
+///
+/// * **minimum_receive** used to guarantee that the ask amount is above a minimum amount.
+///
+/// * **to** recipient of the ask tokens.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_best_route_swap(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    offer_asset_info: AssetInfo,
+    ask_asset_info: AssetInfo,
+    max_hops: Option<u32>,
+    minimum_receive: Option<Uint128>,
+    to: Option<String>,
+    max_spread: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let offer_amount = offer_asset_info.query_pool(&deps.querier, &env.contract.address)?;
+    let best_route = find_best_route(
+        deps.as_ref(),
+        offer_asset_info,
+        ask_asset_info,
+        offer_amount,
+        max_hops,
+    )?;
+
+    execute_swap_operations(
+        deps,
+        env,
+        sender,
+        best_route.operations,
+        minimum_receive,
+        to,
+        max_spread,
+    )
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg {
@@ -233,6 +403,20 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 ///             offer_amount,
 ///             operations,
 ///         }** Simulates one or multiple swap operations and returns the end result in a [`SimulateSwapOperationsResponse`] object.
+/// * **QueryMsg::SimulateSplitSwapOperations {
+///             offer_amount,
+///             routes,
+///         }** Simulates a swap split across several weighted routes and returns the total end result in a [`SimulateSwapOperationsResponse`] object.
+/// * **QueryMsg::ReverseSimulateSwapOperations {
+///             ask_amount,
+///             operations,
+///         }** Simulates one or multiple swap operations backwards and returns the required offer amount in a [`SimulateSwapOperationsResponse`] object.
+/// * **QueryMsg::BestRoute {
+///             offer_asset_info,
+///             ask_asset_info,
+///             offer_amount,
+///             max_hops,
+///         }** Searches the factory pairs for the best route and returns it in a `BestRouteResponse` object.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
@@ -245,6 +429,20 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
             offer_amount,
             operations,
         )?)?),
+        QueryMsg::SimulateSplitSwapOperations {
+            offer_amount,
+            routes,
+        } => Ok(to_binary(&simulate_split_swap_operations(
+            deps,
+            offer_amount,
+            routes,
+        )?)?),
+        QueryMsg::ReverseSimulateSwapOperations {
+            ask_amount,
+            operations,
+        } => Ok(to_binary(&simulate_reverse_swap_operations(
+            deps, ask_amount, operations,
+        )?)?),
     }
 }
 
@@ -288,7 +486,7 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
 ///
 /// * **operations** is a vector that contains objects of type [`SwapOperation`].
 /// These are all the swap operations for which we perform a simulation.
-fn simulate_swap_operations(
+pub(crate) fn simulate_swap_operations(
     deps: Deps,
     offer_amount: Uint128,
     operations: Vec<SwapOperation>,
@@ -335,6 +533,141 @@ fn simulate_swap_operations(
     })
 }
 
+/// Returns the amount of offer assets needed to receive **ask_amount** of the last ask asset
+/// using a [`SimulateSwapOperationsResponse`] object. Operations are simulated backwards with
+/// each pair's reverse simulation.
+///
+/// * **ask_amount** amount of ask assets to receive.
+///
+/// * **operations** is a vector that contains objects of type [`SwapOperation`].
+/// These are all the swap operations for which we perform a reverse simulation.
+pub(crate) fn simulate_reverse_swap_operations(
+    deps: Deps,
+    ask_amount: Uint128,
+    operations: Vec<SwapOperation>,
+) -> Result<SimulateSwapOperationsResponse, ContractError> {
+    assert_operations(deps.api, &operations)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let mut offer_amount = ask_amount;
+
+    for operation in operations.into_iter().rev() {
+        match operation {
+            SwapOperation::AstroSwap {
+                offer_asset_info,
+                ask_asset_info,
+            } => {
+                let pair_info = query_pair_info(
+                    &deps.querier,
+                    &config.astroport_factory,
+                    &[offer_asset_info.clone(), ask_asset_info.clone()],
+                )?;
+
+                let res: ReverseSimulationResponse = deps.querier.query_wasm_smart(
+                    pair_info.contract_addr,
+                    &PairQueryMsg::ReverseSimulation {
+                        offer_asset_info: Some(offer_asset_info),
+                        ask_asset: Asset {
+                            info: ask_asset_info,
+                            amount: offer_amount,
+                        },
+                    },
+                )?;
+
+                offer_amount = res.offer_amount;
+            }
+            SwapOperation::NativeSwap { .. } => {
+                return Err(ContractError::NativeSwapNotSupported {})
+            }
+        }
+    }
+
+    Ok(SimulateSwapOperationsResponse {
+        amount: offer_amount,
+    })
+}
+
+/// Returns the total end result of a simulation for a swap split across several weighted routes
+/// using a [`SimulateSwapOperationsResponse`] object.
+///
+/// * **offer_amount** total amount of offer assets being swapped.
+///
+/// * **routes** is a vector that contains objects of type [`SwapRoute`].
+fn simulate_split_swap_operations(
+    deps: Deps,
+    offer_amount: Uint128,
+    routes: Vec<SwapRoute>,
+) -> Result<SimulateSwapOperationsResponse, ContractError> {
+    assert_routes(deps.api, &routes)?;
+
+    let route_amounts = split_offer_amount(offer_amount, &routes)?;
+    let mut return_amount = Uint128::zero();
+
+    for (route, route_amount) in routes.into_iter().zip(route_amounts) {
+        let res = simulate_swap_operations(deps, route_amount, route.operations)?;
+        return_amount = return_amount.checked_add(res.amount)?;
+    }
+
+    Ok(SimulateSwapOperationsResponse {
+        amount: return_amount,
+    })
+}
+
+/// Validates split swap routes. Every route must be a valid path between the same offer and ask assets.
+///
+/// * **routes** is a vector that contains objects of type [`SwapRoute`]. These are all the routes we check.
+pub(crate) fn assert_routes(api: &dyn Api, routes: &[SwapRoute]) -> Result<(), ContractError> {
+    if routes.is_empty() {
+        return Err(ContractError::MustProvideRoutes {});
+    }
+
+    if routes.len() > MAX_SWAP_ROUTES {
+        return Err(ContractError::RouteLimitExceeded {});
+    }
+
+    let operations_len: usize = routes.iter().map(|route| route.operations.len()).sum();
+    if operations_len > MAX_SWAP_OPERATIONS {
+        return Err(ContractError::SwapLimitExceeded {});
+    }
+
+    let mut route_assets: Option<(AssetInfo, AssetInfo)> = None;
+
+    for route in routes {
+        if route.weight == 0 {
+            return Err(ContractError::ZeroRouteWeight {});
+        }
+
+        assert_operations(api, &route.operations)?;
+
+        let offer_asset = route.operations[0].get_offer_asset_info();
+        let ask_asset = route.operations.last().unwrap().get_target_asset_info();
+
+        // Swapping back into the offer asset would mix this route's output with other routes' shares
+        if route.operations[1..]
+            .iter()
+            .any(|operation| operation.get_offer_asset_info() == offer_asset)
+        {
+            return Err(ContractError::RouteRevisitsOfferAsset {
+                offer_asset: offer_asset.to_string(),
+            });
+        }
+
+        match &route_assets {
+            Some((route_offer_asset, route_ask_asset)) => {
+                if *route_offer_asset != offer_asset || *route_ask_asset != ask_asset {
+                    return Err(ContractError::RouteAssetsMismatch {
+                        offer_asset: route_offer_asset.to_string(),
+                        ask_asset: route_ask_asset.to_string(),
+                    });
+                }
+            }
+            None => route_assets = Some((offer_asset, ask_asset)),
+        }
+    }
+
+    Ok(())
+}
+
 /// Validates swap operations.
 ///
 /// * **operations** is a vector that contains objects of type [`SwapOperation`]. These are all the swap operations we check.
@@ -35,9 +35,47 @@ pub enum ContractError {
     #[error("Assertion failed; minimum receive amount: {receive}, swap amount: {amount}")]
     AssertionMinimumReceive { receive: Uint128, amount: Uint128 },
 
+    #[error(
+        "Assertion failed; maximum offer amount: {max_offer}, required offer amount: {offer_amount}"
+    )]
+    AssertionMaxOffer {
+        max_offer: Uint128,
+        offer_amount: Uint128,
+    },
+
     #[error("The swap operation limit was exceeded!")]
     SwapLimitExceeded {},
 
+    #[error("Must specify swap routes!")]
+    MustProvideRoutes {},
+
+    #[error("The swap route limit was exceeded!")]
+    RouteLimitExceeded {},
+
+    #[error("Route weight must be greater than zero")]
+    ZeroRouteWeight {},
+
+    #[error("All routes must swap {offer_asset} to {ask_asset}")]
+    RouteAssetsMismatch {
+        offer_asset: String,
+        ask_asset: String,
+    },
+
+    #[error("Route must not swap back into the offer asset {offer_asset}")]
+    RouteRevisitsOfferAsset { offer_asset: String },
+
+    #[error("Offer amount is too small to be split across the given routes")]
+    ZeroRouteAmount {},
+
+    #[error("max_hops must be between 1 and {max_hops}")]
+    InvalidMaxHops { max_hops: u32 },
+
+    #[error("No route found from {offer_asset} to {ask_asset}")]
+    RouteNotFound {
+        offer_asset: String,
+        ask_asset: String,
+    },
+
     #[error("Native swap operations are not supported!")]
     NativeSwapNotSupported {},
 
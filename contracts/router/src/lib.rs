@@ -4,6 +4,7 @@ pub mod state;
 pub mod error;
 
 mod operations;
+mod path_finder;
 
 #[cfg(test)]
 mod testing;
@@ -1,14 +1,16 @@
-use astroport::asset::{Asset, AssetInfo};
+use astroport::asset::{addr_opt_validate, Asset, AssetInfo};
 use astroport::pair::ExecuteMsg as PairExecuteMsg;
 use astroport::querier::{query_balance, query_pair_info, query_token_balance};
-use astroport::router::SwapOperation;
+use astroport::router::{ExecuteMsg, SwapOperation, SwapRoute};
 use cosmwasm_std::{
-    to_binary, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response, StdResult, WasmMsg,
+    to_binary, wasm_execute, Addr, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    ReplyOn, Response, StdResult, SubMsg, Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
 
+use crate::contract::{assert_routes, simulate_reverse_swap_operations, AFTER_SWAP_REPLY_ID};
 use crate::error::ContractError;
-use crate::state::CONFIG;
+use crate::state::{ReplyData, CONFIG, REPLY_DATA};
 
 /// Execute a swap operation.
 ///
@@ -128,3 +130,259 @@ pub fn asset_into_swap_msg(
         })),
     }
 }
+
+/// Performs a swap split across several weighted routes. The whole router balance of the offer asset
+/// is distributed between routes according to their weights.
+///
+/// * **sender** address that swaps tokens.
+///
+/// * **routes** weighted routes between the same offer and ask assets.
+///
+/// * **minimum_receive** used to guarantee that the ask amount received across all routes is above a minimum amount.
+///
+/// * **to** recipient of the ask tokens.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_split_swap_operations(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    routes: Vec<SwapRoute>,
+    minimum_receive: Option<Uint128>,
+    to: Option<String>,
+    max_spread: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    assert_routes(deps.api, &routes)?;
+
+    let to = addr_opt_validate(deps.api, &to)?.unwrap_or(sender);
+    let offer_asset_info = routes[0].operations[0].get_offer_asset_info();
+    let target_asset_info = routes[0].operations.last().unwrap().get_target_asset_info();
+
+    let offer_amount = offer_asset_info.query_pool(&deps.querier, &env.contract.address)?;
+    let route_amounts = split_offer_amount(offer_amount, &routes)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut messages = vec![];
+    for (route, route_amount) in routes.into_iter().zip(route_amounts) {
+        messages.extend(exact_offer_swap_messages(
+            deps.as_ref(),
+            &env,
+            &config.astroport_factory,
+            route.operations,
+            route_amount,
+            &to,
+            max_spread,
+        )?);
+    }
+
+    // The minimum receive amount is asserted once, after the last operation of the last route
+    assert_receive_after_last_swap(deps, &mut messages, target_asset_info, minimum_receive, &to)?;
+
+    Ok(Response::new().add_submessages(messages))
+}
+
+/// Swaps the amount of offer assets needed to receive **ask_amount** of the ask asset.
+/// The required offer amount is found with a reverse simulation of the path and the rest of
+/// the router balance of the offer asset is refunded to the sender.
+///
+/// * **sender** address that swaps tokens and receives the refund.
+///
+/// * **operations** all swap operations to perform.
+///
+/// * **ask_amount** amount of ask assets to receive.
+///
+/// * **max_offer** maximum amount of offer assets to spend.
+///
+/// * **minimum_receive** used to guarantee that the ask amount is above a minimum amount.
+///
+/// * **to** recipient of the ask tokens.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_reverse_swap_operations(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    operations: Vec<SwapOperation>,
+    ask_amount: Uint128,
+    max_offer: Option<Uint128>,
+    minimum_receive: Option<Uint128>,
+    to: Option<String>,
+    max_spread: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let offer_amount =
+        simulate_reverse_swap_operations(deps.as_ref(), ask_amount, operations.clone())?.amount;
+
+    let to = addr_opt_validate(deps.api, &to)?.unwrap_or_else(|| sender.clone());
+    let offer_asset_info = operations[0].get_offer_asset_info();
+    let target_asset_info = operations.last().unwrap().get_target_asset_info();
+
+    let provided_amount = offer_asset_info.query_pool(&deps.querier, &env.contract.address)?;
+    let max_offer = max_offer.map_or(provided_amount, |max_offer| max_offer.min(provided_amount));
+    if offer_amount > max_offer {
+        return Err(ContractError::AssertionMaxOffer {
+            max_offer,
+            offer_amount,
+        });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let mut messages = exact_offer_swap_messages(
+        deps.as_ref(),
+        &env,
+        &config.astroport_factory,
+        operations,
+        offer_amount,
+        &to,
+        max_spread,
+    )?;
+    assert_receive_after_last_swap(deps, &mut messages, target_asset_info, minimum_receive, &to)?;
+
+    let mut response = Response::new();
+
+    let refund_amount = provided_amount.checked_sub(offer_amount)?;
+    if !refund_amount.is_zero() {
+        response = response.add_message(
+            Asset {
+                info: offer_asset_info,
+                amount: refund_amount,
+            }
+            .into_msg(&sender)?,
+        );
+    }
+
+    Ok(response
+        .add_submessages(messages)
+        .add_attribute("offer_amount", offer_amount)
+        .add_attribute("refund_amount", refund_amount))
+}
+
+/// Makes the last swap message reply to the router so that the amount received by **receiver**
+/// is checked against **minimum_receive**.
+///
+/// * **messages** swap messages of the whole swap.
+///
+/// * **asset_info** asset received by **receiver**.
+fn assert_receive_after_last_swap(
+    deps: DepsMut,
+    messages: &mut [SubMsg],
+    asset_info: AssetInfo,
+    minimum_receive: Option<Uint128>,
+    receiver: &Addr,
+) -> Result<(), ContractError> {
+    if let Some(last_message) = messages.last_mut() {
+        last_message.id = AFTER_SWAP_REPLY_ID;
+        last_message.reply_on = ReplyOn::Success;
+    }
+
+    let prev_balance = asset_info.query_pool(&deps.querier, receiver)?;
+    REPLY_DATA.save(
+        deps.storage,
+        &ReplyData {
+            asset_info,
+            prev_balance,
+            minimum_receive,
+            receiver: receiver.to_string(),
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Creates swap messages for a path that must consume exactly **offer_amount** of the offer asset.
+/// The first hop is sent to the pair directly, since [`ExecuteMsg::ExecuteSwapOperation`] swaps
+/// the whole router balance. The following hops are processed as usual.
+///
+/// * **operations** swap operations of the path.
+///
+/// * **offer_amount** amount of offer assets swapped in the first hop.
+///
+/// * **to** address that receives the ask assets of the last hop.
+pub(crate) fn exact_offer_swap_messages(
+    deps: Deps,
+    env: &Env,
+    astroport_factory: &Addr,
+    operations: Vec<SwapOperation>,
+    offer_amount: Uint128,
+    to: &Addr,
+    max_spread: Option<Decimal>,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let operations_len = operations.len();
+    let mut messages = Vec::with_capacity(operations_len);
+
+    for (operation_index, operation) in operations.into_iter().enumerate() {
+        let receiver = if operation_index == operations_len - 1 {
+            Some(to.to_string())
+        } else {
+            None
+        };
+
+        let message = if operation_index == 0 {
+            let offer_asset_info = operation.get_offer_asset_info();
+            let ask_asset_info = operation.get_target_asset_info();
+            let pair_info = query_pair_info(
+                &deps.querier,
+                astroport_factory,
+                &[offer_asset_info.clone(), ask_asset_info.clone()],
+            )?;
+
+            asset_into_swap_msg(
+                pair_info.contract_addr.to_string(),
+                Asset {
+                    info: offer_asset_info,
+                    amount: offer_amount,
+                },
+                ask_asset_info,
+                max_spread,
+                receiver,
+                operations_len == 1,
+            )?
+        } else {
+            wasm_execute(
+                env.contract.address.to_string(),
+                &ExecuteMsg::ExecuteSwapOperation {
+                    operation,
+                    to: receiver,
+                    max_spread,
+                    single: false,
+                },
+                vec![],
+            )?
+            .into()
+        };
+
+        messages.push(SubMsg::new(message));
+    }
+
+    Ok(messages)
+}
+
+/// Splits the offer amount between routes proportionally to their weights.
+/// The last route receives the rounding remainder so that the whole offer amount is used.
+///
+/// * **offer_amount** total amount of offer assets being swapped.
+///
+/// * **routes** weighted routes between which the offer amount is split.
+pub(crate) fn split_offer_amount(
+    offer_amount: Uint128,
+    routes: &[SwapRoute],
+) -> Result<Vec<Uint128>, ContractError> {
+    let total_weight: Uint128 = routes.iter().map(|route| Uint128::from(route.weight)).sum();
+
+    let mut remaining_amount = offer_amount;
+    let mut route_amounts = Vec::with_capacity(routes.len());
+
+    for (route_index, route) in routes.iter().enumerate() {
+        let route_amount = if route_index == routes.len() - 1 {
+            remaining_amount
+        } else {
+            offer_amount.multiply_ratio(route.weight, total_weight)
+        };
+
+        if route_amount.is_zero() {
+            return Err(ContractError::ZeroRouteAmount {});
+        }
+
+        remaining_amount = remaining_amount.checked_sub(route_amount)?;
+        route_amounts.push(route_amount);
+    }
+
+    Ok(route_amounts)
+}
@@ -0,0 +1,165 @@
+use astroport::asset::AssetInfo;
+use astroport::factory::{PairType, QueryMsg as FactoryQueryMsg};
+use astroport::querier::query_pairs_info;
+use astroport::router::{BestRouteResponse, SwapOperation, MAX_ROUTE_HOPS};
+use cosmwasm_std::{Deps, Uint128};
+
+use crate::contract::simulate_swap_operations;
+use crate::error::ContractError;
+use crate::state::CONFIG;
+
+/// The number of pairs read from the factory per query
+const PAIRS_QUERY_LIMIT: u32 = 30;
+/// The maximum number of candidate routes simulated while searching for the best route
+const MAX_CANDIDATE_ROUTES: usize = 20;
+
+/// Returns the route between **offer_asset_info** and **ask_asset_info** that returns the most
+/// ask assets for **offer_amount**. Candidate routes are built from the pairs registered in the factory,
+/// so deregistered pairs are never used. Pairs of blacklisted types are skipped as well.
+///
+/// * **max_hops** maximum number of swaps in a route, defaults to [`MAX_ROUTE_HOPS`].
+pub(crate) fn find_best_route(
+    deps: Deps,
+    offer_asset_info: AssetInfo,
+    ask_asset_info: AssetInfo,
+    offer_amount: Uint128,
+    max_hops: Option<u32>,
+) -> Result<BestRouteResponse, ContractError> {
+    let max_hops = max_hops.unwrap_or(MAX_ROUTE_HOPS);
+    if max_hops == 0 || max_hops > MAX_ROUTE_HOPS {
+        return Err(ContractError::InvalidMaxHops {
+            max_hops: MAX_ROUTE_HOPS,
+        });
+    }
+
+    offer_asset_info.check(deps.api)?;
+    ask_asset_info.check(deps.api)?;
+
+    if offer_asset_info.equal(&ask_asset_info) {
+        return Err(ContractError::DoublingAssetsPath {
+            offer_asset: offer_asset_info.to_string(),
+            ask_asset: ask_asset_info.to_string(),
+        });
+    }
+
+    let edges = query_swap_edges(deps)?;
+
+    let mut paths = vec![];
+    collect_paths(
+        &edges,
+        &mut vec![offer_asset_info.clone()],
+        &ask_asset_info,
+        max_hops as usize,
+        &mut paths,
+    );
+
+    // Shorter routes are cheaper to simulate and to execute, so they are tried first
+    paths.sort_by_key(|path| path.len());
+
+    paths
+        .into_iter()
+        .take(MAX_CANDIDATE_ROUTES)
+        .filter_map(|path| {
+            let operations: Vec<_> = path
+                .windows(2)
+                .map(|assets| SwapOperation::AstroSwap {
+                    offer_asset_info: assets[0].clone(),
+                    ask_asset_info: assets[1].clone(),
+                })
+                .collect();
+
+            // Routes through pools that can't process the swap are skipped
+            simulate_swap_operations(deps, offer_amount, operations.clone())
+                .ok()
+                .map(|res| BestRouteResponse {
+                    operations,
+                    amount: res.amount,
+                })
+        })
+        .fold(None, |best: Option<BestRouteResponse>, route| match best {
+            Some(best) if best.amount >= route.amount => Some(best),
+            _ => Some(route),
+        })
+        .ok_or_else(|| ContractError::RouteNotFound {
+            offer_asset: offer_asset_info.to_string(),
+            ask_asset: ask_asset_info.to_string(),
+        })
+}
+
+/// Returns the asset pairs which can be swapped through the router. Only pairs with two assets
+/// are included, as the router looks pairs up by the offer and ask assets.
+fn query_swap_edges(deps: Deps) -> Result<Vec<(AssetInfo, AssetInfo)>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let blacklisted_pair_types: Vec<PairType> = deps.querier.query_wasm_smart(
+        &config.astroport_factory,
+        &FactoryQueryMsg::BlacklistedPairTypes {},
+    )?;
+
+    let mut edges = vec![];
+    let mut start_after = None;
+
+    loop {
+        let pairs = query_pairs_info(
+            &deps.querier,
+            &config.astroport_factory,
+            start_after,
+            Some(PAIRS_QUERY_LIMIT),
+        )?
+        .pairs;
+
+        start_after = pairs.last().map(|pair| pair.asset_infos.clone());
+
+        edges.extend(
+            pairs
+                .iter()
+                .filter(|pair| {
+                    pair.asset_infos.len() == 2 && !blacklisted_pair_types.contains(&pair.pair_type)
+                })
+                .map(|pair| (pair.asset_infos[0].clone(), pair.asset_infos[1].clone())),
+        );
+
+        if pairs.len() < PAIRS_QUERY_LIMIT as usize {
+            break;
+        }
+    }
+
+    Ok(edges)
+}
+
+/// Collects all paths from the last asset in **path** to **ask_asset_info** which don't visit
+/// any asset twice and consist of at most **max_hops** swaps.
+fn collect_paths(
+    edges: &[(AssetInfo, AssetInfo)],
+    path: &mut Vec<AssetInfo>,
+    ask_asset_info: &AssetInfo,
+    max_hops: usize,
+    paths: &mut Vec<Vec<AssetInfo>>,
+) {
+    let current = path.last().cloned().unwrap();
+
+    let next_assets = edges.iter().filter_map(|(first, second)| {
+        if *first == current {
+            Some(second)
+        } else if *second == current {
+            Some(first)
+        } else {
+            None
+        }
+    });
+
+    for next in next_assets {
+        if path.contains(next) {
+            continue;
+        }
+
+        if next == ask_asset_info {
+            let mut found = path.clone();
+            found.push(next.clone());
+            paths.push(found);
+        } else if path.len() < max_hops {
+            path.push(next.clone());
+            collect_paths(edges, path, ask_asset_info, max_hops, paths);
+            path.pop();
+        }
+    }
+}
@@ -8,7 +8,7 @@ use std::collections::HashMap;
 
 use astroport::asset::{Asset, AssetInfo, PairInfo};
 use astroport::factory::PairType;
-use astroport::pair::SimulationResponse;
+use astroport::pair::{ReverseSimulationResponse, SimulationResponse};
 use cw20::{BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 
 #[cw_serde]
@@ -20,6 +20,10 @@ pub enum QueryMsg {
         offer_asset: Asset,
         ask_asset_info: Option<AssetInfo>,
     },
+    ReverseSimulation {
+        offer_asset_info: Option<AssetInfo>,
+        ask_asset: Asset,
+    },
 }
 
 /// mock_dependencies is a drop-in replacement for cosmwasm_std::testing::mock_dependencies.
@@ -162,6 +166,13 @@ impl WasmMockQuerier {
                     spread_amount: Uint128::zero(),
                 })))
             }
+            QueryMsg::ReverseSimulation { ask_asset, .. } => SystemResult::Ok(
+                ContractResult::from(to_binary(&ReverseSimulationResponse {
+                    offer_amount: ask_asset.amount,
+                    commission_amount: Uint128::zero(),
+                    spread_amount: Uint128::zero(),
+                })),
+            ),
         }
     }
 
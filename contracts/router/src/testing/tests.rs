@@ -1,11 +1,13 @@
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-use cosmwasm_std::{from_binary, to_binary, Addr, Coin, ReplyOn, SubMsg, Uint128, WasmMsg};
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, BankMsg, Coin, Decimal, ReplyOn, SubMsg, Uint128, WasmMsg,
+};
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
-use astroport::asset::{native_asset_info, AssetInfo};
+use astroport::asset::{native_asset_info, Asset, AssetInfo};
 use astroport::router::{
     ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg,
-    SimulateSwapOperationsResponse, SwapOperation, MAX_SWAP_OPERATIONS,
+    SimulateSwapOperationsResponse, SwapOperation, SwapRoute, MAX_SWAP_OPERATIONS,
 };
 
 use crate::contract::{execute, instantiate, query, AFTER_SWAP_REPLY_ID};
@@ -463,3 +465,380 @@ fn assert_maximum_receive_swap_operations() {
 
     assert_eq!(res, ContractError::SwapLimitExceeded {});
 }
+
+#[test]
+fn execute_split_swap_operations() {
+    let mut deps = mock_dependencies(&[Coin {
+        amount: Uint128::new(1000u128),
+        denom: "uusd".to_string(),
+    }]);
+    let msg = InstantiateMsg {
+        astroport_factory: String::from("astroportfactory"),
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+
+    // We can just call .unwrap() to assert this was a success
+    let _res = instantiate(deps.as_mut(), env, info, msg).unwrap();
+
+    deps.querier.with_astroport_pairs(&[
+        (&"uusduluna".to_string(), &String::from("pair0000")),
+        (&"uusdasset0000".to_string(), &String::from("pair0001")),
+    ]);
+
+    let direct_route = SwapRoute {
+        operations: vec![SwapOperation::AstroSwap {
+            offer_asset_info: native_asset_info("uusd".to_string()),
+            ask_asset_info: native_asset_info("uluna".to_string()),
+        }],
+        weight: 1,
+    };
+    let multi_hop_route = SwapRoute {
+        operations: vec![
+            SwapOperation::AstroSwap {
+                offer_asset_info: native_asset_info("uusd".to_string()),
+                ask_asset_info: AssetInfo::Token {
+                    contract_addr: Addr::unchecked("asset0000"),
+                },
+            },
+            SwapOperation::AstroSwap {
+                offer_asset_info: AssetInfo::Token {
+                    contract_addr: Addr::unchecked("asset0000"),
+                },
+                ask_asset_info: native_asset_info("uluna".to_string()),
+            },
+        ],
+        weight: 3,
+    };
+
+    // Routes must end in the same ask asset
+    let msg = ExecuteMsg::ExecuteSplitSwapOperations {
+        routes: vec![
+            direct_route.clone(),
+            SwapRoute {
+                operations: vec![multi_hop_route.operations[0].clone()],
+                weight: 1,
+            },
+        ],
+        minimum_receive: None,
+        to: None,
+        max_spread: None,
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RouteAssetsMismatch {
+            offer_asset: "uusd".to_string(),
+            ask_asset: "uluna".to_string(),
+        }
+    );
+
+    let msg = ExecuteMsg::ExecuteSplitSwapOperations {
+        routes: vec![SwapRoute {
+            weight: 0,
+            ..direct_route.clone()
+        }],
+        minimum_receive: None,
+        to: None,
+        max_spread: None,
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap_err();
+    assert_eq!(err, ContractError::ZeroRouteWeight {});
+
+    let msg = ExecuteMsg::ExecuteSplitSwapOperations {
+        routes: vec![direct_route, multi_hop_route],
+        minimum_receive: Some(Uint128::new(900u128)),
+        to: None,
+        max_spread: None,
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg {
+                msg: WasmMsg::Execute {
+                    contract_addr: String::from("pair0000"),
+                    funds: vec![Coin {
+                        denom: "uusd".to_string(),
+                        amount: Uint128::new(250u128),
+                    }],
+                    msg: to_binary(&astroport::pair::ExecuteMsg::Swap {
+                        offer_asset: Asset {
+                            info: native_asset_info("uusd".to_string()),
+                            amount: Uint128::new(250u128),
+                        },
+                        ask_asset_info: Some(native_asset_info("uluna".to_string())),
+                        belief_price: None,
+                        max_spread: None,
+                        to: Some(String::from("addr0000")),
+                    })
+                    .unwrap(),
+                }
+                .into(),
+                id: 0,
+                gas_limit: None,
+                reply_on: ReplyOn::Never,
+            },
+            SubMsg {
+                msg: WasmMsg::Execute {
+                    contract_addr: String::from("pair0001"),
+                    funds: vec![Coin {
+                        denom: "uusd".to_string(),
+                        amount: Uint128::new(750u128),
+                    }],
+                    msg: to_binary(&astroport::pair::ExecuteMsg::Swap {
+                        offer_asset: Asset {
+                            info: native_asset_info("uusd".to_string()),
+                            amount: Uint128::new(750u128),
+                        },
+                        ask_asset_info: Some(AssetInfo::Token {
+                            contract_addr: Addr::unchecked("asset0000"),
+                        }),
+                        belief_price: Some(Decimal::MAX),
+                        max_spread: None,
+                        to: None,
+                    })
+                    .unwrap(),
+                }
+                .into(),
+                id: 0,
+                gas_limit: None,
+                reply_on: ReplyOn::Never,
+            },
+            SubMsg {
+                msg: WasmMsg::Execute {
+                    contract_addr: String::from(MOCK_CONTRACT_ADDR),
+                    funds: vec![],
+                    msg: to_binary(&ExecuteMsg::ExecuteSwapOperation {
+                        operation: SwapOperation::AstroSwap {
+                            offer_asset_info: AssetInfo::Token {
+                                contract_addr: Addr::unchecked("asset0000"),
+                            },
+                            ask_asset_info: native_asset_info("uluna".to_string()),
+                        },
+                        to: Some(String::from("addr0000")),
+                        max_spread: None,
+                        single: false
+                    })
+                    .unwrap(),
+                }
+                .into(),
+                id: AFTER_SWAP_REPLY_ID,
+                gas_limit: None,
+                reply_on: ReplyOn::Success,
+            },
+        ]
+    );
+}
+
+#[test]
+fn query_split_swap_operations() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        astroport_factory: String::from("astroportfactory"),
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+
+    // We can just call .unwrap() to assert this was a success
+    let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    deps.querier.with_astroport_pairs(&[
+        (&"ukrwuluna".to_string(), &String::from("pair0000")),
+        (&"ukrwasset0000".to_string(), &String::from("pair0001")),
+        (&"asset0000uluna".to_string(), &String::from("pair0002")),
+    ]);
+
+    let msg = QueryMsg::SimulateSplitSwapOperations {
+        offer_amount: Uint128::from(1000001u128),
+        routes: vec![
+            SwapRoute {
+                operations: vec![SwapOperation::AstroSwap {
+                    offer_asset_info: native_asset_info("ukrw".to_string()),
+                    ask_asset_info: native_asset_info("uluna".to_string()),
+                }],
+                weight: 1,
+            },
+            SwapRoute {
+                operations: vec![
+                    SwapOperation::AstroSwap {
+                        offer_asset_info: native_asset_info("ukrw".to_string()),
+                        ask_asset_info: AssetInfo::Token {
+                            contract_addr: Addr::unchecked("asset0000"),
+                        },
+                    },
+                    SwapOperation::AstroSwap {
+                        offer_asset_info: AssetInfo::Token {
+                            contract_addr: Addr::unchecked("asset0000"),
+                        },
+                        ask_asset_info: native_asset_info("uluna".to_string()),
+                    },
+                ],
+                weight: 1,
+            },
+        ],
+    };
+
+    // The mocked pairs return the offer amount, so the whole amount is received back
+    let res: SimulateSwapOperationsResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        SimulateSwapOperationsResponse {
+            amount: Uint128::from(1000001u128)
+        }
+    );
+
+    let msg = QueryMsg::SimulateSplitSwapOperations {
+        offer_amount: Uint128::from(1000000u128),
+        routes: vec![],
+    };
+    let err = query(deps.as_ref(), env, msg).unwrap_err();
+    assert_eq!(err, ContractError::MustProvideRoutes {});
+}
+
+#[test]
+fn reverse_swap_operations() {
+    let mut deps = mock_dependencies(&[Coin {
+        amount: Uint128::new(1000u128),
+        denom: "uusd".to_string(),
+    }]);
+    let msg = InstantiateMsg {
+        astroport_factory: String::from("astroportfactory"),
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+
+    // We can just call .unwrap() to assert this was a success
+    let _res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    deps.querier.with_astroport_pairs(&[
+        (&"uusdasset0000".to_string(), &String::from("pair0000")),
+        (&"asset0000uluna".to_string(), &String::from("pair0001")),
+    ]);
+
+    let operations = vec![
+        SwapOperation::AstroSwap {
+            offer_asset_info: native_asset_info("uusd".to_string()),
+            ask_asset_info: AssetInfo::Token {
+                contract_addr: Addr::unchecked("asset0000"),
+            },
+        },
+        SwapOperation::AstroSwap {
+            offer_asset_info: AssetInfo::Token {
+                contract_addr: Addr::unchecked("asset0000"),
+            },
+            ask_asset_info: native_asset_info("uluna".to_string()),
+        },
+    ];
+
+    let res: SimulateSwapOperationsResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            env,
+            QueryMsg::ReverseSimulateSwapOperations {
+                ask_amount: Uint128::new(600u128),
+                operations: operations.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res,
+        SimulateSwapOperationsResponse {
+            amount: Uint128::new(600u128)
+        }
+    );
+
+    let msg = ExecuteMsg::ExecuteReverseSwapOperations {
+        operations: operations.clone(),
+        ask_amount: Uint128::new(600u128),
+        max_offer: Some(Uint128::new(500u128)),
+        minimum_receive: None,
+        to: None,
+        max_spread: None,
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AssertionMaxOffer {
+            max_offer: Uint128::new(500u128),
+            offer_amount: Uint128::new(600u128),
+        }
+    );
+
+    let msg = ExecuteMsg::ExecuteReverseSwapOperations {
+        operations,
+        ask_amount: Uint128::new(600u128),
+        max_offer: None,
+        minimum_receive: Some(Uint128::new(600u128)),
+        to: Some(String::from("addr0001")),
+        max_spread: None,
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(BankMsg::Send {
+                to_address: String::from("addr0000"),
+                amount: vec![Coin {
+                    denom: "uusd".to_string(),
+                    amount: Uint128::new(400u128),
+                }],
+            }),
+            SubMsg {
+                msg: WasmMsg::Execute {
+                    contract_addr: String::from("pair0000"),
+                    funds: vec![Coin {
+                        denom: "uusd".to_string(),
+                        amount: Uint128::new(600u128),
+                    }],
+                    msg: to_binary(&astroport::pair::ExecuteMsg::Swap {
+                        offer_asset: Asset {
+                            info: native_asset_info("uusd".to_string()),
+                            amount: Uint128::new(600u128),
+                        },
+                        ask_asset_info: Some(AssetInfo::Token {
+                            contract_addr: Addr::unchecked("asset0000"),
+                        }),
+                        belief_price: Some(Decimal::MAX),
+                        max_spread: None,
+                        to: None,
+                    })
+                    .unwrap(),
+                }
+                .into(),
+                id: 0,
+                gas_limit: None,
+                reply_on: ReplyOn::Never,
+            },
+            SubMsg {
+                msg: WasmMsg::Execute {
+                    contract_addr: String::from(MOCK_CONTRACT_ADDR),
+                    funds: vec![],
+                    msg: to_binary(&ExecuteMsg::ExecuteSwapOperation {
+                        operation: SwapOperation::AstroSwap {
+                            offer_asset_info: AssetInfo::Token {
+                                contract_addr: Addr::unchecked("asset0000"),
+                            },
+                            ask_asset_info: native_asset_info("uluna".to_string()),
+                        },
+                        to: Some(String::from("addr0001")),
+                        max_spread: None,
+                        single: false
+                    })
+                    .unwrap(),
+                }
+                .into(),
+                id: AFTER_SWAP_REPLY_ID,
+                gas_limit: None,
+                reply_on: ReplyOn::Success,
+            },
+        ]
+    );
+}
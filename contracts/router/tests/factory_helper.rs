@@ -79,6 +79,7 @@ impl FactoryHelper {
                     maker_fee_bps: 0,
                     is_disabled: false,
                     is_generator_disabled: false,
+                    pair_creation_fee: None,
                 },
                 PairConfig {
                     code_id: pair_code_id,
@@ -87,6 +88,7 @@ impl FactoryHelper {
                     maker_fee_bps: 0,
                     is_disabled: false,
                     is_generator_disabled: false,
+                    pair_creation_fee: None,
                 },
             ],
             token_code_id: cw20_token_code_id,
@@ -880,3 +880,148 @@ fn test_swap_route() {
     let profit = balance_res.balance.saturating_sub(donated_atom);
     println!("Attacker2's profit: {:?}", profit);
 }
+
+#[test]
+fn best_route_swap() {
+    use astroport::router::{BestRouteResponse, Cw20HookMsg, QueryMsg};
+    use cosmwasm_std::Uint128;
+    use cw20::{BalanceResponse, Cw20QueryMsg};
+
+    let mut app = App::default();
+
+    let owner = Addr::unchecked("owner");
+    let mut helper = FactoryHelper::init(&mut app, &owner);
+
+    let token_x = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "TOX", None);
+    let token_y = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "TOY", None);
+    let token_z = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "TOZ", None);
+
+    // The direct pool is shallow, so routing through Y should return more Z
+    for (a, b, liq) in [
+        (&token_x, &token_y, 100_000_000000),
+        (&token_y, &token_z, 100_000_000000),
+        (&token_x, &token_z, 1_000000),
+    ] {
+        let pair = helper
+            .create_pair(
+                &mut app,
+                &owner,
+                PairType::Xyk {},
+                [token_asset_info(a.clone()), token_asset_info(b.clone())],
+                None,
+            )
+            .unwrap();
+        mint(&mut app, &owner, a, liq, &pair).unwrap();
+        mint(&mut app, &owner, b, liq, &pair).unwrap();
+    }
+
+    let router_code = app.store_code(router_contract());
+    let router = app
+        .instantiate_contract(
+            router_code,
+            owner.clone(),
+            &InstantiateMsg {
+                astroport_factory: helper.factory.to_string(),
+            },
+            &[],
+            "router",
+            None,
+        )
+        .unwrap();
+
+    let swap_amount = Uint128::new(1_000_000000);
+
+    let best_route: BestRouteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &router,
+            &QueryMsg::BestRoute {
+                offer_asset_info: token_asset_info(token_x.clone()),
+                ask_asset_info: token_asset_info(token_z.clone()),
+                offer_amount: swap_amount,
+                max_hops: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        best_route.operations,
+        vec![
+            SwapOperation::AstroSwap {
+                offer_asset_info: token_asset_info(token_x.clone()),
+                ask_asset_info: token_asset_info(token_y.clone()),
+            },
+            SwapOperation::AstroSwap {
+                offer_asset_info: token_asset_info(token_y.clone()),
+                ask_asset_info: token_asset_info(token_z.clone()),
+            },
+        ]
+    );
+
+    // Only the direct pool can be used with a single hop
+    let direct_route: BestRouteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &router,
+            &QueryMsg::BestRoute {
+                offer_asset_info: token_asset_info(token_x.clone()),
+                ask_asset_info: token_asset_info(token_z.clone()),
+                offer_amount: swap_amount,
+                max_hops: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(direct_route.operations.len(), 1);
+    assert!(direct_route.amount < best_route.amount);
+
+    let err = app
+        .wrap()
+        .query_wasm_smart::<BestRouteResponse>(
+            &router,
+            &QueryMsg::BestRoute {
+                offer_asset_info: token_asset_info(token_x.clone()),
+                ask_asset_info: native_asset_info("uluna".to_string()),
+                offer_amount: swap_amount,
+                max_hops: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(format!(
+            "Querier contract error: No route found from {token_x} to uluna"
+        ))
+    );
+
+    let user = Addr::unchecked("user");
+    mint(&mut app, &owner, &token_x, swap_amount.u128(), &user).unwrap();
+
+    app.execute_contract(
+        user.clone(),
+        token_x.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: router.to_string(),
+            amount: swap_amount,
+            msg: to_binary(&Cw20HookMsg::ExecuteBestRouteSwap {
+                ask_asset_info: token_asset_info(token_z.clone()),
+                max_hops: None,
+                minimum_receive: Some(best_route.amount),
+                to: None,
+                max_spread: None,
+            })
+            .unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let balance_res: BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &token_z,
+            &Cw20QueryMsg::Balance {
+                address: user.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(balance_res.balance, best_route.amount);
+}
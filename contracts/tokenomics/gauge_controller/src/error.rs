@@ -0,0 +1,55 @@
+use cosmwasm_std::{OverflowError, StdError};
+use thiserror::Error;
+
+use astroport::gauge_controller::{MAX_POOLS_LIMIT, MAX_VOTED_POOLS, MAX_VOTE_BPS};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Duplicated pool {lp_token} in votes")]
+    DuplicatedPool { lp_token: String },
+
+    #[error("Vote for pool {lp_token} must be positive")]
+    ZeroVote { lp_token: String },
+
+    #[error("Votes exceed {MAX_VOTE_BPS} bps")]
+    VotesExceedMaxBps {},
+
+    #[error("Too many pools in votes. Maximum allowed is {MAX_VOTED_POOLS}")]
+    TooManyPools {},
+
+    #[error("Pool {lp_token} is killed")]
+    KilledPool { lp_token: String },
+
+    #[error("Pool {lp_token} is not killed")]
+    PoolNotKilled { lp_token: String },
+
+    #[error("List of pools can't be empty")]
+    EmptyPoolsList {},
+
+    #[error("User {user} has no voting power")]
+    ZeroVotingPower { user: String },
+
+    #[error("Pools have already been tuned in the epoch started at {epoch_start}")]
+    TuneCooldown { epoch_start: u64 },
+
+    #[error("There are no votes to tune pools with")]
+    NoVotes {},
+
+    #[error("Vote decay must be more than 0 and less than or equal to 1")]
+    IncorrectVoteDecay {},
+
+    #[error("Max user share must be more than 0 and less than or equal to 1")]
+    IncorrectMaxUserShare {},
+
+    #[error("Pools limit must be more than 0 and less than or equal to {MAX_POOLS_LIMIT}")]
+    IncorrectPoolsLimit {},
+}
@@ -0,0 +1,351 @@
+use std::collections::HashSet;
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    attr, ensure, wasm_execute, Decimal, DepsMut, Env, MessageInfo, Response, StdError,
+};
+
+use astroport::asset::determine_asset_info;
+use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
+use astroport::gauge_controller::{ExecuteMsg, TuneInfo, UserInfo, MAX_VOTED_POOLS, MAX_VOTE_BPS};
+use astroport::incentives;
+
+use crate::error::ContractError;
+use crate::state::{
+    update_pool_weight, CONFIG, KILLED_POOLS, OWNERSHIP_PROPOSAL, TUNE_INFO, USER_INFO,
+};
+use crate::utils::{
+    apply_decay, check_pool_registered, epoch_snapshot, query_voting_power, select_tuned_pools,
+    validate_params,
+};
+
+/// Exposes all the execute functions available in the contract.
+///
+/// ## Variants
+/// * **ExecuteMsg::Vote { votes }** Distribute user voting power across pools.
+///
+/// * **ExecuteMsg::Tune {}** Push current pool weights to the incentives contract.
+///
+/// * **ExecuteMsg::KillPools { lp_tokens }** Remove pools from voting.
+///
+/// * **ExecuteMsg::RevivePools { lp_tokens }** Return killed pools to voting.
+///
+/// * **ExecuteMsg::UpdateConfig { .. }** Update contract parameters.
+///
+/// * **ExecuteMsg::ProposeNewOwner { owner, expires_in }** Creates a new request to change
+/// contract ownership.
+///
+/// * **ExecuteMsg::DropOwnershipProposal {}** Removes a request to change contract ownership.
+///
+/// * **ExecuteMsg::ClaimOwnership {}** Claims contract ownership.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Vote { votes } => vote(deps, env, info, votes),
+        ExecuteMsg::Tune {} => tune(deps, env),
+        ExecuteMsg::KillPools { lp_tokens } => kill_pools(deps, info, lp_tokens),
+        ExecuteMsg::RevivePools { lp_tokens } => revive_pools(deps, info, lp_tokens),
+        ExecuteMsg::UpdateConfig {
+            vote_decay,
+            max_user_share,
+            pools_limit,
+        } => update_config(deps, info, vote_decay, max_user_share, pools_limit),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+            let config = CONFIG.load(deps.storage)?;
+
+            propose_new_owner(
+                deps,
+                info,
+                env,
+                owner,
+                expires_in,
+                config.owner,
+                OWNERSHIP_PROPOSAL,
+            )
+            .map_err(Into::into)
+        }
+        ExecuteMsg::DropOwnershipProposal {} => {
+            let config = CONFIG.load(deps.storage)?;
+
+            drop_ownership_proposal(deps, info, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(Into::into)
+        }
+        ExecuteMsg::ClaimOwnership {} => {
+            claim_ownership(deps, info, env, OWNERSHIP_PROPOSAL, |deps, new_owner| {
+                CONFIG
+                    .update::<_, StdError>(deps.storage, |mut v| {
+                        v.owner = new_owner;
+                        Ok(v)
+                    })
+                    .map(|_| ())
+            })
+            .map_err(Into::into)
+        }
+    }
+}
+
+/// Replaces user votes with the new ones.
+/// Previous votes are subtracted from pools with the decay accrued since they were cast.
+///
+/// * **votes** array of (LP token, basis points) tuples.
+fn vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    votes: Vec<(String, u16)>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    ensure!(
+        votes.len() <= MAX_VOTED_POOLS,
+        ContractError::TooManyPools {}
+    );
+
+    let mut unique_pools = HashSet::new();
+    let mut total_bps: u16 = 0;
+    for (lp_token, bps) in &votes {
+        ensure!(
+            unique_pools.insert(lp_token),
+            ContractError::DuplicatedPool {
+                lp_token: lp_token.clone()
+            }
+        );
+        ensure!(
+            *bps > 0,
+            ContractError::ZeroVote {
+                lp_token: lp_token.clone()
+            }
+        );
+        ensure!(
+            !KILLED_POOLS.has(deps.storage, lp_token),
+            ContractError::KilledPool {
+                lp_token: lp_token.clone()
+            }
+        );
+        check_pool_registered(deps.as_ref(), &config, lp_token)?;
+
+        total_bps = total_bps.saturating_add(*bps);
+    }
+    ensure!(
+        total_bps <= MAX_VOTE_BPS,
+        ContractError::VotesExceedMaxBps {}
+    );
+
+    let (epoch_ts, snapshot_block) = epoch_snapshot(deps.storage, &env)?;
+    let voting_power = query_voting_power(deps.querier, &config, &info.sender, snapshot_block)?;
+    ensure!(
+        votes.is_empty() || !voting_power.is_zero(),
+        ContractError::ZeroVotingPower {
+            user: info.sender.to_string()
+        }
+    );
+
+    // Withdraw previous votes
+    if let Some(user_info) = USER_INFO.may_load(deps.storage, &info.sender)? {
+        for (lp_token, bps) in &user_info.votes {
+            let contribution = apply_decay(
+                user_info.voting_power.multiply_ratio(*bps, MAX_VOTE_BPS),
+                config.vote_decay,
+                user_info.vote_ts,
+                epoch_ts,
+            )?;
+
+            // Rounding may leave the decayed pool weight slightly below the sum of decayed votes
+            update_pool_weight(
+                deps.storage,
+                config.vote_decay,
+                epoch_ts,
+                lp_token,
+                |weight| Ok(weight.saturating_sub(contribution)),
+            )?;
+        }
+    }
+
+    // Apply new votes
+    for (lp_token, bps) in &votes {
+        let contribution = voting_power.multiply_ratio(*bps, MAX_VOTE_BPS);
+        update_pool_weight(
+            deps.storage,
+            config.vote_decay,
+            epoch_ts,
+            lp_token,
+            |weight| Ok(weight.checked_add(contribution)?),
+        )?;
+    }
+
+    if votes.is_empty() {
+        USER_INFO.remove(deps.storage, &info.sender);
+    } else {
+        USER_INFO.save(
+            deps.storage,
+            &info.sender,
+            &UserInfo {
+                vote_ts: epoch_ts,
+                voting_power,
+                votes,
+            },
+        )?;
+    }
+
+    Ok(Response::new().add_attributes([
+        attr("action", "vote"),
+        attr("user", info.sender),
+        attr("voting_power", voting_power),
+    ]))
+}
+
+/// Sends the heaviest pools to the incentives contract as alloc points.
+/// Killed pools and pools which are no longer registered in the factory are skipped.
+/// Anyone can call this once per epoch.
+fn tune(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let (epoch_ts, _) = epoch_snapshot(deps.storage, &env)?;
+
+    if let Some(tune_info) = TUNE_INFO.may_load(deps.storage)? {
+        ensure!(
+            tune_info.tune_ts < epoch_ts,
+            ContractError::TuneCooldown {
+                epoch_start: epoch_ts
+            }
+        );
+    }
+
+    let pool_alloc_points = select_tuned_pools(deps.as_ref(), &config, epoch_ts)?;
+
+    ensure!(!pool_alloc_points.is_empty(), ContractError::NoVotes {});
+
+    TUNE_INFO.save(
+        deps.storage,
+        &TuneInfo {
+            tune_ts: epoch_ts,
+            pool_alloc_points: pool_alloc_points.clone(),
+        },
+    )?;
+
+    let setup_pools_msg = wasm_execute(
+        &config.incentives,
+        &incentives::ExecuteMsg::SetupPools {
+            pools: pool_alloc_points.clone(),
+        },
+        vec![],
+    )?;
+
+    Ok(Response::new()
+        .add_message(setup_pools_msg)
+        .add_attributes([
+            attr("action", "tune"),
+            attr("epoch_start", epoch_ts.to_string()),
+            attr("pools", pool_alloc_points.len().to_string()),
+        ]))
+}
+
+/// Removes pools from voting. Votes for killed pools are ignored during tuning,
+/// so killed pools are dropped from incentives on the next tuning.
+///
+/// * **lp_tokens** list of LP tokens (either cw20 or native) to kill.
+///
+/// ## Executor
+/// Only the owner can execute this.
+fn kill_pools(
+    deps: DepsMut,
+    info: MessageInfo,
+    lp_tokens: Vec<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    ensure!(info.sender == config.owner, ContractError::Unauthorized {});
+    ensure!(!lp_tokens.is_empty(), ContractError::EmptyPoolsList {});
+
+    for lp_token in &lp_tokens {
+        determine_asset_info(lp_token, deps.api)?;
+        ensure!(
+            !KILLED_POOLS.has(deps.storage, lp_token),
+            ContractError::KilledPool {
+                lp_token: lp_token.clone()
+            }
+        );
+        KILLED_POOLS.save(deps.storage, lp_token, &())?;
+    }
+
+    Ok(Response::new().add_attributes([
+        attr("action", "kill_pools"),
+        attr("lp_tokens", lp_tokens.join(",")),
+    ]))
+}
+
+/// Returns killed pools to voting.
+///
+/// * **lp_tokens** list of killed LP tokens to revive.
+///
+/// ## Executor
+/// Only the owner can execute this.
+fn revive_pools(
+    deps: DepsMut,
+    info: MessageInfo,
+    lp_tokens: Vec<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    ensure!(info.sender == config.owner, ContractError::Unauthorized {});
+    ensure!(!lp_tokens.is_empty(), ContractError::EmptyPoolsList {});
+
+    for lp_token in &lp_tokens {
+        ensure!(
+            KILLED_POOLS.has(deps.storage, lp_token),
+            ContractError::PoolNotKilled {
+                lp_token: lp_token.clone()
+            }
+        );
+        KILLED_POOLS.remove(deps.storage, lp_token);
+    }
+
+    Ok(Response::new().add_attributes([
+        attr("action", "revive_pools"),
+        attr("lp_tokens", lp_tokens.join(",")),
+    ]))
+}
+
+/// Updates contract parameters.
+///
+/// ## Executor
+/// Only the owner can execute this.
+fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    vote_decay: Option<Decimal>,
+    max_user_share: Option<Decimal>,
+    pools_limit: Option<u8>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // Permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut attrs = vec![attr("action", "update_config")];
+
+    if let Some(vote_decay) = vote_decay {
+        config.vote_decay = vote_decay;
+        attrs.push(attr("new_vote_decay", vote_decay.to_string()));
+    }
+
+    if let Some(max_user_share) = max_user_share {
+        config.max_user_share = max_user_share;
+        attrs.push(attr("new_max_user_share", max_user_share.to_string()));
+    }
+
+    if let Some(pools_limit) = pools_limit {
+        config.pools_limit = pools_limit;
+        attrs.push(attr("new_pools_limit", pools_limit.to_string()));
+    }
+
+    validate_params(&config)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
@@ -0,0 +1,39 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use astroport::gauge_controller::{Config, InstantiateMsg};
+
+use crate::error::ContractError;
+use crate::state::CONFIG;
+use crate::utils::validate_params;
+
+/// Contract name that is used for migration.
+pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+/// Contract version that is used for migration.
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        owner: deps.api.addr_validate(&msg.owner)?,
+        incentives: deps.api.addr_validate(&msg.incentives)?,
+        factory: deps.api.addr_validate(&msg.factory)?,
+        staking: deps.api.addr_validate(&msg.staking)?,
+        vote_decay: msg.vote_decay,
+        max_user_share: msg.max_user_share,
+        pools_limit: msg.pools_limit,
+    };
+    validate_params(&config)?;
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new())
+}
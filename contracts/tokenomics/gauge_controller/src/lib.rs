@@ -0,0 +1,6 @@
+pub mod error;
+pub mod execute;
+pub mod instantiate;
+pub mod query;
+pub mod state;
+pub mod utils;
@@ -0,0 +1,91 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_binary, Binary, Deps, Env, Order, StdResult, Uint128};
+
+use astroport::gauge_controller::{epoch_start, QueryMsg, TuneInfo, UserInfoResponse};
+
+use crate::error::ContractError;
+use crate::state::{CONFIG, EPOCH_SNAPSHOTS, KILLED_POOLS, POOL_VOTES, TUNE_INFO, USER_INFO};
+use crate::utils::{apply_decay, query_voting_power};
+
+/// Exposes all the queries available in the contract.
+///
+/// ## Queries
+/// * **QueryMsg::Config {}** Returns the contract configuration.
+///
+/// * **QueryMsg::UserInfo { user }** Returns the latest user votes.
+///
+/// * **QueryMsg::VotingPower { user }** Returns the user voting power in the current epoch.
+///
+/// * **QueryMsg::PoolWeight { lp_token }** Returns the pool weight decayed to the current epoch.
+///
+/// * **QueryMsg::TuneInfo {}** Returns the result of the latest tuning.
+///
+/// * **QueryMsg::KilledPools {}** Returns the list of killed pools.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let epoch_ts = epoch_start(env.block.time.seconds());
+
+    match msg {
+        QueryMsg::Config {} => Ok(to_binary(&CONFIG.load(deps.storage)?)?),
+        QueryMsg::UserInfo { user } => {
+            let config = CONFIG.load(deps.storage)?;
+            let user_addr = deps.api.addr_validate(&user)?;
+            let user_info = USER_INFO.load(deps.storage, &user_addr)?;
+
+            Ok(to_binary(&UserInfoResponse {
+                vote_ts: user_info.vote_ts,
+                voting_power: user_info.voting_power,
+                applied_voting_power: apply_decay(
+                    user_info.voting_power,
+                    config.vote_decay,
+                    user_info.vote_ts,
+                    epoch_ts,
+                )?,
+                votes: user_info.votes,
+            })?)
+        }
+        QueryMsg::VotingPower { user } => {
+            let config = CONFIG.load(deps.storage)?;
+            let user_addr = deps.api.addr_validate(&user)?;
+            // If nobody has voted in this epoch yet the snapshot would be taken at the current block
+            let block = EPOCH_SNAPSHOTS
+                .may_load(deps.storage, epoch_ts)?
+                .unwrap_or(env.block.height);
+
+            Ok(to_binary(&query_voting_power(
+                deps.querier,
+                &config,
+                user_addr,
+                block,
+            )?)?)
+        }
+        QueryMsg::PoolWeight { lp_token } => {
+            let config = CONFIG.load(deps.storage)?;
+            // Votes for killed pools are ignored
+            let weight = match POOL_VOTES.may_load(deps.storage, &lp_token)? {
+                Some(pool_votes) if !KILLED_POOLS.has(deps.storage, &lp_token) => {
+                    pool_votes.weight_at(config.vote_decay, epoch_ts)?
+                }
+                _ => Uint128::zero(),
+            };
+
+            Ok(to_binary(&weight)?)
+        }
+        QueryMsg::TuneInfo {} => {
+            let tune_info = TUNE_INFO.may_load(deps.storage)?.unwrap_or(TuneInfo {
+                tune_ts: 0,
+                pool_alloc_points: vec![],
+            });
+
+            Ok(to_binary(&tune_info)?)
+        }
+        QueryMsg::KilledPools {} => {
+            let killed_pools = KILLED_POOLS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+
+            Ok(to_binary(&killed_pools)?)
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+
+use astroport::common::OwnershipProposal;
+use astroport::gauge_controller::{Config, TuneInfo, UserInfo};
+
+use crate::utils::apply_decay;
+
+/// General gauge controller contract settings
+pub const CONFIG: Item<Config> = Item::new("config");
+/// Contains a proposal to change contract ownership.
+pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
+/// Result of the latest tuning
+pub const TUNE_INFO: Item<TuneInfo> = Item::new("tune_info");
+/// key: user address, value: latest user votes
+pub const USER_INFO: Map<&Addr, UserInfo> = Map::new("user_info");
+/// key: lp_token (either cw20 or native), value: accumulated pool votes
+pub const POOL_VOTES: Map<&str, PoolVotes> = Map::new("pool_votes");
+/// Pools ordered by weight within the epoch they were last updated in.
+/// key: (epoch start timestamp, pool weight at the start of this epoch, lp_token)
+pub const POOL_WEIGHTS: Map<(u64, u128, &str), ()> = Map::new("pool_weights");
+/// key: epoch start timestamp, value: number of pools last updated in this epoch
+pub const WEIGHT_EPOCHS: Map<u64, u32> = Map::new("weight_epochs");
+/// Pools which can't be voted for. key: lp_token (either cw20 or native)
+pub const KILLED_POOLS: Map<&str, ()> = Map::new("killed_pools");
+/// key: epoch start timestamp, value: block height used to snapshot voting power in this epoch
+pub const EPOCH_SNAPSHOTS: Map<u64, u64> = Map::new("epoch_snapshots");
+
+/// Accumulated votes for a pool. Weight decays lazily every time it is read in a newer epoch.
+#[cw_serde]
+#[derive(Default)]
+pub struct PoolVotes {
+    /// Pool weight at the start of the epoch below
+    pub weight: Uint128,
+    /// Start of the epoch when the weight was last updated
+    pub epoch_ts: u64,
+}
+
+impl PoolVotes {
+    /// Returns the pool weight decayed to the specified epoch.
+    pub fn weight_at(&self, vote_decay: Decimal, epoch_ts: u64) -> StdResult<Uint128> {
+        apply_decay(self.weight, vote_decay, self.epoch_ts, epoch_ts)
+    }
+}
+
+/// Decays the pool weight to the specified epoch, updates it and keeps the weight ordering in sync.
+/// Pools whose weight drops to zero are pruned.
+pub fn update_pool_weight<F>(
+    storage: &mut dyn Storage,
+    vote_decay: Decimal,
+    epoch_ts: u64,
+    lp_token: &str,
+    action: F,
+) -> StdResult<Uint128>
+where
+    F: FnOnce(Uint128) -> StdResult<Uint128>,
+{
+    let weight = match POOL_VOTES.may_load(storage, lp_token)? {
+        Some(pool_votes) => {
+            remove_weight(storage, pool_votes.epoch_ts, pool_votes.weight, lp_token)?;
+            pool_votes.weight_at(vote_decay, epoch_ts)?
+        }
+        None => Uint128::zero(),
+    };
+    let new_weight = action(weight)?;
+
+    if new_weight.is_zero() {
+        POOL_VOTES.remove(storage, lp_token);
+    } else {
+        POOL_VOTES.save(
+            storage,
+            lp_token,
+            &PoolVotes {
+                weight: new_weight,
+                epoch_ts,
+            },
+        )?;
+        POOL_WEIGHTS.save(storage, (epoch_ts, new_weight.u128(), lp_token), &())?;
+        WEIGHT_EPOCHS
+            .update::<_, StdError>(storage, epoch_ts, |pools| Ok(pools.unwrap_or_default() + 1))?;
+    }
+
+    Ok(new_weight)
+}
+
+/// Removes the pool from the weight ordering of the epoch it was last updated in.
+fn remove_weight(
+    storage: &mut dyn Storage,
+    epoch_ts: u64,
+    weight: Uint128,
+    lp_token: &str,
+) -> StdResult<()> {
+    POOL_WEIGHTS.remove(storage, (epoch_ts, weight.u128(), lp_token));
+
+    match WEIGHT_EPOCHS.may_load(storage, epoch_ts)? {
+        Some(pools) if pools > 1 => WEIGHT_EPOCHS.save(storage, epoch_ts, &(pools - 1)),
+        _ => {
+            WEIGHT_EPOCHS.remove(storage, epoch_ts);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use cosmwasm_std::{
+    ensure, Decimal, Deps, Env, Order, QuerierWrapper, StdError, StdResult, Storage, Uint128,
+};
+use itertools::Itertools;
+
+use astroport::asset::{determine_asset_info, pair_info_by_pool, AssetInfo, PairInfo};
+use astroport::gauge_controller::{epoch_start, Config, MAX_POOLS_LIMIT};
+use astroport::incentives::EPOCH_LENGTH;
+use astroport::{factory, pair, staking};
+
+use crate::error::ContractError;
+use crate::state::{EPOCH_SNAPSHOTS, KILLED_POOLS, POOL_WEIGHTS, WEIGHT_EPOCHS};
+
+/// Decays the given amount by `vote_decay` for every epoch passed between `from_ts` and `to_ts`.
+pub fn apply_decay(
+    amount: Uint128,
+    vote_decay: Decimal,
+    from_ts: u64,
+    to_ts: u64,
+) -> StdResult<Uint128> {
+    let epochs_passed = to_ts.saturating_sub(from_ts) / EPOCH_LENGTH;
+    if epochs_passed == 0 || amount.is_zero() {
+        return Ok(amount);
+    }
+
+    let epochs_passed = u32::try_from(epochs_passed)
+        .map_err(|_| StdError::generic_err("Too many epochs passed"))?;
+    let factor = (Decimal::one() - vote_decay).pow(epochs_passed);
+
+    Ok(amount * factor)
+}
+
+/// Returns the current epoch start and the block height voting power is snapshotted at.
+/// The first call within an epoch fixes the snapshot to the current block.
+pub fn epoch_snapshot(storage: &mut dyn Storage, env: &Env) -> StdResult<(u64, u64)> {
+    let epoch_ts = epoch_start(env.block.time.seconds());
+    let block = match EPOCH_SNAPSHOTS.may_load(storage, epoch_ts)? {
+        Some(block) => block,
+        None => {
+            EPOCH_SNAPSHOTS.save(storage, epoch_ts, &env.block.height)?;
+            env.block.height
+        }
+    };
+
+    Ok((epoch_ts, block))
+}
+
+/// Returns the user staking voting power at the given block capped by the max share of total voting power.
+/// Staking voting power is the user xASTRO balance plus the boosted weight of the user lock positions.
+pub fn query_voting_power(
+    querier: QuerierWrapper,
+    config: &Config,
+    user: impl Into<String>,
+    block: u64,
+) -> StdResult<Uint128> {
+    let voting_power: Uint128 = querier.query_wasm_smart(
+        &config.staking,
+        &staking::QueryMsg::VotingPowerAt {
+            user: user.into(),
+            block,
+        },
+    )?;
+    let total_voting_power: Uint128 = querier.query_wasm_smart(
+        &config.staking,
+        &staking::QueryMsg::TotalVotingPowerAt { block },
+    )?;
+
+    Ok(voting_power.min(total_voting_power * config.max_user_share))
+}
+
+/// Checks that the LP token belongs to a pair registered in the factory contract.
+pub fn check_pool_registered(deps: Deps, config: &Config, lp_token: &str) -> StdResult<()> {
+    let pair_info: PairInfo = match determine_asset_info(lp_token, deps.api)? {
+        AssetInfo::Token { contract_addr } => pair_info_by_pool(&deps.querier, contract_addr)?,
+        AssetInfo::NativeToken { denom } => {
+            let parts = denom.split('/').collect_vec();
+            if denom.starts_with("factory") && parts.len() >= 3 {
+                let lp_minter = parts[1];
+                deps.api.addr_validate(lp_minter)?;
+                deps.querier
+                    .query_wasm_smart(lp_minter, &pair::QueryMsg::Pair {})?
+            } else {
+                return Err(StdError::generic_err(format!(
+                    "LP token {denom} doesn't follow token factory format: factory/{{lp_minter}}/{{token_name}}",
+                )));
+            }
+        }
+    };
+
+    let registered = deps
+        .querier
+        .query_wasm_smart::<PairInfo>(
+            &config.factory,
+            &factory::QueryMsg::Pair {
+                asset_infos: pair_info.asset_infos.to_vec(),
+            },
+        )
+        .map_err(|_| {
+            StdError::generic_err(format!(
+                "The pair is not registered: {}-{}",
+                pair_info.asset_infos[0], pair_info.asset_infos[1]
+            ))
+        })?;
+
+    // Eventually liquidity_token will become just a String once token factory LP tokens are implemented
+    if registered.liquidity_token.as_str() == lp_token {
+        Ok(())
+    } else {
+        Err(StdError::generic_err(format!(
+            "LP token {lp_token} doesn't match LP token registered in factory {}",
+            registered.liquidity_token
+        )))
+    }
+}
+
+/// Returns the heaviest pools by weight decayed to the specified epoch within the pools limit.
+/// Killed pools and pools which are no longer registered in the factory are skipped.
+/// Pools with equal weights are ordered by LP token.
+///
+/// Pools are indexed by weight within the epoch they were last updated in. All pools of an epoch
+/// decay by the same factor, so only the heaviest pools of every epoch need to be read.
+pub fn select_tuned_pools(
+    deps: Deps,
+    config: &Config,
+    epoch_ts: u64,
+) -> StdResult<Vec<(String, Uint128)>> {
+    let pools_limit = config.pools_limit as usize;
+    let mut pools = vec![];
+
+    for weight_epoch in WEIGHT_EPOCHS.keys(deps.storage, None, None, Order::Ascending) {
+        let weight_epoch = weight_epoch?;
+        let mut epoch_pools = 0;
+
+        for item in
+            POOL_WEIGHTS
+                .sub_prefix(weight_epoch)
+                .keys(deps.storage, None, None, Order::Descending)
+        {
+            if epoch_pools >= pools_limit {
+                break;
+            }
+
+            let (weight, lp_token) = item?;
+            let weight = apply_decay(
+                Uint128::new(weight),
+                config.vote_decay,
+                weight_epoch,
+                epoch_ts,
+            )?;
+            // Lighter pools of this epoch have decayed to zero as well
+            if weight.is_zero() {
+                break;
+            }
+
+            if KILLED_POOLS.has(deps.storage, &lp_token)
+                || check_pool_registered(deps, config, &lp_token).is_err()
+            {
+                continue;
+            }
+
+            pools.push((lp_token, weight));
+            epoch_pools += 1;
+        }
+    }
+
+    // Heaviest pools go first. Pools with equal weights are ordered by LP token to make tuning deterministic
+    pools.sort_by(|(a_lp, a_weight), (b_lp, b_weight)| {
+        b_weight.cmp(a_weight).then_with(|| a_lp.cmp(b_lp))
+    });
+    pools.truncate(pools_limit);
+
+    Ok(pools)
+}
+
+/// Validates tunable contract parameters.
+pub fn validate_params(config: &Config) -> Result<(), ContractError> {
+    ensure!(
+        !config.vote_decay.is_zero() && config.vote_decay <= Decimal::one(),
+        ContractError::IncorrectVoteDecay {}
+    );
+    ensure!(
+        !config.max_user_share.is_zero() && config.max_user_share <= Decimal::one(),
+        ContractError::IncorrectMaxUserShare {}
+    );
+    ensure!(
+        config.pools_limit > 0 && config.pools_limit <= MAX_POOLS_LIMIT,
+        ContractError::IncorrectPoolsLimit {}
+    );
+
+    Ok(())
+}
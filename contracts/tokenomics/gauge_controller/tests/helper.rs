@@ -0,0 +1,519 @@
+#![allow(dead_code)]
+#![cfg(not(tarpaulin_include))]
+
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{to_binary, Addr, Decimal, Empty, Timestamp, Uint128};
+use cw20::{Cw20ExecuteMsg, MinterResponse};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+
+use astroport::asset::{token_asset_info, AssetInfo, PairInfo};
+use astroport::factory::{PairConfig, PairType};
+use astroport::gauge_controller::{
+    Config, ExecuteMsg, InstantiateMsg, QueryMsg, TuneInfo, UserInfoResponse,
+};
+use astroport::incentives::{EPOCHS_START, EPOCH_LENGTH};
+use astroport::staking::LockTier;
+use astroport::{factory, incentives, native_coin_registry, staking};
+
+fn factory_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            astroport_factory::contract::execute,
+            astroport_factory::contract::instantiate,
+            astroport_factory::contract::query,
+        )
+        .with_reply_empty(astroport_factory::contract::reply),
+    )
+}
+
+fn pair_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            astroport_pair::contract::execute,
+            astroport_pair::contract::instantiate,
+            astroport_pair::contract::query,
+        )
+        .with_reply_empty(astroport_pair::contract::reply),
+    )
+}
+
+fn coin_registry_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        astroport_native_coin_registry::contract::execute,
+        astroport_native_coin_registry::contract::instantiate,
+        astroport_native_coin_registry::contract::query,
+    ))
+}
+
+fn token_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    ))
+}
+
+fn xastro_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        astroport_xastro_token::contract::execute,
+        astroport_xastro_token::contract::instantiate,
+        astroport_xastro_token::contract::query,
+    ))
+}
+
+fn staking_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            astroport_staking::contract::execute,
+            astroport_staking::contract::instantiate,
+            astroport_staking::contract::query,
+        )
+        .with_reply_empty(astroport_staking::contract::reply),
+    )
+}
+
+fn incentives_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            astroport_incentives::execute::execute,
+            astroport_incentives::instantiate::instantiate,
+            astroport_incentives::query::query,
+        )
+        .with_reply_empty(astroport_incentives::reply::reply),
+    )
+}
+
+fn gauge_controller_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        astroport_gauge_controller::execute::execute,
+        astroport_gauge_controller::instantiate::instantiate,
+        astroport_gauge_controller::query::query,
+    ))
+}
+
+pub struct Helper {
+    pub app: App,
+    pub owner: Addr,
+    pub factory: Addr,
+    pub incentives: Addr,
+    pub astro: Addr,
+    pub staking: Addr,
+    pub gauge_controller: Addr,
+    pub token_code_id: u64,
+}
+
+impl Helper {
+    pub fn new(
+        owner: &str,
+        staked_balances: &[(&str, u128)],
+        vote_decay: Decimal,
+        max_user_share: Decimal,
+        pools_limit: u8,
+    ) -> AnyResult<Self> {
+        let mut app = App::default();
+        app.update_block(|block| block.time = Timestamp::from_seconds(EPOCHS_START));
+        let owner = Addr::unchecked(owner);
+
+        let coin_registry_code = app.store_code(coin_registry_contract());
+        let coin_registry = app.instantiate_contract(
+            coin_registry_code,
+            owner.clone(),
+            &native_coin_registry::InstantiateMsg {
+                owner: owner.to_string(),
+            },
+            &[],
+            "Astroport Coin Registry",
+            None,
+        )?;
+
+        let token_code_id = app.store_code(token_contract());
+        let pair_code = app.store_code(pair_contract());
+        let factory_code = app.store_code(factory_contract());
+        let factory = app.instantiate_contract(
+            factory_code,
+            owner.clone(),
+            &factory::InstantiateMsg {
+                pair_configs: vec![PairConfig {
+                    code_id: pair_code,
+                    pair_type: PairType::Xyk {},
+                    total_fee_bps: 0,
+                    maker_fee_bps: 0,
+                    is_disabled: false,
+                    is_generator_disabled: false,
+                    pair_creation_fee: None,
+                }],
+                token_code_id,
+                fee_address: None,
+                generator_address: None,
+                owner: owner.to_string(),
+                whitelist_code_id: 0,
+                coin_registry_address: coin_registry.to_string(),
+            },
+            &[],
+            "Astroport Factory",
+            None,
+        )?;
+
+        let incentives_code = app.store_code(incentives_contract());
+        let incentives = app.instantiate_contract(
+            incentives_code,
+            owner.clone(),
+            &incentives::InstantiateMsg {
+                owner: owner.to_string(),
+                factory: factory.to_string(),
+                astro_token: AssetInfo::native("astro"),
+                vesting_contract: "vesting".to_string(),
+                incentivization_fee_info: None,
+                guardian: None,
+            },
+            &[],
+            "Astroport Incentives",
+            None,
+        )?;
+
+        let astro = app.instantiate_contract(
+            token_code_id,
+            owner.clone(),
+            &cw20_base::msg::InstantiateMsg {
+                name: "Astroport".to_string(),
+                symbol: "ASTRO".to_string(),
+                decimals: 6,
+                initial_balances: vec![],
+                mint: Some(MinterResponse {
+                    minter: owner.to_string(),
+                    cap: None,
+                }),
+                marketing: None,
+            },
+            &[],
+            "ASTRO",
+            None,
+        )?;
+
+        let xastro_code = app.store_code(xastro_contract());
+        let staking_code = app.store_code(staking_contract());
+        let staking = app.instantiate_contract(
+            staking_code,
+            owner.clone(),
+            &staking::InstantiateMsg {
+                owner: owner.to_string(),
+                token_code_id: xastro_code,
+                deposit_token_addr: astro.to_string(),
+                marketing: None,
+            },
+            &[],
+            "Astroport Staking",
+            None,
+        )?;
+
+        let gauge_controller_code = app.store_code(gauge_controller_contract());
+        let gauge_controller = app.instantiate_contract(
+            gauge_controller_code,
+            owner.clone(),
+            &InstantiateMsg {
+                owner: owner.to_string(),
+                incentives: incentives.to_string(),
+                factory: factory.to_string(),
+                staking: staking.to_string(),
+                vote_decay,
+                max_user_share,
+                pools_limit,
+            },
+            &[],
+            "Astroport Gauge Controller",
+            None,
+        )?;
+
+        app.execute_contract(
+            owner.clone(),
+            incentives.clone(),
+            &incentives::ExecuteMsg::UpdateConfig {
+                vesting_contract: None,
+                generator_controller: Some(gauge_controller.to_string()),
+                guardian: None,
+                incentivization_fee_info: None,
+            },
+            &[],
+        )?;
+
+        let mut helper = Self {
+            app,
+            owner: owner.clone(),
+            factory,
+            incentives,
+            astro,
+            staking,
+            gauge_controller,
+            token_code_id,
+        };
+
+        // The first deposit locks the minimum stake amount in the staking contract.
+        // It keeps the ASTRO to xASTRO ratio at 1:1 for the test stakes
+        helper.stake(owner.as_str(), 1001);
+        for (user, amount) in staked_balances {
+            helper.stake(user, *amount);
+        }
+
+        // Voting power snapshots become available starting from the next block
+        helper.next_block(5);
+
+        Ok(helper)
+    }
+
+    pub fn init_cw20(&mut self, name: &str) -> Addr {
+        self.app
+            .instantiate_contract(
+                self.token_code_id,
+                self.owner.clone(),
+                &cw20_base::msg::InstantiateMsg {
+                    name: name.to_string(),
+                    symbol: name.to_uppercase(),
+                    decimals: 6,
+                    initial_balances: vec![],
+                    mint: Some(MinterResponse {
+                        minter: self.owner.to_string(),
+                        cap: None,
+                    }),
+                    marketing: None,
+                },
+                &[],
+                name,
+                None,
+            )
+            .unwrap()
+    }
+
+    /// Creates an XYK pair for the given cw20 tokens and returns its LP token address.
+    pub fn create_pair(&mut self, tokens: &[&Addr]) -> String {
+        let asset_infos = tokens
+            .iter()
+            .map(|token| token_asset_info((*token).clone()))
+            .collect::<Vec<_>>();
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                self.factory.clone(),
+                &factory::ExecuteMsg::CreatePair {
+                    pair_type: PairType::Xyk {},
+                    asset_infos: asset_infos.clone(),
+                    init_params: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let pair_info: PairInfo = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.factory, &factory::QueryMsg::Pair { asset_infos })
+            .unwrap();
+
+        pair_info.liquidity_token.to_string()
+    }
+
+    /// Removes the pair for the given cw20 tokens from the factory.
+    pub fn deregister_pair(&mut self, tokens: &[&Addr]) {
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                self.factory.clone(),
+                &factory::ExecuteMsg::Deregister {
+                    asset_infos: tokens
+                        .iter()
+                        .map(|token| token_asset_info((*token).clone()))
+                        .collect(),
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn mint_astro(&mut self, recipient: &str, amount: u128) {
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                self.astro.clone(),
+                &Cw20ExecuteMsg::Mint {
+                    recipient: recipient.to_string(),
+                    amount: Uint128::new(amount),
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn send_to_staking(&mut self, user: &str, amount: u128, msg: &staking::Cw20HookMsg) {
+        self.mint_astro(user, amount);
+        self.app
+            .execute_contract(
+                Addr::unchecked(user),
+                self.astro.clone(),
+                &Cw20ExecuteMsg::Send {
+                    contract: self.staking.to_string(),
+                    amount: Uint128::new(amount),
+                    msg: to_binary(msg).unwrap(),
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    /// Mints ASTRO to the user and stakes it for xASTRO.
+    pub fn stake(&mut self, user: &str, amount: u128) {
+        self.send_to_staking(user, amount, &staking::Cw20HookMsg::Enter {});
+    }
+
+    /// Mints ASTRO to the user and stakes it in a lock position with the given duration.
+    pub fn stake_locked(&mut self, user: &str, amount: u128, duration: u64) {
+        self.send_to_staking(
+            user,
+            amount,
+            &staking::Cw20HookMsg::EnterLocked { duration },
+        );
+    }
+
+    pub fn set_lock_tiers(&mut self, tiers: Vec<LockTier>) {
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                self.staking.clone(),
+                &staking::ExecuteMsg::UpdateLockTiers { tiers },
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn vote(&mut self, user: &str, votes: &[(&str, u16)]) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(user),
+            self.gauge_controller.clone(),
+            &ExecuteMsg::Vote {
+                votes: votes
+                    .iter()
+                    .map(|(lp_token, bps)| (lp_token.to_string(), *bps))
+                    .collect(),
+            },
+            &[],
+        )
+    }
+
+    pub fn tune(&mut self, sender: &str) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.gauge_controller.clone(),
+            &ExecuteMsg::Tune {},
+            &[],
+        )
+    }
+
+    pub fn kill_pools(&mut self, sender: &Addr, lp_tokens: &[&str]) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.gauge_controller.clone(),
+            &ExecuteMsg::KillPools {
+                lp_tokens: lp_tokens.iter().map(|lp| lp.to_string()).collect(),
+            },
+            &[],
+        )
+    }
+
+    pub fn revive_pools(&mut self, sender: &Addr, lp_tokens: &[&str]) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.gauge_controller.clone(),
+            &ExecuteMsg::RevivePools {
+                lp_tokens: lp_tokens.iter().map(|lp| lp.to_string()).collect(),
+            },
+            &[],
+        )
+    }
+
+    pub fn update_config(
+        &mut self,
+        sender: &Addr,
+        vote_decay: Option<Decimal>,
+        max_user_share: Option<Decimal>,
+        pools_limit: Option<u8>,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.gauge_controller.clone(),
+            &ExecuteMsg::UpdateConfig {
+                vote_decay,
+                max_user_share,
+                pools_limit,
+            },
+            &[],
+        )
+    }
+
+    pub fn query_config(&self) -> Config {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.gauge_controller, &QueryMsg::Config {})
+            .unwrap()
+    }
+
+    pub fn query_user_info(&self, user: &str) -> UserInfoResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.gauge_controller,
+                &QueryMsg::UserInfo {
+                    user: user.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_voting_power(&self, user: &str) -> u128 {
+        self.app
+            .wrap()
+            .query_wasm_smart::<Uint128>(
+                &self.gauge_controller,
+                &QueryMsg::VotingPower {
+                    user: user.to_string(),
+                },
+            )
+            .unwrap()
+            .u128()
+    }
+
+    pub fn query_pool_weight(&self, lp_token: &str) -> u128 {
+        self.app
+            .wrap()
+            .query_wasm_smart::<Uint128>(
+                &self.gauge_controller,
+                &QueryMsg::PoolWeight {
+                    lp_token: lp_token.to_string(),
+                },
+            )
+            .unwrap()
+            .u128()
+    }
+
+    pub fn query_tune_info(&self) -> TuneInfo {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.gauge_controller, &QueryMsg::TuneInfo {})
+            .unwrap()
+    }
+
+    pub fn query_incentives_config(&self) -> incentives::Config {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.incentives, &incentives::QueryMsg::Config {})
+            .unwrap()
+    }
+
+    pub fn next_block(&mut self, plus_seconds: u64) {
+        self.app.update_block(|block| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(plus_seconds);
+        });
+    }
+
+    pub fn next_epoch(&mut self) {
+        self.next_block(EPOCH_LENGTH);
+    }
+}
@@ -0,0 +1,356 @@
+#![cfg(not(tarpaulin_include))]
+
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, Decimal, Uint128};
+
+use astroport::gauge_controller::epoch_start;
+use astroport::incentives::{EPOCHS_START, EPOCH_LENGTH};
+use astroport::staking::LockTier;
+use astroport_gauge_controller::error::ContractError;
+
+use crate::helper::Helper;
+
+mod helper;
+
+fn dec(value: &str) -> Decimal {
+    Decimal::from_str(value).unwrap()
+}
+
+#[test]
+fn test_epoch_start() {
+    assert_eq!(epoch_start(0), EPOCHS_START);
+    assert_eq!(epoch_start(EPOCHS_START), EPOCHS_START);
+    assert_eq!(epoch_start(EPOCHS_START + EPOCH_LENGTH - 1), EPOCHS_START);
+    assert_eq!(
+        epoch_start(EPOCHS_START + 3 * EPOCH_LENGTH + 100),
+        EPOCHS_START + 3 * EPOCH_LENGTH
+    );
+}
+
+#[test]
+fn test_vote_and_tune() {
+    let mut helper = Helper::new(
+        "owner",
+        &[("user1", 600), ("user2", 300), ("user3", 100)],
+        dec("0.2"),
+        dec("0.5"),
+        2,
+    )
+    .unwrap();
+
+    let token_a = helper.init_cw20("tokena");
+    let token_b = helper.init_cw20("tokenb");
+    let token_c = helper.init_cw20("tokenc");
+    let lp_ab = helper.create_pair(&[&token_a, &token_b]);
+    let lp_ac = helper.create_pair(&[&token_a, &token_c]);
+    let lp_bc = helper.create_pair(&[&token_b, &token_c]);
+
+    let err = helper.vote("user1", &[(&lp_ab, 0)]).unwrap_err();
+    assert_eq!(
+        ContractError::ZeroVote {
+            lp_token: lp_ab.clone()
+        },
+        err.downcast().unwrap()
+    );
+
+    let err = helper
+        .vote("user1", &[(&lp_ab, 6000), (&lp_ac, 5000)])
+        .unwrap_err();
+    assert_eq!(ContractError::VotesExceedMaxBps {}, err.downcast().unwrap());
+
+    let err = helper
+        .vote("user1", &[(&lp_ab, 5000), (&lp_ab, 5000)])
+        .unwrap_err();
+    assert_eq!(
+        ContractError::DuplicatedPool {
+            lp_token: lp_ab.clone()
+        },
+        err.downcast().unwrap()
+    );
+
+    // Only pools registered in the factory can be voted for
+    helper
+        .vote("user1", &[(token_a.as_str(), 10000)])
+        .unwrap_err();
+
+    let err = helper.vote("random", &[(&lp_ab, 10000)]).unwrap_err();
+    assert_eq!(
+        ContractError::ZeroVotingPower {
+            user: "random".to_string()
+        },
+        err.downcast().unwrap()
+    );
+
+    let err = helper.tune("keeper").unwrap_err();
+    assert_eq!(ContractError::NoVotes {}, err.downcast().unwrap());
+
+    // user1 voting power is capped by 50% of total voting power
+    assert_eq!(helper.query_voting_power("user1"), 500);
+    helper.vote("user1", &[(&lp_ab, 10000)]).unwrap();
+    helper
+        .vote("user2", &[(&lp_ab, 5000), (&lp_ac, 5000)])
+        .unwrap();
+    helper.vote("user3", &[(&lp_bc, 10000)]).unwrap();
+
+    assert_eq!(helper.query_pool_weight(&lp_ab), 650);
+    assert_eq!(helper.query_pool_weight(&lp_ac), 150);
+    assert_eq!(helper.query_pool_weight(&lp_bc), 100);
+
+    // Anyone can tune. Only the heaviest pools within the limit receive alloc points
+    helper.tune("keeper").unwrap();
+    let tune_info = helper.query_tune_info();
+    assert_eq!(tune_info.tune_ts, EPOCHS_START);
+    assert_eq!(
+        tune_info.pool_alloc_points,
+        vec![
+            (lp_ab.clone(), Uint128::new(650)),
+            (lp_ac.clone(), Uint128::new(150))
+        ]
+    );
+    assert_eq!(
+        helper.query_incentives_config().total_alloc_points.u128(),
+        800
+    );
+
+    let err = helper.tune("keeper").unwrap_err();
+    assert_eq!(
+        ContractError::TuneCooldown {
+            epoch_start: EPOCHS_START
+        },
+        err.downcast().unwrap()
+    );
+
+    // Votes decay by 20% every epoch
+    helper.next_epoch();
+    assert_eq!(helper.query_pool_weight(&lp_ab), 520);
+    assert_eq!(helper.query_pool_weight(&lp_ac), 120);
+    assert_eq!(helper.query_pool_weight(&lp_bc), 80);
+    assert_eq!(
+        helper.query_user_info("user3").applied_voting_power.u128(),
+        80
+    );
+
+    // Re-voting replaces decayed votes with the current voting power
+    helper.vote("user3", &[(&lp_ac, 10000)]).unwrap();
+    assert_eq!(helper.query_pool_weight(&lp_bc), 0);
+    assert_eq!(helper.query_pool_weight(&lp_ac), 220);
+    let user_info = helper.query_user_info("user3");
+    assert_eq!(user_info.vote_ts, EPOCHS_START + EPOCH_LENGTH);
+    assert_eq!(user_info.voting_power.u128(), 100);
+
+    // Balance changes after the epoch snapshot don't affect voting power
+    helper.stake("user2", 1000);
+    helper.next_block(10);
+    assert_eq!(helper.query_voting_power("user2"), 300);
+
+    helper.tune("keeper").unwrap();
+    assert_eq!(
+        helper.query_tune_info().pool_alloc_points,
+        vec![
+            (lp_ab.clone(), Uint128::new(520)),
+            (lp_ac.clone(), Uint128::new(220))
+        ]
+    );
+    assert_eq!(
+        helper.query_incentives_config().total_alloc_points.u128(),
+        740
+    );
+
+    // New epoch snapshot accounts for the new stake
+    helper.next_epoch();
+    helper.vote("user2", &[(&lp_bc, 10000)]).unwrap();
+    assert_eq!(helper.query_user_info("user2").voting_power.u128(), 1000);
+    assert_eq!(helper.query_pool_weight(&lp_bc), 1000);
+    // 650 decayed twice minus 150 of user2 votes decayed twice
+    assert_eq!(helper.query_pool_weight(&lp_ab), 320);
+
+    // Empty votes withdraw all user votes
+    helper.vote("user2", &[]).unwrap();
+    assert_eq!(helper.query_pool_weight(&lp_bc), 0);
+}
+
+#[test]
+fn test_locked_stake_boosts_voting_power() {
+    let mut helper = Helper::new("owner", &[("alice", 1000)], dec("0.1"), dec("1"), 5).unwrap();
+    helper.set_lock_tiers(vec![LockTier {
+        duration: 4 * EPOCH_LENGTH,
+        multiplier: dec("3"),
+    }]);
+    helper.stake_locked("bob", 1000, 4 * EPOCH_LENGTH);
+    helper.next_block(5);
+
+    let token_a = helper.init_cw20("tokena");
+    let token_b = helper.init_cw20("tokenb");
+    let token_c = helper.init_cw20("tokenc");
+    let lp_ab = helper.create_pair(&[&token_a, &token_b]);
+    let lp_ac = helper.create_pair(&[&token_a, &token_c]);
+
+    // Locked shares are held by the staking contract, but the lock weight counts as voting power
+    assert_eq!(helper.query_voting_power("alice"), 1000);
+    assert_eq!(helper.query_voting_power("bob"), 3000);
+
+    helper.vote("alice", &[(&lp_ab, 10000)]).unwrap();
+    helper.vote("bob", &[(&lp_ac, 10000)]).unwrap();
+
+    helper.tune("keeper").unwrap();
+    assert_eq!(
+        helper.query_tune_info().pool_alloc_points,
+        vec![
+            (lp_ac.clone(), Uint128::new(3000)),
+            (lp_ab.clone(), Uint128::new(1000))
+        ]
+    );
+}
+
+#[test]
+fn test_tune_skips_deregistered_pools() {
+    let mut helper = Helper::new("owner", &[("user1", 100)], dec("0.1"), dec("1"), 5).unwrap();
+
+    let token_a = helper.init_cw20("tokena");
+    let token_b = helper.init_cw20("tokenb");
+    let token_c = helper.init_cw20("tokenc");
+    let lp_ab = helper.create_pair(&[&token_a, &token_b]);
+    let lp_ac = helper.create_pair(&[&token_a, &token_c]);
+
+    helper
+        .vote("user1", &[(&lp_ab, 7000), (&lp_ac, 3000)])
+        .unwrap();
+    helper.deregister_pair(&[&token_a, &token_b]);
+
+    helper.tune("keeper").unwrap();
+    assert_eq!(
+        helper.query_tune_info().pool_alloc_points,
+        vec![(lp_ac.clone(), Uint128::new(30))]
+    );
+}
+
+#[test]
+fn test_kill_pools_and_config() {
+    let owner = Addr::unchecked("owner");
+    let mut helper = Helper::new("owner", &[("user1", 100)], dec("0.1"), dec("1"), 5).unwrap();
+
+    let token_a = helper.init_cw20("tokena");
+    let token_b = helper.init_cw20("tokenb");
+    let token_c = helper.init_cw20("tokenc");
+    let lp_ab = helper.create_pair(&[&token_a, &token_b]);
+    let lp_ac = helper.create_pair(&[&token_a, &token_c]);
+
+    helper
+        .vote("user1", &[(&lp_ab, 5000), (&lp_ac, 5000)])
+        .unwrap();
+
+    let err = helper
+        .kill_pools(&Addr::unchecked("random"), &[&lp_ab])
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    let err = helper.kill_pools(&owner, &[]).unwrap_err();
+    assert_eq!(ContractError::EmptyPoolsList {}, err.downcast().unwrap());
+
+    let err = helper.kill_pools(&owner, &["AB"]).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Generic error: Cannot determine asset info from AB"
+    );
+
+    helper.kill_pools(&owner, &[&lp_ab]).unwrap();
+    assert_eq!(helper.query_pool_weight(&lp_ab), 0);
+    assert_eq!(helper.query_pool_weight(&lp_ac), 50);
+
+    let err = helper.kill_pools(&owner, &[&lp_ab]).unwrap_err();
+    assert_eq!(
+        ContractError::KilledPool {
+            lp_token: lp_ab.clone()
+        },
+        err.downcast().unwrap()
+    );
+
+    let err = helper.vote("user1", &[(&lp_ab, 10000)]).unwrap_err();
+    assert_eq!(
+        ContractError::KilledPool {
+            lp_token: lp_ab.clone()
+        },
+        err.downcast().unwrap()
+    );
+
+    // Killed pools are excluded from tuning
+    helper.tune("keeper").unwrap();
+    assert_eq!(
+        helper.query_tune_info().pool_alloc_points,
+        vec![(lp_ac.clone(), Uint128::new(50))]
+    );
+
+    let err = helper
+        .revive_pools(&Addr::unchecked("random"), &[&lp_ab])
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    let err = helper.revive_pools(&owner, &[&lp_ac]).unwrap_err();
+    assert_eq!(
+        ContractError::PoolNotKilled {
+            lp_token: lp_ac.clone()
+        },
+        err.downcast().unwrap()
+    );
+
+    // Votes cast before the pool was killed count again after reviving
+    helper.revive_pools(&owner, &[&lp_ab]).unwrap();
+    assert_eq!(helper.query_pool_weight(&lp_ab), 50);
+
+    helper.vote("user1", &[(&lp_ab, 10000)]).unwrap();
+    assert_eq!(helper.query_pool_weight(&lp_ab), 100);
+    assert_eq!(helper.query_pool_weight(&lp_ac), 0);
+
+    helper.next_epoch();
+    helper.tune("keeper").unwrap();
+    assert_eq!(
+        helper.query_tune_info().pool_alloc_points,
+        vec![(lp_ab.clone(), Uint128::new(90))]
+    );
+
+    let err = helper
+        .update_config(&Addr::unchecked("random"), None, None, Some(1))
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    for vote_decay in [Decimal::zero(), dec("1.1")] {
+        let err = helper
+            .update_config(&owner, Some(vote_decay), None, None)
+            .unwrap_err();
+        assert_eq!(
+            ContractError::IncorrectVoteDecay {},
+            err.downcast().unwrap()
+        );
+    }
+
+    let err = helper
+        .update_config(&owner, None, Some(Decimal::zero()), None)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::IncorrectMaxUserShare {},
+        err.downcast().unwrap()
+    );
+
+    let err = helper
+        .update_config(&owner, None, None, Some(0))
+        .unwrap_err();
+    assert_eq!(
+        ContractError::IncorrectPoolsLimit {},
+        err.downcast().unwrap()
+    );
+
+    // Full decay makes votes expire every epoch
+    helper
+        .update_config(&owner, Some(Decimal::one()), Some(dec("0.3")), Some(1))
+        .unwrap();
+    let config = helper.query_config();
+    assert_eq!(config.vote_decay, Decimal::one());
+    assert_eq!(config.max_user_share, dec("0.3"));
+    assert_eq!(config.pools_limit, 1);
+
+    helper.next_epoch();
+    assert_eq!(helper.query_pool_weight(&lp_ab), 0);
+    let err = helper.tune("keeper").unwrap_err();
+    assert_eq!(ContractError::NoVotes {}, err.downcast().unwrap());
+}
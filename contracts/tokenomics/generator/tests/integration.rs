@@ -1140,6 +1140,7 @@ fn disabling_pool() {
             maker_fee_bps: 10,
             is_disabled: false,
             is_generator_disabled: true,
+            pair_creation_fee: None,
         },
     };
 
@@ -1169,6 +1170,7 @@ fn disabling_pool() {
             maker_fee_bps: 10,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         },
     };
 
@@ -3344,6 +3346,7 @@ fn deactivate_pools_by_pair_types() {
             to_binary(&StablePoolParams {
                 amp: 100,
                 owner: None,
+                rate_providers: None,
             })
             .unwrap(),
         ),
@@ -3430,6 +3433,7 @@ fn deactivate_pools_by_pair_types() {
             maker_fee_bps: 10,
             is_disabled: false,
             is_generator_disabled: true,
+            pair_creation_fee: None,
         },
     };
 
@@ -3533,6 +3537,7 @@ fn deactivate_pools_by_pair_types() {
             maker_fee_bps: 10,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         },
     };
 
@@ -3938,6 +3943,7 @@ fn instantiate_factory(
             maker_fee_bps: 10,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id,
         fee_address: None,
@@ -3955,6 +3961,7 @@ fn instantiate_factory(
             maker_fee_bps: 10,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         });
     }
 
@@ -4062,6 +4069,11 @@ fn instantiate_generator(
                         amount,
                     },
                     end_point: None,
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 }],
             }],
         })
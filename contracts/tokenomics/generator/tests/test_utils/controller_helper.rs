@@ -60,6 +60,7 @@ impl ControllerHelper {
                 maker_fee_bps: 10,
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
             }],
             token_code_id: escrow_helper.astro_token_code_id,
             fee_address: None,
@@ -180,6 +181,11 @@ impl ControllerHelper {
                             amount: Uint128::new(100000_000000),
                         },
                         end_point: None,
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     }],
                 }],
             })
@@ -286,6 +286,7 @@ impl Helper {
                             maker_fee_bps: 0,
                             is_disabled: false,
                             is_generator_disabled: false,
+                            pair_creation_fee: None,
                         },
                         PairConfig {
                             code_id: pair_stable_code,
@@ -294,6 +295,7 @@ impl Helper {
                             maker_fee_bps: 0,
                             is_disabled: false,
                             is_generator_disabled: false,
+                            pair_creation_fee: None,
                         },
                     ],
                     token_code_id,
@@ -369,6 +371,11 @@ impl Helper {
                             amount: astro_for_vesting.amount,
                         },
                         end_point: None,
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     }],
                 }],
             },
@@ -935,6 +942,7 @@ impl Helper {
                         to_binary(&StablePoolParams {
                             amp: 10,
                             owner: None,
+                            rate_providers: None,
                         })
                         .unwrap(),
                     ),
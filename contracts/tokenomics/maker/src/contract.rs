@@ -1,20 +1,24 @@
 use crate::error::ContractError;
-use crate::state::{BRIDGES, CONFIG, OWNERSHIP_PROPOSAL};
+use crate::state::{
+    BRIDGES, COLLECT_KEEPERS, CONFIG, DCA_SCHEDULES, OWNERSHIP_PROPOSAL, PRICE_GUARDS,
+};
 use std::cmp::min;
 
-use crate::migration::{migrate_from_v1, migrate_from_v120};
+use crate::migration::{migrate_from_v1, migrate_from_v120, migrate_from_v130};
 
 use crate::utils::{
-    build_distribute_msg, build_send_msg, build_swap_msg, try_build_swap_msg,
-    update_second_receiver_cfg, validate_bridge, BRIDGES_EXECUTION_MAX_DEPTH,
-    BRIDGES_INITIAL_DEPTH,
+    assert_price_deviation, build_distribute_msg, build_raw_fee_msgs, build_send_msg,
+    build_swap_msg, dca_swap_amount, query_all_pairs, query_pool_depth, trace_bridge_route,
+    try_build_swap_msg, update_fee_recipients, update_second_receiver_cfg, validate_bridge,
+    BRIDGES_EXECUTION_MAX_DEPTH, BRIDGES_INITIAL_DEPTH,
 };
 use astroport::asset::{addr_opt_validate, Asset, AssetInfo};
 use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
 use astroport::factory::UpdateAddr;
 use astroport::maker::{
-    AssetWithLimit, BalancesResponse, Config, ConfigResponse, ExecuteMsg, InstantiateMsg,
-    MigrateMsg, QueryMsg, SecondReceiverConfig, SecondReceiverParams,
+    AssetWithLimit, BalancesResponse, BridgeRouteResponse, Config, ConfigResponse, DcaParams,
+    DcaSchedule, DcaScheduleResponse, ExecuteMsg, FeeRecipientParams, InstantiateMsg, MigrateMsg,
+    QueryMsg, SecondReceiverConfig, SecondReceiverParams,
 };
 use astroport::pair::MAX_ALLOWED_SLIPPAGE;
 use cosmwasm_std::{
@@ -31,6 +35,8 @@ const CONTRACT_NAME: &str = "astroport-maker";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// Sets the default maximum spread (as a percentage) used when swapping fee tokens to ASTRO.
 const DEFAULT_MAX_SPREAD: u64 = 5; // 5%
+/// The max number of upcoming swaps returned for a DCA schedule
+const DCA_SCHEDULE_LIMIT: usize = 10;
 
 /// Creates a new contract with the specified parameters in [`InstantiateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -87,9 +93,14 @@ pub fn instantiate(
         governance_percent,
         max_spread,
         second_receiver_cfg: None,
+        fee_recipients: vec![],
     };
 
     update_second_receiver_cfg(deps.as_ref(), &mut cfg, &msg.second_receiver_params)?;
+    update_fee_recipients(deps.as_ref(), &mut cfg, msg.fee_recipients)?;
+    if cfg.second_receiver_cfg.is_some() && !cfg.fee_recipients.is_empty() {
+        return Err(ContractError::SecondReceiverWithFeeRecipients {});
+    }
 
     if cfg.staking_contract.is_none() && cfg.governance_contract.is_none() {
         return Err(
@@ -135,6 +146,7 @@ pub fn instantiate(
         attr("max_spread", max_spread.to_string()),
         attr("second_fee_receiver", second_fee_receiver),
         attr("second_receiver_cut", second_receiver_cut),
+        attr("fee_recipients", cfg.fee_recipients.len().to_string()),
     ]))
 }
 
@@ -151,6 +163,7 @@ pub fn instantiate(
 ///             governance_percent,
 ///             max_spread,
 ///             second_receiver_params,
+///             fee_recipients,
 ///         }** Updates general contract settings stores in the [`Config`].
 ///
 /// * **ExecuteMsg::UpdateBridges { add, remove }** Adds or removes bridge assets used to swap fee tokens to ASTRO.
@@ -166,6 +179,16 @@ pub fn instantiate(
 /// * **ExecuteMsg::ClaimOwnership {}** Claims contract ownership.
 ///
 /// * **ExecuteMsg::EnableRewards** Enables collected ASTRO (pre Maker upgrade) to be distributed to ITO stakers.
+///
+/// * **ExecuteMsg::UpdatePriceGuards { add, remove }** Sets or removes max allowed deviations of fee token swaps
+/// from prices observed in pairs.
+///
+/// * **ExecuteMsg::UpdateCollectKeepers { add, remove }** Adds or removes addresses allowed to call Collect.
+///
+/// * **ExecuteMsg::UpdateDcaSchedules { add, remove }** Sets or removes schedules used to swap fee tokens
+/// in parts over time.
+///
+/// * **ExecuteMsg::AutoFillBridges { assets }** Sets bridges of fee tokens using the deepest factory pools.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -174,7 +197,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Collect { assets } => collect(deps, env, assets),
+        ExecuteMsg::Collect { assets } => collect(deps, env, info, assets),
         ExecuteMsg::UpdateConfig {
             factory_contract,
             staking_contract,
@@ -183,6 +206,7 @@ pub fn execute(
             basic_asset,
             max_spread,
             second_receiver_params,
+            fee_recipients,
         } => update_config(
             deps,
             info,
@@ -193,6 +217,7 @@ pub fn execute(
             basic_asset,
             max_spread,
             second_receiver_params,
+            fee_recipients,
         ),
         ExecuteMsg::UpdateBridges { add, remove } => update_bridges(deps, info, add, remove),
         ExecuteMsg::SwapBridgeAssets { assets, depth } => {
@@ -256,19 +281,39 @@ pub fn execute(
 
             Ok(Response::default().add_attribute("action", "enable_rewards"))
         }
+        ExecuteMsg::UpdatePriceGuards { add, remove } => {
+            update_price_guards(deps, info, add, remove)
+        }
+        ExecuteMsg::UpdateCollectKeepers { add, remove } => {
+            update_collect_keepers(deps, info, add, remove)
+        }
+        ExecuteMsg::UpdateDcaSchedules { add, remove } => {
+            update_dca_schedules(deps, info, add, remove)
+        }
+        ExecuteMsg::AutoFillBridges { assets } => auto_fill_bridges(deps, info, assets),
     }
 }
 
 /// Swaps fee tokens to ASTRO and distribute the resulting ASTRO to ITO and vITO stakers.
 ///
 /// * **assets** array with fee tokens being swapped to ASTRO.
+///
+/// ## Executor
+/// Only the owner and collect keepers can execute this if the keeper list is not empty.
 fn collect(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
+    info: MessageInfo,
     assets: Vec<AssetWithLimit>,
 ) -> Result<Response, ContractError> {
     let mut cfg = CONFIG.load(deps.storage)?;
 
+    // Permission check
+    let keepers = COLLECT_KEEPERS.may_load(deps.storage)?.unwrap_or_default();
+    if !keepers.is_empty() && info.sender != cfg.owner && !keepers.contains(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
     let astro = cfg.astro_token.clone();
 
     // Check for duplicate assets
@@ -281,6 +326,13 @@ fn collect(
         return Err(ContractError::DuplicatedAsset {});
     }
 
+    // Limit amounts of fee tokens swapped according to their DCA schedules
+    let assets = apply_dca_schedules(deps.branch(), &env, assets)?;
+
+    // Send shares of fee tokens to recipients who receive them as is
+    let (raw_fee_msgs, assets) =
+        split_raw_fees(deps.as_ref(), &env.contract.address, &cfg, assets)?;
+
     // Swap all non ASTRO tokens
     let (mut response, bridge_assets) = swap_assets(
         deps.as_ref(),
@@ -289,6 +341,7 @@ fn collect(
         assets.into_iter().filter(|a| a.info.ne(&astro)).collect(),
         true,
     )?;
+    response.messages.splice(0..0, raw_fee_msgs);
 
     // If no swap messages - send ASTRO directly to x/vITO stakers
     if response.messages.is_empty() {
@@ -308,6 +361,104 @@ fn collect(
     Ok(response.add_attribute("action", "collect"))
 }
 
+/// Limits the amounts of fee tokens with DCA schedules to the amounts allowed in the current interval.
+/// Fee tokens whose interval has not passed yet are skipped.
+///
+/// * **assets** array with fee tokens being collected.
+fn apply_dca_schedules(
+    deps: DepsMut,
+    env: &Env,
+    assets: Vec<AssetWithLimit>,
+) -> Result<Vec<AssetWithLimit>, ContractError> {
+    let now = env.block.time.seconds();
+    let mut assets_left = vec![];
+
+    for a in assets {
+        let mut schedule = match DCA_SCHEDULES.may_load(deps.storage, a.info.to_string())? {
+            Some(schedule) => schedule,
+            None => {
+                assets_left.push(a);
+                continue;
+            }
+        };
+
+        if let Some(last_execution) = schedule.last_execution {
+            if now < last_execution.saturating_add(schedule.params.interval) {
+                continue;
+            }
+        }
+
+        let balance = a.info.query_pool(&deps.querier, &env.contract.address)?;
+        let mut amount = dca_swap_amount(&schedule.params, balance);
+        if let Some(limit) = a.limit {
+            if limit < amount && limit > Uint128::zero() {
+                amount = limit;
+            }
+        }
+
+        if !amount.is_zero() {
+            schedule.last_execution = Some(now);
+            schedule.remaining = balance - amount;
+            DCA_SCHEDULES.save(deps.storage, a.info.to_string(), &schedule)?;
+
+            assets_left.push(AssetWithLimit {
+                info: a.info,
+                limit: Some(amount),
+            });
+        }
+    }
+
+    Ok(assets_left)
+}
+
+/// Builds transfer messages for fee recipients who receive fee tokens as is and reduces
+/// the amounts of fee tokens left for swapping accordingly.
+///
+/// * **contract_addr** maker contract address.
+///
+/// * **assets** array with fee tokens being collected.
+fn split_raw_fees(
+    deps: Deps,
+    contract_addr: &Addr,
+    cfg: &Config,
+    assets: Vec<AssetWithLimit>,
+) -> Result<(Vec<SubMsg>, Vec<AssetWithLimit>), ContractError> {
+    if !cfg.fee_recipients.iter().any(|r| r.raw_asset) {
+        return Ok((vec![], assets));
+    }
+
+    let mut messages = vec![];
+    let mut assets_left = vec![];
+
+    for a in assets {
+        // Get balance
+        let mut balance = a.info.query_pool(&deps.querier, contract_addr)?;
+        if let Some(limit) = a.limit {
+            if limit < balance && limit > Uint128::zero() {
+                balance = limit;
+            }
+        }
+
+        let (mut raw_fee_msgs, amount_left) = build_raw_fee_msgs(
+            cfg,
+            &Asset {
+                info: a.info.clone(),
+                amount: balance,
+            },
+        )?;
+        messages.append(&mut raw_fee_msgs);
+
+        if !amount_left.is_zero() {
+            assets_left.push(AssetWithLimit {
+                info: a.info,
+                limit: Some(amount_left),
+            });
+        }
+    }
+
+    Ok((messages, assets_left))
+}
+
 /// This enum describes available token types that can be used as a SwapTarget.
 enum SwapTarget {
     Astro(SubMsg),
@@ -384,6 +535,7 @@ fn swap(
             &cfg.astro_token,
             BRIDGES_INITIAL_DEPTH,
         )?;
+        assert_price_deviation(deps, &bridge_pool, &from_token, &bridge_token, amount_in)?;
 
         let msg = build_swap_msg(
             cfg.max_spread,
@@ -401,25 +553,25 @@ fn swap(
     // 2. Check for a pair with a default bridge
     if let Some(default_bridge) = &cfg.default_bridge {
         if from_token.ne(default_bridge) {
-            let swap_to_default =
-                try_build_swap_msg(&deps.querier, cfg, &from_token, default_bridge, amount_in);
-            if let Ok(msg) = swap_to_default {
-                return Ok(SwapTarget::Bridge {
-                    asset: default_bridge.clone(),
-                    msg,
-                });
+            match try_build_swap_msg(deps, cfg, &from_token, default_bridge, amount_in) {
+                Ok(msg) => {
+                    return Ok(SwapTarget::Bridge {
+                        asset: default_bridge.clone(),
+                        msg,
+                    })
+                }
+                Err(err) if err.is_price_guard_error() => return Err(err),
+                Err(_) => {}
             }
         }
     }
 
     // 3. Check for a direct pair with ASTRO
-    let swap_to_astro =
-        try_build_swap_msg(&deps.querier, cfg, &from_token, &cfg.astro_token, amount_in);
-    if let Ok(msg) = swap_to_astro {
-        return Ok(SwapTarget::Astro(msg));
+    match try_build_swap_msg(deps, cfg, &from_token, &cfg.astro_token, amount_in) {
+        Ok(msg) => Ok(SwapTarget::Astro(msg)),
+        Err(err) if err.is_price_guard_error() => Err(err),
+        Err(_) => Err(ContractError::CannotSwap(from_token)),
     }
-
-    Err(ContractError::CannotSwap(from_token))
 }
 
 /// Performs a swap operation to ASTRO without additional checks.
@@ -439,12 +591,12 @@ fn swap_no_validate(
     // Check if next level bridge exists
     let bridge_token = BRIDGES.load(deps.storage, from_token.to_string());
     if let Ok(asset) = bridge_token {
-        let msg = try_build_swap_msg(&deps.querier, cfg, &from_token, &asset, amount_in)?;
+        let msg = try_build_swap_msg(deps, cfg, &from_token, &asset, amount_in)?;
         return Ok(SwapTarget::Bridge { asset, msg });
     }
 
     // Check for a direct swap to ASTRO
-    let swap_to_astro = try_build_swap_msg(&deps.querier, cfg, &from_token, &astro, amount_in);
+    let swap_to_astro = try_build_swap_msg(deps, cfg, &from_token, &astro, amount_in);
     if let Ok(msg) = swap_to_astro {
         return Ok(SwapTarget::Astro(msg));
     }
@@ -580,6 +732,33 @@ fn distribute(
         CONFIG.save(deps.storage, cfg)?;
     }
 
+    // Recipients who receive fee tokens as is got their shares before swaps
+    // thus shares of ASTRO recipients are scaled to the rest
+    let raw_share = cfg
+        .fee_recipients
+        .iter()
+        .filter(|r| r.raw_asset)
+        .fold(Decimal::zero(), |acc, r| acc + r.share);
+    let astro_share = Decimal::one() - raw_share;
+    let mut recipients_amount = Uint128::zero();
+    for recipient in cfg.fee_recipients.iter().filter(|r| !r.raw_asset) {
+        let recipient_amount = if astro_share.is_zero() {
+            Uint128::zero()
+        } else {
+            amount.multiply_ratio(recipient.share.atomics(), astro_share.atomics())
+        };
+
+        if !recipient_amount.is_zero() {
+            let asset = Asset {
+                info: cfg.astro_token.clone(),
+                amount: recipient_amount,
+            };
+            result.push(SubMsg::new(asset.into_msg(&recipient.address)?));
+            recipients_amount += recipient_amount;
+        }
+    }
+    amount = amount.checked_sub(recipients_amount)?;
+
     let second_receiver_amount = if let Some(second_receiver_cfg) = &cfg.second_receiver_cfg {
         let amount = amount.multiply_ratio(
             Uint128::from(second_receiver_cfg.second_receiver_cut),
@@ -664,6 +843,8 @@ fn distribute(
 ///
 /// * **second_receiver_params** describes the second receiver of fees
 ///
+/// * **fee_recipients** new list of recipients that get their shares of collected fees.
+///
 /// ## Executor
 /// Only the owner can execute this.
 #[allow(clippy::too_many_arguments)]
@@ -677,6 +858,7 @@ fn update_config(
     default_bridge_opt: Option<AssetInfo>,
     max_spread: Option<Decimal>,
     second_receiver_params: Option<SecondReceiverParams>,
+    fee_recipients: Option<Vec<FeeRecipientParams>>,
 ) -> Result<Response, ContractError> {
     let mut attributes = vec![attr("action", "set_config")];
 
@@ -687,6 +869,10 @@ fn update_config(
         return Err(ContractError::Unauthorized {});
     }
 
+    if second_receiver_params.is_some() && fee_recipients.is_some() {
+        return Err(ContractError::SecondReceiverWithFeeRecipients {});
+    }
+
     if let Some(factory_contract) = factory_contract {
         config.factory_contract = deps.api.addr_validate(&factory_contract)?;
         attributes.push(attr("factory_contract", &factory_contract));
@@ -756,6 +942,20 @@ fn update_config(
         ));
     }
 
+    if fee_recipients.is_some() {
+        update_fee_recipients(deps.as_ref(), &mut config, fee_recipients)?;
+        // Fee recipients supersede the second receiver which must be included into the list if still needed
+        config.second_receiver_cfg = None;
+        attributes.push(attr(
+            "fee_recipients",
+            config.fee_recipients.len().to_string(),
+        ));
+    }
+
+    if config.second_receiver_cfg.is_some() && !config.fee_recipients.is_empty() {
+        return Err(ContractError::SecondReceiverWithFeeRecipients {});
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::new().add_attributes(attributes))
@@ -817,6 +1017,226 @@ fn update_bridges(
     Ok(Response::default().add_attribute("action", "update_bridges"))
 }
 
+/// Sets bridges of fee tokens to the assets they are paired with in the deepest factory pools.
+/// Only pools whose other asset is ASTRO or can be used as a bridge are considered. If the deepest
+/// pool is paired with ASTRO, the fee token's bridge is removed.
+///
+/// * **assets** array of fee tokens to set bridges for.
+///
+/// ## Executor
+/// Only the owner can execute this.
+fn auto_fill_bridges(
+    deps: DepsMut,
+    info: MessageInfo,
+    assets: Vec<AssetInfo>,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+
+    // Permission check
+    if info.sender != cfg.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pairs = query_all_pairs(&deps.querier, &cfg.factory_contract)?;
+    let mut attributes = vec![attr("action", "auto_fill_bridges")];
+
+    for asset in assets {
+        asset.check(deps.api)?;
+        if asset.equal(&cfg.astro_token) {
+            continue;
+        }
+
+        let mut deepest: Option<(AssetInfo, Uint128)> = None;
+        for pair in &pairs {
+            // Swaps are routed through two-asset pools only
+            if pair.asset_infos.len() != 2 || !pair.asset_infos.iter().any(|i| i.equal(&asset)) {
+                continue;
+            }
+            let other = match pair.asset_infos.iter().find(|i| !i.equal(&asset)) {
+                Some(other) => other.clone(),
+                None => continue,
+            };
+
+            let is_valid = other.equal(&cfg.astro_token)
+                || validate_bridge(
+                    deps.as_ref(),
+                    &cfg.factory_contract,
+                    &asset,
+                    &other,
+                    &cfg.astro_token,
+                    BRIDGES_INITIAL_DEPTH,
+                )
+                .is_ok();
+            if !is_valid {
+                continue;
+            }
+
+            let depth = query_pool_depth(&deps.querier, pair, &asset)?;
+            if deepest
+                .as_ref()
+                .map_or(true, |(_, max_depth)| depth > *max_depth)
+            {
+                deepest = Some((other, depth));
+            }
+        }
+
+        match deepest {
+            Some((bridge, _)) if bridge.equal(&cfg.astro_token) => {
+                BRIDGES.remove(deps.storage, asset.to_string());
+                attributes.push(attr(asset.to_string(), "direct"));
+            }
+            Some((bridge, _)) => {
+                BRIDGES.save(deps.storage, asset.to_string(), &bridge)?;
+                attributes.push(attr(asset.to_string(), bridge.to_string()));
+            }
+            None => attributes.push(attr(asset.to_string(), "none")),
+        }
+    }
+
+    Ok(Response::new().add_attributes(attributes))
+}
+
+/// Sets or removes max allowed deviations of fee token swaps from prices observed in pairs.
+///
+/// * **add** array of fee tokens with their max allowed price deviations.
+///
+/// * **remove** array of fee tokens whose swaps are not checked anymore.
+///
+/// ## Executor
+/// Only the owner can execute this.
+fn update_price_guards(
+    deps: DepsMut,
+    info: MessageInfo,
+    add: Option<Vec<(AssetInfo, Decimal)>>,
+    remove: Option<Vec<AssetInfo>>,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+
+    // Permission check
+    if info.sender != cfg.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(remove_guards) = remove {
+        for asset in remove_guards {
+            PRICE_GUARDS.remove(deps.storage, asset.to_string());
+        }
+    }
+
+    if let Some(add_guards) = add {
+        for (asset, max_deviation) in add_guards {
+            asset.check(deps.api)?;
+            if max_deviation.is_zero() || max_deviation >= Decimal::one() {
+                return Err(ContractError::IncorrectPriceDeviation(asset.to_string()));
+            }
+
+            PRICE_GUARDS.save(deps.storage, asset.to_string(), &max_deviation)?;
+        }
+    }
+
+    Ok(Response::default().add_attribute("action", "update_price_guards"))
+}
+
+/// Adds or removes addresses allowed to call Collect.
+///
+/// * **add** array of addresses allowed to call Collect.
+///
+/// * **remove** array of addresses which are not allowed to call Collect anymore.
+///
+/// ## Executor
+/// Only the owner can execute this.
+fn update_collect_keepers(
+    deps: DepsMut,
+    info: MessageInfo,
+    add: Option<Vec<String>>,
+    remove: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+
+    // Permission check
+    if info.sender != cfg.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut keepers = COLLECT_KEEPERS.may_load(deps.storage)?.unwrap_or_default();
+
+    if let Some(remove_keepers) = remove {
+        let remove_keepers = remove_keepers
+            .iter()
+            .map(|addr| deps.api.addr_validate(addr))
+            .collect::<StdResult<Vec<_>>>()?;
+        keepers.retain(|keeper| !remove_keepers.contains(keeper));
+    }
+
+    if let Some(add_keepers) = add {
+        for addr in add_keepers {
+            let keeper = deps.api.addr_validate(&addr)?;
+            if !keepers.contains(&keeper) {
+                keepers.push(keeper);
+            }
+        }
+    }
+
+    COLLECT_KEEPERS.save(deps.storage, &keepers)?;
+
+    Ok(Response::default().add_attribute("action", "update_collect_keepers"))
+}
+
+/// Sets or removes schedules used to swap fee tokens in parts over time.
+///
+/// * **add** array of fee tokens with their DCA parameters.
+///
+/// * **remove** array of fee tokens which are swapped at once again.
+///
+/// ## Executor
+/// Only the owner can execute this.
+fn update_dca_schedules(
+    deps: DepsMut,
+    info: MessageInfo,
+    add: Option<Vec<(AssetInfo, DcaParams)>>,
+    remove: Option<Vec<AssetInfo>>,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+
+    // Permission check
+    if info.sender != cfg.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(remove_schedules) = remove {
+        for asset in remove_schedules {
+            DCA_SCHEDULES.remove(deps.storage, asset.to_string());
+        }
+    }
+
+    if let Some(add_schedules) = add {
+        for (asset, params) in add_schedules {
+            asset.check(deps.api)?;
+            if asset.equal(&cfg.astro_token)
+                || params.max_fraction.is_zero()
+                || params.max_fraction > Decimal::one()
+                || params.interval == 0
+            {
+                return Err(ContractError::IncorrectDcaParams(asset.to_string()));
+            }
+
+            // Keep the execution state if the schedule already exists
+            let schedule = match DCA_SCHEDULES.may_load(deps.storage, asset.to_string())? {
+                Some(schedule) => DcaSchedule { params, ..schedule },
+                None => DcaSchedule {
+                    asset_info: asset.clone(),
+                    params,
+                    last_execution: None,
+                    remaining: Uint128::zero(),
+                },
+            };
+            DCA_SCHEDULES.save(deps.storage, asset.to_string(), &schedule)?;
+        }
+    }
+
+    Ok(Response::default().add_attribute("action", "update_dca_schedules"))
+}
+
 /// Exposes all the queries available in the contract.
 ///
 /// ## Queries
@@ -827,12 +1247,29 @@ fn update_bridges(
 ///
 /// * **QueryMsg::Bridges {}** Returns the bridges used for swapping fee tokens
 /// using a vector of [`(String, String)`] denoting Asset -> Bridge connections.
+///
+/// * **QueryMsg::PriceGuards {}** Returns max allowed price deviations
+/// using a vector of [`(String, Decimal)`] denoting Asset -> Deviation connections.
+///
+/// * **QueryMsg::CollectKeepers {}** Returns addresses allowed to call Collect.
+///
+/// * **QueryMsg::DcaSchedules {}** Returns DCA schedules along with upcoming swaps
+/// using a vector of [`DcaScheduleResponse`] objects.
+///
+/// * **QueryMsg::BridgeRoutes { assets }** Returns routes used to swap fee tokens to ASTRO
+/// using a vector of [`BridgeRouteResponse`] objects.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_get_config(deps)?),
         QueryMsg::Balances { assets } => to_binary(&query_get_balances(deps, env, assets)?),
         QueryMsg::Bridges {} => to_binary(&query_bridges(deps)?),
+        QueryMsg::PriceGuards {} => to_binary(&query_price_guards(deps)?),
+        QueryMsg::CollectKeepers {} => {
+            to_binary(&COLLECT_KEEPERS.may_load(deps.storage)?.unwrap_or_default())
+        }
+        QueryMsg::DcaSchedules {} => to_binary(&query_dca_schedules(deps, env)?),
+        QueryMsg::BridgeRoutes { assets } => to_binary(&query_bridge_routes(deps, env, assets)?),
     }
 }
 
@@ -851,6 +1288,7 @@ fn query_get_config(deps: Deps) -> StdResult<ConfigResponse> {
         pre_upgrade_astro_amount: config.pre_upgrade_astro_amount,
         default_bridge: config.default_bridge,
         second_receiver_cfg: config.second_receiver_cfg,
+        fee_recipients: config.fee_recipients,
     })
 }
 
@@ -885,6 +1323,82 @@ fn query_bridges(deps: Deps) -> StdResult<Vec<(String, String)>> {
         .collect()
 }
 
+/// Returns max allowed price deviations for guarded fee tokens.
+fn query_price_guards(deps: Deps) -> StdResult<Vec<(String, Decimal)>> {
+    PRICE_GUARDS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// Returns DCA schedules along with upcoming swaps of the current fee token balances
+/// assuming no more fees are collected.
+fn query_dca_schedules(deps: Deps, env: Env) -> StdResult<Vec<DcaScheduleResponse>> {
+    let now = env.block.time.seconds();
+
+    DCA_SCHEDULES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (_, schedule) = item?;
+            let balance = schedule
+                .asset_info
+                .query_pool(&deps.querier, &env.contract.address)?;
+
+            let mut pending_swaps = vec![];
+            let mut next_execution = schedule
+                .last_execution
+                .map(|last_execution| {
+                    now.max(last_execution.saturating_add(schedule.params.interval))
+                })
+                .unwrap_or(now);
+            let mut balance_left = balance;
+            while !balance_left.is_zero() && pending_swaps.len() < DCA_SCHEDULE_LIMIT {
+                let amount = dca_swap_amount(&schedule.params, balance_left);
+                pending_swaps.push((next_execution, amount));
+                balance_left -= amount;
+                next_execution = next_execution.saturating_add(schedule.params.interval);
+            }
+
+            Ok(DcaScheduleResponse {
+                schedule,
+                balance,
+                pending_swaps,
+            })
+        })
+        .collect()
+}
+
+/// Returns routes used to swap fee tokens to ASTRO along with simulated outputs and broken hops.
+///
+/// * **assets** array of fee tokens to check in addition to native tokens held by the Maker.
+fn query_bridge_routes(
+    deps: Deps,
+    env: Env,
+    assets: Vec<AssetInfo>,
+) -> StdResult<Vec<BridgeRouteResponse>> {
+    let cfg = CONFIG.load(deps.storage)?;
+
+    let native_assets = deps
+        .querier
+        .query_all_balances(&env.contract.address)?
+        .into_iter()
+        .map(|coin| AssetInfo::NativeToken { denom: coin.denom });
+
+    let mut uniq = HashSet::new();
+    let mut routes = vec![];
+    for info in assets.into_iter().chain(native_assets) {
+        if info.equal(&cfg.astro_token) || !uniq.insert(info.to_string()) {
+            continue;
+        }
+
+        let amount = info.query_pool(&deps.querier, &env.contract.address)?;
+        if !amount.is_zero() {
+            routes.push(trace_bridge_route(deps, &cfg, Asset { info, amount })?);
+        }
+    }
+
+    Ok(routes)
+}
+
 /// Manages contract migration.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
@@ -896,7 +1410,8 @@ pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response
                 migrate_from_v1(deps.branch(), &msg)?;
             }
             "1.2.0" => migrate_from_v120(deps.branch(), msg)?,
-            "1.3.0" => {}
+            "1.3.0" | "1.3.1" | "1.4.0" => migrate_from_v130(deps.branch())?,
+            "1.5.0" | "1.6.0" => {}
             _ => return Err(ContractError::MigrationError {}),
         },
         _ => return Err(ContractError::MigrationError {}),
@@ -1,5 +1,5 @@
 use astroport::asset::AssetInfo;
-use cosmwasm_std::{DivideByZeroError, OverflowError, StdError};
+use cosmwasm_std::{DivideByZeroError, OverflowError, StdError, Uint128};
 use thiserror::Error;
 
 /// This enum describes maker contract errors
@@ -41,10 +41,45 @@ pub enum ContractError {
     #[error("Rewards collecting is already enabled")]
     RewardsAlreadyEnabled {},
 
+    #[error("Incorrect max price deviation for {0}")]
+    IncorrectPriceDeviation(String),
+
+    #[error("Price observation for {0} is not available in pool {1}")]
+    PriceObservationUnavailable(String, String),
+
+    #[error("Swap of {asset} deviates from the observed price: expected {expected}, received {received}")]
+    PriceDeviation {
+        asset: String,
+        expected: Uint128,
+        received: Uint128,
+    },
+
+    #[error("Fee recipient shares must be positive and their sum must not exceed 1")]
+    IncorrectFeeRecipientShares {},
+
+    #[error("Duplicated fee recipient {0}")]
+    DuplicatedFeeRecipient(String),
+
+    #[error("Second receiver can't be used together with fee recipients")]
+    SecondReceiverWithFeeRecipients {},
+
+    #[error("Incorrect DCA parameters for {0}")]
+    IncorrectDcaParams(String),
+
     #[error("An error occurred during migration")]
     MigrationError {},
 }
 
+impl ContractError {
+    /// Returns true if a swap was rejected by a price guard rather than due to a missing route.
+    pub fn is_price_guard_error(&self) -> bool {
+        matches!(
+            self,
+            ContractError::PriceObservationUnavailable(..) | ContractError::PriceDeviation { .. }
+        )
+    }
+}
+
 impl From<OverflowError> for ContractError {
     fn from(o: OverflowError) -> Self {
         StdError::from(o).into()
@@ -5,7 +5,7 @@ use cw_storage_plus::Item;
 use crate::state::CONFIG;
 use crate::utils::update_second_receiver_cfg;
 use astroport::asset::{token_asset_info, AssetInfo};
-use astroport::maker::{Config, MigrateMsg};
+use astroport::maker::{Config, FeeRecipient, MigrateMsg, SecondReceiverConfig};
 
 pub(crate) fn migrate_from_v1(deps: DepsMut, msg: &MigrateMsg) -> StdResult<()> {
     #[cw_serde]
@@ -44,6 +44,7 @@ pub(crate) fn migrate_from_v1(deps: DepsMut, msg: &MigrateMsg) -> StdResult<()>
         remainder_reward: old_config.remainder_reward,
         pre_upgrade_astro_amount: old_config.pre_upgrade_astro_amount,
         second_receiver_cfg: None,
+        fee_recipients: vec![],
     };
 
     update_second_receiver_cfg(deps.as_ref(), &mut new_config, &msg.second_receiver_params)?;
@@ -85,9 +86,62 @@ pub(crate) fn migrate_from_v120(deps: DepsMut, msg: MigrateMsg) -> StdResult<()>
         remainder_reward: cfg_v120.remainder_reward,
         pre_upgrade_astro_amount: cfg_v120.pre_upgrade_astro_amount,
         second_receiver_cfg: None,
+        fee_recipients: vec![],
     };
 
     update_second_receiver_cfg(deps.as_ref(), &mut new_config, &msg.second_receiver_params)?;
 
     CONFIG.save(deps.storage, &new_config)
 }
+
+/// Moves the second receiver of fees to the list of fee recipients.
+pub(crate) fn migrate_from_v130(deps: DepsMut) -> StdResult<()> {
+    #[cw_serde]
+    struct ConfigV130 {
+        pub owner: Addr,
+        pub factory_contract: Addr,
+        pub staking_contract: Option<Addr>,
+        pub default_bridge: Option<AssetInfo>,
+        pub governance_contract: Option<Addr>,
+        pub governance_percent: Uint64,
+        pub astro_token: AssetInfo,
+        pub max_spread: Decimal,
+        pub rewards_enabled: bool,
+        pub pre_upgrade_blocks: u64,
+        pub last_distribution_block: u64,
+        pub remainder_reward: Uint128,
+        pub pre_upgrade_astro_amount: Uint128,
+        pub second_receiver_cfg: Option<SecondReceiverConfig>,
+    }
+    let cfg_v130: ConfigV130 = Item::new("config").load(deps.storage)?;
+
+    let fee_recipients = cfg_v130
+        .second_receiver_cfg
+        .into_iter()
+        .map(|second_receiver_cfg| FeeRecipient {
+            address: second_receiver_cfg.second_fee_receiver,
+            share: Decimal::percent(second_receiver_cfg.second_receiver_cut.u64()),
+            raw_asset: false,
+        })
+        .collect();
+
+    let new_config = Config {
+        owner: cfg_v130.owner,
+        factory_contract: cfg_v130.factory_contract,
+        staking_contract: cfg_v130.staking_contract,
+        default_bridge: cfg_v130.default_bridge,
+        governance_contract: cfg_v130.governance_contract,
+        governance_percent: cfg_v130.governance_percent,
+        astro_token: cfg_v130.astro_token,
+        max_spread: cfg_v130.max_spread,
+        rewards_enabled: cfg_v130.rewards_enabled,
+        pre_upgrade_blocks: cfg_v130.pre_upgrade_blocks,
+        last_distribution_block: cfg_v130.last_distribution_block,
+        remainder_reward: cfg_v130.remainder_reward,
+        pre_upgrade_astro_amount: cfg_v130.pre_upgrade_astro_amount,
+        second_receiver_cfg: None,
+        fee_recipients,
+    };
+
+    CONFIG.save(deps.storage, &new_config)
+}
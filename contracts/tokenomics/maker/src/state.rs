@@ -1,6 +1,7 @@
 use astroport::asset::AssetInfo;
 use astroport::common::OwnershipProposal;
-use astroport::maker::Config;
+use astroport::maker::{Config, DcaSchedule};
+use cosmwasm_std::{Addr, Decimal};
 use cw_storage_plus::{Item, Map};
 
 /// Stores the contract configuration at the given key
@@ -11,3 +12,12 @@ pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_pro
 
 /// Stores bridge tokens used to swap fee tokens to ASTRO
 pub const BRIDGES: Map<String, AssetInfo> = Map::new("bridges");
+
+/// Stores the max allowed deviation of a swap output from the observed pair price for each guarded fee token
+pub const PRICE_GUARDS: Map<String, Decimal> = Map::new("price_guards");
+
+/// Stores addresses allowed to call Collect. Anyone can call Collect if the list is empty
+pub const COLLECT_KEEPERS: Item<Vec<Addr>> = Item::new("collect_keepers");
+
+/// Stores DCA schedules used to swap large fee token balances in parts over time
+pub const DCA_SCHEDULES: Map<String, DcaSchedule> = Map::new("dca_schedules");
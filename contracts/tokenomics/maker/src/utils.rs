@@ -1,14 +1,17 @@
 use crate::error::ContractError;
-use crate::state::BRIDGES;
+use crate::state::{BRIDGES, PRICE_GUARDS};
 use astroport::asset::{Asset, AssetInfo, PairInfo};
+use astroport::factory::{PairsResponse, QueryMsg as FactoryQueryMsg};
 use astroport::maker::{
-    Config, ExecuteMsg, SecondReceiverConfig, SecondReceiverParams, MAX_SECOND_RECEIVER_CUT,
+    BridgeRouteResponse, BrokenHop, Config, DcaParams, ExecuteMsg, FeeRecipient,
+    FeeRecipientParams, SecondReceiverConfig, SecondReceiverParams, MAX_SECOND_RECEIVER_CUT,
 };
-use astroport::pair::Cw20HookMsg;
+use astroport::observation::OracleObservation;
+use astroport::pair::{Cw20HookMsg, PoolResponse, QueryMsg as PairQueryMsg, SimulationResponse};
 use astroport::querier::query_pair_info;
 
 use cosmwasm_std::{
-    coins, to_binary, wasm_execute, Addr, Binary, CosmosMsg, Decimal, Deps, Empty, Env,
+    coins, to_binary, wasm_execute, Addr, Binary, CosmosMsg, Decimal, Deps, Empty, Env, Fraction,
     QuerierWrapper, StdError, StdResult, SubMsg, Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
@@ -19,6 +22,8 @@ pub const BRIDGES_INITIAL_DEPTH: u64 = 0;
 pub const BRIDGES_MAX_DEPTH: u64 = 2;
 /// Swap execution depth limit
 pub const BRIDGES_EXECUTION_MAX_DEPTH: u64 = 3;
+/// The number of pairs read from the factory at once
+const PAIRS_QUERY_LIMIT: u32 = 30;
 
 /// The function checks from<>to pool exists and creates swap message.
 ///
@@ -28,17 +33,107 @@ pub const BRIDGES_EXECUTION_MAX_DEPTH: u64 = 3;
 ///
 /// * **amount_in** amount of tokens to swap.
 pub fn try_build_swap_msg(
-    querier: &QuerierWrapper,
+    deps: Deps,
     cfg: &Config,
     from: &AssetInfo,
     to: &AssetInfo,
     amount_in: Uint128,
 ) -> Result<SubMsg, ContractError> {
-    let pool = get_pool(querier, &cfg.factory_contract, from, to)?;
+    let pool = get_pool(&deps.querier, &cfg.factory_contract, from, to)?;
+    assert_price_deviation(deps, &pool, from, to, amount_in)?;
     let msg = build_swap_msg(cfg.max_spread, &pool, from, Some(to), amount_in)?;
     Ok(msg)
 }
 
+/// Checks that the swap output doesn't deviate from the price observed in the pool more than allowed
+/// for the fee token. The price is observed one second ago thus swaps executed in the same block
+/// as the Maker's one can't move it.
+///
+/// * **pool** pool's information.
+///
+/// * **from** asset we want to swap.
+///
+/// * **to** asset we want to swap to.
+///
+/// * **amount_in** amount of tokens to swap.
+pub fn assert_price_deviation(
+    deps: Deps,
+    pool: &PairInfo,
+    from: &AssetInfo,
+    to: &AssetInfo,
+    amount_in: Uint128,
+) -> Result<(), ContractError> {
+    let max_deviation = match PRICE_GUARDS.may_load(deps.storage, from.to_string())? {
+        Some(max_deviation) => max_deviation,
+        None => return Ok(()),
+    };
+
+    let observation_unavailable = || {
+        ContractError::PriceObservationUnavailable(from.to_string(), pool.contract_addr.to_string())
+    };
+    let observation: OracleObservation = deps
+        .querier
+        .query_wasm_smart(
+            &pool.contract_addr,
+            &PairQueryMsg::Observe { seconds_ago: 1 },
+        )
+        .map_err(|_| observation_unavailable())?;
+    if observation.price.is_zero() {
+        return Err(observation_unavailable());
+    }
+
+    // The observed price is the amount of the first pool asset per one unit of the second one
+    let expected = if pool.asset_infos[0].equal(from) {
+        amount_in.multiply_ratio(
+            observation.price.denominator(),
+            observation.price.numerator(),
+        )
+    } else {
+        amount_in * observation.price
+    };
+
+    let received = simulate_swap(&deps.querier, pool, from, to, amount_in)?;
+    if received < expected * (Decimal::one() - max_deviation) {
+        return Err(ContractError::PriceDeviation {
+            asset: from.to_string(),
+            expected,
+            received,
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the amount of `to` tokens received for swapping `amount_in` of `from` tokens in the pool.
+///
+/// * **pool** pool's information.
+///
+/// * **from** asset we want to swap.
+///
+/// * **to** asset we want to swap to.
+///
+/// * **amount_in** amount of tokens to swap.
+pub fn simulate_swap(
+    querier: &QuerierWrapper,
+    pool: &PairInfo,
+    from: &AssetInfo,
+    to: &AssetInfo,
+    amount_in: Uint128,
+) -> StdResult<Uint128> {
+    let simulation: SimulationResponse = querier.query_wasm_smart(
+        &pool.contract_addr,
+        &PairQueryMsg::Simulation {
+            offer_asset: Asset {
+                info: from.clone(),
+                amount: amount_in,
+            },
+            ask_asset_info: Some(to.clone()),
+        },
+    )?;
+
+    Ok(simulation.return_amount)
+}
+
 /// This function creates swap message.
 ///
 /// * **max_spread** max allowed spread.
@@ -253,3 +348,224 @@ pub fn update_second_receiver_cfg(
 
     Ok(())
 }
+
+/// Replaces the list of fee recipients. Each share must be positive and the sum of all shares
+/// must not exceed 1.
+pub fn update_fee_recipients(
+    deps: Deps,
+    cfg: &mut Config,
+    params: Option<Vec<FeeRecipientParams>>,
+) -> Result<(), ContractError> {
+    if let Some(params) = params {
+        let mut total_share = Decimal::zero();
+        let mut fee_recipients: Vec<FeeRecipient> = Vec::with_capacity(params.len());
+
+        for params in params {
+            let address = deps.api.addr_validate(&params.address)?;
+            if fee_recipients
+                .iter()
+                .any(|recipient| recipient.address == address)
+            {
+                return Err(ContractError::DuplicatedFeeRecipient(address.to_string()));
+            }
+
+            total_share = total_share.checked_add(params.share)?;
+            if params.share.is_zero() || total_share > Decimal::one() {
+                return Err(ContractError::IncorrectFeeRecipientShares {});
+            }
+
+            fee_recipients.push(FeeRecipient {
+                address,
+                share: params.share,
+                raw_asset: params.raw_asset,
+            });
+        }
+
+        cfg.fee_recipients = fee_recipients;
+    }
+
+    Ok(())
+}
+
+/// Splits the amount of a collected fee token between recipients who receive fee tokens as is.
+/// Returns transfer messages along with the amount left for swapping to ASTRO.
+///
+/// * **asset** fee token with the amount being collected.
+pub fn build_raw_fee_msgs(cfg: &Config, asset: &Asset) -> StdResult<(Vec<SubMsg>, Uint128)> {
+    let mut messages = vec![];
+    let mut amount_left = asset.amount;
+
+    for recipient in cfg.fee_recipients.iter().filter(|r| r.raw_asset) {
+        let amount = asset.amount * recipient.share;
+        if !amount.is_zero() {
+            let raw_asset = Asset {
+                info: asset.info.clone(),
+                amount,
+            };
+            messages.push(SubMsg::new(raw_asset.into_msg(&recipient.address)?));
+            amount_left = amount_left.checked_sub(amount)?;
+        }
+    }
+
+    Ok((messages, amount_left))
+}
+
+/// Returns the amount of a fee token swapped at once according to its DCA parameters.
+///
+/// * **balance** current fee token balance.
+pub fn dca_swap_amount(params: &DcaParams, balance: Uint128) -> Uint128 {
+    (balance * params.max_fraction)
+        .max(params.min_swap_amount)
+        .min(balance)
+}
+
+/// Follows the route the Maker uses to swap a fee token to ASTRO and simulates swaps along it.
+/// Hops that can't be executed are reported instead of returning an error.
+///
+/// * **asset** fee token with the amount to swap.
+pub fn trace_bridge_route(
+    deps: Deps,
+    cfg: &Config,
+    asset: Asset,
+) -> StdResult<BridgeRouteResponse> {
+    let mut route = vec![asset.info.clone()];
+    let mut broken_hops = vec![];
+    let mut from = asset.info.clone();
+    let mut amount = asset.amount;
+
+    // The first swap is sent by Collect and each next one is sent by SwapBridgeAssets
+    for depth in 0..=BRIDGES_EXECUTION_MAX_DEPTH {
+        let (to, pool) = match BRIDGES.may_load(deps.storage, from.to_string())? {
+            Some(bridge) => {
+                let pool = get_pool(&deps.querier, &cfg.factory_contract, &from, &bridge);
+                (bridge, pool)
+            }
+            None => {
+                let default_bridge = cfg
+                    .default_bridge
+                    .as_ref()
+                    .filter(|default_bridge| depth == 0 && !from.equal(default_bridge));
+                let default_pool = default_bridge.map(|default_bridge| {
+                    get_pool(&deps.querier, &cfg.factory_contract, &from, default_bridge)
+                });
+
+                match (default_bridge, default_pool) {
+                    (Some(default_bridge), Some(Ok(pool))) => (default_bridge.clone(), Ok(pool)),
+                    (default_bridge, _) => {
+                        let pool = get_pool(
+                            &deps.querier,
+                            &cfg.factory_contract,
+                            &from,
+                            &cfg.astro_token,
+                        );
+                        // Report the missing default bridge pool only if there is no direct pool either
+                        if let (Some(default_bridge), Err(_)) = (default_bridge, &pool) {
+                            broken_hops.push(BrokenHop {
+                                from: from.clone(),
+                                to: default_bridge.clone(),
+                                reason: "Pair not found".to_string(),
+                            });
+                        }
+                        (cfg.astro_token.clone(), pool)
+                    }
+                }
+            }
+        };
+
+        let hop_result = pool
+            .map_err(|_| "Pair not found".to_string())
+            .and_then(|pool| {
+                simulate_swap(&deps.querier, &pool, &from, &to, amount)
+                    .map_err(|err| err.to_string())
+            });
+        match hop_result {
+            Ok(return_amount) => amount = return_amount,
+            Err(reason) => {
+                broken_hops.push(BrokenHop {
+                    from: from.clone(),
+                    to: to.clone(),
+                    reason,
+                });
+                break;
+            }
+        }
+
+        route.push(to.clone());
+        if to.equal(&cfg.astro_token) {
+            return Ok(BridgeRouteResponse {
+                asset,
+                route_exists: true,
+                route,
+                simulated_output: amount,
+                broken_hops,
+            });
+        }
+        from = to;
+    }
+
+    if broken_hops.is_empty() {
+        broken_hops.push(BrokenHop {
+            from,
+            to: cfg.astro_token.clone(),
+            reason: ContractError::MaxBridgeDepth(BRIDGES_EXECUTION_MAX_DEPTH).to_string(),
+        });
+    }
+
+    Ok(BridgeRouteResponse {
+        asset,
+        route_exists: false,
+        route,
+        simulated_output: Uint128::zero(),
+        broken_hops,
+    })
+}
+
+/// Returns all pairs registered in the factory.
+///
+/// * **factory_contract** address of the factory contract.
+pub fn query_all_pairs(
+    querier: &QuerierWrapper,
+    factory_contract: &Addr,
+) -> StdResult<Vec<PairInfo>> {
+    let mut pairs: Vec<PairInfo> = vec![];
+
+    loop {
+        let start_after = pairs.last().map(|pair| pair.asset_infos.clone());
+        let response: PairsResponse = querier.query_wasm_smart(
+            factory_contract,
+            &FactoryQueryMsg::Pairs {
+                start_after,
+                limit: Some(PAIRS_QUERY_LIMIT),
+            },
+        )?;
+
+        let received = response.pairs.len();
+        pairs.extend(response.pairs);
+        if received < PAIRS_QUERY_LIMIT as usize {
+            break;
+        }
+    }
+
+    Ok(pairs)
+}
+
+/// Returns the amount of the asset in the pool.
+///
+/// * **pool** pool's information.
+///
+/// * **asset_info** asset whose amount is returned.
+pub fn query_pool_depth(
+    querier: &QuerierWrapper,
+    pool: &PairInfo,
+    asset_info: &AssetInfo,
+) -> StdResult<Uint128> {
+    let response: PoolResponse =
+        querier.query_wasm_smart(&pool.contract_addr, &PairQueryMsg::Pool {})?;
+
+    Ok(response
+        .assets
+        .into_iter()
+        .find(|asset| asset.info.equal(asset_info))
+        .map(|asset| asset.amount)
+        .unwrap_or_default())
+}
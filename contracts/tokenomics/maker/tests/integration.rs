@@ -5,7 +5,8 @@ use astroport::asset::{
 };
 use astroport::factory::{PairConfig, PairType, UpdateAddr};
 use astroport::maker::{
-    AssetWithLimit, BalancesResponse, ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg,
+    AssetWithLimit, BalancesResponse, BridgeRouteResponse, BrokenHop, ConfigResponse, DcaParams,
+    DcaScheduleResponse, ExecuteMsg, FeeRecipient, FeeRecipientParams, InstantiateMsg, QueryMsg,
     SecondReceiverConfig, SecondReceiverParams,
 };
 use astroport::token::InstantiateMsg as TokenInstantiateMsg;
@@ -173,6 +174,7 @@ fn instantiate_contracts(
             maker_fee_bps: 0,
             is_disabled: false,
             is_generator_disabled: false,
+            pair_creation_fee: None,
         }],
         token_code_id: 1u64,
         fee_address: None,
@@ -238,6 +240,7 @@ fn instantiate_contracts(
         default_bridge: Some(native_asset_info("uluna".to_string())),
         max_spread,
         second_receiver_params,
+        fee_recipients: None,
     };
     let maker_instance = router
         .instantiate_contract(
@@ -508,6 +511,7 @@ fn update_config() {
         basic_asset: None,
         max_spread: Some(new_max_spread),
         second_receiver_params: None,
+        fee_recipients: None,
     };
 
     // Assert cannot update with improper owner
@@ -549,6 +553,7 @@ fn update_config() {
             second_fee_receiver: "second_fee_receiver".to_string(),
             second_receiver_cut: Default::default(),
         }),
+        fee_recipients: None,
     };
 
     let err = router
@@ -567,6 +572,7 @@ fn update_config() {
             second_fee_receiver: "second_fee_receiver".to_string(),
             second_receiver_cut: Uint64::new(10),
         }),
+        fee_recipients: None,
     };
 
     router
@@ -2574,3 +2580,863 @@ fn collect_3pools() {
     assert_eq!(balances[0].amount.u128(), 100_000);
     assert_eq!(balances[1].amount.u128(), 100_000);
 }
+
+#[test]
+fn collect_with_price_guard() {
+    let owner = Addr::unchecked("owner");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+        ],
+    );
+    let user = Addr::unchecked("user0000");
+    let attacker = Addr::unchecked("attacker");
+    let keeper = Addr::unchecked("keeper");
+    let staking = Addr::unchecked("staking");
+    let governance_percent = Uint64::new(50);
+
+    let (astro_token_instance, factory_instance, maker_instance, _) = instantiate_contracts(
+        &mut router,
+        owner.clone(),
+        staking.clone(),
+        governance_percent,
+        None,
+        None,
+        None,
+    );
+
+    let uusd = native_asset_info("uusd".to_string());
+    let pair_info = create_pair(
+        &mut router,
+        owner.clone(),
+        user.clone(),
+        &factory_instance,
+        vec![
+            native_asset("uusd".to_string(), Uint128::new(1_000_000_000)),
+            token_asset(astro_token_instance.clone(), Uint128::new(1_000_000_000)),
+        ],
+        None,
+    );
+
+    let swap_uusd = |router: &mut App, sender: &Addr, amount: u128| {
+        router
+            .execute_contract(
+                sender.clone(),
+                pair_info.contract_addr.clone(),
+                &astroport::pair::ExecuteMsg::Swap {
+                    offer_asset: native_asset("uusd".to_string(), Uint128::new(amount)),
+                    ask_asset_info: None,
+                    belief_price: None,
+                    max_spread: Some(Decimal::percent(50)),
+                    to: None,
+                },
+                &[coin(amount, "uusd")],
+            )
+            .unwrap();
+    };
+
+    let err = router
+        .execute_contract(
+            user.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdatePriceGuards {
+                add: Some(vec![(uusd.clone(), Decimal::percent(5))]),
+                remove: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdatePriceGuards {
+                add: Some(vec![(uusd.clone(), Decimal::one())]),
+                remove: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Incorrect max price deviation for uusd"
+    );
+
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdatePriceGuards {
+                add: Some(vec![(uusd.clone(), Decimal::percent(5))]),
+                remove: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let price_guards: Vec<(String, Decimal)> = router
+        .wrap()
+        .query_wasm_smart(&maker_instance, &QueryMsg::PriceGuards {})
+        .unwrap();
+    assert_eq!(
+        price_guards,
+        vec![("uusd".to_string(), Decimal::percent(5))]
+    );
+
+    router
+        .send_tokens(
+            owner.clone(),
+            maker_instance.clone(),
+            &[coin(1_000_000, "uusd")],
+        )
+        .unwrap();
+
+    let collect_msg = ExecuteMsg::Collect {
+        assets: vec![AssetWithLimit {
+            info: uusd.clone(),
+            limit: None,
+        }],
+    };
+
+    // The pair has no observations yet
+    let err = router
+        .execute_contract(user.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!(
+            "Price observation for uusd is not available in pool {}",
+            pair_info.contract_addr
+        )
+    );
+
+    swap_uusd(&mut router, &user, 10_000_000);
+    router.update_block(next_block);
+
+    // Only keepers and the owner can collect once the keeper list is set
+    let err = router
+        .execute_contract(
+            user.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdateCollectKeepers {
+                add: Some(vec![keeper.to_string()]),
+                remove: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdateCollectKeepers {
+                add: Some(vec![keeper.to_string(), keeper.to_string()]),
+                remove: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let keepers: Vec<Addr> = router
+        .wrap()
+        .query_wasm_smart(&maker_instance, &QueryMsg::CollectKeepers {})
+        .unwrap();
+    assert_eq!(keepers, vec![keeper.clone()]);
+
+    let err = router
+        .execute_contract(user.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    router
+        .execute_contract(keeper.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap();
+
+    let maker_balance = router
+        .wrap()
+        .query_balance(&maker_instance, "uusd")
+        .unwrap();
+    assert_eq!(maker_balance.amount, Uint128::zero());
+
+    router.update_block(next_block);
+    router
+        .send_tokens(
+            owner.clone(),
+            maker_instance.clone(),
+            &[coin(1_000_000, "uusd")],
+        )
+        .unwrap();
+    router
+        .send_tokens(
+            owner.clone(),
+            attacker.clone(),
+            &[coin(200_000_000, "uusd")],
+        )
+        .unwrap();
+
+    // Swap executed in the same block moves the price but not the observation
+    swap_uusd(&mut router, &attacker, 200_000_000);
+
+    let err = router
+        .execute_contract(owner.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap_err();
+    assert!(err
+        .root_cause()
+        .to_string()
+        .starts_with("Swap of uusd deviates from the observed price"));
+
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdatePriceGuards {
+                add: None,
+                remove: Some(vec![uusd.clone()]),
+            },
+            &[],
+        )
+        .unwrap();
+
+    router
+        .execute_contract(keeper, maker_instance.clone(), &collect_msg, &[])
+        .unwrap();
+}
+
+#[test]
+fn collect_with_fee_recipients() {
+    let owner = Addr::unchecked("owner");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+        ],
+    );
+    let user = Addr::unchecked("user0000");
+    let staking = Addr::unchecked("staking");
+    let treasury = Addr::unchecked("treasury");
+    let partner = Addr::unchecked("partner");
+    let governance_percent = Uint64::new(10);
+
+    let (astro_token_instance, factory_instance, maker_instance, governance_instance) =
+        instantiate_contracts(
+            &mut router,
+            owner.clone(),
+            staking.clone(),
+            governance_percent,
+            Some(Decimal::percent(50)),
+            None,
+            None,
+        );
+
+    create_pair(
+        &mut router,
+        owner.clone(),
+        user.clone(),
+        &factory_instance,
+        vec![
+            native_asset("uusd".to_string(), Uint128::new(1_000_000_000)),
+            token_asset(astro_token_instance.clone(), Uint128::new(1_000_000_000)),
+        ],
+        None,
+    );
+
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::EnableRewards { blocks: 1 },
+            &[],
+        )
+        .unwrap();
+
+    let update_fee_recipients =
+        |fee_recipients: Vec<(&Addr, u64, bool)>| ExecuteMsg::UpdateConfig {
+            factory_contract: None,
+            staking_contract: None,
+            governance_contract: None,
+            governance_percent: None,
+            basic_asset: None,
+            max_spread: None,
+            second_receiver_params: None,
+            fee_recipients: Some(
+                fee_recipients
+                    .into_iter()
+                    .map(|(address, share, raw_asset)| FeeRecipientParams {
+                        address: address.to_string(),
+                        share: Decimal::percent(share),
+                        raw_asset,
+                    })
+                    .collect(),
+            ),
+        };
+
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &update_fee_recipients(vec![(&treasury, 60, false), (&partner, 50, true)]),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Fee recipient shares must be positive and their sum must not exceed 1"
+    );
+
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &update_fee_recipients(vec![(&treasury, 20, false), (&treasury, 30, true)]),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Duplicated fee recipient treasury"
+    );
+
+    // The second receiver and fee recipients can't be set together
+    let mut msg = update_fee_recipients(vec![(&treasury, 20, false), (&partner, 30, true)]);
+    if let ExecuteMsg::UpdateConfig {
+        second_receiver_params,
+        ..
+    } = &mut msg
+    {
+        *second_receiver_params = Some(SecondReceiverParams {
+            second_fee_receiver: partner.to_string(),
+            second_receiver_cut: Uint64::new(10),
+        });
+    }
+    let err = router
+        .execute_contract(owner.clone(), maker_instance.clone(), &msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Second receiver can't be used together with fee recipients"
+    );
+
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &update_fee_recipients(vec![(&treasury, 20, false), (&partner, 30, true)]),
+            &[],
+        )
+        .unwrap();
+
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdateConfig {
+                factory_contract: None,
+                staking_contract: None,
+                governance_contract: None,
+                governance_percent: None,
+                basic_asset: None,
+                max_spread: None,
+                second_receiver_params: Some(SecondReceiverParams {
+                    second_fee_receiver: partner.to_string(),
+                    second_receiver_cut: Uint64::new(10),
+                }),
+                fee_recipients: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Second receiver can't be used together with fee recipients"
+    );
+
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(&maker_instance, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(
+        res.fee_recipients,
+        vec![
+            FeeRecipient {
+                address: treasury.clone(),
+                share: Decimal::percent(20),
+                raw_asset: false,
+            },
+            FeeRecipient {
+                address: partner.clone(),
+                share: Decimal::percent(30),
+                raw_asset: true,
+            }
+        ]
+    );
+
+    router
+        .send_tokens(
+            owner.clone(),
+            maker_instance.clone(),
+            &[coin(1_000_000, "uusd")],
+        )
+        .unwrap();
+
+    router
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            maker_instance.clone(),
+            &ExecuteMsg::Collect {
+                assets: vec![AssetWithLimit {
+                    info: native_asset_info("uusd".to_string()),
+                    limit: None,
+                }],
+            },
+            &[],
+        )
+        .unwrap();
+
+    // The partner receives its share in the collected fee token
+    let partner_balance = router.wrap().query_balance(&partner, "uusd").unwrap();
+    assert_eq!(partner_balance.amount.u128(), 300_000);
+    let maker_balance = router
+        .wrap()
+        .query_balance(&maker_instance, "uusd")
+        .unwrap();
+    assert_eq!(maker_balance.amount.u128(), 0);
+
+    let astro_balance = |router: &App, address: &Addr| -> Uint128 {
+        let res: BalanceResponse = router
+            .wrap()
+            .query_wasm_smart(
+                &astro_token_instance,
+                &Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        res.balance
+    };
+
+    let treasury_amount = astro_balance(&router, &treasury);
+    let governance_amount = astro_balance(&router, &governance_instance);
+    let staking_amount = astro_balance(&router, &staking);
+    assert_eq!(astro_balance(&router, &maker_instance), Uint128::zero());
+
+    // The rest 70% of fees were swapped to ASTRO thus the treasury gets 20/70 of ASTRO,
+    // i.e. 20% of total fees
+    let total_amount = treasury_amount + governance_amount + staking_amount;
+    assert!(!total_amount.is_zero());
+    assert_eq!(treasury_amount, total_amount.multiply_ratio(2u128, 7u128));
+    // 200_000 uusd worth of ASTRO minus swap slippage
+    assert!(treasury_amount.u128() > 199_000 && treasury_amount.u128() <= 200_000);
+    let rest_amount = total_amount - treasury_amount;
+    assert_eq!(
+        governance_amount,
+        rest_amount.multiply_ratio(Uint128::from(governance_percent), Uint128::new(100))
+    );
+    assert_eq!(staking_amount, rest_amount - governance_amount);
+}
+
+#[test]
+fn collect_with_dca_schedule() {
+    let owner = Addr::unchecked("owner");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+        ],
+    );
+    let user = Addr::unchecked("user0000");
+    let staking = Addr::unchecked("staking");
+    let governance_percent = Uint64::new(10);
+
+    let (astro_token_instance, factory_instance, maker_instance, _) = instantiate_contracts(
+        &mut router,
+        owner.clone(),
+        staking.clone(),
+        governance_percent,
+        None,
+        None,
+        None,
+    );
+
+    create_pair(
+        &mut router,
+        owner.clone(),
+        user.clone(),
+        &factory_instance,
+        vec![
+            native_asset("uusd".to_string(), Uint128::new(1_000_000_000)),
+            token_asset(astro_token_instance.clone(), Uint128::new(1_000_000_000)),
+        ],
+        None,
+    );
+
+    let uusd = native_asset_info("uusd".to_string());
+    let dca_params = DcaParams {
+        max_fraction: Decimal::percent(25),
+        interval: 3600,
+        min_swap_amount: Uint128::new(100_000),
+    };
+
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdateDcaSchedules {
+                add: Some(vec![(
+                    token_asset_info(astro_token_instance.clone()),
+                    dca_params.clone(),
+                )]),
+                remove: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!("Incorrect DCA parameters for {}", astro_token_instance)
+    );
+
+    let err = router
+        .execute_contract(
+            user.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdateDcaSchedules {
+                add: Some(vec![(uusd.clone(), dca_params.clone())]),
+                remove: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdateDcaSchedules {
+                add: Some(vec![(uusd.clone(), dca_params.clone())]),
+                remove: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    router
+        .send_tokens(
+            owner.clone(),
+            maker_instance.clone(),
+            &[coin(1_000_000, "uusd")],
+        )
+        .unwrap();
+
+    let now = router.block_info().time.seconds();
+    let schedules: Vec<DcaScheduleResponse> = router
+        .wrap()
+        .query_wasm_smart(&maker_instance, &QueryMsg::DcaSchedules {})
+        .unwrap();
+    assert_eq!(schedules.len(), 1);
+    assert_eq!(schedules[0].schedule.last_execution, None);
+    assert_eq!(schedules[0].balance.u128(), 1_000_000);
+    assert_eq!(
+        schedules[0].pending_swaps[..2],
+        [
+            (now, Uint128::new(250_000)),
+            (now + 3600, Uint128::new(187_500))
+        ]
+    );
+    // Balances below the min swap amount are swapped at once
+    assert_eq!(
+        schedules[0].pending_swaps.last().unwrap().1,
+        Uint128::new(16_407)
+    );
+
+    let collect_msg = ExecuteMsg::Collect {
+        assets: vec![AssetWithLimit {
+            info: uusd.clone(),
+            limit: None,
+        }],
+    };
+    let uusd_balance = |router: &App| {
+        router
+            .wrap()
+            .query_balance(&maker_instance, "uusd")
+            .unwrap()
+            .amount
+            .u128()
+    };
+
+    router
+        .execute_contract(user.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap();
+    assert_eq!(uusd_balance(&router), 750_000);
+
+    // The interval has not passed yet
+    router.update_block(next_block);
+    router
+        .execute_contract(user.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap();
+    assert_eq!(uusd_balance(&router), 750_000);
+
+    let schedules: Vec<DcaScheduleResponse> = router
+        .wrap()
+        .query_wasm_smart(&maker_instance, &QueryMsg::DcaSchedules {})
+        .unwrap();
+    assert_eq!(schedules[0].schedule.last_execution, Some(now));
+    assert_eq!(schedules[0].schedule.remaining.u128(), 750_000);
+    assert_eq!(
+        schedules[0].pending_swaps[0],
+        (now + 3600, Uint128::new(187_500))
+    );
+
+    router.update_block(|block| {
+        block.time = block.time.plus_seconds(3600);
+        block.height += 1;
+    });
+    router
+        .execute_contract(user.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap();
+    assert_eq!(uusd_balance(&router), 562_500);
+
+    // Huge intervals postpone the next swap instead of overflowing
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdateDcaSchedules {
+                add: Some(vec![(
+                    uusd.clone(),
+                    DcaParams {
+                        interval: u64::MAX,
+                        ..dca_params
+                    },
+                )]),
+                remove: None,
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(user.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap();
+    assert_eq!(uusd_balance(&router), 562_500);
+    let schedules: Vec<DcaScheduleResponse> = router
+        .wrap()
+        .query_wasm_smart(&maker_instance, &QueryMsg::DcaSchedules {})
+        .unwrap();
+    assert_eq!(schedules[0].pending_swaps[0].0, u64::MAX);
+
+    // Removing the schedule swaps the whole balance at once
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdateDcaSchedules {
+                add: None,
+                remove: Some(vec![uusd.clone()]),
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(user, maker_instance.clone(), &collect_msg, &[])
+        .unwrap();
+    assert_eq!(uusd_balance(&router), 0);
+}
+
+#[test]
+fn bridge_routes_and_auto_fill() {
+    let owner = Addr::unchecked("owner");
+    let mut router = mock_app(
+        owner.clone(),
+        ["uluna", "uusd", "ukrt", "uabc"]
+            .iter()
+            .map(|denom| coin(100_000_000_000u128, *denom))
+            .collect(),
+    );
+    let user = Addr::unchecked("user0000");
+    let staking = Addr::unchecked("staking");
+    let governance_percent = Uint64::new(10);
+
+    let (astro_token_instance, factory_instance, maker_instance, _) = instantiate_contracts(
+        &mut router,
+        owner.clone(),
+        staking.clone(),
+        governance_percent,
+        None,
+        None,
+        None,
+    );
+    let astro = token_asset_info(astro_token_instance.clone());
+    let uluna = native_asset_info("uluna".to_string());
+    let uusd = native_asset_info("uusd".to_string());
+    let ukrt = native_asset_info("ukrt".to_string());
+    let uabc = native_asset_info("uabc".to_string());
+
+    for pair in [
+        vec![
+            native_asset("uluna".to_string(), Uint128::new(1_000_000_000)),
+            token_asset(astro_token_instance.clone(), Uint128::new(1_000_000_000)),
+        ],
+        vec![
+            native_asset("uusd".to_string(), Uint128::new(1_000_000_000)),
+            native_asset("uluna".to_string(), Uint128::new(1_000_000_000)),
+        ],
+        vec![
+            native_asset("uusd".to_string(), Uint128::new(1_000_000)),
+            token_asset(astro_token_instance.clone(), Uint128::new(1_000_000)),
+        ],
+        vec![
+            native_asset("ukrt".to_string(), Uint128::new(1_000_000_000)),
+            native_asset("uusd".to_string(), Uint128::new(1_000_000_000)),
+        ],
+    ] {
+        create_pair(
+            &mut router,
+            owner.clone(),
+            user.clone(),
+            &factory_instance,
+            pair,
+            None,
+        );
+    }
+
+    router
+        .send_tokens(
+            owner.clone(),
+            maker_instance.clone(),
+            &[coin(1000, "uabc"), coin(1000, "ukrt"), coin(1000, "uusd")],
+        )
+        .unwrap();
+
+    let query_routes = |router: &App| -> Vec<BridgeRouteResponse> {
+        router
+            .wrap()
+            .query_wasm_smart(
+                &maker_instance,
+                &QueryMsg::BridgeRoutes {
+                    assets: vec![astro.clone()],
+                },
+            )
+            .unwrap()
+    };
+
+    let routes = query_routes(&router);
+    assert_eq!(routes.len(), 3);
+
+    // No pools with the default bridge and ASTRO
+    assert_eq!(routes[0].asset.info, uabc);
+    assert!(!routes[0].route_exists);
+    assert_eq!(routes[0].simulated_output, Uint128::zero());
+    assert_eq!(
+        routes[0].broken_hops,
+        vec![
+            BrokenHop {
+                from: uabc.clone(),
+                to: uluna.clone(),
+                reason: "Pair not found".to_string(),
+            },
+            BrokenHop {
+                from: uabc.clone(),
+                to: astro.clone(),
+                reason: "Pair not found".to_string(),
+            }
+        ]
+    );
+
+    // The bridge is not set yet
+    assert_eq!(routes[1].asset.info, ukrt);
+    assert!(!routes[1].route_exists);
+    assert_eq!(routes[1].broken_hops.len(), 2);
+
+    // Swapped through the default bridge
+    assert_eq!(
+        routes[2].asset,
+        native_asset("uusd".to_string(), Uint128::new(1000))
+    );
+    assert!(routes[2].route_exists);
+    assert_eq!(
+        routes[2].route,
+        vec![uusd.clone(), uluna.clone(), astro.clone()]
+    );
+    assert!(!routes[2].simulated_output.is_zero());
+    assert!(routes[2].broken_hops.is_empty());
+
+    let auto_fill_msg = ExecuteMsg::AutoFillBridges {
+        assets: vec![uabc.clone(), ukrt.clone(), uusd.clone()],
+    };
+    let err = router
+        .execute_contract(user.clone(), maker_instance.clone(), &auto_fill_msg, &[])
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    router
+        .execute_contract(owner.clone(), maker_instance.clone(), &auto_fill_msg, &[])
+        .unwrap();
+
+    // The deepest uusd pool is paired with the default bridge rather than ASTRO
+    let bridges: Vec<(String, String)> = router
+        .wrap()
+        .query_wasm_smart(&maker_instance, &QueryMsg::Bridges {})
+        .unwrap();
+    assert_eq!(
+        bridges,
+        vec![
+            ("ukrt".to_string(), "uusd".to_string()),
+            ("uusd".to_string(), "uluna".to_string()),
+        ]
+    );
+
+    let routes = query_routes(&router);
+    assert!(!routes[0].route_exists);
+    assert!(routes[1].route_exists);
+    assert_eq!(
+        routes[1].route,
+        vec![ukrt.clone(), uusd.clone(), uluna.clone(), astro.clone()]
+    );
+    assert!(routes[2].route_exists);
+
+    router
+        .execute_contract(
+            Addr::unchecked("anyone"),
+            maker_instance.clone(),
+            &ExecuteMsg::Collect {
+                assets: vec![
+                    AssetWithLimit {
+                        info: ukrt,
+                        limit: None,
+                    },
+                    AssetWithLimit {
+                        info: uusd,
+                        limit: None,
+                    },
+                ],
+            },
+            &[],
+        )
+        .unwrap();
+}
@@ -1,20 +1,27 @@
 use cosmwasm_std::{
-    attr, entry_point, from_binary, to_binary, wasm_execute, Addr, Binary, CosmosMsg, Deps,
-    DepsMut, Env, MessageInfo, Reply, ReplyOn, Response, StdError, StdResult, SubMsg,
+    attr, entry_point, from_binary, to_binary, wasm_execute, Addr, Binary, CosmosMsg, Decimal,
+    Deps, DepsMut, Env, MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, SubMsg,
     SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
 };
+use cw_storage_plus::Bound;
 use cw_utils::parse_instantiate_response_data;
 
 use crate::error::ContractError;
-use crate::state::{Config, CONFIG};
+use crate::migration;
+use crate::state::{
+    Config, ASTRO_TOKEN_ADDR_UPDATE, CONFIG, LOCK_POSITIONS, LOCK_TIERS, NEXT_LOCK_POSITION_ID,
+    OWNERSHIP_PROPOSAL, TOTAL_LOCK_WEIGHT, TOTAL_UNBONDING, UNBONDINGS, USER_LOCK_WEIGHT,
+};
+use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
 use astroport::staking::{
-    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
+    AstroTokenAddrUpdate, ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, LockPosition,
+    LockTier, MigrateMsg, QueryMsg, Unbonding,
 };
 use cw2::{get_contract_version, set_contract_version};
-use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg, MinterResponse};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20ReceiveMsg, MinterResponse};
 
 use astroport::querier::{query_supply, query_token_balance};
-use astroport::xastro_token::InstantiateMsg as TokenInstantiateMsg;
+use astroport::xastro_token::{InstantiateMsg as TokenInstantiateMsg, QueryMsg as TokenQueryMsg};
 
 /// Contract name that is used for migration.
 const CONTRACT_NAME: &str = "ito-staking";
@@ -31,6 +38,24 @@ const INSTANTIATE_TOKEN_REPLY_ID: u64 = 1;
 /// Minimum initial xastro share
 pub(crate) const MINIMUM_STAKE_AMOUNT: Uint128 = Uint128::new(1_000);
 
+/// The time lock for deposit token address changes (7 days)
+pub(crate) const ASTRO_TOKEN_UPDATE_TIMELOCK: u64 = 7 * 86400;
+
+/// The maximum lock duration (4 years)
+const MAX_LOCK_DURATION: u64 = 4 * 365 * 86400;
+/// The maximum lock weight multiplier of a lock tier
+const MAX_LOCK_MULTIPLIER: Decimal = Decimal::raw(10_000000000000000000);
+
+/// The maximum unbonding period (30 days)
+const MAX_UNBONDING_PERIOD: u64 = 30 * 86400;
+/// The maximum amount of pending unbondings per user
+const MAX_PENDING_UNBONDINGS: usize = 20;
+
+/// Default pagination limit
+const DEFAULT_LIMIT: u32 = 10;
+/// Maximum pagination limit
+const MAX_LIMIT: u32 = 30;
+
 /// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -47,6 +72,8 @@ pub fn instantiate(
         &Config {
             astro_token_addr: deps.api.addr_validate(&msg.deposit_token_addr)?,
             xastro_token_addr: Addr::unchecked(""),
+            owner: deps.api.addr_validate(&msg.owner)?,
+            unbonding_period: 0,
         },
     )?;
 
@@ -83,6 +110,26 @@ pub fn instantiate(
 /// ## Variants
 /// * **ExecuteMsg::Receive(msg)** Receives a message of type [`Cw20ReceiveMsg`] and processes
 /// it depending on the received template.
+///
+/// * **ExecuteMsg::UpdateAstroTokenAddr { new_addr }** Schedules a change of the deposit token address.
+///
+/// * **ExecuteMsg::ApplyAstroTokenAddrUpdate {}** Applies the scheduled change of the deposit token address.
+///
+/// * **ExecuteMsg::CancelAstroTokenAddrUpdate {}** Cancels the scheduled change of the deposit token address.
+///
+/// * **ExecuteMsg::ProposeNewOwner { owner, expires_in }** Creates a new request to change contract ownership.
+///
+/// * **ExecuteMsg::DropOwnershipProposal {}** Removes a request to change contract ownership.
+///
+/// * **ExecuteMsg::ClaimOwnership {}** Claims contract ownership.
+///
+/// * **ExecuteMsg::UpdateLockTiers { tiers }** Replaces the list of available lock tiers.
+///
+/// * **ExecuteMsg::Unlock { position_id }** Releases the shares of an expired lock position.
+///
+/// * **ExecuteMsg::UpdateConfig { unbonding_period }** Updates the contract configuration.
+///
+/// * **ExecuteMsg::Claim {}** Withdraws ASTRO from all matured unbondings of the sender.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -92,7 +139,167 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
+        ExecuteMsg::UpdateAstroTokenAddr { new_addr } => {
+            update_astro_token_addr(deps, env, info, new_addr)
+        }
+        ExecuteMsg::ApplyAstroTokenAddrUpdate {} => apply_astro_token_addr_update(deps, env, info),
+        ExecuteMsg::CancelAstroTokenAddrUpdate {} => cancel_astro_token_addr_update(deps, info),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+            let config = CONFIG.load(deps.storage)?;
+
+            propose_new_owner(
+                deps,
+                info,
+                env,
+                owner,
+                expires_in,
+                config.owner,
+                OWNERSHIP_PROPOSAL,
+            )
+            .map_err(Into::into)
+        }
+        ExecuteMsg::DropOwnershipProposal {} => {
+            let config = CONFIG.load(deps.storage)?;
+
+            drop_ownership_proposal(deps, info, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(Into::into)
+        }
+        ExecuteMsg::ClaimOwnership {} => {
+            claim_ownership(deps, info, env, OWNERSHIP_PROPOSAL, |deps, new_owner| {
+                CONFIG.update::<_, StdError>(deps.storage, |mut v| {
+                    v.owner = new_owner;
+                    Ok(v)
+                })?;
+
+                Ok(())
+            })
+            .map_err(Into::into)
+        }
+        ExecuteMsg::UpdateLockTiers { tiers } => update_lock_tiers(deps, info, tiers),
+        ExecuteMsg::Unlock { position_id } => unlock(deps, env, info, position_id),
+        ExecuteMsg::UpdateConfig { unbonding_period } => {
+            update_config(deps, info, unbonding_period)
+        }
+        ExecuteMsg::Claim {} => claim(deps, env, info),
+    }
+}
+
+/// Schedules a change of the deposit token address which can be applied after
+/// [`ASTRO_TOKEN_UPDATE_TIMELOCK`] seconds. A previously scheduled change is replaced.
+///
+/// * **new_addr** new deposit token contract address.
+fn update_astro_token_addr(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_addr: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let new_addr = deps.api.addr_validate(&new_addr)?;
+    if new_addr == config.astro_token_addr || new_addr == config.xastro_token_addr {
+        return Err(ContractError::InvalidAstroTokenAddr {});
+    }
+
+    let update = AstroTokenAddrUpdate {
+        new_addr,
+        effective_after: env.block.time.seconds() + ASTRO_TOKEN_UPDATE_TIMELOCK,
+    };
+    ASTRO_TOKEN_ADDR_UPDATE.save(deps.storage, &update)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update_astro_token_addr"),
+        attr("astro_token_addr", config.astro_token_addr),
+        attr("new_astro_token_addr", update.new_addr),
+        attr("effective_after", update.effective_after.to_string()),
+    ]))
+}
+
+/// Applies the scheduled change of the deposit token address. The contract must already hold
+/// at least as many new tokens as old ones so the value of existing shares is preserved.
+/// The whole old token balance is sent to the owner in exchange for the provided new tokens
+/// so it doesn't get stranded in the contract.
+fn apply_astro_token_addr_update(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let update = ASTRO_TOKEN_ADDR_UPDATE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::AstroTokenAddrUpdateNotFound {})?;
+
+    if env.block.time.seconds() < update.effective_after {
+        return Err(ContractError::AstroTokenAddrUpdateTimelocked(
+            update.effective_after,
+        ));
+    }
+
+    let old_balance = query_token_balance(
+        &deps.querier,
+        &config.astro_token_addr,
+        &env.contract.address,
+    )?;
+    let new_balance = query_token_balance(&deps.querier, &update.new_addr, &env.contract.address)?;
+    if new_balance < old_balance {
+        return Err(ContractError::InsufficientNewTokenBalance {
+            old_balance,
+            new_balance,
+        });
+    }
+
+    let old_addr = config.astro_token_addr;
+    config.astro_token_addr = update.new_addr;
+    CONFIG.save(deps.storage, &config)?;
+    ASTRO_TOKEN_ADDR_UPDATE.remove(deps.storage);
+
+    let mut response = Response::new();
+    if !old_balance.is_zero() {
+        response = response.add_message(wasm_execute(
+            &old_addr,
+            &Cw20ExecuteMsg::Transfer {
+                recipient: config.owner.to_string(),
+                amount: old_balance,
+            },
+            vec![],
+        )?);
     }
+
+    Ok(response.add_attributes(vec![
+        attr("action", "apply_astro_token_addr_update"),
+        attr("previous_astro_token_addr", old_addr),
+        attr("astro_token_addr", config.astro_token_addr),
+        attr("previous_balance", old_balance),
+        attr("balance", new_balance),
+    ]))
+}
+
+/// Cancels the scheduled change of the deposit token address.
+fn cancel_astro_token_addr_update(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let update = ASTRO_TOKEN_ADDR_UPDATE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::AstroTokenAddrUpdateNotFound {})?;
+    ASTRO_TOKEN_ADDR_UPDATE.remove(deps.storage);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "cancel_astro_token_addr_update"),
+        attr("new_astro_token_addr", update.new_addr),
+    ]))
 }
 
 /// The entry point to the contract for processing replies from submessages.
@@ -137,64 +344,23 @@ fn receive_cw20(
     let config: Config = CONFIG.load(deps.storage)?;
 
     let recipient = cw20_msg.sender;
-    let mut amount = cw20_msg.amount;
+    let amount = cw20_msg.amount;
 
-    let mut total_deposit = query_token_balance(
+    // ASTRO which is being unbonded doesn't belong to stakers anymore
+    let total_deposit = query_token_balance(
         &deps.querier,
         &config.astro_token_addr,
         env.contract.address.clone(),
-    )?;
+    )?
+    .checked_sub(TOTAL_UNBONDING.may_load(deps.storage)?.unwrap_or_default())?;
     let total_shares = query_supply(&deps.querier, &config.xastro_token_addr)?;
 
     match from_binary(&cw20_msg.msg)? {
         Cw20HookMsg::Enter {} => {
-            // Check if the deposit exceeds the overall limit
-            let new_total_deposit = total_deposit + amount;
-            if new_total_deposit > Uint128::new(21_000_000_000_000) {
-                return Err(ContractError::ExceedsOverallDepositLimit {});
-            }
+            let (mut messages, mint_amount) =
+                calc_enter_shares(&config, &env, &info, total_deposit, total_shares, amount)?;
 
-            let mut messages = vec![];
-            if info.sender != config.astro_token_addr {
-                return Err(ContractError::Unauthorized {});
-            }
-
-            // In a CW20 `send`, the total balance of the recipient is already increased.
-            // To properly calculate the total amount of ASTRO deposited in staking, we should subtract the user deposit from the pool
-            total_deposit -= amount;
-            let mint_amount: Uint128 = if total_shares.is_zero() || total_deposit.is_zero() {
-                amount = amount
-                    .checked_sub(MINIMUM_STAKE_AMOUNT)
-                    .map_err(|_| ContractError::MinimumStakeAmountError {})?;
-
-                // amount cannot become zero after minimum stake subtraction
-                if amount.is_zero() {
-                    return Err(ContractError::MinimumStakeAmountError {});
-                }
-
-                messages.push(wasm_execute(
-                    config.xastro_token_addr.clone(),
-                    &Cw20ExecuteMsg::Mint {
-                        recipient: env.contract.address.to_string(),
-                        amount: MINIMUM_STAKE_AMOUNT,
-                    },
-                    vec![],
-                )?);
-
-                amount
-            } else {
-                amount = amount
-                    .checked_mul(total_shares)?
-                    .checked_div(total_deposit)?;
-
-                if amount.is_zero() {
-                    return Err(ContractError::StakeAmountTooSmall {});
-                }
-
-                amount
-            };
-
-                        messages.push(wasm_execute(
+            messages.push(wasm_execute(
                 config.xastro_token_addr.clone(),
                 &Cw20ExecuteMsg::Mint {
                     recipient: recipient.clone(),
@@ -210,6 +376,65 @@ fn receive_cw20(
                 attr("xastro_amount", mint_amount),
             ]))
         }
+        Cw20HookMsg::EnterLocked { duration } => {
+            let tier = LOCK_TIERS
+                .may_load(deps.storage)?
+                .unwrap_or_default()
+                .into_iter()
+                .find(|tier| tier.duration == duration)
+                .ok_or(ContractError::LockTierNotFound(duration))?;
+
+            let (mut messages, mint_amount) =
+                calc_enter_shares(&config, &env, &info, total_deposit, total_shares, amount)?;
+            // The boost only increases the lock weight which counts as voting power.
+            // Minting extra shares would dilute other stakers
+            let weight = mint_amount * tier.multiplier;
+
+            // Shares are held by the staking contract until the lock expires
+            messages.push(wasm_execute(
+                config.xastro_token_addr.clone(),
+                &Cw20ExecuteMsg::Mint {
+                    recipient: env.contract.address.to_string(),
+                    amount: mint_amount,
+                },
+                vec![],
+            )?);
+
+            let recipient = deps.api.addr_validate(&recipient)?;
+            let position_id = NEXT_LOCK_POSITION_ID
+                .may_load(deps.storage)?
+                .unwrap_or_default();
+            let position = LockPosition {
+                id: position_id,
+                deposit_amount: cw20_msg.amount,
+                shares: mint_amount,
+                weight,
+                unlock_time: env.block.time.seconds() + duration,
+            };
+            LOCK_POSITIONS.save(deps.storage, (&recipient, position_id), &position)?;
+            NEXT_LOCK_POSITION_ID.save(deps.storage, &(position_id + 1))?;
+            USER_LOCK_WEIGHT.update::<_, ContractError>(
+                deps.storage,
+                &recipient,
+                env.block.height,
+                |user_weight| Ok(user_weight.unwrap_or_default().checked_add(weight)?),
+            )?;
+            TOTAL_LOCK_WEIGHT.update::<_, ContractError>(
+                deps.storage,
+                env.block.height,
+                |total| Ok(total.unwrap_or_default().checked_add(weight)?),
+            )?;
+
+            Ok(Response::new().add_messages(messages).add_attributes(vec![
+                attr("action", "enter_locked"),
+                attr("recipient", recipient),
+                attr("astro_amount", cw20_msg.amount),
+                attr("xastro_amount", mint_amount),
+                attr("lock_weight", weight),
+                attr("position_id", position_id.to_string()),
+                attr("unlock_time", position.unlock_time.to_string()),
+            ]))
+        }
         Cw20HookMsg::Leave {} => {
             if info.sender != config.xastro_token_addr {
                 return Err(ContractError::Unauthorized {});
@@ -220,20 +445,48 @@ fn receive_cw20(
                 .checked_div(total_shares)?;
 
             // Burn share
-            let res = Response::new()
-                .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: config.xastro_token_addr.to_string(),
-                    msg: to_binary(&Cw20ExecuteMsg::Burn { amount })?,
-                    funds: vec![],
-                }))
-                .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: config.astro_token_addr.to_string(),
-                    msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                        recipient: recipient.clone(),
-                        amount: what,
-                    })?,
-                    funds: vec![],
-                }));
+            let res = Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.xastro_token_addr.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Burn { amount })?,
+                funds: vec![],
+            }));
+
+            if config.unbonding_period > 0 {
+                let recipient = deps.api.addr_validate(&recipient)?;
+                let unlock_time = env.block.time.seconds() + config.unbonding_period;
+
+                let mut unbondings = UNBONDINGS
+                    .may_load(deps.storage, &recipient)?
+                    .unwrap_or_default();
+                if unbondings.len() >= MAX_PENDING_UNBONDINGS {
+                    return Err(ContractError::TooManyUnbondings {});
+                }
+                unbondings.push(Unbonding {
+                    amount: what,
+                    unlock_time,
+                });
+                UNBONDINGS.save(deps.storage, &recipient, &unbondings)?;
+                TOTAL_UNBONDING.update::<_, StdError>(deps.storage, |total| {
+                    Ok(total.unwrap_or_default().checked_add(what)?)
+                })?;
+
+                return Ok(res.add_attributes(vec![
+                    attr("action", "leave"),
+                    attr("recipient", recipient),
+                    attr("xastro_amount", cw20_msg.amount),
+                    attr("astro_amount", what),
+                    attr("unlock_time", unlock_time.to_string()),
+                ]));
+            }
+
+            let res = res.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: config.astro_token_addr.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.clone(),
+                    amount: what,
+                })?,
+                funds: vec![],
+            }));
 
             Ok(res.add_attributes(vec![
                 attr("action", "leave"),
@@ -245,6 +498,228 @@ fn receive_cw20(
     }
 }
 
+/// Validates a deposit and calculates the amount of shares to mint for it. Returns the messages
+/// which must be executed before minting the shares along with the amount of shares.
+///
+/// * **total_deposit** total amount of ASTRO in the staking contract including the deposit.
+///
+/// * **total_shares** total amount of ITO.
+///
+/// * **amount** amount of ASTRO deposited.
+fn calc_enter_shares(
+    config: &Config,
+    env: &Env,
+    info: &MessageInfo,
+    mut total_deposit: Uint128,
+    total_shares: Uint128,
+    mut amount: Uint128,
+) -> Result<(Vec<WasmMsg>, Uint128), ContractError> {
+    // Check if the deposit exceeds the overall limit
+    let new_total_deposit = total_deposit + amount;
+    if new_total_deposit > Uint128::new(21_000_000_000_000) {
+        return Err(ContractError::ExceedsOverallDepositLimit {});
+    }
+
+    let mut messages = vec![];
+    if info.sender != config.astro_token_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // In a CW20 `send`, the total balance of the recipient is already increased.
+    // To properly calculate the total amount of ASTRO deposited in staking, we should subtract the user deposit from the pool
+    total_deposit -= amount;
+    let mint_amount: Uint128 = if total_shares.is_zero() || total_deposit.is_zero() {
+        amount = amount
+            .checked_sub(MINIMUM_STAKE_AMOUNT)
+            .map_err(|_| ContractError::MinimumStakeAmountError {})?;
+
+        // amount cannot become zero after minimum stake subtraction
+        if amount.is_zero() {
+            return Err(ContractError::MinimumStakeAmountError {});
+        }
+
+        messages.push(wasm_execute(
+            config.xastro_token_addr.clone(),
+            &Cw20ExecuteMsg::Mint {
+                recipient: env.contract.address.to_string(),
+                amount: MINIMUM_STAKE_AMOUNT,
+            },
+            vec![],
+        )?);
+
+        amount
+    } else {
+        amount = amount
+            .checked_mul(total_shares)?
+            .checked_div(total_deposit)?;
+
+        if amount.is_zero() {
+            return Err(ContractError::StakeAmountTooSmall {});
+        }
+
+        amount
+    };
+
+    Ok((messages, mint_amount))
+}
+
+/// Replaces the list of available lock tiers.
+///
+/// * **tiers** new list of lock tiers.
+fn update_lock_tiers(
+    deps: DepsMut,
+    info: MessageInfo,
+    mut tiers: Vec<LockTier>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    tiers.sort_by_key(|tier| tier.duration);
+    for (i, tier) in tiers.iter().enumerate() {
+        if tier.duration == 0
+            || tier.duration > MAX_LOCK_DURATION
+            || tier.multiplier < Decimal::one()
+            || tier.multiplier > MAX_LOCK_MULTIPLIER
+        {
+            return Err(ContractError::InvalidLockTier(tier.duration));
+        }
+        if i > 0 && tiers[i - 1].duration == tier.duration {
+            return Err(ContractError::DuplicateLockTier(tier.duration));
+        }
+    }
+
+    LOCK_TIERS.save(deps.storage, &tiers)?;
+
+    Ok(Response::new().add_attribute("action", "update_lock_tiers"))
+}
+
+/// Transfers the shares of an expired lock position to its owner and removes the position.
+///
+/// * **position_id** lock position identifier.
+fn unlock(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    position_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let position = LOCK_POSITIONS
+        .may_load(deps.storage, (&info.sender, position_id))?
+        .ok_or(ContractError::LockPositionNotFound(position_id))?;
+
+    if env.block.time.seconds() < position.unlock_time {
+        return Err(ContractError::LockPositionNotExpired(position.unlock_time));
+    }
+
+    LOCK_POSITIONS.remove(deps.storage, (&info.sender, position_id));
+    USER_LOCK_WEIGHT.update::<_, ContractError>(
+        deps.storage,
+        &info.sender,
+        env.block.height,
+        |user_weight| {
+            Ok(user_weight
+                .unwrap_or_default()
+                .checked_sub(position.weight)?)
+        },
+    )?;
+    TOTAL_LOCK_WEIGHT.update::<_, ContractError>(deps.storage, env.block.height, |total| {
+        Ok(total.unwrap_or_default().checked_sub(position.weight)?)
+    })?;
+
+    let transfer_msg = wasm_execute(
+        config.xastro_token_addr,
+        &Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount: position.shares,
+        },
+        vec![],
+    )?;
+
+    Ok(Response::new()
+        .add_message(transfer_msg)
+        .add_attributes(vec![
+            attr("action", "unlock"),
+            attr("recipient", info.sender),
+            attr("position_id", position_id.to_string()),
+            attr("xastro_amount", position.shares),
+        ]))
+}
+
+/// Updates the contract configuration.
+///
+/// * **unbonding_period** new unbonding period in seconds.
+fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    unbonding_period: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut attributes = vec![attr("action", "update_config")];
+
+    if let Some(unbonding_period) = unbonding_period {
+        if unbonding_period > MAX_UNBONDING_PERIOD {
+            return Err(ContractError::UnbondingPeriodTooLong(MAX_UNBONDING_PERIOD));
+        }
+        config.unbonding_period = unbonding_period;
+        attributes.push(attr("unbonding_period", unbonding_period.to_string()));
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(attributes))
+}
+
+/// Transfers ASTRO from all matured unbondings of the sender.
+fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let (matured, pending): (Vec<_>, Vec<_>) = UNBONDINGS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default()
+        .into_iter()
+        .partition(|unbonding| unbonding.unlock_time <= env.block.time.seconds());
+
+    let amount = matured.iter().try_fold(Uint128::zero(), |acc, unbonding| {
+        acc.checked_add(unbonding.amount)
+    })?;
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    if pending.is_empty() {
+        UNBONDINGS.remove(deps.storage, &info.sender);
+    } else {
+        UNBONDINGS.save(deps.storage, &info.sender, &pending)?;
+    }
+    TOTAL_UNBONDING.update::<_, StdError>(deps.storage, |total| {
+        Ok(total.unwrap_or_default().checked_sub(amount)?)
+    })?;
+
+    let transfer_msg = wasm_execute(
+        config.astro_token_addr,
+        &Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount,
+        },
+        vec![],
+    )?;
+
+    Ok(Response::new()
+        .add_message(transfer_msg)
+        .add_attributes(vec![
+            attr("action", "claim"),
+            attr("recipient", info.sender),
+            attr("astro_amount", amount),
+        ]))
+}
+
 /// Exposes all the queries available in the contract.
 ///
 /// ## Queries
@@ -253,6 +728,23 @@ fn receive_cw20(
 /// * **QueryMsg::TotalShares {}** Returns the total ITO supply using a [`Uint128`] object.
 ///
 /// * **QueryMsg::Config {}** Returns the amount of ASTRO that's currently in the staking pool using a [`Uint128`] object.
+///
+/// * **QueryMsg::PendingAstroTokenAddrUpdate {}** Returns the scheduled change of the deposit token address
+/// using an [`AstroTokenAddrUpdate`] object.
+///
+/// * **QueryMsg::LockTiers {}** Returns the list of available lock tiers.
+///
+/// * **QueryMsg::LockPositions { user, start_after, limit }** Returns the lock positions of a specific user.
+///
+/// * **QueryMsg::PendingUnbondings { user }** Returns the pending unbondings of a specific user.
+///
+/// * **QueryMsg::TotalUnbonding {}** Returns the total amount of ASTRO which is being unbonded.
+///
+/// * **QueryMsg::TotalLockWeight {}** Returns the total boosted weight of all lock positions.
+///
+/// * **QueryMsg::VotingPowerAt { user, block }** Returns the voting power of a specific user at the given block.
+///
+/// * **QueryMsg::TotalVotingPowerAt { block }** Returns the total voting power at the given block.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     let config = CONFIG.load(deps.storage)?;
@@ -260,18 +752,140 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Config {} => Ok(to_binary(&ConfigResponse {
             deposit_token_addr: config.astro_token_addr,
             share_token_addr: config.xastro_token_addr,
+            owner: config.owner,
+            unbonding_period: config.unbonding_period,
         })?),
         QueryMsg::TotalShares {} => {
             to_binary(&query_supply(&deps.querier, &config.xastro_token_addr)?)
         }
-        QueryMsg::TotalDeposit {} => to_binary(&query_token_balance(
-            &deps.querier,
-            &config.astro_token_addr,
-            env.contract.address,
-        )?),
+        QueryMsg::TotalDeposit {} => to_binary(
+            &query_token_balance(
+                &deps.querier,
+                &config.astro_token_addr,
+                env.contract.address,
+            )?
+            .checked_sub(TOTAL_UNBONDING.may_load(deps.storage)?.unwrap_or_default())?,
+        ),
+        QueryMsg::PendingAstroTokenAddrUpdate {} => {
+            to_binary(&ASTRO_TOKEN_ADDR_UPDATE.may_load(deps.storage)?)
+        }
+        QueryMsg::LockTiers {} => {
+            to_binary(&LOCK_TIERS.may_load(deps.storage)?.unwrap_or_default())
+        }
+        QueryMsg::LockPositions {
+            user,
+            start_after,
+            limit,
+        } => to_binary(&query_lock_positions(deps, user, start_after, limit)?),
+        QueryMsg::PendingUnbondings { user } => to_binary(
+            &UNBONDINGS
+                .may_load(deps.storage, &deps.api.addr_validate(&user)?)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::TotalUnbonding {} => {
+            to_binary(&TOTAL_UNBONDING.may_load(deps.storage)?.unwrap_or_default())
+        }
+        QueryMsg::TotalLockWeight {} => to_binary(
+            &TOTAL_LOCK_WEIGHT
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::VotingPowerAt { user, block } => {
+            to_binary(&query_voting_power_at(deps, &config, user, block)?)
+        }
+        QueryMsg::TotalVotingPowerAt { block } => {
+            to_binary(&query_total_voting_power_at(deps, env, &config, block)?)
+        }
     }
 }
 
+/// Returns the voting power of a specific user at the given block.
+/// It is the sum of the user xASTRO balance and the boosted weight of the user lock positions.
+///
+/// * **user** user address.
+///
+/// * **block** block height to read the voting power at.
+fn query_voting_power_at(
+    deps: Deps,
+    config: &Config,
+    user: String,
+    block: u64,
+) -> StdResult<Uint128> {
+    let user = deps.api.addr_validate(&user)?;
+    let balance: BalanceResponse = deps.querier.query_wasm_smart(
+        &config.xastro_token_addr,
+        &TokenQueryMsg::BalanceAt {
+            address: user.to_string(),
+            block,
+        },
+    )?;
+    let lock_weight = USER_LOCK_WEIGHT
+        .may_load_at_height(deps.storage, &user, block)?
+        .unwrap_or_default();
+
+    Ok(balance.balance.checked_add(lock_weight)?)
+}
+
+/// Returns the total voting power at the given block.
+/// Shares held by the staking contract (locked shares and the minimum stake) are replaced
+/// with the total boosted lock weight.
+///
+/// * **block** block height to read the voting power at.
+fn query_total_voting_power_at(
+    deps: Deps,
+    env: Env,
+    config: &Config,
+    block: u64,
+) -> StdResult<Uint128> {
+    let total_supply: Uint128 = deps.querier.query_wasm_smart(
+        &config.xastro_token_addr,
+        &TokenQueryMsg::TotalSupplyAt { block },
+    )?;
+    let locked_shares: BalanceResponse = deps.querier.query_wasm_smart(
+        &config.xastro_token_addr,
+        &TokenQueryMsg::BalanceAt {
+            address: env.contract.address.to_string(),
+            block,
+        },
+    )?;
+    let total_lock_weight = TOTAL_LOCK_WEIGHT
+        .may_load_at_height(deps.storage, block)?
+        .unwrap_or_default();
+
+    Ok(total_supply
+        .saturating_sub(locked_shares.balance)
+        .checked_add(total_lock_weight)?)
+}
+
+/// Returns the lock positions of a specific user.
+///
+/// * **user** user address.
+///
+/// * **start_after** position identifier to start reading from.
+///
+/// * **limit** amount of positions to read.
+fn query_lock_positions(
+    deps: Deps,
+    user: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<LockPosition>> {
+    let user = deps.api.addr_validate(&user)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    LOCK_POSITIONS
+        .prefix(&user)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, position)| position))
+        .collect()
+}
+
 /// ## Description
 /// Used for migration of contract. Returns the default object of type [`Response`].
 /// ## Params
@@ -279,14 +893,17 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 ///
 /// * **_env** is the object of type [`Env`].
 ///
-/// * **_msg** is the object of type [`MigrateMsg`].
+/// * **msg** is the object of type [`MigrateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract_version = get_contract_version(deps.storage)?;
 
     match contract_version.contract.as_ref() {
         "ito-staking" => match contract_version.version.as_ref() {
-            "1.1.0" | "1.0.1" | "1.0.2" => {}
+            "1.1.0" | "1.0.1" | "1.0.2" => {
+                let owner = msg.owner.ok_or(ContractError::MigrationError {})?;
+                migration::add_owner(deps.branch(), &owner)?;
+            }
             _ => return Err(ContractError::MigrationError {}),
         },
         _ => return Err(ContractError::MigrationError {}),
@@ -299,4 +916,4 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
         .add_attribute("previous_contract_version", &contract_version.version)
         .add_attribute("new_contract_name", CONTRACT_NAME)
         .add_attribute("new_contract_version", CONTRACT_VERSION))
-                    }
+}
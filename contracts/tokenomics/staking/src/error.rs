@@ -1,5 +1,5 @@
 use crate::contract::MINIMUM_STAKE_AMOUNT;
-use cosmwasm_std::{DivideByZeroError, OverflowError, StdError};
+use cosmwasm_std::{DivideByZeroError, OverflowError, StdError, Uint128};
 use thiserror::Error;
 
 /// This enum describes staking contract errors
@@ -22,9 +22,52 @@ pub enum ContractError {
 
     #[error("Failed to parse or process reply message")]
     FailedToParseReply {},
-    
+
     #[error("Total supply max 21 million")]
     ExceedsOverallDepositLimit {},
+
+    #[error(
+        "New deposit token address must differ from the current deposit and share token addresses"
+    )]
+    InvalidAstroTokenAddr {},
+
+    #[error("No deposit token address update is scheduled")]
+    AstroTokenAddrUpdateNotFound {},
+
+    #[error("Deposit token address update can be applied after {0}")]
+    AstroTokenAddrUpdateTimelocked(u64),
+
+    #[error(
+        "Staking holds {new_balance} of the new deposit token, at least {old_balance} is required"
+    )]
+    InsufficientNewTokenBalance {
+        old_balance: Uint128,
+        new_balance: Uint128,
+    },
+
+    #[error("Lock tier with duration {0} not found")]
+    LockTierNotFound(u64),
+
+    #[error("Invalid lock tier with duration {0}")]
+    InvalidLockTier(u64),
+
+    #[error("Duplicate lock tier with duration {0}")]
+    DuplicateLockTier(u64),
+
+    #[error("Lock position {0} not found")]
+    LockPositionNotFound(u64),
+
+    #[error("Lock position can be unlocked after {0}")]
+    LockPositionNotExpired(u64),
+
+    #[error("Unbonding period can't exceed {0} seconds")]
+    UnbondingPeriodTooLong(u64),
+
+    #[error("Too many pending unbondings. Claim matured unbondings first")]
+    TooManyUnbondings {},
+
+    #[error("Nothing to claim")]
+    NothingToClaim {},
 }
 
 impl From<OverflowError> for ContractError {
@@ -2,3 +2,5 @@ pub mod contract;
 pub mod state;
 
 pub mod error;
+
+mod migration;
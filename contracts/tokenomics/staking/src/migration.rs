@@ -0,0 +1,31 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, DepsMut, StdResult};
+use cw_storage_plus::Item;
+
+use crate::state::{Config, CONFIG};
+
+/// Adds the contract owner to the config stored by versions up to 1.1.0.
+pub(crate) fn add_owner(deps: DepsMut, owner: &str) -> StdResult<()> {
+    /// This structure stores the main parameters for the staking contract.
+    #[cw_serde]
+    pub struct ConfigUntilV110 {
+        /// The ASTRO token contract address
+        pub astro_token_addr: Addr,
+        /// The ITO token contract address
+        pub xastro_token_addr: Addr,
+    }
+
+    const CONFIG_UNTIL_V110: Item<ConfigUntilV110> = Item::new("config");
+
+    let old_config = CONFIG_UNTIL_V110.load(deps.storage)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            astro_token_addr: old_config.astro_token_addr,
+            xastro_token_addr: old_config.xastro_token_addr,
+            owner: deps.api.addr_validate(owner)?,
+            unbonding_period: 0,
+        },
+    )
+}
@@ -1,6 +1,8 @@
+use astroport::common::OwnershipProposal;
+use astroport::staking::{AstroTokenAddrUpdate, LockPosition, LockTier, Unbonding};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map, SnapshotItem, SnapshotMap, Strategy};
 
 /// This structure stores the main parameters for the staking contract.
 #[cw_serde]
@@ -11,8 +13,48 @@ pub struct Config {
     pub xastro_token_addr: Addr,
     /// The owner of the contract
     pub owner: Addr,
+    /// The unbonding period in seconds
+    #[serde(default)]
+    pub unbonding_period: u64,
 }
 
-
 /// Stores the contract config at the given key
 pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Stores the scheduled change of the deposit token address
+pub const ASTRO_TOKEN_ADDR_UPDATE: Item<AstroTokenAddrUpdate> =
+    Item::new("astro_token_addr_update");
+
+/// Contains a proposal to change contract ownership.
+pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
+
+/// Stores the available lock tiers sorted by duration
+pub const LOCK_TIERS: Item<Vec<LockTier>> = Item::new("lock_tiers");
+
+/// Stores lock positions by user address and position identifier
+pub const LOCK_POSITIONS: Map<(&Addr, u64), LockPosition> = Map::new("lock_positions");
+
+/// Stores the total boosted weight of all lock positions snapshotted at every block
+pub const TOTAL_LOCK_WEIGHT: SnapshotItem<Uint128> = SnapshotItem::new(
+    "total_lock_weight",
+    "total_lock_weight__checkpoints",
+    "total_lock_weight__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Stores the boosted weight of all lock positions of a user snapshotted at every block
+pub const USER_LOCK_WEIGHT: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
+    "user_lock_weight",
+    "user_lock_weight__checkpoints",
+    "user_lock_weight__changelog",
+    Strategy::EveryBlock,
+);
+
+/// Stores the identifier of the next lock position
+pub const NEXT_LOCK_POSITION_ID: Item<u64> = Item::new("next_lock_position_id");
+
+/// Stores pending unbondings by user address
+pub const UNBONDINGS: Map<&Addr, Vec<Unbonding>> = Map::new("unbondings");
+
+/// Stores the total amount of ASTRO which is being unbonded
+pub const TOTAL_UNBONDING: Item<Uint128> = Item::new("total_unbonding");
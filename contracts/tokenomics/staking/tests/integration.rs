@@ -1,8 +1,11 @@
 #![cfg(not(tarpaulin_include))]
 
-use astroport::staking::{ConfigResponse, Cw20HookMsg, InstantiateMsg as xInstatiateMsg, QueryMsg};
+use astroport::staking::{
+    AstroTokenAddrUpdate, ConfigResponse, Cw20HookMsg, ExecuteMsg,
+    InstantiateMsg as xInstatiateMsg, LockPosition, LockTier, QueryMsg, Unbonding,
+};
 use astroport::token::InstantiateMsg;
-use cosmwasm_std::{attr, to_binary, Addr, QueryRequest, Uint128, WasmQuery};
+use cosmwasm_std::{attr, to_binary, Addr, Decimal, QueryRequest, Uint128, WasmQuery};
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, MinterResponse};
 use cw_multi_test::{App, ContractWrapper, Executor};
 
@@ -181,14 +184,7 @@ fn instantiate_contracts(router: &mut App, owner: Addr) -> (Addr, Addr, Addr) {
         marketing: None,
     };
     let staking_instance = router
-        .instantiate_contract(
-            staking_code_id,
-            owner,
-            &msg,
-            &[],
-            String::from("ITO"),
-            None,
-        )
+        .instantiate_contract(staking_code_id, owner, &msg, &[], String::from("ITO"), None)
         .unwrap();
 
     let msg = QueryMsg::Config {};
@@ -870,3 +866,928 @@ fn should_not_allow_directly_burn_from_xastro() {
         .unwrap_err();
     assert_eq!(res.root_cause().to_string(), "Unauthorized");
 }
+
+#[test]
+fn update_astro_token_addr() {
+    let mut router = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (astro_token_instance, staking_instance, x_astro_token_instance) =
+        instantiate_contracts(&mut router, owner.clone());
+
+    mint_some_astro(
+        &mut router,
+        owner.clone(),
+        astro_token_instance.clone(),
+        ALICE,
+    );
+
+    let alice_address = Addr::unchecked(ALICE);
+
+    // Alice enters the staking contract
+    let msg = Cw20ExecuteMsg::Send {
+        contract: staking_instance.to_string(),
+        msg: to_binary(&Cw20HookMsg::Enter {}).unwrap(),
+        amount: Uint128::from(2000u128),
+    };
+    router
+        .execute_contract(
+            alice_address.clone(),
+            astro_token_instance.clone(),
+            &msg,
+            &[],
+        )
+        .unwrap();
+
+    // Create the rebranded deposit token
+    let new_token_code_id = router.store_code(Box::new(ContractWrapper::new_with_empty(
+        astroport_token::contract::execute,
+        astroport_token::contract::instantiate,
+        astroport_token::contract::query,
+    )));
+    let new_token_instance = router
+        .instantiate_contract(
+            new_token_code_id,
+            owner.clone(),
+            &InstantiateMsg {
+                name: String::from("Ito token"),
+                symbol: String::from("ITOT"),
+                decimals: 6,
+                initial_balances: vec![],
+                mint: Some(MinterResponse {
+                    minter: owner.to_string(),
+                    cap: None,
+                }),
+                marketing: None,
+            },
+            &[],
+            String::from("ITOT"),
+            None,
+        )
+        .unwrap();
+
+    let update_msg = ExecuteMsg::UpdateAstroTokenAddr {
+        new_addr: new_token_instance.to_string(),
+    };
+
+    // Only the owner can schedule the update
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            staking_instance.clone(),
+            &update_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    // The share token can't become the deposit token
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::UpdateAstroTokenAddr {
+                new_addr: x_astro_token_instance.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "New deposit token address must differ from the current deposit and share token addresses"
+    );
+
+    router
+        .execute_contract(owner.clone(), staking_instance.clone(), &update_msg, &[])
+        .unwrap();
+
+    let effective_after = router.block_info().time.seconds() + 7 * 86400;
+    let pending: Option<AstroTokenAddrUpdate> = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::PendingAstroTokenAddrUpdate {})
+        .unwrap();
+    assert_eq!(
+        pending,
+        Some(AstroTokenAddrUpdate {
+            new_addr: new_token_instance.clone(),
+            effective_after,
+        })
+    );
+
+    // The update is time locked
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::ApplyAstroTokenAddrUpdate {},
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!("Deposit token address update can be applied after {effective_after}")
+    );
+
+    router.update_block(|block| {
+        block.time = block.time.plus_seconds(7 * 86400);
+        block.height += 1;
+    });
+
+    // Staking doesn't hold enough new tokens yet
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::ApplyAstroTokenAddrUpdate {},
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Staking holds 0 of the new deposit token, at least 2000 is required"
+    );
+
+    // Fund staking with the new token
+    router
+        .execute_contract(
+            owner.clone(),
+            new_token_instance.clone(),
+            &Cw20ExecuteMsg::Mint {
+                recipient: staking_instance.to_string(),
+                amount: Uint128::from(2000u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+    router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::ApplyAstroTokenAddrUpdate {},
+            &[],
+        )
+        .unwrap();
+
+    let config: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.deposit_token_addr, new_token_instance);
+    assert_eq!(config.owner, owner);
+
+    // Old tokens are swapped to the owner instead of being stranded in staking
+    let res: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &astro_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: staking_instance.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::zero());
+    let res: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &astro_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: owner.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::from(2000u128));
+
+    let pending: Option<AstroTokenAddrUpdate> = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::PendingAstroTokenAddrUpdate {})
+        .unwrap();
+    assert_eq!(pending, None);
+
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::CancelAstroTokenAddrUpdate {},
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "No deposit token address update is scheduled"
+    );
+
+    // Alice leaves and receives the new token
+    router
+        .execute_contract(
+            alice_address.clone(),
+            x_astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::Leave {}).unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let res: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &new_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: alice_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::from(1000u128));
+}
+
+#[test]
+fn enter_locked_and_unlock() {
+    let mut router = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (astro_token_instance, staking_instance, x_astro_token_instance) =
+        instantiate_contracts(&mut router, owner.clone());
+
+    mint_some_astro(
+        &mut router,
+        owner.clone(),
+        astro_token_instance.clone(),
+        ALICE,
+    );
+    mint_some_astro(
+        &mut router,
+        owner.clone(),
+        astro_token_instance.clone(),
+        BOB,
+    );
+
+    let alice_address = Addr::unchecked(ALICE);
+    let bob_address = Addr::unchecked(BOB);
+
+    let month = 30 * 86400;
+    let tiers = vec![
+        LockTier {
+            duration: 3 * month,
+            multiplier: Decimal::percent(150),
+        },
+        LockTier {
+            duration: month,
+            multiplier: Decimal::percent(200),
+        },
+    ];
+
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::UpdateLockTiers {
+                tiers: tiers.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::UpdateLockTiers {
+                tiers: vec![LockTier {
+                    duration: month,
+                    multiplier: Decimal::percent(50),
+                }],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!("Invalid lock tier with duration {month}")
+    );
+
+    router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::UpdateLockTiers { tiers },
+            &[],
+        )
+        .unwrap();
+
+    let res: Vec<LockTier> = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::LockTiers {})
+        .unwrap();
+    assert_eq!(res[0].duration, month);
+    assert_eq!(res[1].duration, 3 * month);
+
+    // Alice enters the staking contract
+    router
+        .execute_contract(
+            alice_address.clone(),
+            astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::Enter {}).unwrap(),
+                amount: Uint128::from(2000u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Bob can't lock for a duration which is not listed in the tiers
+    let err = router
+        .execute_contract(
+            bob_address.clone(),
+            astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::EnterLocked { duration: 100 }).unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Lock tier with duration 100 not found"
+    );
+
+    // Bob locks for a month and receives a doubled lock weight
+    router
+        .execute_contract(
+            bob_address.clone(),
+            astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::EnterLocked { duration: month }).unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let unlock_time = router.block_info().time.seconds() + month;
+    let res: Vec<LockPosition> = router
+        .wrap()
+        .query_wasm_smart(
+            &staking_instance,
+            &QueryMsg::LockPositions {
+                user: BOB.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![LockPosition {
+            id: 0,
+            deposit_amount: Uint128::from(1000u128),
+            shares: Uint128::from(1000u128),
+            weight: Uint128::from(2000u128),
+            unlock_time,
+        }]
+    );
+
+    let total_shares: Uint128 = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::TotalShares {})
+        .unwrap();
+    assert_eq!(total_shares, Uint128::from(3000u128));
+    let total_weight: Uint128 = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::TotalLockWeight {})
+        .unwrap();
+    assert_eq!(total_weight, Uint128::from(2000u128));
+    let total_deposit: Uint128 = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::TotalDeposit {})
+        .unwrap();
+    assert_eq!(total_deposit, Uint128::from(3000u128));
+
+    // Locked shares are held by the staking contract
+    let res: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &x_astro_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: bob_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::zero());
+
+    let err = router
+        .execute_contract(
+            bob_address.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::Unlock { position_id: 0 },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!("Lock position can be unlocked after {unlock_time}")
+    );
+
+    // Alice can't unlock Bob's position
+    router.update_block(|block| {
+        block.time = block.time.plus_seconds(month);
+        block.height += 1;
+    });
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::Unlock { position_id: 0 },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Lock position 0 not found");
+
+    router
+        .execute_contract(
+            bob_address.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::Unlock { position_id: 0 },
+            &[],
+        )
+        .unwrap();
+
+    let res: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &x_astro_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: bob_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::from(1000u128));
+
+    let res: Vec<LockPosition> = router
+        .wrap()
+        .query_wasm_smart(
+            &staking_instance,
+            &QueryMsg::LockPositions {
+                user: BOB.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn boosted_lock_does_not_dilute_stakers() {
+    let mut router = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (astro_token_instance, staking_instance, x_astro_token_instance) =
+        instantiate_contracts(&mut router, owner.clone());
+
+    mint_some_astro(
+        &mut router,
+        owner.clone(),
+        astro_token_instance.clone(),
+        ALICE,
+    );
+    mint_some_astro(
+        &mut router,
+        owner.clone(),
+        astro_token_instance.clone(),
+        BOB,
+    );
+
+    let alice_address = Addr::unchecked(ALICE);
+    let bob_address = Addr::unchecked(BOB);
+
+    let month = 30 * 86400;
+    router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::UpdateLockTiers {
+                tiers: vec![LockTier {
+                    duration: month,
+                    multiplier: Decimal::percent(300),
+                }],
+            },
+            &[],
+        )
+        .unwrap();
+
+    router
+        .execute_contract(
+            alice_address.clone(),
+            astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::Enter {}).unwrap(),
+                amount: Uint128::from(2000u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+    router
+        .execute_contract(
+            bob_address.clone(),
+            astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::EnterLocked { duration: month }).unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+    router.update_block(|block| {
+        block.time = block.time.plus_seconds(month);
+        block.height += 1;
+    });
+    router
+        .execute_contract(
+            bob_address.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::Unlock { position_id: 0 },
+            &[],
+        )
+        .unwrap();
+
+    let total_weight: Uint128 = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::TotalLockWeight {})
+        .unwrap();
+    assert_eq!(total_weight, Uint128::zero());
+
+    // Alice's 1000 shares are still worth 1000 ASTRO (1000 ASTRO were locked as minimum stake)
+    router
+        .execute_contract(
+            alice_address.clone(),
+            x_astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::Leave {}).unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let res: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &astro_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: alice_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::from(9000u128));
+
+    // Bob can't redeem more than he locked
+    router
+        .execute_contract(
+            bob_address.clone(),
+            x_astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::Leave {}).unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let res: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &astro_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: bob_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::from(10000u128));
+}
+
+#[test]
+fn locked_position_boosts_voting_power() {
+    let mut router = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (astro_token_instance, staking_instance, _) =
+        instantiate_contracts(&mut router, owner.clone());
+
+    for user in [ALICE, BOB, CAROL] {
+        mint_some_astro(
+            &mut router,
+            owner.clone(),
+            astro_token_instance.clone(),
+            user,
+        );
+    }
+
+    let month = 30 * 86400;
+    router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::UpdateLockTiers {
+                tiers: vec![LockTier {
+                    duration: month,
+                    multiplier: Decimal::percent(300),
+                }],
+            },
+            &[],
+        )
+        .unwrap();
+
+    // 1000 ASTRO of the first deposit are locked as minimum stake
+    let deposits = [
+        (ALICE, Cw20HookMsg::Enter {}, 2000u128),
+        (BOB, Cw20HookMsg::EnterLocked { duration: month }, 1000u128),
+        (CAROL, Cw20HookMsg::Enter {}, 1000u128),
+    ];
+    for (user, msg, amount) in deposits {
+        router
+            .execute_contract(
+                Addr::unchecked(user),
+                astro_token_instance.clone(),
+                &Cw20ExecuteMsg::Send {
+                    contract: staking_instance.to_string(),
+                    msg: to_binary(&msg).unwrap(),
+                    amount: Uint128::from(amount),
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    router.update_block(|block| block.height += 1);
+
+    let voting_power_at = |router: &App, user: &str, block: u64| -> u128 {
+        router
+            .wrap()
+            .query_wasm_smart::<Uint128>(
+                &staking_instance,
+                &QueryMsg::VotingPowerAt {
+                    user: user.to_string(),
+                    block,
+                },
+            )
+            .unwrap()
+            .u128()
+    };
+    let total_voting_power_at = |router: &App, block: u64| -> u128 {
+        router
+            .wrap()
+            .query_wasm_smart::<Uint128>(&staking_instance, &QueryMsg::TotalVotingPowerAt { block })
+            .unwrap()
+            .u128()
+    };
+
+    // Bob and Carol deposited the same amount but Bob's position is boosted by the lock
+    let block = router.block_info().height;
+    assert_eq!(voting_power_at(&router, ALICE, block), 1000);
+    assert_eq!(voting_power_at(&router, BOB, block), 3000);
+    assert_eq!(voting_power_at(&router, CAROL, block), 1000);
+    assert_eq!(total_voting_power_at(&router, block), 5000);
+
+    router.update_block(|block| {
+        block.time = block.time.plus_seconds(month);
+        block.height += 1;
+    });
+    router
+        .execute_contract(
+            Addr::unchecked(BOB),
+            staking_instance.clone(),
+            &ExecuteMsg::Unlock { position_id: 0 },
+            &[],
+        )
+        .unwrap();
+    router.update_block(|block| block.height += 1);
+
+    // The boost ends with the lock while past snapshots are kept
+    let new_block = router.block_info().height;
+    assert_eq!(voting_power_at(&router, BOB, new_block), 1000);
+    assert_eq!(total_voting_power_at(&router, new_block), 3000);
+    assert_eq!(voting_power_at(&router, BOB, block), 3000);
+    assert_eq!(total_voting_power_at(&router, block), 5000);
+}
+
+#[test]
+fn leave_with_unbonding_period() {
+    let mut router = mock_app();
+
+    let owner = Addr::unchecked("owner");
+
+    let (astro_token_instance, staking_instance, x_astro_token_instance) =
+        instantiate_contracts(&mut router, owner.clone());
+
+    mint_some_astro(
+        &mut router,
+        owner.clone(),
+        astro_token_instance.clone(),
+        ALICE,
+    );
+    mint_some_astro(
+        &mut router,
+        owner.clone(),
+        astro_token_instance.clone(),
+        BOB,
+    );
+
+    let alice_address = Addr::unchecked(ALICE);
+    let bob_address = Addr::unchecked(BOB);
+
+    let unbonding_period = 7 * 86400;
+
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::UpdateConfig {
+                unbonding_period: Some(unbonding_period),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Unauthorized");
+
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::UpdateConfig {
+                unbonding_period: Some(365 * 86400),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Unbonding period can't exceed 2592000 seconds"
+    );
+
+    router
+        .execute_contract(
+            owner.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::UpdateConfig {
+                unbonding_period: Some(unbonding_period),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let config: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.unbonding_period, unbonding_period);
+
+    // Alice enters and leaves the staking contract
+    router
+        .execute_contract(
+            alice_address.clone(),
+            astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::Enter {}).unwrap(),
+                amount: Uint128::from(2000u128),
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            alice_address.clone(),
+            x_astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::Leave {}).unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let unlock_time = router.block_info().time.seconds() + unbonding_period;
+    let res: Vec<Unbonding> = router
+        .wrap()
+        .query_wasm_smart(
+            &staking_instance,
+            &QueryMsg::PendingUnbondings {
+                user: ALICE.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![Unbonding {
+            amount: Uint128::from(1000u128),
+            unlock_time,
+        }]
+    );
+
+    // Unbonding ASTRO is excluded from the pool
+    let total_unbonding: Uint128 = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::TotalUnbonding {})
+        .unwrap();
+    assert_eq!(total_unbonding, Uint128::from(1000u128));
+    let total_deposit: Uint128 = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::TotalDeposit {})
+        .unwrap();
+    assert_eq!(total_deposit, Uint128::from(1000u128));
+
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::Claim {},
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err.root_cause().to_string(), "Nothing to claim");
+
+    // Bob enters at the same share price
+    router
+        .execute_contract(
+            bob_address.clone(),
+            astro_token_instance.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: staking_instance.to_string(),
+                msg: to_binary(&Cw20HookMsg::Enter {}).unwrap(),
+                amount: Uint128::from(1000u128),
+            },
+            &[],
+        )
+        .unwrap();
+    let res: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &x_astro_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: bob_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::from(1000u128));
+
+    router.update_block(|block| {
+        block.time = block.time.plus_seconds(unbonding_period);
+        block.height += 1;
+    });
+
+    router
+        .execute_contract(
+            alice_address.clone(),
+            staking_instance.clone(),
+            &ExecuteMsg::Claim {},
+            &[],
+        )
+        .unwrap();
+
+    let res: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &astro_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: alice_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::from(9000u128));
+
+    let res: Vec<Unbonding> = router
+        .wrap()
+        .query_wasm_smart(
+            &staking_instance,
+            &QueryMsg::PendingUnbondings {
+                user: ALICE.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+
+    let total_unbonding: Uint128 = router
+        .wrap()
+        .query_wasm_smart(&staking_instance, &QueryMsg::TotalUnbonding {})
+        .unwrap();
+    assert_eq!(total_unbonding, Uint128::zero());
+}
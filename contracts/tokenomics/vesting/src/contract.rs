@@ -3,15 +3,19 @@ use cosmwasm_std::{
     Response, StdError, StdResult, SubMsg, Uint128,
 };
 
-use crate::state::{read_vesting_infos, Config, CONFIG, OWNERSHIP_PROPOSAL, VESTING_INFO};
+use crate::state::{
+    read_vesting_infos, Config, BENEFICIARY_PROPOSALS, CONFIG, OWNERSHIP_PROPOSAL, VESTING_INFO,
+};
 
 use crate::error::ContractError;
 use astroport::asset::{addr_opt_validate, token_asset_info, AssetInfo, AssetInfoExt};
-use astroport::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
+use astroport::common::{
+    claim_ownership, drop_ownership_proposal, propose_new_owner, MAX_PROPOSAL_TTL,
+};
 use astroport::vesting::{
-    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, OrderBy, QueryMsg,
-    VestingAccount, VestingAccountResponse, VestingAccountsResponse, VestingInfo, VestingSchedule,
-    VestingSchedulePoint,
+    BeneficiaryProposal, ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg,
+    OrderBy, QueryMsg, VestingAccount, VestingAccountResponse, VestingAccountsResponse,
+    VestingInfo, VestingSchedule, VestingSchedulePoint,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::Cw20ReceiveMsg;
@@ -41,6 +45,7 @@ pub fn instantiate(
         &Config {
             owner: deps.api.addr_validate(&msg.owner)?,
             vesting_token: msg.vesting_token,
+            require_beneficiary_approval: false,
         },
     )?;
 
@@ -60,6 +65,20 @@ pub fn instantiate(
 /// * **ExecuteMsg::WithdrawFromActiveSchedule { account, recipient, withdraw_amount }**
 /// Withdraws tokens from the only one active vesting schedule of the specified account.
 ///
+/// * **ExecuteMsg::RevokeVestingSchedules { account, recipient }** Revokes unvested tokens
+/// from all revocable schedules of the specified account.
+///
+/// * **ExecuteMsg::ProposeNewBeneficiary { new_beneficiary, expires_in }** Creates a new request
+/// to transfer the sender's vesting account.
+///
+/// * **ExecuteMsg::DropBeneficiaryProposal {}** Removes the sender's request to transfer its vesting account.
+///
+/// * **ExecuteMsg::ApproveBeneficiaryProposal { account }** Approves a request to transfer a vesting account.
+///
+/// * **ExecuteMsg::ClaimBeneficiary { account }** Claims a vesting account.
+///
+/// * **ExecuteMsg::UpdateConfig { require_beneficiary_approval }** Updates contract parameters.
+///
 /// * **ExecuteMsg::ProposeNewOwner { owner, expires_in }** Creates a new request to change contract ownership.
 ///
 /// * **ExecuteMsg::DropOwnershipProposal {}** Removes a request to change contract ownership.
@@ -92,6 +111,21 @@ pub fn execute(
             recipient,
             withdraw_amount,
         } => withdraw_from_active_schedule(deps, env, info, account, recipient, withdraw_amount),
+        ExecuteMsg::RevokeVestingSchedules { account, recipient } => {
+            revoke_vesting_schedules(deps, env, info, account, recipient)
+        }
+        ExecuteMsg::ProposeNewBeneficiary {
+            new_beneficiary,
+            expires_in,
+        } => propose_new_beneficiary(deps, env, info, new_beneficiary, expires_in),
+        ExecuteMsg::DropBeneficiaryProposal {} => drop_beneficiary_proposal(deps, info),
+        ExecuteMsg::ApproveBeneficiaryProposal { account } => {
+            approve_beneficiary_proposal(deps, info, account)
+        }
+        ExecuteMsg::ClaimBeneficiary { account } => claim_beneficiary(deps, env, info, account),
+        ExecuteMsg::UpdateConfig {
+            require_beneficiary_approval,
+        } => update_config(deps, info, require_beneficiary_approval),
         ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
             let config: Config = CONFIG.load(deps.storage)?;
 
@@ -171,12 +205,7 @@ pub fn register_vesting_accounts(
         assert_vesting_schedules(&env, &account_address, &vesting_account.schedules)?;
 
         for sch in &vesting_account.schedules {
-            let amount = if let Some(end_point) = &sch.end_point {
-                end_point.amount
-            } else {
-                sch.start_point.amount
-            };
-            to_deposit = to_deposit.checked_add(amount)?;
+            to_deposit = to_deposit.checked_add(schedule_total_amount(sch))?;
         }
 
         if let Some(mut old_info) = VESTING_INFO.may_load(deps.storage, &account_address)? {
@@ -230,6 +259,25 @@ fn assert_vesting_schedules(
                 return Err(ContractError::VestingScheduleError(addr.to_string()));
             }
         }
+
+        let end_time = sch.end_point.map(|end_point| end_point.time);
+
+        let valid_cliff = sch.cliff.map_or(true, |cliff| {
+            cliff >= sch.start_point.time && end_time.map_or(true, |end_time| cliff <= end_time)
+        });
+        let valid_step_period = sch.step_period.map_or(true, |step_period| {
+            end_time.map_or(false, |end_time| {
+                step_period > 0 && step_period <= end_time - sch.start_point.time
+            })
+        });
+
+        if !valid_cliff
+            || !valid_step_period
+            || sch.revoked_at.is_some()
+            || sch.withdrawal_point.is_some()
+        {
+            return Err(ContractError::VestingScheduleParamsError(addr.to_string()));
+        }
     }
 
     Ok(())
@@ -305,22 +353,60 @@ fn compute_available_amount(current_time: u64, vesting_info: &VestingInfo) -> St
         .map_err(StdError::from)
 }
 
+/// Returns the total amount of tokens vested by particular [`VestingSchedule`] if it isn't revoked.
+fn schedule_total_amount(schedule: &VestingSchedule) -> Uint128 {
+    if let Some(end_point) = &schedule.end_point {
+        end_point.amount
+    } else {
+        schedule.start_point.amount
+    }
+}
+
 /// Calculate unlocked amount for particular [`VestingSchedule`].
 /// This function does not consider released amount.
+/// Revoked schedules stop unlocking tokens at the moment of revocation.
 fn calc_schedule_unlocked_amount(
     schedule: &VestingSchedule,
     current_time: u64,
 ) -> StdResult<Uint128> {
+    let current_time = schedule
+        .revoked_at
+        .map_or(current_time, |revoked_at| revoked_at.min(current_time));
+
+    if current_time < schedule.start_point.time
+        || schedule.cliff.map_or(false, |cliff| current_time < cliff)
+    {
+        return Ok(Uint128::zero());
+    }
+
     let mut available_amount = schedule.start_point.amount;
 
     if let Some(end_point) = &schedule.end_point {
-        let passed_time = current_time.min(end_point.time) - schedule.start_point.time;
-        let time_period = end_point.time - schedule.start_point.time;
+        // Tokens are unlocked at the end of each step. All tokens are unlocked at the end point
+        let stepped_passed_time = |time: u64| {
+            let mut passed_time = time.min(end_point.time) - schedule.start_point.time;
+            if let Some(step_period) = schedule.step_period {
+                if time < end_point.time {
+                    passed_time -= passed_time % step_period;
+                }
+            }
+            passed_time
+        };
+
+        // After a withdrawal the remaining tokens unlock starting from the withdrawal point
+        let (from_amount, from_passed_time) = match &schedule.withdrawal_point {
+            Some(point) => {
+                available_amount = point.amount;
+                (point.amount, stepped_passed_time(point.time))
+            }
+            None => (schedule.start_point.amount, 0),
+        };
+
+        let passed_time = stepped_passed_time(current_time).saturating_sub(from_passed_time);
+        let time_period = end_point.time - schedule.start_point.time - from_passed_time;
         if passed_time != 0 {
-            let release_amount = Uint128::from(passed_time).multiply_ratio(
-                end_point.amount.checked_sub(schedule.start_point.amount)?,
-                time_period,
-            );
+            let release_amount = Uint128::from(passed_time)
+                .multiply_ratio(end_point.amount.checked_sub(from_amount)?, time_period);
             available_amount = available_amount.checked_add(release_amount)?;
         }
     }
@@ -334,8 +420,9 @@ fn calc_schedule_unlocked_amount(
 /// Only schedules with end_point are considered as active.
 /// Active schedule's remaining amount must be greater than withdraw amount.
 /// This function changes the current active schedule
-/// setting current block time and already unlocked amount for start point
+/// setting current block time and already unlocked amount for the withdrawal point
 /// and reducing end point amount by the withdrawn amount.
+/// The start point is kept so step unlocks stay on the original grid.
 ///
 /// * **account** whose schedule to withdraw from.
 ///
@@ -365,13 +452,15 @@ fn withdraw_from_active_schedule(
     let mut vesting_info = VESTING_INFO.load(deps.storage, &acc)?;
     let block_time = env.block.time.seconds();
 
-    let mut active_schedules = vesting_info.schedules.iter_mut().filter(|schedule| {
-        if let Some(end_point) = schedule.end_point {
-            block_time >= schedule.start_point.time && block_time < end_point.time
-        } else {
-            false
-        }
-    });
+    let mut active_schedules = vesting_info
+        .schedules
+        .iter_mut()
+        .filter(|schedule| match schedule.end_point {
+            Some(end_point) if schedule.revoked_at.is_none() => {
+                block_time >= schedule.start_point.time && block_time < end_point.time
+            }
+            _ => false,
+        });
 
     if let Some(schedule) = active_schedules.next() {
         // Withdraw is not allowed if there are multiple active schedules
@@ -389,10 +478,10 @@ fn withdraw_from_active_schedule(
             return Err(ContractError::NotEnoughTokens(amount_left));
         }
 
-        schedule.start_point = VestingSchedulePoint {
+        schedule.withdrawal_point = Some(VestingSchedulePoint {
             time: block_time,
             amount: sch_unlocked_amount,
-        };
+        });
 
         end_point.amount -= amount;
         schedule.end_point = Some(end_point);
@@ -416,6 +505,214 @@ fn withdraw_from_active_schedule(
     ]))
 }
 
+/// Revokes all revocable schedules of the specified account and transfers unvested tokens
+/// to the receiver. Revoked schedules stop vesting, tokens vested before the revocation
+/// can still be claimed by the account.
+///
+/// * **account** whose schedules to revoke.
+///
+/// * **receiver** who will receive the unvested tokens.
+/// **info.sender** is used if it is not specified.
+fn revoke_vesting_schedules(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+    receiver: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let acc = deps.api.addr_validate(&account)?;
+    let mut vesting_info = VESTING_INFO.load(deps.storage, &acc)?;
+    let block_time = env.block.time.seconds();
+
+    let mut revoked_amount = Uint128::zero();
+    for schedule in vesting_info
+        .schedules
+        .iter_mut()
+        .filter(|schedule| schedule.revocable && schedule.revoked_at.is_none())
+    {
+        let unlocked_amount = calc_schedule_unlocked_amount(schedule, block_time)?;
+        revoked_amount = revoked_amount
+            .checked_add(schedule_total_amount(schedule).checked_sub(unlocked_amount)?)?;
+        schedule.revoked_at = Some(block_time);
+    }
+
+    if revoked_amount.is_zero() {
+        return Err(ContractError::NothingToRevoke(account));
+    }
+
+    VESTING_INFO.save(deps.storage, &acc, &vesting_info)?;
+
+    let receiver = addr_opt_validate(deps.api, &receiver)?.unwrap_or(info.sender);
+    let transfer_msg = config
+        .vesting_token
+        .with_balance(revoked_amount)
+        .into_msg(receiver.clone())?;
+
+    Ok(Response::new().add_message(transfer_msg).add_attributes([
+        attr("action", "revoke_vesting_schedules"),
+        attr("account", account),
+        attr("revoked_amount", revoked_amount),
+        attr("receiver", receiver),
+    ]))
+}
+
+/// Creates a request to transfer the sender's vesting account to a new beneficiary.
+/// Follows the same flow as the contract ownership transfer.
+///
+/// * **new_beneficiary** newly proposed beneficiary.
+///
+/// * **expires_in** time during which the proposal is valid.
+fn propose_new_beneficiary(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_beneficiary: String,
+    expires_in: u64,
+) -> Result<Response, ContractError> {
+    // Only accounts which are vesting tokens can be transferred
+    VESTING_INFO.load(deps.storage, &info.sender)?;
+
+    let new_beneficiary = deps.api.addr_validate(&new_beneficiary)?;
+    if new_beneficiary == info.sender {
+        return Err(ContractError::SameBeneficiary {});
+    }
+
+    if MAX_PROPOSAL_TTL < expires_in {
+        return Err(ContractError::ProposalTtlTooLong(MAX_PROPOSAL_TTL));
+    }
+
+    BENEFICIARY_PROPOSALS.save(
+        deps.storage,
+        &info.sender,
+        &BeneficiaryProposal {
+            new_beneficiary: new_beneficiary.clone(),
+            ttl: env.block.time.seconds() + expires_in,
+            approved: false,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "propose_new_beneficiary"),
+        attr("account", info.sender),
+        attr("new_beneficiary", new_beneficiary),
+    ]))
+}
+
+/// Removes the sender's request to transfer its vesting account.
+fn drop_beneficiary_proposal(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    if !BENEFICIARY_PROPOSALS.has(deps.storage, &info.sender) {
+        return Err(ContractError::BeneficiaryProposalNotFound(
+            info.sender.to_string(),
+        ));
+    }
+
+    BENEFICIARY_PROPOSALS.remove(deps.storage, &info.sender);
+
+    Ok(Response::new().add_attributes([
+        attr("action", "drop_beneficiary_proposal"),
+        attr("account", info.sender),
+    ]))
+}
+
+/// Approves a request to transfer a vesting account.
+///
+/// * **account** current beneficiary of the vesting account.
+fn approve_beneficiary_proposal(
+    deps: DepsMut,
+    info: MessageInfo,
+    account: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let acc = deps.api.addr_validate(&account)?;
+    let mut proposal = BENEFICIARY_PROPOSALS
+        .may_load(deps.storage, &acc)?
+        .ok_or_else(|| ContractError::BeneficiaryProposalNotFound(account.clone()))?;
+    proposal.approved = true;
+    BENEFICIARY_PROPOSALS.save(deps.storage, &acc, &proposal)?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "approve_beneficiary_proposal"),
+        attr("account", account),
+        attr("new_beneficiary", proposal.new_beneficiary),
+    ]))
+}
+
+/// Moves the vesting account including its claim history to the newly proposed beneficiary.
+///
+/// * **account** current beneficiary of the vesting account.
+fn claim_beneficiary(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let acc = deps.api.addr_validate(&account)?;
+    let proposal = BENEFICIARY_PROPOSALS
+        .may_load(deps.storage, &acc)?
+        .ok_or_else(|| ContractError::BeneficiaryProposalNotFound(account.clone()))?;
+
+    if info.sender != proposal.new_beneficiary {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if env.block.time.seconds() > proposal.ttl {
+        return Err(ContractError::BeneficiaryProposalExpired {});
+    }
+
+    if config.require_beneficiary_approval && !proposal.approved {
+        return Err(ContractError::BeneficiaryChangeNotApproved {});
+    }
+
+    if VESTING_INFO.has(deps.storage, &info.sender) {
+        return Err(ContractError::BeneficiaryAlreadyVesting(
+            info.sender.to_string(),
+        ));
+    }
+
+    let vesting_info = VESTING_INFO.load(deps.storage, &acc)?;
+    VESTING_INFO.remove(deps.storage, &acc);
+    VESTING_INFO.save(deps.storage, &info.sender, &vesting_info)?;
+    BENEFICIARY_PROPOSALS.remove(deps.storage, &acc);
+
+    Ok(Response::new().add_attributes([
+        attr("action", "claim_beneficiary"),
+        attr("previous_beneficiary", account),
+        attr("new_beneficiary", info.sender),
+    ]))
+}
+
+/// Updates contract parameters.
+///
+/// * **require_beneficiary_approval** whether beneficiary changes must be approved by the owner.
+fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    require_beneficiary_approval: Option<bool>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(require_beneficiary_approval) = require_beneficiary_approval {
+        config.require_beneficiary_approval = require_beneficiary_approval;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
 /// Exposes all the queries available in the contract.
 ///
 /// ## Queries
@@ -430,12 +727,14 @@ fn withdraw_from_active_schedule(
 ///         }** Returns a list of vesting schedules together with their vesting recipients.
 ///
 /// * **QueryMsg::AvailableAmount { address }** Returns the available amount of tokens that can be claimed by a specific vesting recipient.
+///
+/// * **QueryMsg::BeneficiaryProposal { account }** Returns the pending request to transfer a vesting account.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => Ok(to_binary(&query_config(deps)?)?),
         QueryMsg::VestingAccount { address } => {
-            Ok(to_binary(&query_vesting_account(deps, address)?)?)
+            Ok(to_binary(&query_vesting_account(deps, env, address)?)?)
         }
         QueryMsg::VestingAccounts {
             start_after,
@@ -443,6 +742,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             order_by,
         } => Ok(to_binary(&query_vesting_accounts(
             deps,
+            env,
             start_after,
             limit,
             order_by,
@@ -451,6 +751,12 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             deps, env, address,
         )?)?),
         QueryMsg::Timestamp {} => Ok(to_binary(&query_timestamp(env)?)?),
+        QueryMsg::BeneficiaryProposal { account } => {
+            let account = deps.api.addr_validate(&account)?;
+            Ok(to_binary(
+                &BENEFICIARY_PROPOSALS.may_load(deps.storage, &account)?,
+            )?)
+        }
     }
 }
 
@@ -461,6 +767,7 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     Ok(ConfigResponse {
         owner: config.owner,
         vesting_token: config.vesting_token,
+        require_beneficiary_approval: config.require_beneficiary_approval,
     })
 }
 
@@ -473,11 +780,48 @@ pub fn query_timestamp(env: Env) -> StdResult<u64> {
 /// Returns the vesting data for a specific vesting recipient using a [`VestingAccountResponse`] object.
 ///
 /// * **address** vesting recipient for which to return vesting data.
-pub fn query_vesting_account(deps: Deps, address: String) -> StdResult<VestingAccountResponse> {
+pub fn query_vesting_account(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> StdResult<VestingAccountResponse> {
     let address = deps.api.addr_validate(&address)?;
     let info = VESTING_INFO.load(deps.storage, &address)?;
 
-    Ok(VestingAccountResponse { address, info })
+    vesting_account_response(env.block.time.seconds(), address, info)
+}
+
+/// Builds a [`VestingAccountResponse`] reporting vested, unvested and revoked amounts separately.
+///
+/// * **current_time** timestamp at which the amounts are computed.
+fn vesting_account_response(
+    current_time: u64,
+    address: Addr,
+    info: VestingInfo,
+) -> StdResult<VestingAccountResponse> {
+    let mut vested_amount = Uint128::zero();
+    let mut unvested_amount = Uint128::zero();
+    let mut revoked_amount = Uint128::zero();
+
+    for sch in &info.schedules {
+        let unlocked_amount = calc_schedule_unlocked_amount(sch, current_time)?;
+        let locked_amount = schedule_total_amount(sch).checked_sub(unlocked_amount)?;
+
+        vested_amount = vested_amount.checked_add(unlocked_amount)?;
+        if sch.revoked_at.is_some() {
+            revoked_amount = revoked_amount.checked_add(locked_amount)?;
+        } else {
+            unvested_amount = unvested_amount.checked_add(locked_amount)?;
+        }
+    }
+
+    Ok(VestingAccountResponse {
+        address,
+        info,
+        vested_amount,
+        unvested_amount,
+        revoked_amount,
+    })
 }
 
 /// Returns a list of vesting schedules using a [`VestingAccountsResponse`] object.
@@ -489,6 +833,7 @@ pub fn query_vesting_account(deps: Deps, address: String) -> StdResult<VestingAc
 /// * **order_by** whether results should be returned in an ascending or descending order.
 pub fn query_vesting_accounts(
     deps: Deps,
+    env: Env,
     start_after: Option<String>,
     limit: Option<u32>,
     order_by: Option<OrderBy>,
@@ -497,10 +842,10 @@ pub fn query_vesting_accounts(
 
     let vesting_infos = read_vesting_infos(deps, start_after, limit, order_by)?;
 
-    let vesting_accounts: Vec<_> = vesting_infos
+    let vesting_accounts = vesting_infos
         .into_iter()
-        .map(|(address, info)| VestingAccountResponse { address, info })
-        .collect();
+        .map(|(address, info)| vesting_account_response(env.block.time.seconds(), address, info))
+        .collect::<StdResult<Vec<_>>>()?;
 
     Ok(VestingAccountsResponse { vesting_accounts })
 }
@@ -523,7 +868,7 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
 
     match contract_version.contract.as_ref() {
         "astroport-vesting" => match contract_version.version.as_ref() {
-            "1.1.0" | "1.2.0" | "1.3.0" | "1.3.1" => {}
+            "1.1.0" | "1.2.0" | "1.3.0" | "1.3.1" | "1.3.2" => {}
             _ => return Err(ContractError::MigrationError {}),
         },
         _ => return Err(ContractError::MigrationError {}),
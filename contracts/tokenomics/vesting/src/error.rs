@@ -23,6 +23,9 @@ pub enum ContractError {
     #[error("Vesting schedule error on addr: {0}. Should satisfy: (start < end, end > current_time and start_amount < end_amount)")]
     VestingScheduleError(String),
 
+    #[error("Vesting schedule error on addr: {0}. Cliff should be between start and end points, step period should be positive and fit between start and end points, revoked_at and withdrawal_point should not be set")]
+    VestingScheduleParamsError(String),
+
     #[error(
         "Vesting schedule amount error. The total amount should be equal to the received amount."
     )]
@@ -42,6 +45,27 @@ pub enum ContractError {
 
     #[error("Failed to withdraw from active schedule: amount left {0}")]
     NotEnoughTokens(Uint128),
+
+    #[error("Account {0} has no unvested tokens to revoke")]
+    NothingToRevoke(String),
+
+    #[error("New beneficiary cannot be the same as the current one")]
+    SameBeneficiary {},
+
+    #[error("Parameter expires_in cannot be higher than {0}")]
+    ProposalTtlTooLong(u64),
+
+    #[error("Beneficiary proposal not found for account {0}")]
+    BeneficiaryProposalNotFound(String),
+
+    #[error("Beneficiary proposal expired")]
+    BeneficiaryProposalExpired {},
+
+    #[error("Beneficiary change must be approved by the owner")]
+    BeneficiaryChangeNotApproved {},
+
+    #[error("Account {0} already has vesting schedules")]
+    BeneficiaryAlreadyVesting(String),
 }
 
 impl From<OverflowError> for ContractError {
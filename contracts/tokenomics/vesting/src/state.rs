@@ -2,7 +2,7 @@ use cosmwasm_schema::cw_serde;
 
 use astroport::asset::AssetInfo;
 use astroport::common::OwnershipProposal;
-use astroport::vesting::{OrderBy, VestingInfo};
+use astroport::vesting::{BeneficiaryProposal, OrderBy, VestingInfo};
 use cosmwasm_std::{Addr, Deps, StdResult};
 use cw_storage_plus::{Bound, Item, Map};
 
@@ -13,6 +13,9 @@ pub struct Config {
     pub owner: Addr,
     /// [`AssetInfo`] of the ASTRO token
     pub vesting_token: AssetInfo,
+    /// Whether beneficiary changes must be approved by the owner
+    #[serde(default)]
+    pub require_beneficiary_approval: bool,
 }
 
 /// Stores the contract config at the given key.
@@ -21,6 +24,11 @@ pub const CONFIG: Item<Config> = Item::new("config");
 /// The first key is the address of an account that's vesting, the second key is an object of type [`VestingInfo`].
 pub const VESTING_INFO: Map<&Addr, VestingInfo> = Map::new("vesting_info");
 
+/// The key is the current beneficiary of a vesting account, the value is a pending request
+/// to transfer the account to a new beneficiary.
+pub const BENEFICIARY_PROPOSALS: Map<&Addr, BeneficiaryProposal> =
+    Map::new("beneficiary_proposals");
+
 /// Contains a proposal to change contract ownership.
 pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
 
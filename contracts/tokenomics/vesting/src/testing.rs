@@ -24,6 +24,7 @@ fn proper_initialization() {
         ConfigResponse {
             owner: Addr::unchecked("owner"),
             vesting_token: token_asset_info(Addr::unchecked("astro_token")),
+            require_beneficiary_approval: false,
         }
     );
 }
@@ -2,7 +2,9 @@
 
 use astroport::asset::{native_asset_info, token_asset_info};
 use astroport::querier::query_balance;
-use astroport::vesting::{QueryMsg, VestingAccountResponse, VestingAccountsResponse, VestingInfo};
+use astroport::vesting::{
+    BeneficiaryProposal, QueryMsg, VestingAccountResponse, VestingAccountsResponse, VestingInfo,
+};
 use astroport::{
     token::InstantiateMsg as TokenInstantiateMsg,
     vesting::{
@@ -54,6 +56,11 @@ fn claim() {
                             time: current_time + 101,
                             amount: Uint128::new(200),
                         }),
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -64,6 +71,11 @@ fn claim() {
                             time: current_time + 110,
                             amount: Uint128::new(100),
                         }),
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -74,6 +86,11 @@ fn claim() {
                             time: current_time + 200,
                             amount: Uint128::new(100),
                         }),
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     },
                 ],
             }],
@@ -105,6 +122,11 @@ fn claim() {
                             time: current_time + 101,
                             amount: Uint128::new(100),
                         }),
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -115,6 +137,11 @@ fn claim() {
                             time: current_time + 110,
                             amount: Uint128::new(100),
                         }),
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -125,6 +152,11 @@ fn claim() {
                             time: current_time + 200,
                             amount: Uint128::new(100),
                         }),
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     },
                 ],
             }],
@@ -249,6 +281,11 @@ fn claim_native() {
                         time: current_time + 101,
                         amount: Uint128::new(200),
                     }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 }],
             }],
         })
@@ -274,6 +311,11 @@ fn claim_native() {
                         time: current_time + 101,
                         amount: Uint128::new(100),
                     }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 },
                 VestingSchedule {
                     start_point: VestingSchedulePoint {
@@ -284,6 +326,11 @@ fn claim_native() {
                         time: current_time + 110,
                         amount: Uint128::new(100),
                     }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 },
                 VestingSchedule {
                     start_point: VestingSchedulePoint {
@@ -294,6 +341,11 @@ fn claim_native() {
                         time: current_time + 200,
                         amount: Uint128::new(100),
                     }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 },
             ],
         }],
@@ -428,6 +480,11 @@ fn register_vesting_accounts() {
                         time: current_time + 100,
                         amount: Uint128::new(100),
                     }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 }],
             }],
         })
@@ -454,6 +511,11 @@ fn register_vesting_accounts() {
                         time: current_time + 150,
                         amount: Uint128::new(100),
                     }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 }],
             }],
         })
@@ -489,6 +551,11 @@ fn register_vesting_accounts() {
                     time: current_time + 150,
                     amount: Uint128::new(100),
                 }),
+                cliff: None,
+                step_period: None,
+                revocable: false,
+                revoked_at: None,
+                withdrawal_point: None,
             }],
         }],
     };
@@ -552,6 +619,11 @@ fn register_vesting_accounts() {
                         time: current_time + 150,
                         amount: Uint128::new(200),
                     }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 }],
             }],
         })
@@ -611,6 +683,11 @@ fn register_vesting_accounts() {
                         time: current_time + 200,
                         amount: Uint128::new(10),
                     }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 }],
             }],
         })
@@ -708,9 +785,17 @@ fn register_vesting_accounts() {
                                 time: 1571797719,
                                 amount: Uint128::new(200),
                             }),
+                            cliff: None,
+                            step_period: None,
+                            revocable: false,
+                            revoked_at: None,
+                            withdrawal_point: None,
                         }],
                         released_amount: Uint128::zero(),
-                    }
+                    },
+                    vested_amount: Uint128::new(200),
+                    unvested_amount: Uint128::zero(),
+                    revoked_amount: Uint128::zero(),
                 },
                 VestingAccountResponse {
                     address: user1,
@@ -725,6 +810,11 @@ fn register_vesting_accounts() {
                                     time: 1571797919,
                                     amount: Uint128::new(10),
                                 }),
+                                cliff: None,
+                                step_period: None,
+                                revocable: false,
+                                revoked_at: None,
+                                withdrawal_point: None,
                             },
                             VestingSchedule {
                                 start_point: VestingSchedulePoint {
@@ -734,11 +824,19 @@ fn register_vesting_accounts() {
                                 end_point: Some(VestingSchedulePoint {
                                     time: 1571797569,
                                     amount: Uint128::new(100),
-                                })
+                                }),
+                                cliff: None,
+                                step_period: None,
+                                revocable: false,
+                                revoked_at: None,
+                                withdrawal_point: None,
                             }
                         ],
                         released_amount: Uint128::new(110),
-                    }
+                    },
+                    vested_amount: Uint128::new(110),
+                    unvested_amount: Uint128::zero(),
+                    revoked_amount: Uint128::zero(),
                 }
             ]
         }
@@ -783,6 +881,11 @@ fn register_vesting_accounts_native() {
                         time: current_time + 150,
                         amount: Uint128::new(100),
                     }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 }],
             }],
         })
@@ -808,6 +911,11 @@ fn register_vesting_accounts_native() {
                     time: current_time + 150,
                     amount: Uint128::new(100),
                 }),
+                cliff: None,
+                step_period: None,
+                revocable: false,
+                revoked_at: None,
+                withdrawal_point: None,
             }],
         }],
     };
@@ -873,6 +981,11 @@ fn register_vesting_accounts_native() {
                     time: current_time + 150,
                     amount: Uint128::new(200),
                 }),
+                cliff: None,
+                step_period: None,
+                revocable: false,
+                revoked_at: None,
+                withdrawal_point: None,
             }],
         }],
     };
@@ -928,6 +1041,11 @@ fn register_vesting_accounts_native() {
                     time: current_time + 200,
                     amount: Uint128::new(10),
                 }),
+                cliff: None,
+                step_period: None,
+                revocable: false,
+                revoked_at: None,
+                withdrawal_point: None,
             }],
         }],
     };
@@ -1027,6 +1145,11 @@ fn withdraw_from_active_schedule() {
                         time: end_time,
                         amount: Uint128::new(100_000_000_000000),
                     }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
                 }],
             }],
         })
@@ -1124,6 +1247,11 @@ fn withdraw_overlapping_schedules() {
                             time: end_time,
                             amount: Uint128::new(50_000_000_000000),
                         }),
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -1131,6 +1259,11 @@ fn withdraw_overlapping_schedules() {
                             amount: Uint128::new(50_000_000_000000),
                         },
                         end_point: None,
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     },
                 ],
             }],
@@ -1221,6 +1354,11 @@ fn withdraw_overlapping_schedules2() {
                             time: end_time,
                             amount: Uint128::new(50_000_000_000000),
                         }),
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -1231,6 +1369,11 @@ fn withdraw_overlapping_schedules2() {
                             time: end_time + 86400 * 7,
                             amount: Uint128::new(50_000_000_000000),
                         }),
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
                     },
                 ],
             }],
@@ -1322,6 +1465,359 @@ fn withdraw_overlapping_schedules2() {
     );
 }
 
+#[test]
+fn withdraw_keeps_step_grid() {
+    let owner = Addr::unchecked(OWNER1);
+    let mut app = mock_app(&owner);
+    let token_code_id = store_token_code(&mut app);
+    let astro_token = instantiate_token(&mut app, token_code_id, "Astro", None);
+    let vesting_instance = instantiate_vesting(&mut app, &astro_token);
+
+    let user1 = Addr::unchecked(USER1);
+    let start_time = app.block_info().time.seconds();
+
+    let msg = Cw20ExecuteMsg::Send {
+        contract: vesting_instance.to_string(),
+        msg: to_binary(&Cw20HookMsg::RegisterVestingAccounts {
+            vesting_accounts: vec![VestingAccount {
+                address: user1.to_string(),
+                schedules: vec![VestingSchedule {
+                    start_point: VestingSchedulePoint {
+                        time: start_time,
+                        amount: Uint128::zero(),
+                    },
+                    end_point: Some(VestingSchedulePoint {
+                        time: start_time + 1000,
+                        amount: Uint128::new(1000),
+                    }),
+                    cliff: None,
+                    step_period: Some(100),
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
+                }],
+            }],
+        })
+        .unwrap(),
+        amount: Uint128::new(1000),
+    };
+    app.execute_contract(owner.clone(), astro_token.clone(), &msg, &[])
+        .unwrap();
+
+    app.update_block(|b| b.time = Timestamp::from_seconds(start_time + 150));
+    claim_and_check(&mut app, &user1, &vesting_instance, &astro_token, 100);
+
+    app.execute_contract(
+        owner.clone(),
+        vesting_instance.clone(),
+        &ExecuteMsg::WithdrawFromActiveSchedule {
+            account: user1.to_string(),
+            recipient: None,
+            withdraw_amount: Uint128::new(450),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The next step still ends at the original grid point
+    app.update_block(|b| b.time = Timestamp::from_seconds(start_time + 199));
+    claim_and_check(&mut app, &user1, &vesting_instance, &astro_token, 100);
+
+    // Remaining 450 tokens are unlocked over the 9 steps left
+    app.update_block(|b| b.time = Timestamp::from_seconds(start_time + 200));
+    claim_and_check(&mut app, &user1, &vesting_instance, &astro_token, 150);
+
+    app.update_block(|b| b.time = Timestamp::from_seconds(start_time + 1000));
+    claim_and_check(&mut app, &user1, &vesting_instance, &astro_token, 550);
+}
+
+#[test]
+fn revoke_cliff_schedule() {
+    let owner = Addr::unchecked(OWNER1);
+    let mut app = mock_app(&owner);
+    let token_code_id = store_token_code(&mut app);
+    let astro_token = instantiate_token(&mut app, token_code_id, "Astro", None);
+    let vesting_instance = instantiate_vesting(&mut app, &astro_token);
+
+    let user1 = Addr::unchecked(USER1);
+    let start_time = app.block_info().time.seconds();
+
+    let register_msg = |cliff: u64| Cw20ExecuteMsg::Send {
+        contract: vesting_instance.to_string(),
+        msg: to_binary(&Cw20HookMsg::RegisterVestingAccounts {
+            vesting_accounts: vec![VestingAccount {
+                address: user1.to_string(),
+                schedules: vec![
+                    VestingSchedule {
+                        start_point: VestingSchedulePoint {
+                            time: start_time,
+                            amount: Uint128::zero(),
+                        },
+                        end_point: Some(VestingSchedulePoint {
+                            time: start_time + 1200,
+                            amount: Uint128::new(1200),
+                        }),
+                        cliff: Some(cliff),
+                        step_period: Some(100),
+                        revocable: true,
+                        revoked_at: None,
+                        withdrawal_point: None,
+                    },
+                    VestingSchedule {
+                        start_point: VestingSchedulePoint {
+                            time: start_time,
+                            amount: Uint128::zero(),
+                        },
+                        end_point: Some(VestingSchedulePoint {
+                            time: start_time + 1200,
+                            amount: Uint128::new(600),
+                        }),
+                        cliff: None,
+                        step_period: None,
+                        revocable: false,
+                        revoked_at: None,
+                        withdrawal_point: None,
+                    },
+                ],
+            }],
+        })
+        .unwrap(),
+        amount: Uint128::new(1800),
+    };
+
+    // Cliff must not be set before the start point
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            astro_token.clone(),
+            &register_msg(start_time - 1),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Vesting schedule error on addr: user1. Cliff should be between start and end points, step period should be positive and fit between start and end points, revoked_at and withdrawal_point should not be set"
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        astro_token.clone(),
+        &register_msg(start_time + 300),
+        &[],
+    )
+    .unwrap();
+
+    // Nothing is unlocked from the first schedule before the cliff
+    app.update_block(|b| b.time = b.time.plus_seconds(250));
+    claim_and_check(&mut app, &user1, &vesting_instance, &astro_token, 125);
+
+    // After the cliff the first schedule unlocks tokens in steps
+    app.update_block(|b| b.time = b.time.plus_seconds(100));
+    claim_and_check(&mut app, &user1, &vesting_instance, &astro_token, 475);
+
+    let vesting_res: VestingAccountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::VestingAccount {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(vesting_res.vested_amount, Uint128::new(475));
+    assert_eq!(vesting_res.unvested_amount, Uint128::new(1325));
+    assert_eq!(vesting_res.revoked_amount, Uint128::zero());
+
+    let recipient = Addr::unchecked("recipient");
+    let revoke_msg = ExecuteMsg::RevokeVestingSchedules {
+        account: user1.to_string(),
+        recipient: Some(recipient.to_string()),
+    };
+
+    let err = app
+        .execute_contract(user1.clone(), vesting_instance.clone(), &revoke_msg, &[])
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    app.execute_contract(owner.clone(), vesting_instance.clone(), &revoke_msg, &[])
+        .unwrap();
+    check_token_balance(&mut app, &astro_token, &recipient, 900);
+
+    let vesting_res: VestingAccountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::VestingAccount {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(vesting_res.vested_amount, Uint128::new(475));
+    assert_eq!(vesting_res.unvested_amount, Uint128::new(425));
+    assert_eq!(vesting_res.revoked_amount, Uint128::new(900));
+
+    let err = app
+        .execute_contract(owner.clone(), vesting_instance.clone(), &revoke_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        ContractError::NothingToRevoke(user1.to_string()),
+        err.downcast().unwrap()
+    );
+
+    // Only the non-revocable schedule keeps vesting
+    app.update_block(|b| b.time = Timestamp::from_seconds(start_time + 1200));
+    claim_and_check(&mut app, &user1, &vesting_instance, &astro_token, 900);
+    check_token_balance(&mut app, &astro_token, &vesting_instance, 0);
+}
+
+#[test]
+fn transfer_vesting_account() {
+    let owner = Addr::unchecked(OWNER1);
+    let mut app = mock_app(&owner);
+    let token_code_id = store_token_code(&mut app);
+    let astro_token = instantiate_token(&mut app, token_code_id, "Astro", None);
+    let vesting_instance = instantiate_vesting(&mut app, &astro_token);
+
+    let user1 = Addr::unchecked(USER1);
+    let user2 = Addr::unchecked(USER2);
+    let multisig = Addr::unchecked("multisig");
+    let current_time = app.block_info().time.seconds();
+
+    let msg = Cw20ExecuteMsg::Send {
+        contract: vesting_instance.to_string(),
+        msg: to_binary(&Cw20HookMsg::RegisterVestingAccounts {
+            vesting_accounts: vec![VestingAccount {
+                address: user1.to_string(),
+                schedules: vec![VestingSchedule {
+                    start_point: VestingSchedulePoint {
+                        time: current_time,
+                        amount: Uint128::zero(),
+                    },
+                    end_point: Some(VestingSchedulePoint {
+                        time: current_time + 100,
+                        amount: Uint128::new(100),
+                    }),
+                    cliff: None,
+                    step_period: None,
+                    revocable: false,
+                    revoked_at: None,
+                    withdrawal_point: None,
+                }],
+            }],
+        })
+        .unwrap(),
+        amount: Uint128::new(100),
+    };
+    app.execute_contract(owner.clone(), astro_token.clone(), &msg, &[])
+        .unwrap();
+
+    app.update_block(|b| b.time = b.time.plus_seconds(50));
+    claim_and_check(&mut app, &user1, &vesting_instance, &astro_token, 50);
+
+    app.execute_contract(
+        owner.clone(),
+        vesting_instance.clone(),
+        &ExecuteMsg::UpdateConfig {
+            require_beneficiary_approval: Some(true),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            user1.clone(),
+            vesting_instance.clone(),
+            &ExecuteMsg::ProposeNewBeneficiary {
+                new_beneficiary: multisig.to_string(),
+                expires_in: 1209601,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::ProposalTtlTooLong(1209600),
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        user1.clone(),
+        vesting_instance.clone(),
+        &ExecuteMsg::ProposeNewBeneficiary {
+            new_beneficiary: multisig.to_string(),
+            expires_in: 100,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let claim_msg = ExecuteMsg::ClaimBeneficiary {
+        account: user1.to_string(),
+    };
+
+    let err = app
+        .execute_contract(user2.clone(), vesting_instance.clone(), &claim_msg, &[])
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    let err = app
+        .execute_contract(multisig.clone(), vesting_instance.clone(), &claim_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        ContractError::BeneficiaryChangeNotApproved {},
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        vesting_instance.clone(),
+        &ExecuteMsg::ApproveBeneficiaryProposal {
+            account: user1.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(multisig.clone(), vesting_instance.clone(), &claim_msg, &[])
+        .unwrap();
+
+    // The whole vesting account including the claim history has been moved
+    let vesting_res: VestingAccountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::VestingAccount {
+                address: multisig.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(vesting_res.info.released_amount, Uint128::new(50));
+
+    app.wrap()
+        .query_wasm_smart::<VestingAccountResponse>(
+            &vesting_instance,
+            &QueryMsg::VestingAccount {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap_err();
+
+    let proposal: Option<BeneficiaryProposal> = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::BeneficiaryProposal {
+                account: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(proposal, None);
+
+    app.update_block(|b| b.time = b.time.plus_seconds(50));
+    claim_and_check(&mut app, &multisig, &vesting_instance, &astro_token, 50);
+    check_token_balance(&mut app, &astro_token, &vesting_instance, 0);
+}
+
 fn mock_app(owner: &Addr) -> App {
     App::new(|app, _, storage| {
         app.bank
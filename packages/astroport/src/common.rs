@@ -4,7 +4,8 @@ use cosmwasm_std::{
 };
 use cw_storage_plus::Item;
 
-const MAX_PROPOSAL_TTL: u64 = 1209600;
+/// The maximum time during which a proposal to change ownership is valid
+pub const MAX_PROPOSAL_TTL: u64 = 1209600;
 
 /// This structure describes the parameters used for creating a request for a change of contract ownership.
 #[cw_serde]
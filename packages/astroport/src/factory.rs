@@ -1,7 +1,7 @@
 use crate::asset::{AssetInfo, PairInfo};
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary};
+use cosmwasm_std::{Addr, Binary, Coin};
 use std::fmt::{Display, Formatter, Result};
 
 const MAX_TOTAL_FEE_BPS: u16 = 10_000;
@@ -22,6 +22,8 @@ pub struct Config {
     pub whitelist_code_id: u64,
     /// The address of the contract that contains the coins with their precision
     pub coin_registry_address: Addr,
+    /// Address allowed to pause swaps and liquidity provision for pair types and pairs
+    pub guardian: Option<Addr>,
 }
 
 /// This enum describes available pair types.
@@ -71,6 +73,9 @@ pub struct PairConfig {
     /// Setting this to true means that pairs of this type will not be able
     /// to get an ASTRO generator
     pub is_generator_disabled: bool,
+    /// The native coin fee charged for creating a pair of this type. It is forwarded to the
+    /// `fee_address`
+    pub pair_creation_fee: Option<Coin>,
 }
 
 impl PairConfig {
@@ -78,6 +83,50 @@ impl PairConfig {
     pub fn valid_fee_bps(&self) -> bool {
         self.total_fee_bps <= MAX_TOTAL_FEE_BPS && self.maker_fee_bps <= MAX_MAKER_FEE_BPS
     }
+
+    /// This method is used to check the pair creation fee.
+    pub fn valid_pair_creation_fee(&self) -> bool {
+        self.pair_creation_fee
+            .as_ref()
+            .map(|fee| !fee.denom.is_empty() && !fee.amount.is_zero())
+            .unwrap_or(true)
+    }
+}
+
+/// This structure stores the fees of a single pair which override its pair type fees.
+#[cw_serde]
+pub struct PairFeeOverride {
+    /// The total fees (in bps) charged by the pair
+    pub total_fee_bps: u16,
+    /// The amount of fees (in bps) collected by the Maker contract from the pair
+    pub maker_fee_bps: u16,
+}
+
+impl PairFeeOverride {
+    /// This method is used to check fee bps.
+    pub fn valid_fee_bps(&self) -> bool {
+        self.total_fee_bps <= MAX_TOTAL_FEE_BPS && self.maker_fee_bps <= MAX_MAKER_FEE_BPS
+    }
+}
+
+/// This structure describes which pair operations are paused. Balanced withdrawals are never paused,
+/// while single asset provision and withdrawal are blocked by paused swaps as they swap internally.
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseStatus {
+    /// Whether swaps are paused
+    pub swaps_paused: bool,
+    /// Whether liquidity provision is paused
+    pub provide_paused: bool,
+}
+
+/// This enum describes the target of a pause status update.
+#[cw_serde]
+pub enum PauseTarget {
+    /// All pairs of a pair type
+    PairType(PairType),
+    /// A single pair contract
+    Pair(String),
 }
 
 /// This structure stores the basic settings for creating a new factory contract.
@@ -146,6 +195,40 @@ pub enum ExecuteMsg {
     DropOwnershipProposal {},
     /// Used to claim contract ownership.
     ClaimOwnership {},
+    /// UpdateGuardian sets or removes the address allowed to pause pairs.
+    UpdateGuardian {
+        /// The new guardian address. If not set, the guardian is removed
+        guardian: Option<String>,
+    },
+    /// UpdatePauseStatus pauses or unpauses swaps and liquidity provision for a pair type or a single pair.
+    UpdatePauseStatus {
+        /// The pair type or the pair to update
+        target: PauseTarget,
+        /// The new pause status
+        status: PauseStatus,
+    },
+    /// MigratePairs migrates a batch of registered pairs of the specified type to a new code id.
+    /// The progress is saved so the migration is resumed by sending the same message again until
+    /// all pairs are processed. The factory must be the admin of the pairs.
+    MigratePairs {
+        /// The pair type to migrate
+        pair_type: PairType,
+        /// The code id to migrate the pairs to
+        code_id: u64,
+        /// The message passed to the pairs' migrate entry point
+        msg: Binary,
+        /// The maximum number of registered pairs to process in this batch
+        limit: Option<u32>,
+    },
+    /// CancelPairsMigration drops the progress of the current pairs migration.
+    CancelPairsMigration {},
+    /// UpdatePairFeeOverride sets or removes the fees of a single pair which override its pair type fees.
+    UpdatePairFeeOverride {
+        /// The pair contract address
+        pair_addr: String,
+        /// The new fees of the pair. If not set, the pair falls back to its pair type fees
+        fee_override: Option<PairFeeOverride>,
+    },
 }
 
 /// This structure describes the available query messages for the factory contract.
@@ -174,10 +257,49 @@ pub enum QueryMsg {
     FeeInfo {
         /// The pair type for which we return fee information. Pair type is a [`PairType`] struct
         pair_type: PairType,
+        /// The pair contract address. If the pair has a fee override, it is returned instead of
+        /// the pair type fees
+        pair_addr: Option<String>,
     },
     /// Returns a vector that contains blacklisted pair types
     #[returns(Vec<PairType>)]
     BlacklistedPairTypes {},
+    /// PairsByCreator returns an array of pairs created by a specific address
+    #[returns(PairsResponse)]
+    PairsByCreator {
+        /// The address which created the pairs
+        creator: String,
+        /// The pair contract address to start reading from
+        start_after: Option<String>,
+        /// The number of pairs to read and return
+        limit: Option<u32>,
+    },
+    /// PairCreator returns the address which created a specific pair if it was recorded
+    #[returns(Option<Addr>)]
+    PairCreator {
+        /// The pair contract address
+        pair_addr: String,
+    },
+    /// PauseStatus returns the pause status of a pair type. If a pair address is specified,
+    /// the pair's own status is merged with the status of its type
+    #[returns(PauseStatus)]
+    PauseStatus {
+        /// The pair type
+        pair_type: PairType,
+        /// The pair contract address
+        pair_addr: Option<String>,
+    },
+    /// PairCodeIds returns the code id and the admin of each registered pair
+    #[returns(Vec<PairCodeIdResponse>)]
+    PairCodeIds {
+        /// The pair item to start reading from. It is an [`Option`] type that accepts [`AssetInfo`] elements.
+        start_after: Option<Vec<AssetInfo>>,
+        /// The number of pairs to read and return. It is an [`Option`] type.
+        limit: Option<u32>,
+    },
+    /// PairsMigration returns the progress of the current pairs migration if there is one
+    #[returns(Option<PairsMigrationState>)]
+    PairsMigration {},
 }
 
 /// A custom struct for each query response that returns general contract settings/configs.
@@ -197,6 +319,8 @@ pub struct ConfigResponse {
     pub whitelist_code_id: u64,
     /// The address of the contract that contains the coins and their accuracy
     pub coin_registry_address: Addr,
+    /// Address allowed to pause swaps and liquidity provision for pair types and pairs
+    pub guardian: Option<Addr>,
 }
 
 /// This structure stores the parameters used in a migration message.
@@ -223,6 +347,34 @@ pub struct FeeInfoResponse {
     pub maker_fee_bps: u16,
 }
 
+/// This structure describes the code a registered pair is running.
+#[cw_serde]
+pub struct PairCodeIdResponse {
+    /// The pair contract address
+    pub contract_addr: Addr,
+    /// The pair type
+    pub pair_type: PairType,
+    /// The code id of the pair contract
+    pub code_id: u64,
+    /// The admin of the pair contract which is allowed to migrate it
+    pub admin: Option<String>,
+}
+
+/// This structure describes the progress of a batch pairs migration.
+#[cw_serde]
+pub struct PairsMigrationState {
+    /// The pair type being migrated
+    pub pair_type: PairType,
+    /// The code id the pairs are migrated to. It's written to the pair config once the migration completes
+    pub code_id: u64,
+    /// The key of the last processed pair in the pair registry
+    pub last_pair_key: Option<Binary>,
+    /// The number of pairs migrated so far
+    pub migrated: u32,
+    /// The number of pairs skipped because the factory isn't their admin
+    pub skipped: u32,
+}
+
 /// This is an enum used for setting and removing a contract address.
 #[cw_serde]
 pub enum UpdateAddr {
@@ -0,0 +1,160 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Uint128};
+
+use crate::incentives::{EPOCHS_START, EPOCH_LENGTH};
+
+/// The sum of basis points a user can distribute across pools
+pub const MAX_VOTE_BPS: u16 = 10000;
+/// Max number of pools a user can vote for at once
+pub const MAX_VOTED_POOLS: usize = 10;
+/// Max number of pools which can receive ASTRO emissions after tuning
+pub const MAX_POOLS_LIMIT: u8 = 50;
+
+/// Returns the start timestamp of the epoch the given timestamp belongs to.
+/// Epochs are aligned with the incentives contract schedules.
+pub fn epoch_start(timestamp: u64) -> u64 {
+    let epochs_passed = timestamp.saturating_sub(EPOCHS_START) / EPOCH_LENGTH;
+    EPOCHS_START + epochs_passed * EPOCH_LENGTH
+}
+
+/// This structure stores the main parameters for the gauge controller contract.
+#[cw_serde]
+pub struct Config {
+    /// Address that's allowed to change contract parameters
+    pub owner: Addr,
+    /// The incentives contract which receives tuned alloc points
+    pub incentives: Addr,
+    /// The Astroport factory contract. Only pools registered in the factory can be voted for
+    pub factory: Addr,
+    /// The xASTRO staking contract. Its voting power snapshots include boosted lock weight
+    pub staking: Addr,
+    /// Share of pool votes which decays every epoch. One means votes expire every epoch
+    pub vote_decay: Decimal,
+    /// Max share of total voting power a single user can vote with
+    pub max_user_share: Decimal,
+    /// Max number of pools which receive alloc points after tuning
+    pub pools_limit: u8,
+}
+
+/// This structure stores the parameters used to instantiate the contract.
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Address that's allowed to change contract parameters
+    pub owner: String,
+    /// The incentives contract address
+    pub incentives: String,
+    /// The Astroport factory contract address
+    pub factory: String,
+    /// The xASTRO staking contract address
+    pub staking: String,
+    /// Share of pool votes which decays every epoch. One means votes expire every epoch
+    pub vote_decay: Decimal,
+    /// Max share of total voting power a single user can vote with
+    pub max_user_share: Decimal,
+    /// Max number of pools which receive alloc points after tuning
+    pub pools_limit: u8,
+}
+
+/// This structure describes the execute messages available in the contract.
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Distribute voting power across pools. Replaces previous user votes.
+    /// Voting power is a user's staking voting power (xASTRO balance plus lock weight) at the current epoch snapshot.
+    /// Votes decay by `vote_decay` every epoch, so users have to re-vote to restore their full voting power.
+    /// An empty list withdraws all user votes.
+    Vote {
+        /// Array of (LP token, basis points) tuples. Basis points must sum up to at most 10000
+        votes: Vec<(String, u16)>,
+    },
+    /// Push pool weights decayed to the current epoch to the incentives contract as alloc points.
+    /// Killed pools and pools which are no longer registered in the factory are skipped.
+    /// Can be called by anyone once per epoch.
+    Tune {},
+    /// Remove pools from voting. Votes cast for these pools are ignored while they are killed.
+    /// Only the owner can execute this.
+    KillPools { lp_tokens: Vec<String> },
+    /// Return killed pools to voting.
+    /// Only the owner can execute this.
+    RevivePools { lp_tokens: Vec<String> },
+    /// Update contract parameters.
+    /// Only the owner can execute this.
+    UpdateConfig {
+        /// New share of pool votes which decays every epoch
+        vote_decay: Option<Decimal>,
+        /// New max share of total xASTRO supply a single user can vote with
+        max_user_share: Option<Decimal>,
+        /// New max number of pools which receive alloc points after tuning
+        pools_limit: Option<u8>,
+    },
+    /// Creates a request to change contract ownership
+    /// Only the current owner can execute this
+    ProposeNewOwner {
+        /// The newly proposed owner
+        owner: String,
+        /// The validity period of the proposal to change the contract owner
+        expires_in: u64,
+    },
+    /// Removes a request to change contract ownership
+    /// Only the current owner can execute this
+    DropOwnershipProposal {},
+    /// Claims contract ownership
+    /// Only the newly proposed owner can execute this
+    ClaimOwnership {},
+}
+
+/// This structure describes the query messages available in the contract.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Returns the contract configuration
+    #[returns(Config)]
+    Config {},
+    /// Returns the latest votes of a user
+    #[returns(UserInfoResponse)]
+    UserInfo { user: String },
+    /// Returns the voting power a user would vote with in the current epoch
+    #[returns(Uint128)]
+    VotingPower { user: String },
+    /// Returns the pool weight decayed to the current epoch. Zero for killed pools
+    #[returns(Uint128)]
+    PoolWeight { lp_token: String },
+    /// Returns the result of the latest tuning
+    #[returns(TuneInfo)]
+    TuneInfo {},
+    /// Returns the list of killed pools
+    #[returns(Vec<String>)]
+    KilledPools {},
+}
+
+/// This structure stores the latest votes of a user.
+#[cw_serde]
+pub struct UserInfo {
+    /// Start of the epoch when the user voted
+    pub vote_ts: u64,
+    /// Voting power applied at the moment of voting
+    pub voting_power: Uint128,
+    /// Array of (LP token, basis points) tuples
+    pub votes: Vec<(String, u16)>,
+}
+
+/// This structure describes a user votes response.
+#[cw_serde]
+pub struct UserInfoResponse {
+    /// Start of the epoch when the user voted
+    pub vote_ts: u64,
+    /// Voting power applied at the moment of voting
+    pub voting_power: Uint128,
+    /// Voting power decayed to the current epoch
+    pub applied_voting_power: Uint128,
+    /// Array of (LP token, basis points) tuples
+    pub votes: Vec<(String, u16)>,
+}
+
+/// This structure stores the result of the latest tuning.
+#[cw_serde]
+pub struct TuneInfo {
+    /// Start of the epoch when pools were tuned
+    pub tune_ts: u64,
+    /// Array of (LP token, alloc points) tuples pushed to the incentives contract
+    pub pool_alloc_points: Vec<(String, Uint128)>,
+}
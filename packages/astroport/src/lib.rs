@@ -4,6 +4,7 @@ pub mod cosmwasm_ext;
 pub mod cw20_ics20;
 pub mod factory;
 pub mod fee_granter;
+pub mod gauge_controller;
 pub mod generator;
 pub mod generator_proxy;
 #[cfg(feature = "injective")]
@@ -18,6 +19,7 @@ pub mod pair;
 pub mod pair_bonded;
 pub mod pair_concentrated;
 pub mod pair_concentrated_inj;
+pub mod pair_utils;
 pub mod querier;
 pub mod restricted_vector;
 pub mod router;
@@ -1,9 +1,10 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{wasm_execute, Addr, CosmosMsg, Decimal, StdResult, Uint128};
 use cw20::Cw20ReceiveMsg;
 
 use crate::asset::{Asset, AssetInfo, PairInfo};
 use crate::pair::{Cw20HookMsg as PairCw20HookMsg, ExecuteMsg as PairExecuteMsg, FeeShareConfig};
+use crate::router::SwapOperation;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -18,6 +19,94 @@ pub enum ExecuteMsg {
         min_lp_to_receive: Option<Uint128>,
     },
     Receive(Cw20ReceiveMsg),
+    /// Creates an auto-compounding vault for the specified LP token. Only the factory owner can execute this.
+    CreateVault {
+        /// The LP token of the pair which vault compounds into
+        lp_token: String,
+        /// The router contract used to swap rewards into pool assets
+        router: String,
+        /// Swap routes for the rewards which are not pool assets
+        reward_routes: Vec<RewardRoute>,
+        /// The max spread used for every reward swap and liquidity provision
+        max_spread: Decimal,
+        /// The address which is allowed to compound the vault
+        keeper: String,
+    },
+    /// Updates the vault reward routes, max spread and keeper. Only the factory owner can execute this.
+    UpdateVault {
+        lp_token: String,
+        reward_routes: Option<Vec<RewardRoute>>,
+        max_spread: Option<Decimal>,
+        keeper: Option<String>,
+    },
+    /// Burns vault shares and sends the corresponding amount of LP tokens and idle rewards to the sender
+    WithdrawFromVault {
+        lp_token: String,
+        shares: Uint128,
+    },
+    /// Claims vault rewards, swaps them to pool assets, provides liquidity and stakes the minted LP tokens.
+    /// Only the vault keeper can execute this.
+    CompoundVault {
+        lp_token: String,
+        /// The min amount of LP tokens the vault must receive from compounding
+        min_lp_to_receive: Uint128,
+    },
+    /// Internal callbacks which can be executed only by the liquidity manager itself
+    Callback(CallbackMsg),
+}
+
+/// Describes how a reward token is swapped into one of the pool assets
+#[cw_serde]
+pub struct RewardRoute {
+    /// The reward token
+    pub reward: AssetInfo,
+    /// The router operations. The last operation must end up with one of the pool assets
+    pub operations: Vec<SwapOperation>,
+}
+
+/// Internal vault callbacks. Balances are snapshotted before an external call and compared after it
+/// since rewards are claimed implicitly on every deposit and withdrawal in the incentives contract.
+/// Shares are minted and burned in callbacks so pending rewards are claimed beforehand.
+#[cw_serde]
+pub enum CallbackMsg {
+    /// Attributes received rewards to the vault
+    TrackRewards {
+        lp_token: Addr,
+        balances_before: Vec<Asset>,
+    },
+    /// Swaps the vault idle rewards to pool assets
+    SwapRewards { lp_token: Addr },
+    /// Provides received pool assets to the pair and stakes the minted LP tokens
+    ProvideAndStake {
+        lp_token: Addr,
+        balances_before: Vec<Asset>,
+    },
+    /// Mints vault shares for the LP tokens held by the liquidity manager and stakes them
+    MintShares {
+        lp_token: Addr,
+        receiver: Addr,
+        amount: Uint128,
+        min_shares: Option<Uint128>,
+    },
+    /// Burns vault shares and sends their part of staked LP tokens and idle rewards to the owner
+    BurnShares {
+        lp_token: Addr,
+        owner: Addr,
+        shares: Uint128,
+    },
+    /// Ensures the vault received at least the specified amount of LP tokens since the snapshot
+    AssertCompounded {
+        lp_token: Addr,
+        lp_before: Uint128,
+        min_lp_to_receive: Uint128,
+    },
+}
+
+impl CallbackMsg {
+    /// Wraps the callback into a message executed by the liquidity manager on itself
+    pub fn into_cosmos_msg(self, contract_addr: &Addr) -> StdResult<CosmosMsg> {
+        Ok(wasm_execute(contract_addr, &ExecuteMsg::Callback(self), vec![])?.into())
+    }
 }
 
 /// This structure describes a CW20 hook message.
@@ -28,6 +117,12 @@ pub enum Cw20HookMsg {
         #[serde(default)]
         min_assets_to_receive: Vec<Asset>,
     },
+    /// Compounds pending vault rewards, deposits LP tokens to the vault and mints vault shares for the receiver.
+    /// Fails if the receiver gets less than `min_shares`
+    DepositToVault {
+        receiver: Option<String>,
+        min_shares: Option<Uint128>,
+    },
 }
 
 #[cw_serde]
@@ -43,6 +138,35 @@ pub enum QueryMsg {
         pair_addr: String,
         lp_tokens: Uint128,
     },
+    /// Returns the vault state
+    #[returns(VaultResponse)]
+    Vault { lp_token: String },
+    /// Returns the user vault shares and the amount of LP tokens they are worth
+    #[returns(VaultSharesResponse)]
+    VaultShares { lp_token: String, user: String },
+}
+
+#[cw_serde]
+pub struct VaultResponse {
+    pub pair_addr: Addr,
+    pub lp_token: Addr,
+    pub router_addr: Addr,
+    pub reward_routes: Vec<RewardRoute>,
+    pub max_spread: Decimal,
+    /// The address which is allowed to compound the vault
+    pub keeper: Addr,
+    /// The amount of LP tokens staked by the vault
+    pub total_lp: Uint128,
+    /// The total amount of vault shares
+    pub total_shares: Uint128,
+    /// Rewards which were claimed but not compounded yet
+    pub idle_rewards: Vec<Asset>,
+}
+
+#[cw_serde]
+pub struct VaultSharesResponse {
+    pub shares: Uint128,
+    pub lp_amount: Uint128,
 }
 
 /// Stable swap config which is used in raw queries. It's compatible with v1, v2 and v3 stable pair contract.
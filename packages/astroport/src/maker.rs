@@ -34,6 +34,8 @@ pub struct Config {
     pub pre_upgrade_astro_amount: Uint128,
     /// Parameters that describe the second receiver of fees
     pub second_receiver_cfg: Option<SecondReceiverConfig>,
+    /// Recipients that get their shares of collected fees before ITO and vITO stakers
+    pub fee_recipients: Vec<FeeRecipient>,
 }
 
 /// This structure stores general parameters for the contract.
@@ -57,6 +59,8 @@ pub struct InstantiateMsg {
     pub max_spread: Option<Decimal>,
     /// The second receiver parameters of fees
     pub second_receiver_params: Option<SecondReceiverParams>,
+    /// Recipients that get their shares of collected fees before ITO and vITO stakers
+    pub fee_recipients: Option<Vec<FeeRecipientParams>>,
 }
 
 /// This structure describes the functions that can be executed in this contract.
@@ -81,8 +85,11 @@ pub enum ExecuteMsg {
         basic_asset: Option<AssetInfo>,
         /// The maximum spread used when swapping fee tokens to ASTRO
         max_spread: Option<Decimal>,
-        /// The second receiver parameters of fees
+        /// The second receiver parameters of fees. Can't be set while there are fee recipients
         second_receiver_params: Option<SecondReceiverParams>,
+        /// The new list of fee recipients. An empty list removes all of them.
+        /// Removes the second receiver which must be included into the list if still needed
+        fee_recipients: Option<Vec<FeeRecipientParams>>,
     },
     /// Add bridge tokens used to swap specific fee tokens to ASTRO (effectively declaring a swap route)
     UpdateBridges {
@@ -106,6 +113,23 @@ pub enum ExecuteMsg {
     ClaimOwnership {},
     /// Enables the distribution of current fees accrued in the contract over "blocks" number of blocks
     EnableRewards { blocks: u64 },
+    /// Sets or removes the max allowed deviation of a fee token swap output from the price observed in the pair
+    UpdatePriceGuards {
+        add: Option<Vec<(AssetInfo, Decimal)>>,
+        remove: Option<Vec<AssetInfo>>,
+    },
+    /// Adds or removes addresses allowed to call Collect. If the list is empty anyone can call Collect
+    UpdateCollectKeepers {
+        add: Option<Vec<String>>,
+        remove: Option<Vec<String>>,
+    },
+    /// Sets or removes schedules used to swap large fee token balances in parts over time
+    UpdateDcaSchedules {
+        add: Option<Vec<(AssetInfo, DcaParams)>>,
+        remove: Option<Vec<AssetInfo>>,
+    },
+    /// Sets bridges of the specified fee tokens to the assets they are paired with in the deepest factory pools
+    AutoFillBridges { assets: Vec<AssetInfo> },
 }
 
 /// This structure describes the query functions available in the contract.
@@ -120,6 +144,19 @@ pub enum QueryMsg {
     Balances { assets: Vec<AssetInfo> },
     #[returns(Vec<(String, String)>)]
     Bridges {},
+    /// Returns the max allowed price deviation for each guarded fee token
+    #[returns(Vec<(String, Decimal)>)]
+    PriceGuards {},
+    /// Returns addresses allowed to call Collect
+    #[returns(Vec<Addr>)]
+    CollectKeepers {},
+    /// Returns DCA schedules along with upcoming swaps of the current fee token balances
+    #[returns(Vec<DcaScheduleResponse>)]
+    DcaSchedules {},
+    /// Returns routes to ASTRO for the specified assets and all native tokens held by the Maker.
+    /// Assets with zero balances are skipped
+    #[returns(Vec<BridgeRouteResponse>)]
+    BridgeRoutes { assets: Vec<AssetInfo> },
 }
 
 /// A custom struct that holds contract parameters and is used to retrieve them.
@@ -147,6 +184,8 @@ pub struct ConfigResponse {
     pub pre_upgrade_astro_amount: Uint128,
     /// Parameters that describe the second receiver of fees
     pub second_receiver_cfg: Option<SecondReceiverConfig>,
+    /// Recipients that get their shares of collected fees before ITO and vITO stakers
+    pub fee_recipients: Vec<FeeRecipient>,
 }
 
 /// A custom struct used to return multiple asset balances.
@@ -189,5 +228,89 @@ pub struct SecondReceiverConfig {
     pub second_receiver_cut: Uint64,
 }
 
+/// This structure describes the parameters for setting a recipient of fees.
+#[cw_serde]
+pub struct FeeRecipientParams {
+    /// The fee recipient address
+    pub address: String,
+    /// The share of collected fees that goes to the recipient. ASTRO recipients get
+    /// the share of ASTRO distributed by the Maker
+    pub share: Decimal,
+    /// Whether the recipient receives collected fee tokens as is instead of ASTRO
+    pub raw_asset: bool,
+}
+
+/// This structure stores the parameters for a recipient of fees.
+#[cw_serde]
+pub struct FeeRecipient {
+    /// The fee recipient address
+    pub address: Addr,
+    /// The share of collected fees that goes to the recipient
+    pub share: Decimal,
+    /// Whether the recipient receives collected fee tokens as is instead of ASTRO
+    pub raw_asset: bool,
+}
+
+/// This structure describes the parameters of swapping a fee token in parts over time.
+#[cw_serde]
+pub struct DcaParams {
+    /// The max fraction of the fee token balance swapped per interval
+    pub max_fraction: Decimal,
+    /// The min number of seconds between two swaps
+    pub interval: u64,
+    /// Balances up to this amount are swapped at once
+    pub min_swap_amount: Uint128,
+}
+
+/// This structure stores the DCA schedule of a fee token.
+#[cw_serde]
+pub struct DcaSchedule {
+    /// The fee token
+    pub asset_info: AssetInfo,
+    /// The DCA parameters
+    pub params: DcaParams,
+    /// The timestamp of the last swap
+    pub last_execution: Option<u64>,
+    /// The fee token balance left unswapped after the last swap
+    pub remaining: Uint128,
+}
+
+/// A custom struct used to return a DCA schedule along with upcoming swaps.
+#[cw_serde]
+pub struct DcaScheduleResponse {
+    /// The DCA schedule of a fee token
+    pub schedule: DcaSchedule,
+    /// The current fee token balance of the Maker
+    pub balance: Uint128,
+    /// Upcoming swaps of the current balance as (timestamp, amount) pairs
+    pub pending_swaps: Vec<(u64, Uint128)>,
+}
+
+/// This structure describes a hop of a route to ASTRO which can't be executed.
+#[cw_serde]
+pub struct BrokenHop {
+    /// The asset being swapped
+    pub from: AssetInfo,
+    /// The asset to receive
+    pub to: AssetInfo,
+    /// The reason why the swap can't be executed
+    pub reason: String,
+}
+
+/// A custom struct used to return a route used to swap a fee token to ASTRO.
+#[cw_serde]
+pub struct BridgeRouteResponse {
+    /// The fee token along with the Maker's balance
+    pub asset: Asset,
+    /// Whether the fee token can be swapped to ASTRO
+    pub route_exists: bool,
+    /// The assets the fee token is swapped through, starting with the fee token itself
+    pub route: Vec<AssetInfo>,
+    /// The simulated amount of ASTRO received for the whole balance
+    pub simulated_output: Uint128,
+    /// Hops that prevent the fee token from being swapped to ASTRO
+    pub broken_hops: Vec<BrokenHop>,
+}
+
 /// The maximum allowed second receiver share (percents)
 pub const MAX_SECOND_RECEIVER_CUT: Uint64 = Uint64::new(50);
@@ -5,6 +5,7 @@ use cosmwasm_std::{
 };
 use cw_storage_plus::Item;
 
+use astroport_circular_buffer::error::BufferResult;
 use astroport_circular_buffer::{BufferManager, CircularBuffer};
 
 use crate::cosmwasm_ext::AbsDiff;
@@ -31,6 +32,102 @@ pub struct OracleObservation {
     pub price: Decimal,
 }
 
+#[cw_serde]
+pub struct OracleTwap {
+    /// Start of the averaging window
+    pub start_timestamp: u64,
+    /// End of the averaging window
+    pub end_timestamp: u64,
+    /// Time-weighted average price over the window
+    pub price: Decimal,
+}
+
+/// Returns the time-weighted average price over the window which started 'start_seconds_ago' seconds ago
+/// and ended 'end_seconds_ago' seconds ago. Each observed price is considered valid until the next
+/// observation. The window must be fully covered by stored observations.
+pub fn query_observation_twap<C>(
+    deps: Deps<C>,
+    env: Env,
+    observations: CircularBuffer<Observation>,
+    start_seconds_ago: u64,
+    end_seconds_ago: u64,
+) -> StdResult<OracleTwap>
+where
+    C: CustomQuery,
+{
+    if start_seconds_ago <= end_seconds_ago {
+        return Err(StdError::generic_err(
+            "start_seconds_ago must be greater than end_seconds_ago",
+        ));
+    }
+
+    let buffer = BufferManager::new(deps.storage, observations)?;
+    let start = env
+        .block
+        .time
+        .seconds()
+        .checked_sub(start_seconds_ago)
+        .ok_or_else(|| StdError::generic_err("start_seconds_ago exceeds block time"))?;
+    let end = env.block.time.seconds() - end_seconds_ago;
+
+    let mut oldest_ind = buffer.head();
+    let mut newest_ind = buffer.head() + buffer.capacity() - 1;
+
+    if !buffer.exists(deps.storage, oldest_ind) {
+        if buffer.head() > 0 {
+            oldest_ind = 0;
+            newest_ind %= buffer.capacity();
+        } else {
+            return Err(StdError::generic_err("Buffer is empty"));
+        }
+    }
+
+    let oldest_obs = buffer.read_single(deps.storage, oldest_ind)?.unwrap();
+    if start < oldest_obs.ts {
+        return Err(StdError::generic_err(format!(
+            "Requested window is not fully covered by observations. Last known observation is at {}",
+            oldest_obs.ts
+        )));
+    }
+
+    let mut ind = floor_search(deps.storage, &buffer, start, oldest_ind, newest_ind)?;
+    let mut current = buffer.read_single(deps.storage, ind)?.unwrap();
+    let mut price_times_time = Uint256::zero();
+
+    loop {
+        let next = if ind < newest_ind {
+            buffer.read_single(deps.storage, ind + 1)?
+        } else {
+            None
+        };
+        let segment_start = current.ts.max(start);
+        let segment_end = next.map(|obs| obs.ts).unwrap_or(end).min(end);
+
+        if segment_end > segment_start {
+            price_times_time += Uint256::from(current.price.numerator())
+                * Uint256::from(segment_end - segment_start);
+        }
+
+        match next {
+            Some(next) if next.ts < end => {
+                current = next;
+                ind += 1;
+            }
+            _ => break,
+        }
+    }
+
+    let price = try_dec256_into_dec(Decimal256::new(
+        price_times_time / Uint256::from(end - start),
+    ))?;
+
+    Ok(OracleTwap {
+        start_timestamp: start,
+        end_timestamp: end,
+        price,
+    })
+}
+
 /// Returns price observation at point that was 'seconds_ago' seconds ago.
 pub fn query_observation<C>(
     deps: Deps<C>,
@@ -152,6 +249,33 @@ fn binary_search(
     }
 }
 
+/// Returns the index of the latest observation in [start, end] which was made at or before target.
+/// The observation at start must be made at or before target.
+fn floor_search(
+    storage: &dyn Storage,
+    buffer: &BufferManager<Observation>,
+    target: u64,
+    mut start: u32,
+    mut end: u32,
+) -> StdResult<u32> {
+    while start < end {
+        let mid = (start + end + 1) / 2;
+        let obs = buffer.read_single(storage, mid)?.ok_or_else(|| {
+            StdError::generic_err(format!(
+                "Unexpected error in floor_search: observation is None at index {mid}",
+            ))
+        })?;
+
+        if obs.ts <= target {
+            start = mid;
+        } else {
+            end = mid - 1;
+        }
+    }
+
+    Ok(start)
+}
+
 #[cw_serde]
 pub struct PrecommitObservation {
     pub base_amount: Uint128,
@@ -193,6 +317,71 @@ impl<'a> PrecommitObservation {
     }
 }
 
+/// Calculates the price moving average from the precommitted swap sizes and saves new observation.
+/// Precommitted observation is saved only once the block it was made in is over.
+pub fn accumulate_swap_sizes(
+    storage: &mut dyn Storage,
+    env: &Env,
+    observations: CircularBuffer<Observation>,
+) -> BufferResult<()> {
+    if let Some(PrecommitObservation {
+        base_amount,
+        quote_amount,
+        precommit_ts,
+    }) = PrecommitObservation::may_load(storage)?
+    {
+        let mut buffer = BufferManager::new(storage, observations)?;
+        let observed_price = Decimal::from_ratio(base_amount, quote_amount);
+
+        let new_observation;
+        if let Some(last_obs) = buffer.read_last(storage)? {
+            // Skip saving observation if it has been already saved
+            if last_obs.ts < precommit_ts {
+                // Since this is circular buffer the next index contains the oldest value
+                let count = buffer.capacity();
+                if let Some(oldest_obs) = buffer.read_single(storage, buffer.head() + 1)? {
+                    let price_sma = safe_sma_calculation(
+                        last_obs.price_sma,
+                        oldest_obs.price,
+                        count,
+                        observed_price,
+                    )?;
+                    new_observation = Observation {
+                        ts: precommit_ts,
+                        price: observed_price,
+                        price_sma,
+                    };
+                } else {
+                    // Buffer is not full yet
+                    let count = buffer.head();
+                    let price_sma =
+                        safe_sma_buffer_not_full(last_obs.price_sma, count, observed_price)?;
+                    new_observation = Observation {
+                        ts: precommit_ts,
+                        price: observed_price,
+                        price_sma,
+                    };
+                }
+
+                buffer.instant_push(storage, &new_observation)?
+            }
+        } else {
+            // Buffer is empty
+            if env.block.time.seconds() > precommit_ts {
+                new_observation = Observation {
+                    ts: precommit_ts,
+                    price: observed_price,
+                    price_sma: observed_price,
+                };
+
+                buffer.instant_push(storage, &new_observation)?
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub fn try_dec256_into_dec(val: Decimal256) -> StdResult<Decimal> {
     let numerator: Uint128 = val.numerator().try_into()?;
 
@@ -1,6 +1,9 @@
 use crate::asset::AssetInfo;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Uint128, Uint256};
+use cosmwasm_std::{Addr, Decimal256, Uint128, Uint256};
+
+/// The maximum number of assets in a path used to compose prices across feeds
+pub const MAX_CONSULT_PATH_LEN: usize = 5;
 
 /// This structure stores general parameters for the contract.
 #[cw_serde]
@@ -16,6 +19,30 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     /// Update/accumulate prices
     Update {},
+    /// Registers a price feed for the factory pair with the specified assets. Only the owner can execute this.
+    RegisterFeed {
+        /// The assets of the pair for which a feed is registered
+        asset_infos: Vec<AssetInfo>,
+        /// The TWAP window of the feed in seconds
+        period: u64,
+    },
+    /// Changes the TWAP window of a registered feed. Only the owner can execute this.
+    UpdateFeedPeriod {
+        /// The pair contract address of the feed
+        pair: String,
+        /// The new TWAP window of the feed in seconds
+        period: u64,
+    },
+    /// Removes a registered feed. Only the owner can execute this.
+    RemoveFeed {
+        /// The pair contract address of the feed
+        pair: String,
+    },
+    /// Update/accumulate prices of a registered feed
+    UpdateFeed {
+        /// The pair contract address of the feed
+        pair: String,
+    },
 }
 
 /// This structure describes the query messages available in the contract.
@@ -30,6 +57,55 @@ pub enum QueryMsg {
         /// The amount of tokens for which to compute the token price
         amount: Uint128,
     },
+    /// Returns information about a registered feed
+    #[returns(FeedResponse)]
+    Feed {
+        /// The pair contract address of the feed
+        pair: String,
+    },
+    /// Returns information about registered feeds
+    #[returns(Vec<FeedResponse>)]
+    Feeds {
+        /// The pair contract address of the feed to start reading from
+        start_after: Option<String>,
+        /// The number of feeds to return
+        limit: Option<u32>,
+    },
+    /// Converts an amount of the first asset in the path to the last one using the TWAPs of
+    /// the feeds registered for each consecutive pair of assets
+    #[returns(ConsultPathResponse)]
+    ConsultPath {
+        /// The assets to convert through, starting with the asset being priced
+        path: Vec<AssetInfo>,
+        /// The amount of tokens of the first asset
+        amount: Uint128,
+        /// The maximum number of seconds since the last update of every feed in the path
+        max_age: Option<u64>,
+    },
+}
+
+/// This structure describes a registered price feed.
+#[cw_serde]
+pub struct FeedResponse {
+    /// The pair contract address
+    pub pair: Addr,
+    /// The assets in the pair
+    pub asset_infos: Vec<AssetInfo>,
+    /// The TWAP window in seconds
+    pub period: u64,
+    /// Average prices for each pair of assets in the pool over the last window
+    pub average_prices: Vec<(AssetInfo, AssetInfo, Decimal256)>,
+    /// The timestamp of the last update
+    pub last_updated: u64,
+}
+
+/// This structure describes a price composed across several feeds.
+#[cw_serde]
+pub struct ConsultPathResponse {
+    /// The amount of the last asset in the path
+    pub amount: Uint256,
+    /// The timestamp of the least recently updated feed in the path
+    pub last_updated: u64,
 }
 
 /// This structure describes a migration message.
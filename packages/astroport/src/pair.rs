@@ -1,7 +1,8 @@
-use crate::observation::OracleObservation;
+use crate::observation::{OracleObservation, OracleTwap};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 
 use crate::asset::{Asset, AssetInfo, PairInfo};
+use crate::factory::PauseStatus;
 
 use cosmwasm_std::{Addr, Binary, Decimal, Decimal256, Uint128, Uint64};
 use cw20::Cw20ReceiveMsg;
@@ -49,6 +50,18 @@ pub enum ExecuteMsg {
         /// The receiver of LP tokens
         receiver: Option<String>,
     },
+    /// ProvideSingleAsset allows someone to provide liquidity with only one of the pool assets.
+    /// Part of the asset is swapped internally so the rest of the deposit matches the pool ratio
+    ProvideSingleAsset {
+        /// The asset to provide
+        asset: Asset,
+        /// The minimum amount of LP tokens to receive
+        min_lp_to_receive: Option<Uint128>,
+        /// Determines whether the LP tokens minted for the user is auto_staked in the Generator contract
+        auto_stake: Option<bool>,
+        /// The receiver of LP tokens
+        receiver: Option<String>,
+    },
     /// Swap performs a swap in the pool
     Swap {
         offer_asset: Asset,
@@ -88,6 +101,14 @@ pub enum Cw20HookMsg {
         #[serde(default)]
         assets: Vec<Asset>,
     },
+    /// Withdraw liquidity from the pool in a single asset. The share of the other asset
+    /// is swapped into the requested asset inside the pool
+    WithdrawSingleAsset {
+        /// The asset to receive
+        ask_asset_info: AssetInfo,
+        /// The minimum amount of the asset to receive
+        min_amount_out: Option<Uint128>,
+    },
 }
 
 /// This structure describes the query messages available in the contract.
@@ -133,6 +154,16 @@ pub enum QueryMsg {
     /// Query price from observations
     #[returns(OracleObservation)]
     Observe { seconds_ago: u64 },
+    /// Query the time-weighted average price from observations over the window
+    /// [start_seconds_ago, end_seconds_ago]
+    #[returns(OracleTwap)]
+    ObserveTwap {
+        start_seconds_ago: u64,
+        end_seconds_ago: u64,
+    },
+    /// Returns the pause status of the pair set in the factory
+    #[returns(PauseStatus)]
+    PauseStatus {},
 }
 
 /// This struct is used to return a query result with the total amount of LP tokens and assets in a specific pool.
@@ -220,6 +251,23 @@ pub struct XYKPoolConfig {
     pub track_asset_balances: bool,
     // The config for swap fee sharing
     pub fee_share: Option<FeeShareConfig>,
+    /// The config for the volatility-based dynamic fee
+    pub dynamic_fee: Option<DynamicFeeConfig>,
+}
+
+/// This structure stores the parameters of the XYK pool dynamic fee. The total fee is scaled
+/// between `min_fee_bps` and `max_fee_bps` by the relative deviation of the last observed price
+/// from its time-weighted average over the `window`.
+#[cw_serde]
+pub struct DynamicFeeConfig {
+    /// The fee (in bps) charged when the price is stable
+    pub min_fee_bps: u16,
+    /// The fee (in bps) charged when the price deviation reaches `volatility_cap`
+    pub max_fee_bps: u16,
+    /// The period (in seconds) over which the average price is calculated
+    pub window: u64,
+    /// The relative price deviation at which the maximum fee is charged
+    pub volatility_cap: Decimal,
 }
 
 /// This enum stores the option available to enable asset balances tracking over blocks.
@@ -235,6 +283,18 @@ pub enum XYKPoolUpdateParams {
         fee_share_address: String,
     },
     DisableFeeShare,
+    /// Enables the volatility-based dynamic fee which replaces the pair type total fee.
+    EnableDynamicFee {
+        /// The fee (in bps) charged when the price is stable
+        min_fee_bps: u16,
+        /// The fee (in bps) charged when the price deviation reaches `volatility_cap`
+        max_fee_bps: u16,
+        /// The period (in seconds) over which the average price is calculated
+        window: u64,
+        /// The relative price deviation at which the maximum fee is charged
+        volatility_cap: Decimal,
+    },
+    DisableDynamicFee,
 }
 
 /// This structure holds stableswap pool parameters.
@@ -244,6 +304,8 @@ pub struct StablePoolParams {
     pub amp: u64,
     /// The contract owner
     pub owner: Option<String>,
+    /// Rate providers of assets which accrue value against the other pool asset
+    pub rate_providers: Option<Vec<RateProvider>>,
 }
 
 /// This structure stores a stableswap pool's configuration.
@@ -253,6 +315,39 @@ pub struct StablePoolConfig {
     pub amp: Decimal,
     // The config for swap fee sharing
     pub fee_share: Option<FeeShareConfig>,
+    /// Rate providers used to scale pool assets
+    #[serde(default)]
+    pub rate_providers: Vec<RateProvider>,
+}
+
+/// This structure describes a rate provider of a stableswap pool asset.
+/// Balances of the asset are multiplied by the rate before the stableswap invariant is calculated.
+#[cw_serde]
+pub struct RateProvider {
+    /// The asset scaled by the rate
+    pub asset_info: AssetInfo,
+    /// The contract which is queried for the current rate using [`RateProviderQueryMsg`]
+    pub provider_addr: String,
+    /// The maximum age (in seconds) of the rate reported by the provider
+    pub max_staleness: u64,
+}
+
+/// This enum describes the queries a rate provider contract must support.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum RateProviderQueryMsg {
+    /// Returns the current redemption rate of the asset in terms of the other pool asset
+    #[returns(RateResponse)]
+    Rate {},
+}
+
+/// This structure holds the response of a [`RateProviderQueryMsg::Rate`] query.
+#[cw_serde]
+pub struct RateResponse {
+    /// The redemption rate of the asset
+    pub rate: Decimal,
+    /// The timestamp (in seconds) when the rate was last updated
+    pub updated_at: u64,
 }
 
 /// This enum stores the options available to start and stop changing a stableswap pool's amplification.
@@ -271,6 +366,29 @@ pub enum StablePoolUpdateParams {
         fee_share_address: String,
     },
     DisableFeeShare,
+    /// Sets or replaces the rate provider of a pool asset.
+    SetRateProvider {
+        rate_provider: RateProvider,
+    },
+    /// Removes the rate provider of a pool asset so that it is traded 1:1 again.
+    RemoveRateProvider {
+        asset_info: AssetInfo,
+    },
+}
+
+/// This structure holds weighted pool parameters.
+#[cw_serde]
+pub struct WeightedPoolParams {
+    /// Normalized asset weights in the same order as the pool assets. Weights must sum up to 1.
+    /// Weights with a sum within 0.000001 of 1 are scaled to sum up to 1
+    pub weights: Vec<Decimal>,
+}
+
+/// This structure stores a weighted pool's configuration.
+#[cw_serde]
+pub struct WeightedPoolConfig {
+    /// Normalized asset weights in the same order as the pool assets
+    pub weights: Vec<Decimal>,
 }
 
 #[cfg(test)]
@@ -320,6 +438,7 @@ mod tests {
                 to_binary(&StablePoolConfig {
                     amp: Decimal::one(),
                     fee_share: None,
+                    rate_providers: vec![],
                 })
                 .unwrap(),
             ),
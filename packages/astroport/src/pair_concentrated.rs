@@ -3,7 +3,8 @@ use cosmwasm_std::{Decimal, Decimal256, Uint128, Uint64};
 
 use crate::asset::PairInfo;
 use crate::asset::{Asset, AssetInfo};
-use crate::observation::OracleObservation;
+use crate::factory::PauseStatus;
+use crate::observation::{OracleObservation, OracleTwap};
 use crate::pair::{
     ConfigResponse, CumulativePricesResponse, FeeShareConfig, PoolResponse,
     ReverseSimulationResponse, SimulationResponse,
@@ -156,6 +157,16 @@ pub enum QueryMsg {
     /// Query price from observations
     #[returns(OracleObservation)]
     Observe { seconds_ago: u64 },
+    /// Query the time-weighted average price from observations over the window
+    /// [start_seconds_ago, end_seconds_ago]
+    #[returns(OracleTwap)]
+    ObserveTwap {
+        start_seconds_ago: u64,
+        end_seconds_ago: u64,
+    },
+    /// Returns the pause status of the pair set in the factory
+    #[returns(PauseStatus)]
+    PauseStatus {},
 }
 
 #[cw_serde]
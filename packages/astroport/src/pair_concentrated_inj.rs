@@ -1,4 +1,4 @@
-use crate::observation::OracleObservation;
+use crate::observation::{OracleObservation, OracleTwap};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Binary, Decimal, Decimal256, Uint128};
 use cw20::Cw20ReceiveMsg;
@@ -112,6 +112,13 @@ pub enum QueryMsg {
     /// Query price from observations
     #[returns(OracleObservation)]
     Observe { seconds_ago: u64 },
+    /// Query the time-weighted average price from observations over the window
+    /// [start_seconds_ago, end_seconds_ago]
+    #[returns(OracleTwap)]
+    ObserveTwap {
+        start_seconds_ago: u64,
+        end_seconds_ago: u64,
+    },
     #[returns(OrderbookStateResponse)]
     OrderbookState {},
 }
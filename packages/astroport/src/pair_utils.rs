@@ -0,0 +1,176 @@
+use cosmwasm_std::{to_binary, wasm_execute, Addr, CosmosMsg, QuerierWrapper, StdError, Uint128};
+use cw20::Cw20ExecuteMsg;
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::asset::{Asset, AssetInfo, DecimalAsset};
+use crate::querier::query_factory_config;
+
+/// This enum describes errors of the helpers shared by multi-asset pair contracts.
+#[derive(Error, Debug, PartialEq)]
+pub enum PairUtilsError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Native token balance mismatch between the argument and the transferred")]
+    AssetMismatch {},
+
+    #[error("Generator address is not set in factory. Cannot autostake")]
+    AutoStakeError {},
+
+    #[error("The asset {0} does not belong to the pair")]
+    InvalidAsset(String),
+
+    #[error("Ask or offer asset is missed")]
+    VariableAssetMissed {},
+
+    #[error("Source and target assets are the same")]
+    SameAssets {},
+}
+
+/// Select offer and ask pools based on given offer and ask infos.
+/// This function works with pools with up to 8 assets. Returns (offer_pool, ask_pool) in case of success.
+/// If it is impossible to define offer and ask pools, returns [`PairUtilsError`].
+///
+/// * **offer_asset_info** - asset info of the offer asset.
+///
+/// * **ask_asset_info** - asset info of the ask asset.
+///
+/// * **pools** - list of pools.
+pub fn select_pools(
+    offer_asset_info: Option<&AssetInfo>,
+    ask_asset_info: Option<&AssetInfo>,
+    pools: &[DecimalAsset],
+) -> Result<(DecimalAsset, DecimalAsset), PairUtilsError> {
+    if pools.len() == 2 {
+        match (offer_asset_info, ask_asset_info) {
+            (Some(offer_asset_info), _) => {
+                let (offer_ind, offer_pool) = pools
+                    .iter()
+                    .find_position(|pool| pool.info.eq(offer_asset_info))
+                    .ok_or(PairUtilsError::AssetMismatch {})?;
+                Ok((offer_pool.clone(), pools[(offer_ind + 1) % 2].clone()))
+            }
+            (_, Some(ask_asset_info)) => {
+                let (ask_ind, ask_pool) = pools
+                    .iter()
+                    .find_position(|pool| pool.info.eq(ask_asset_info))
+                    .ok_or(PairUtilsError::AssetMismatch {})?;
+                Ok((pools[(ask_ind + 1) % 2].clone(), ask_pool.clone()))
+            }
+            _ => Err(PairUtilsError::VariableAssetMissed {}), // Should always be unreachable
+        }
+    } else if let (Some(offer_asset_info), Some(ask_asset_info)) =
+        (offer_asset_info, ask_asset_info)
+    {
+        if ask_asset_info.eq(offer_asset_info) {
+            return Err(PairUtilsError::SameAssets {});
+        }
+
+        let offer_pool = pools
+            .iter()
+            .find(|pool| pool.info.eq(offer_asset_info))
+            .ok_or(PairUtilsError::AssetMismatch {})?;
+        let ask_pool = pools
+            .iter()
+            .find(|pool| pool.info.eq(ask_asset_info))
+            .ok_or(PairUtilsError::AssetMismatch {})?;
+
+        Ok((offer_pool.clone(), ask_pool.clone()))
+    } else {
+        Err(PairUtilsError::VariableAssetMissed {}) // Should always be unreachable
+    }
+}
+
+/// Mint LP tokens for a beneficiary and auto stake the tokens in the Generator contract (if auto staking is specified).
+///
+/// * **lp_token** LP token of the pair.
+///
+/// * **factory_addr** factory contract which stores the Generator address.
+///
+/// * **recipient** LP token recipient.
+///
+/// * **amount** amount of LP tokens that will be minted for the recipient.
+///
+/// * **auto_stake** whether the newly minted LP tokens will be automatically staked in the Generator on behalf of the recipient.
+pub fn mint_liquidity_token_message(
+    querier: QuerierWrapper,
+    lp_token: &Addr,
+    factory_addr: &Addr,
+    contract_address: &Addr,
+    recipient: &Addr,
+    amount: Uint128,
+    auto_stake: bool,
+) -> Result<Vec<CosmosMsg>, PairUtilsError> {
+    // If no auto-stake - just mint to recipient
+    if !auto_stake {
+        return Ok(vec![wasm_execute(
+            lp_token,
+            &Cw20ExecuteMsg::Mint {
+                recipient: recipient.to_string(),
+                amount,
+            },
+            vec![],
+        )?
+        .into()]);
+    }
+
+    // Mint for the pair contract and stake into the Generator contract
+    let generator = query_factory_config(&querier, factory_addr)?.generator_address;
+
+    if let Some(generator) = generator {
+        Ok(vec![
+            wasm_execute(
+                lp_token,
+                &Cw20ExecuteMsg::Mint {
+                    recipient: contract_address.to_string(),
+                    amount,
+                },
+                vec![],
+            )?
+            .into(),
+            wasm_execute(
+                lp_token,
+                &Cw20ExecuteMsg::Send {
+                    contract: generator.to_string(),
+                    amount,
+                    msg: to_binary(&crate::generator::Cw20HookMsg::DepositFor(
+                        recipient.to_string(),
+                    ))?,
+                },
+                vec![],
+            )?
+            .into(),
+        ])
+    } else {
+        Err(PairUtilsError::AutoStakeError {})
+    }
+}
+
+/// Determines which of the swap amounts is the base one and which is the quote one.
+/// The first pool asset is the base asset.
+///
+/// * **pools** - list of pools.
+///
+/// * **offer_asset** - asset that is being offered.
+///
+/// * **return_amount** - amount of the ask asset returned by the swap.
+pub fn determine_base_quote_amount(
+    pools: &[DecimalAsset],
+    offer_asset: &Asset,
+    return_amount: Uint128,
+) -> Result<(Uint128, Uint128), PairUtilsError> {
+    let offer_index = pools
+        .iter()
+        .find_position(|asset| asset.info == offer_asset.info)
+        .ok_or_else(|| PairUtilsError::InvalidAsset(offer_asset.info.to_string()))?
+        .0;
+
+    let (base_amount, quote_amount) = if offer_index == 0 {
+        (offer_asset.amount, return_amount)
+    } else {
+        (return_amount, offer_asset.amount)
+    };
+
+    Ok((base_amount, quote_amount))
+}
@@ -1,6 +1,7 @@
 use crate::asset::{Asset, AssetInfo, PairInfo};
 use crate::factory::{
-    Config as FactoryConfig, FeeInfoResponse, PairType, PairsResponse, QueryMsg as FactoryQueryMsg,
+    Config as FactoryConfig, FeeInfoResponse, PairType, PairsResponse, PauseStatus,
+    QueryMsg as FactoryQueryMsg,
 };
 use crate::pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse};
 
@@ -9,6 +10,8 @@ use cosmwasm_std::{
     QueryRequest, StdError, StdResult, Uint128,
 };
 
+use cw_storage_plus::Map;
+
 use cw20::{BalanceResponse as Cw20BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 
 /// Returns a native token's balance for a specific account.
@@ -161,19 +164,28 @@ pub struct FeeInfo {
     pub maker_fee_rate: Decimal,
 }
 
-/// Returns the fee information for a specific pair type.
+/// Returns the fee information for a specific pair. The pair's fee override is used if it is set
+/// in the factory, otherwise the fees of its pair type are returned.
 ///
 /// * **pair_type** pair type we query information for.
+///
+/// * **pair_addr** pair contract address we query information for.
 pub fn query_fee_info<C>(
     querier: &QuerierWrapper<C>,
     factory_contract: impl Into<String>,
     pair_type: PairType,
+    pair_addr: &Addr,
 ) -> StdResult<FeeInfo>
 where
     C: CustomQuery,
 {
-    let res: FeeInfoResponse =
-        querier.query_wasm_smart(factory_contract, &FactoryQueryMsg::FeeInfo { pair_type })?;
+    let res: FeeInfoResponse = querier.query_wasm_smart(
+        factory_contract,
+        &FactoryQueryMsg::FeeInfo {
+            pair_type,
+            pair_addr: Some(pair_addr.to_string()),
+        },
+    )?;
 
     Ok(FeeInfo {
         fee_address: res.fee_address,
@@ -182,6 +194,38 @@ where
     })
 }
 
+/// Returns the pause status of a pair by reading the factory storage directly. An operation is
+/// paused if it is paused either for the pair type or for the pair itself.
+///
+/// * **pair_type** is the type of the pair.
+///
+/// * **pair_addr** is the pair contract address.
+pub fn query_pause_status<C>(
+    querier: &QuerierWrapper<C>,
+    factory_contract: impl Into<String>,
+    pair_type: &PairType,
+    pair_addr: &Addr,
+) -> StdResult<PauseStatus>
+where
+    C: CustomQuery,
+{
+    let factory_contract = factory_contract.into();
+    let pair_type_key =
+        Map::<String, PauseStatus>::new("pair_type_pauses").key(pair_type.to_string());
+    let pair_key = Map::<&Addr, PauseStatus>::new("pair_pauses").key(pair_addr);
+
+    let mut status = PauseStatus::default();
+    for key in [&*pair_type_key, &*pair_key] {
+        if let Some(res) = querier.query_wasm_raw(&factory_contract, key)? {
+            let res: PauseStatus = from_slice(&res)?;
+            status.swaps_paused |= res.swaps_paused;
+            status.provide_paused |= res.provide_paused;
+        }
+    }
+
+    Ok(status)
+}
+
 /// Accepts two tokens as input and returns a pair's information.
 pub fn query_pair_info(
     querier: &QuerierWrapper,
@@ -6,6 +6,10 @@ use cw20::Cw20ReceiveMsg;
 use crate::asset::AssetInfo;
 
 pub const MAX_SWAP_OPERATIONS: usize = 50;
+/// The maximum number of routes allowed in a single split swap
+pub const MAX_SWAP_ROUTES: usize = 10;
+/// The maximum number of hops allowed when searching for the best route
+pub const MAX_ROUTE_HOPS: u32 = 3;
 
 /// This structure holds the parameters used for creating a contract.
 #[cw_serde]
@@ -42,6 +46,26 @@ impl SwapOperation {
             SwapOperation::AstroSwap { ask_asset_info, .. } => ask_asset_info.clone(),
         }
     }
+
+    pub fn get_offer_asset_info(&self) -> AssetInfo {
+        match self {
+            SwapOperation::NativeSwap { offer_denom, .. } => AssetInfo::NativeToken {
+                denom: offer_denom.clone(),
+            },
+            SwapOperation::AstroSwap {
+                offer_asset_info, ..
+            } => offer_asset_info.clone(),
+        }
+    }
+}
+
+/// This structure describes a single weighted route used in a split swap.
+#[cw_serde]
+pub struct SwapRoute {
+    /// The swap operations to perform on this route
+    pub operations: Vec<SwapOperation>,
+    /// The share of the offer amount routed through this path, relative to the other routes' weights
+    pub weight: u64,
 }
 
 /// This structure describes the execute messages available in the contract.
@@ -56,6 +80,34 @@ pub enum ExecuteMsg {
         to: Option<String>,
         max_spread: Option<Decimal>,
     },
+    /// ExecuteSplitSwapOperations splits the offer amount across several weighted routes
+    /// and enforces a single minimum amount of tokens to receive across all of them
+    ExecuteSplitSwapOperations {
+        routes: Vec<SwapRoute>,
+        minimum_receive: Option<Uint128>,
+        to: Option<String>,
+        max_spread: Option<Decimal>,
+    },
+    /// ExecuteReverseSwapOperations swaps the amount of offer tokens needed to receive `ask_amount`
+    /// of the last ask asset and refunds the leftover offer tokens to the sender
+    ExecuteReverseSwapOperations {
+        operations: Vec<SwapOperation>,
+        ask_amount: Uint128,
+        max_offer: Option<Uint128>,
+        minimum_receive: Option<Uint128>,
+        to: Option<String>,
+        max_spread: Option<Decimal>,
+    },
+    /// ExecuteBestRouteSwap swaps the router balance of the offer asset through the best route
+    /// found among the factory pairs
+    ExecuteBestRouteSwap {
+        offer_asset_info: AssetInfo,
+        ask_asset_info: AssetInfo,
+        max_hops: Option<u32>,
+        minimum_receive: Option<Uint128>,
+        to: Option<String>,
+        max_spread: Option<Decimal>,
+    },
 
     /// Internal use
     /// ExecuteSwapOperation executes a single swap operation
@@ -84,6 +136,42 @@ pub enum Cw20HookMsg {
         /// Max spread
         max_spread: Option<Decimal>,
     },
+    ExecuteSplitSwapOperations {
+        /// A vector of weighted routes
+        routes: Vec<SwapRoute>,
+        /// The minimum amount of tokens to get from all routes combined
+        minimum_receive: Option<Uint128>,
+        /// The recipient
+        to: Option<String>,
+        /// Max spread
+        max_spread: Option<Decimal>,
+    },
+    ExecuteReverseSwapOperations {
+        /// A vector of swap operations
+        operations: Vec<SwapOperation>,
+        /// The amount of ask tokens to get from a swap
+        ask_amount: Uint128,
+        /// The maximum amount of offer tokens to spend
+        max_offer: Option<Uint128>,
+        /// The minimum amount of tokens to get from a swap
+        minimum_receive: Option<Uint128>,
+        /// The recipient
+        to: Option<String>,
+        /// Max spread
+        max_spread: Option<Decimal>,
+    },
+    ExecuteBestRouteSwap {
+        /// The asset to swap to
+        ask_asset_info: AssetInfo,
+        /// The maximum number of hops in the route
+        max_hops: Option<u32>,
+        /// The minimum amount of tokens to get from a swap
+        minimum_receive: Option<Uint128>,
+        /// The recipient
+        to: Option<String>,
+        /// Max spread
+        max_spread: Option<Decimal>,
+    },
 }
 
 /// This structure describes the query messages available in the contract.
@@ -101,6 +189,35 @@ pub enum QueryMsg {
         /// The swap operations to perform, each swap involving a specific pool
         operations: Vec<SwapOperation>,
     },
+    /// SimulateSplitSwapOperations simulates a swap split across several weighted routes
+    #[returns(SimulateSwapOperationsResponse)]
+    SimulateSplitSwapOperations {
+        /// The amount of tokens to swap
+        offer_amount: Uint128,
+        /// The weighted routes used to split the offer amount
+        routes: Vec<SwapRoute>,
+    },
+    /// ReverseSimulateSwapOperations simulates multi-hop swap operations backwards and returns
+    /// the amount of offer tokens needed to receive the specified amount of ask tokens
+    #[returns(SimulateSwapOperationsResponse)]
+    ReverseSimulateSwapOperations {
+        /// The amount of tokens to receive
+        ask_amount: Uint128,
+        /// The swap operations to perform, each swap involving a specific pool
+        operations: Vec<SwapOperation>,
+    },
+    /// BestRoute searches the factory pairs for the route that returns the most ask tokens
+    #[returns(BestRouteResponse)]
+    BestRoute {
+        /// The asset to swap from
+        offer_asset_info: AssetInfo,
+        /// The asset to swap to
+        ask_asset_info: AssetInfo,
+        /// The amount of tokens to swap
+        offer_amount: Uint128,
+        /// The maximum number of hops in the route
+        max_hops: Option<u32>,
+    },
 }
 
 /// This structure describes a custom struct to return a query response containing the base contract configuration.
@@ -117,6 +234,15 @@ pub struct SimulateSwapOperationsResponse {
     pub amount: Uint128,
 }
 
+/// This structure describes a custom struct to return a query response containing the best route found
+#[cw_serde]
+pub struct BestRouteResponse {
+    /// The swap operations of the best route
+    pub operations: Vec<SwapOperation>,
+    /// The amount of tokens received in a swap simulation through the best route
+    pub amount: Uint128,
+}
+
 /// This structure describes a migration message.
 /// We currently take no arguments for migrations.
 #[cw_serde]
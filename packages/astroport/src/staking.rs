@@ -1,6 +1,6 @@
 use crate::xastro_token::InstantiateMarketingInfo;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 use cw20::Cw20ReceiveMsg;
 
 /// This structure describes the parameters used for creating a contract.
@@ -12,8 +12,6 @@ pub struct InstantiateMsg {
     pub token_code_id: u64,
     /// The ASTRO token contract address
     pub deposit_token_addr: String,
-    /// The ADO token contract address
-    pub astro_token_addr: String, // <-- Tambahkan ini
     /// the marketing info of type [`InstantiateMarketingInfo`]
     pub marketing: Option<InstantiateMarketingInfo>,
 }
@@ -23,7 +21,61 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     /// Receive receives a message of type [`Cw20ReceiveMsg`] and processes it depending on the received template.
     Receive(Cw20ReceiveMsg),
-    UpdateAstroTokenAddr { new_addr: String, astro_token_addr: String }, // <-- Added this variant
+    /// Schedules a change of the deposit token address. The change can be applied only
+    /// after the time lock expires.
+    /// ## Executor
+    /// Only the current owner can execute this
+    UpdateAstroTokenAddr {
+        /// The new deposit token contract address
+        new_addr: String,
+    },
+    /// Applies the scheduled change of the deposit token address.
+    /// The old deposit token balance is sent to the owner in exchange for the provided new tokens
+    /// ## Executor
+    /// Only the current owner can execute this
+    ApplyAstroTokenAddrUpdate {},
+    /// Cancels the scheduled change of the deposit token address
+    /// ## Executor
+    /// Only the current owner can execute this
+    CancelAstroTokenAddrUpdate {},
+    /// Creates a request to change contract ownership
+    /// ## Executor
+    /// Only the current owner can execute this
+    ProposeNewOwner {
+        /// The newly proposed owner
+        owner: String,
+        /// The validity period of the offer to change the owner
+        expires_in: u64,
+    },
+    /// Removes a request to change contract ownership
+    /// ## Executor
+    /// Only the current owner can execute this
+    DropOwnershipProposal {},
+    /// Claims contract ownership
+    /// ## Executor
+    /// Only the newly proposed owner can execute this
+    ClaimOwnership {},
+    /// Replaces the list of available lock tiers. Existing lock positions are not affected.
+    /// ## Executor
+    /// Only the current owner can execute this
+    UpdateLockTiers {
+        /// The new list of lock tiers
+        tiers: Vec<LockTier>,
+    },
+    /// Releases the shares of an expired lock position to its owner
+    Unlock {
+        /// The lock position identifier
+        position_id: u64,
+    },
+    /// Updates the contract configuration
+    /// ## Executor
+    /// Only the current owner can execute this
+    UpdateConfig {
+        /// The unbonding period in seconds. Zero means instant withdrawals
+        unbonding_period: Option<u64>,
+    },
+    /// Withdraws ASTRO from all matured unbondings of the sender
+    Claim {},
 }
 
 /// This structure describes the query messages available in the contract.
@@ -37,6 +89,49 @@ pub enum QueryMsg {
     TotalShares {},
     #[returns(Uint128)]
     TotalDeposit {},
+    /// Returns the scheduled change of the deposit token address using an [`AstroTokenAddrUpdate`] object
+    #[returns(Option<AstroTokenAddrUpdate>)]
+    PendingAstroTokenAddrUpdate {},
+    /// Returns the list of available lock tiers
+    #[returns(Vec<LockTier>)]
+    LockTiers {},
+    /// Returns the lock positions of a specific user
+    #[returns(Vec<LockPosition>)]
+    LockPositions {
+        /// The user address
+        user: String,
+        /// The position identifier to start reading from
+        start_after: Option<u64>,
+        /// The amount of positions to read
+        limit: Option<u32>,
+    },
+    /// Returns the pending unbondings of a specific user
+    #[returns(Vec<Unbonding>)]
+    PendingUnbondings {
+        /// The user address
+        user: String,
+    },
+    /// Returns the total amount of ASTRO which is being unbonded
+    #[returns(Uint128)]
+    TotalUnbonding {},
+    /// Returns the total boosted weight of all lock positions
+    #[returns(Uint128)]
+    TotalLockWeight {},
+    /// Returns the voting power of a specific user at the given block.
+    /// It is the user ADO balance plus the boosted weight of the user lock positions
+    #[returns(Uint128)]
+    VotingPowerAt {
+        /// The user address
+        user: String,
+        /// The block height to read the voting power at
+        block: u64,
+    },
+    /// Returns the total voting power at the given block
+    #[returns(Uint128)]
+    TotalVotingPowerAt {
+        /// The block height to read the voting power at
+        block: u64,
+    },
 }
 
 #[cw_serde]
@@ -45,17 +140,73 @@ pub struct ConfigResponse {
     pub deposit_token_addr: Addr,
     /// The ADO token address
     pub share_token_addr: Addr,
+    /// The contract owner
+    pub owner: Addr,
+    /// The unbonding period in seconds
+    pub unbonding_period: u64,
+}
+
+/// This structure describes a scheduled change of the deposit token address.
+#[cw_serde]
+pub struct AstroTokenAddrUpdate {
+    /// The new deposit token contract address
+    pub new_addr: Addr,
+    /// The timestamp after which the change can be applied
+    pub effective_after: u64,
+}
+
+/// This structure describes a lock tier.
+#[cw_serde]
+pub struct LockTier {
+    /// The lock duration in seconds
+    pub duration: u64,
+    /// The multiplier applied to the shares of locked deposits to calculate their lock weight
+    pub multiplier: Decimal,
+}
+
+/// This structure describes a lock position.
+#[cw_serde]
+pub struct LockPosition {
+    /// The lock position identifier
+    pub id: u64,
+    /// The amount of ASTRO deposited
+    pub deposit_amount: Uint128,
+    /// The amount of shares held by the staking contract until unlock
+    pub shares: Uint128,
+    /// The boosted lock weight of the position. It counts as voting power but can't be redeemed for ASTRO
+    pub weight: Uint128,
+    /// The timestamp after which the shares can be unlocked
+    pub unlock_time: u64,
+}
+
+/// This structure describes a pending unbonding.
+#[cw_serde]
+pub struct Unbonding {
+    /// The amount of ASTRO which is being unbonded
+    pub amount: Uint128,
+    /// The timestamp after which the ASTRO can be claimed
+    pub unlock_time: u64,
 }
 
 /// This structure describes a migration message.
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    /// The contract owner. Must be set when migrating from versions which didn't store an owner
+    pub owner: Option<String>,
+}
 
 /// This structure describes a CW20 hook message.
 #[cw_serde]
 pub enum Cw20HookMsg {
     /// Deposits ASTRO in exchange for ADO
     Enter {},
-    /// Burns ADO in exchange for ASTRO
+    /// Deposits ASTRO in exchange for ADO which is held by the staking contract
+    /// until the lock expires. The position receives a boosted lock weight
+    EnterLocked {
+        /// The lock duration in seconds. Must match one of the available lock tiers
+        duration: u64,
+    },
+    /// Burns ADO in exchange for ASTRO. If the unbonding period is set, the ASTRO can be claimed
+    /// only after the period expires
     Leave {},
 }
@@ -38,6 +38,51 @@ pub enum ExecuteMsg {
         /// The amount of tokens to withdraw
         withdraw_amount: Uint128,
     },
+    /// Revokes unvested tokens from all revocable schedules of the specified account.
+    /// Tokens which are already vested remain claimable by the account.
+    /// ## Executor
+    /// Only the current owner can execute this
+    RevokeVestingSchedules {
+        /// The account whose schedules are revoked
+        account: String,
+        /// The address that receives the unvested tokens
+        recipient: Option<String>,
+    },
+    /// Creates a request to transfer the sender's vesting account to a new beneficiary
+    /// ## Executor
+    /// Only the current beneficiary can execute this
+    ProposeNewBeneficiary {
+        /// The newly proposed beneficiary
+        new_beneficiary: String,
+        /// The validity period of the offer to change the beneficiary
+        expires_in: u64,
+    },
+    /// Removes the sender's request to transfer its vesting account
+    /// ## Executor
+    /// Only the current beneficiary can execute this
+    DropBeneficiaryProposal {},
+    /// Approves a request to transfer a vesting account. Required only if
+    /// beneficiary changes must be approved by the owner
+    /// ## Executor
+    /// Only the current owner can execute this
+    ApproveBeneficiaryProposal {
+        /// The current beneficiary of the vesting account
+        account: String,
+    },
+    /// Claims the vesting account including its claim history
+    /// ## Executor
+    /// Only the newly proposed beneficiary can execute this
+    ClaimBeneficiary {
+        /// The current beneficiary of the vesting account
+        account: String,
+    },
+    /// Updates contract parameters
+    /// ## Executor
+    /// Only the current owner can execute this
+    UpdateConfig {
+        /// Whether beneficiary changes must be approved by the owner
+        require_beneficiary_approval: Option<bool>,
+    },
     /// Creates a request to change contract ownership
     /// ## Executor
     /// Only the current owner can execute this
@@ -82,6 +127,20 @@ pub struct VestingSchedule {
     pub start_point: VestingSchedulePoint,
     /// The end point for the vesting schedule
     pub end_point: Option<VestingSchedulePoint>,
+    /// The timestamp before which no tokens are unlocked
+    pub cliff: Option<u64>,
+    /// If set, tokens between the start and end points are unlocked in steps of this many seconds
+    /// instead of linearly
+    pub step_period: Option<u64>,
+    /// Whether the owner can revoke unvested tokens
+    #[serde(default)]
+    pub revocable: bool,
+    /// The timestamp at which the schedule was revoked. Must not be set when registering a schedule
+    pub revoked_at: Option<u64>,
+    /// The time and unlocked amount at the latest withdrawal from the schedule. Tokens left after
+    /// the withdrawal unlock from this point while steps stay anchored to the start point.
+    /// Must not be set when registering a schedule
+    pub withdrawal_point: Option<VestingSchedulePoint>,
 }
 
 /// This structure stores the parameters used to create a vesting schedule.
@@ -117,6 +176,9 @@ pub enum QueryMsg {
     /// Timestamp returns the current timestamp
     #[returns(u64)]
     Timestamp {},
+    /// Returns the pending request to transfer a vesting account using a [`BeneficiaryProposal`] object.
+    #[returns(Option<BeneficiaryProposal>)]
+    BeneficiaryProposal { account: String },
 }
 
 /// This structure describes a custom struct used to return the contract configuration.
@@ -126,6 +188,19 @@ pub struct ConfigResponse {
     pub owner: Addr,
     /// [`AssetInfo`] of the token being vested
     pub vesting_token: AssetInfo,
+    /// Whether beneficiary changes must be approved by the owner
+    pub require_beneficiary_approval: bool,
+}
+
+/// This structure describes a request to transfer a vesting account to a new beneficiary.
+#[cw_serde]
+pub struct BeneficiaryProposal {
+    /// The newly proposed beneficiary
+    pub new_beneficiary: Addr,
+    /// Time until the proposal expires
+    pub ttl: u64,
+    /// Whether the proposal was approved by the owner
+    pub approved: bool,
 }
 
 /// This structure describes a custom struct used to return vesting data about a specific vesting target.
@@ -135,6 +210,12 @@ pub struct VestingAccountResponse {
     pub address: Addr,
     /// Vesting information
     pub info: VestingInfo,
+    /// The amount of tokens vested so far including already claimed tokens
+    pub vested_amount: Uint128,
+    /// The amount of tokens which are yet to be vested
+    pub unvested_amount: Uint128,
+    /// The amount of tokens revoked by the owner
+    pub revoked_amount: Uint128,
 }
 
 /// This structure describes a custom struct used to return vesting data for multiple vesting targets.
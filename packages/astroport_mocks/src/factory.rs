@@ -80,6 +80,7 @@ where
                 pair_type: PairType::Xyk {},
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
                 total_fee_bps: 30,
                 maker_fee_bps: 3333,
             },
@@ -88,6 +89,7 @@ where
                 pair_type: PairType::Stable {},
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
                 total_fee_bps: 5,
                 maker_fee_bps: 5000,
             },
@@ -96,6 +98,7 @@ where
                 pair_type: PairType::Custom("concentrated".to_owned()),
                 is_disabled: false,
                 is_generator_disabled: false,
+                pair_creation_fee: None,
                 total_fee_bps: 30,
                 maker_fee_bps: 3333,
             },
@@ -225,6 +228,7 @@ where
         let default_params = StablePoolParams {
             amp: 100,
             owner: Some(astroport.to_string()),
+            rate_providers: None,
         };
 
         self.app
@@ -140,6 +140,11 @@ where
                                     amount: Uint128::new(1_000_000_000_000),
                                 },
                                 end_point: None,
+                                cliff: None,
+                                step_period: None,
+                                revocable: false,
+                                revoked_at: None,
+                                withdrawal_point: None,
                             }],
                         }],
                     })
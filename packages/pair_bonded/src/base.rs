@@ -203,7 +203,9 @@ pub trait PairBonded<'a> {
                     to_addr,
                 )
             }
-            Cw20HookMsg::WithdrawLiquidity { .. } => Err(ContractError::NotSupported {}),
+            Cw20HookMsg::WithdrawLiquidity { .. } | Cw20HookMsg::WithdrawSingleAsset { .. } => {
+                Err(ContractError::NotSupported {})
+            }
         }
     }
 